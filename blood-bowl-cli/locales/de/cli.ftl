@@ -0,0 +1,51 @@
+cli-status = { $first_team } { $first_score } - { $second_score } { $second_team } ({ $first_casualties } - { $second_casualties } Verl.) : { $status }
+cli-fans = Fans : { $fans }
+cli-journeymen = Aushilfsspieler : { $first } - { $second }
+cli-winnings = Einnahmen : { $first } - { $second }
+cli-dedicated-fans = Treue Fans : { $first } - { $second }
+cli-cancelled = Rückgängig gemacht : { $event }
+cli-nothing-to-cancel = Nichts rückgängig zu machen
+cli-saved = Gespeichert in { $path }
+cli-error = Fehler : { $error }
+cli-same-team = beide Teams haben dieselbe ID
+cli-only-when-playing = save und quit sind nur während des Spiels verfügbar
+cli-invalid-date = Datum im Format 2025-06-01 14:00 erwartet
+cli-missing-number = Zahl fehlt
+cli-invalid-number = ungültige Zahl
+cli-expected-team = Team 1 oder 2 erwartet
+cli-missing-player = Spieler fehlt
+cli-invalid-player-id = ungültige Spieler-ID
+cli-invalid-player-number = ungültige Spielernummer
+cli-missing-injury = Verletzung fehlt
+cli-unknown-weather = unbekanntes Wetter
+cli-unknown-inducement = unbekannte Anreizkarte
+cli-unknown-prayer = unbekanntes Gebet an Nuffle
+cli-unknown-success = unbekannter Erfolg
+cli-unknown-injury = unbekannte Verletzung
+cli-unknown-command = unbekannter Befehl: { $command }
+cli-help-start = start
+cli-help-fans = fans [roll | <Fanfaktor 1> <Fanfaktor 2>]
+cli-help-weather = weather [roll | <Wetter>]
+cli-help-journeymen = journeymen
+cli-help-inducements = inducements <Team>
+cli-help-induce = induce <Team> <Anreiz>
+cli-help-pray = pray <Team> [roll | <Gebet>]
+cli-help-toss = toss [roll | <Team>]
+cli-help-kick = kick <Team>
+cli-help-td = td <Team> <Spieler>
+cli-help-success = success <Team> <Spieler> <Erfolg>
+cli-help-cas = cas <Team> <Spieler> <gegnerischer Spieler> <Verletzung>
+cli-help-injury = injury <Team> <Spieler> <Verletzung> [<Ursache>]
+cli-help-sentoff = sentoff <Team> <Spieler>
+cli-help-halftime = halftime
+cli-help-extratime = extratime
+cli-help-penalties = penalties <Ergebnis 1> <Ergebnis 2>
+cli-help-end = end
+cli-help-winnings = winnings [roll | stalled <Teams> | <Einnahmen 1> <Einnahmen 2>]
+cli-help-dedicated = dedicated [roll | <Änderung 1> <Änderung 2>]
+cli-help-mistakes = mistakes <verlorenes Geld 1> <verlorenes Geld 2>
+cli-help-close = close
+cli-help-general = status | players <Team> | undo | save [<Pfad>] | help | quit  (Team: 1 oder 2, Spieler: Nummer oder @ID)
+cli-tui-commands = Befehle
+cli-tui-command = Befehl
+cli-tui-keys = Enter: ausführen | Strg+Z: rückgängig | Strg+S: speichern | Bild↑/Bild↓: blättern | Esc: beenden
@@ -0,0 +1,51 @@
+cli-status = { $first_team } { $first_score } - { $second_score } { $second_team } ({ $first_casualties } - { $second_casualties } CAS) : { $status }
+cli-fans = Fans : { $fans }
+cli-journeymen = Journeymen : { $first } - { $second }
+cli-winnings = Winnings : { $first } - { $second }
+cli-dedicated-fans = Dedicated fans : { $first } - { $second }
+cli-cancelled = Cancelled : { $event }
+cli-nothing-to-cancel = Nothing to cancel
+cli-saved = Saved to { $path }
+cli-error = Error : { $error }
+cli-same-team = both teams have the same id
+cli-only-when-playing = save and quit are only available when playing
+cli-invalid-date = expected a date like 2025-06-01 14:00
+cli-missing-number = missing number
+cli-invalid-number = invalid number
+cli-expected-team = expected team 1 or 2
+cli-missing-player = missing player
+cli-invalid-player-id = invalid player id
+cli-invalid-player-number = invalid player number
+cli-missing-injury = missing injury
+cli-unknown-weather = unknown weather
+cli-unknown-inducement = unknown inducement
+cli-unknown-prayer = unknown prayer to Nuffle
+cli-unknown-success = unknown success
+cli-unknown-injury = unknown injury
+cli-unknown-command = unknown command: { $command }
+cli-help-start = start
+cli-help-fans = fans [roll | <fan factor 1> <fan factor 2>]
+cli-help-weather = weather [roll | <weather>]
+cli-help-journeymen = journeymen
+cli-help-inducements = inducements <team>
+cli-help-induce = induce <team> <inducement>
+cli-help-pray = pray <team> [roll | <prayer>]
+cli-help-toss = toss [roll | <team>]
+cli-help-kick = kick <team>
+cli-help-td = td <team> <player>
+cli-help-success = success <team> <player> <success>
+cli-help-cas = cas <team> <player> <opponent player> <injury>
+cli-help-injury = injury <team> <player> <injury> [<cause>]
+cli-help-sentoff = sentoff <team> <player>
+cli-help-halftime = halftime
+cli-help-extratime = extratime
+cli-help-penalties = penalties <score 1> <score 2>
+cli-help-end = end
+cli-help-winnings = winnings [roll | stalled <teams> | <winnings 1> <winnings 2>]
+cli-help-dedicated = dedicated [roll | <delta 1> <delta 2>]
+cli-help-mistakes = mistakes <lost money 1> <lost money 2>
+cli-help-close = close
+cli-help-general = status | players <team> | undo | save [<path>] | help | quit  (team: 1 or 2, player: number or @id)
+cli-tui-commands = Commands
+cli-tui-command = Command
+cli-tui-keys = Enter: run | Ctrl+Z: undo | Ctrl+S: save | PgUp/PgDn: scroll | Esc: quit
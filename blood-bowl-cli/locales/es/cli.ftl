@@ -0,0 +1,51 @@
+cli-status = { $first_team } { $first_score } - { $second_score } { $second_team } ({ $first_casualties } - { $second_casualties } LES) : { $status }
+cli-fans = Aficionados : { $fans }
+cli-journeymen = Jugadores temporales : { $first } - { $second }
+cli-winnings = Ganancias : { $first } - { $second }
+cli-dedicated-fans = Hinchas fieles : { $first } - { $second }
+cli-cancelled = Cancelado : { $event }
+cli-nothing-to-cancel = Nada que cancelar
+cli-saved = Guardado en { $path }
+cli-error = Error : { $error }
+cli-same-team = los dos equipos tienen el mismo identificador
+cli-only-when-playing = save y quit solo están disponibles durante la partida
+cli-invalid-date = se esperaba una fecha como 2025-06-01 14:00
+cli-missing-number = falta un número
+cli-invalid-number = número no válido
+cli-expected-team = se esperaba el equipo 1 o 2
+cli-missing-player = falta el jugador
+cli-invalid-player-id = identificador de jugador no válido
+cli-invalid-player-number = número de jugador no válido
+cli-missing-injury = falta la lesión
+cli-unknown-weather = clima desconocido
+cli-unknown-inducement = incentivo desconocido
+cli-unknown-prayer = plegaria a Nuffle desconocida
+cli-unknown-success = éxito desconocido
+cli-unknown-injury = lesión desconocida
+cli-unknown-command = comando desconocido: { $command }
+cli-help-start = start
+cli-help-fans = fans [roll | <factor de hinchas 1> <factor de hinchas 2>]
+cli-help-weather = weather [roll | <clima>]
+cli-help-journeymen = journeymen
+cli-help-inducements = inducements <equipo>
+cli-help-induce = induce <equipo> <incentivo>
+cli-help-pray = pray <equipo> [roll | <plegaria>]
+cli-help-toss = toss [roll | <equipo>]
+cli-help-kick = kick <equipo>
+cli-help-td = td <equipo> <jugador>
+cli-help-success = success <equipo> <jugador> <éxito>
+cli-help-cas = cas <equipo> <jugador> <jugador rival> <lesión>
+cli-help-injury = injury <equipo> <jugador> <lesión> [<causa>]
+cli-help-sentoff = sentoff <equipo> <jugador>
+cli-help-halftime = halftime
+cli-help-extratime = extratime
+cli-help-penalties = penalties <marcador 1> <marcador 2>
+cli-help-end = end
+cli-help-winnings = winnings [roll | stalled <equipos> | <ganancias 1> <ganancias 2>]
+cli-help-dedicated = dedicated [roll | <variación 1> <variación 2>]
+cli-help-mistakes = mistakes <dinero perdido 1> <dinero perdido 2>
+cli-help-close = close
+cli-help-general = status | players <equipo> | undo | save [<ruta>] | help | quit  (equipo: 1 o 2, jugador: número o @identificador)
+cli-tui-commands = Comandos
+cli-tui-command = Comando
+cli-tui-keys = Intro: ejecutar | Ctrl+Z: deshacer | Ctrl+S: guardar | RePág/AvPág: desplazar | Esc: salir
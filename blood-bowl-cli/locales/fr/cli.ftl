@@ -0,0 +1,51 @@
+cli-status = { $first_team } { $first_score } - { $second_score } { $second_team } ({ $first_casualties } - { $second_casualties } SO) : { $status }
+cli-fans = Supporters : { $fans }
+cli-journeymen = Joueurs temporaires : { $first } - { $second }
+cli-winnings = Gains : { $first } - { $second }
+cli-dedicated-fans = Fans dévoués : { $first } - { $second }
+cli-cancelled = Annulé : { $event }
+cli-nothing-to-cancel = Rien à annuler
+cli-saved = Enregistré dans { $path }
+cli-error = Erreur : { $error }
+cli-same-team = les deux équipes ont le même identifiant
+cli-only-when-playing = save et quit ne sont disponibles qu'en cours de partie
+cli-invalid-date = date attendue au format 2025-06-01 14:00
+cli-missing-number = nombre manquant
+cli-invalid-number = nombre invalide
+cli-expected-team = équipe 1 ou 2 attendue
+cli-missing-player = joueur manquant
+cli-invalid-player-id = identifiant de joueur invalide
+cli-invalid-player-number = numéro de joueur invalide
+cli-missing-injury = blessure manquante
+cli-unknown-weather = météo inconnue
+cli-unknown-inducement = coup de pouce inconnu
+cli-unknown-prayer = prière à Nuffle inconnue
+cli-unknown-success = succès inconnu
+cli-unknown-injury = blessure inconnue
+cli-unknown-command = commande inconnue : { $command }
+cli-help-start = start
+cli-help-fans = fans [roll | <facteur de popularité 1> <facteur de popularité 2>]
+cli-help-weather = weather [roll | <météo>]
+cli-help-journeymen = journeymen
+cli-help-inducements = inducements <équipe>
+cli-help-induce = induce <équipe> <coup de pouce>
+cli-help-pray = pray <équipe> [roll | <prière>]
+cli-help-toss = toss [roll | <équipe>]
+cli-help-kick = kick <équipe>
+cli-help-td = td <équipe> <joueur>
+cli-help-success = success <équipe> <joueur> <succès>
+cli-help-cas = cas <équipe> <joueur> <joueur adverse> <blessure>
+cli-help-injury = injury <équipe> <joueur> <blessure> [<cause>]
+cli-help-sentoff = sentoff <équipe> <joueur>
+cli-help-halftime = halftime
+cli-help-extratime = extratime
+cli-help-penalties = penalties <score 1> <score 2>
+cli-help-end = end
+cli-help-winnings = winnings [roll | stalled <équipes> | <gains 1> <gains 2>]
+cli-help-dedicated = dedicated [roll | <variation 1> <variation 2>]
+cli-help-mistakes = mistakes <argent perdu 1> <argent perdu 2>
+cli-help-close = close
+cli-help-general = status | players <équipe> | undo | save [<chemin>] | help | quit  (équipe : 1 ou 2, joueur : numéro ou @identifiant)
+cli-tui-commands = Commandes
+cli-tui-command = Commande
+cli-tui-keys = Entrée : exécuter | Ctrl+Z : annuler | Ctrl+S : enregistrer | PgUp/PgDn : défiler | Échap : quitter
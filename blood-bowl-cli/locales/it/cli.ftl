@@ -0,0 +1,51 @@
+cli-status = { $first_team } { $first_score } - { $second_score } { $second_team } ({ $first_casualties } - { $second_casualties } INF) : { $status }
+cli-fans = Tifosi : { $fans }
+cli-journeymen = Giocatori temporanei : { $first } - { $second }
+cli-winnings = Guadagni : { $first } - { $second }
+cli-dedicated-fans = Tifosi fedeli : { $first } - { $second }
+cli-cancelled = Annullato : { $event }
+cli-nothing-to-cancel = Niente da annullare
+cli-saved = Salvato in { $path }
+cli-error = Errore : { $error }
+cli-same-team = le due squadre hanno lo stesso identificativo
+cli-only-when-playing = save e quit sono disponibili solo durante la partita
+cli-invalid-date = attesa una data come 2025-06-01 14:00
+cli-missing-number = numero mancante
+cli-invalid-number = numero non valido
+cli-expected-team = attesa la squadra 1 o 2
+cli-missing-player = giocatore mancante
+cli-invalid-player-id = identificativo del giocatore non valido
+cli-invalid-player-number = numero del giocatore non valido
+cli-missing-injury = infortunio mancante
+cli-unknown-weather = meteo sconosciuto
+cli-unknown-inducement = incentivo sconosciuto
+cli-unknown-prayer = preghiera a Nuffle sconosciuta
+cli-unknown-success = successo sconosciuto
+cli-unknown-injury = infortunio sconosciuto
+cli-unknown-command = comando sconosciuto: { $command }
+cli-help-start = start
+cli-help-fans = fans [roll | <fattore tifosi 1> <fattore tifosi 2>]
+cli-help-weather = weather [roll | <meteo>]
+cli-help-journeymen = journeymen
+cli-help-inducements = inducements <squadra>
+cli-help-induce = induce <squadra> <incentivo>
+cli-help-pray = pray <squadra> [roll | <preghiera>]
+cli-help-toss = toss [roll | <squadra>]
+cli-help-kick = kick <squadra>
+cli-help-td = td <squadra> <giocatore>
+cli-help-success = success <squadra> <giocatore> <successo>
+cli-help-cas = cas <squadra> <giocatore> <giocatore avversario> <infortunio>
+cli-help-injury = injury <squadra> <giocatore> <infortunio> [<causa>]
+cli-help-sentoff = sentoff <squadra> <giocatore>
+cli-help-halftime = halftime
+cli-help-extratime = extratime
+cli-help-penalties = penalties <punteggio 1> <punteggio 2>
+cli-help-end = end
+cli-help-winnings = winnings [roll | stalled <squadre> | <guadagni 1> <guadagni 2>]
+cli-help-dedicated = dedicated [roll | <variazione 1> <variazione 2>]
+cli-help-mistakes = mistakes <denaro perso 1> <denaro perso 2>
+cli-help-close = close
+cli-help-general = status | players <squadra> | undo | save [<percorso>] | help | quit  (squadra: 1 o 2, giocatore: numero o @identificativo)
+cli-tui-commands = Comandi
+cli-tui-command = Comando
+cli-tui-keys = Invio: esegui | Ctrl+Z: annulla | Ctrl+S: salva | PagSu/PagGiù: scorri | Esc: esci
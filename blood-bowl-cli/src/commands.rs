@@ -0,0 +1,668 @@
+use crate::messages::{message, message_with_args};
+use blood_bowl_rs::actions::Success;
+use blood_bowl_rs::errors::Error;
+use blood_bowl_rs::games::{Game, GameStatus};
+use blood_bowl_rs::inducements::Inducement;
+use blood_bowl_rs::injuries::{Injury, InjuryCause};
+use blood_bowl_rs::prayers::PrayerToNuffle;
+use blood_bowl_rs::teams::Team;
+use blood_bowl_rs::translation::TranslatedName;
+use blood_bowl_rs::translation::format_gold_pieces;
+use blood_bowl_rs::weather::Weather;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TeamSide {
+    First,
+    Second,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayerReference {
+    Number(i32),
+    Id(i32),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    Help,
+    Status,
+    Players(TeamSide),
+    Start,
+    RollFans,
+    Fans(u8, u8),
+    RollWeather,
+    Weather(Weather),
+    Journeymen,
+    Inducements(TeamSide),
+    Induce(TeamSide, Inducement),
+    RollPrayer(TeamSide),
+    Pray(TeamSide, PrayerToNuffle),
+    RollToss,
+    Toss(TeamSide),
+    Kick(TeamSide),
+    Touchdown(TeamSide, PlayerReference),
+    Success(TeamSide, PlayerReference, Success),
+    Casualty(TeamSide, PlayerReference, PlayerReference, Injury),
+    Injury(TeamSide, PlayerReference, Injury, Option<InjuryCause>),
+    SentOff(TeamSide, PlayerReference),
+    HalfTime,
+    ExtraTime,
+    Penalties(usize, usize),
+    EndGame,
+    RollWinnings(bool, bool),
+    Winnings(u32, u32),
+    RollDedicatedFans,
+    DedicatedFans(i8, i8),
+    ExpensiveMistakes(i32, i32),
+    Close,
+    Undo,
+    Save(Option<String>),
+    Quit,
+}
+
+fn find_by_name<T: TranslatedName + Clone>(
+    candidates: &[T],
+    text: &str,
+    lang_id: &str,
+) -> Option<T> {
+    let text = text.replace([' ', '-', '_'], "").to_lowercase();
+
+    candidates
+        .iter()
+        .find(|candidate| {
+            [
+                candidate.type_name(),
+                candidate.name(lang_id),
+                candidate.name("en"),
+            ]
+            .iter()
+            .any(|name| name.replace([' ', '-', '_'], "").to_lowercase().eq(&text))
+        })
+        .cloned()
+}
+
+fn parse_number<T: std::str::FromStr>(word: Option<&&str>, lang_id: &str) -> Result<T, String> {
+    word.ok_or_else(|| message(lang_id, "cli-missing-number"))?
+        .parse()
+        .map_err(|_| message(lang_id, "cli-invalid-number"))
+}
+
+fn parse_side(word: Option<&&str>, lang_id: &str) -> Result<TeamSide, String> {
+    match word.copied() {
+        Some("1") => Ok(TeamSide::First),
+        Some("2") => Ok(TeamSide::Second),
+        _ => Err(message(lang_id, "cli-expected-team")),
+    }
+}
+
+fn parse_player(word: Option<&&str>, lang_id: &str) -> Result<PlayerReference, String> {
+    let word = word.ok_or_else(|| message(lang_id, "cli-missing-player"))?;
+
+    match word.strip_prefix('@') {
+        Some(id) => id
+            .parse()
+            .map(PlayerReference::Id)
+            .map_err(|_| message(lang_id, "cli-invalid-player-id")),
+        None => word
+            .parse()
+            .map(PlayerReference::Number)
+            .map_err(|_| message(lang_id, "cli-invalid-player-number")),
+    }
+}
+
+fn is_roll(word: Option<&&str>) -> bool {
+    word.is_none_or(|word| word.eq(&"roll"))
+}
+
+pub fn parse(line: &str, game: &Game, lang_id: &str) -> Result<Command, String> {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let rest = |from: usize| {
+        words
+            .iter()
+            .skip(from)
+            .copied()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    };
+
+    match words.first().copied() {
+        None | Some("help") => Ok(Command::Help),
+        Some("status") => Ok(Command::Status),
+        Some("players") => Ok(Command::Players(parse_side(words.get(1), lang_id)?)),
+        Some("start") => Ok(Command::Start),
+        Some("fans") if is_roll(words.get(1)) => Ok(Command::RollFans),
+        Some("fans") => Ok(Command::Fans(
+            parse_number(words.get(1), lang_id)?,
+            parse_number(words.get(2), lang_id)?,
+        )),
+        Some("weather") if is_roll(words.get(1)) => Ok(Command::RollWeather),
+        Some("weather") => find_by_name(&Weather::options_list(), &rest(1), lang_id)
+            .map(Command::Weather)
+            .ok_or_else(|| message(lang_id, "cli-unknown-weather")),
+        Some("journeymen") => Ok(Command::Journeymen),
+        Some("inducements") => Ok(Command::Inducements(parse_side(words.get(1), lang_id)?)),
+        Some("induce") => {
+            let side = parse_side(words.get(1), lang_id)?;
+            let team = team(game, &side);
+            let inducement = find_by_name(
+                &Inducement::list_available_for_roster(&team.roster, &team.version),
+                &rest(2),
+                lang_id,
+            )
+            .ok_or_else(|| message(lang_id, "cli-unknown-inducement"))?;
+
+            Ok(Command::Induce(side, inducement))
+        }
+        Some("pray") if is_roll(words.get(2)) => {
+            Ok(Command::RollPrayer(parse_side(words.get(1), lang_id)?))
+        }
+        Some("pray") => Ok(Command::Pray(
+            parse_side(words.get(1), lang_id)?,
+            find_by_name(&PrayerToNuffle::list(&game.version), &rest(2), lang_id)
+                .ok_or_else(|| message(lang_id, "cli-unknown-prayer"))?,
+        )),
+        Some("toss") if is_roll(words.get(1)) => Ok(Command::RollToss),
+        Some("toss") => Ok(Command::Toss(parse_side(words.get(1), lang_id)?)),
+        Some("kick") => Ok(Command::Kick(parse_side(words.get(1), lang_id)?)),
+        Some("td") => Ok(Command::Touchdown(
+            parse_side(words.get(1), lang_id)?,
+            parse_player(words.get(2), lang_id)?,
+        )),
+        Some("success") => Ok(Command::Success(
+            parse_side(words.get(1), lang_id)?,
+            parse_player(words.get(2), lang_id)?,
+            find_by_name(&Success::list(&game.version), &rest(3), lang_id)
+                .ok_or_else(|| message(lang_id, "cli-unknown-success"))?,
+        )),
+        Some("cas") => Ok(Command::Casualty(
+            parse_side(words.get(1), lang_id)?,
+            parse_player(words.get(2), lang_id)?,
+            parse_player(words.get(3), lang_id)?,
+            find_by_name(&Injury::list(&game.version), &rest(4), lang_id)
+                .ok_or_else(|| message(lang_id, "cli-unknown-injury"))?,
+        )),
+        Some("injury") => {
+            let (injury, cause) = match words.len() {
+                0..=3 => return Err(message(lang_id, "cli-missing-injury")),
+                _ => match find_by_name(&InjuryCause::list(), words[words.len() - 1], lang_id) {
+                    Some(cause) if words.len() > 4 => {
+                        (words[3..words.len() - 1].join(" "), Some(cause))
+                    }
+                    _ => (rest(3), None),
+                },
+            };
+
+            Ok(Command::Injury(
+                parse_side(words.get(1), lang_id)?,
+                parse_player(words.get(2), lang_id)?,
+                find_by_name(&Injury::list(&game.version), &injury, lang_id)
+                    .ok_or_else(|| message(lang_id, "cli-unknown-injury"))?,
+                cause,
+            ))
+        }
+        Some("sentoff") => Ok(Command::SentOff(
+            parse_side(words.get(1), lang_id)?,
+            parse_player(words.get(2), lang_id)?,
+        )),
+        Some("halftime") => Ok(Command::HalfTime),
+        Some("extratime") => Ok(Command::ExtraTime),
+        Some("penalties") => Ok(Command::Penalties(
+            parse_number(words.get(1), lang_id)?,
+            parse_number(words.get(2), lang_id)?,
+        )),
+        Some("end") => Ok(Command::EndGame),
+        Some("winnings") if is_roll(words.get(1)) => Ok(Command::RollWinnings(false, false)),
+        Some("winnings") if words.get(1).eq(&Some(&"stalled")) => Ok(Command::RollWinnings(
+            words.iter().skip(2).any(|word| word.eq(&"1")),
+            words.iter().skip(2).any(|word| word.eq(&"2")),
+        )),
+        Some("winnings") => Ok(Command::Winnings(
+            parse_number(words.get(1), lang_id)?,
+            parse_number(words.get(2), lang_id)?,
+        )),
+        Some("dedicated") if is_roll(words.get(1)) => Ok(Command::RollDedicatedFans),
+        Some("dedicated") => Ok(Command::DedicatedFans(
+            parse_number(words.get(1), lang_id)?,
+            parse_number(words.get(2), lang_id)?,
+        )),
+        Some("mistakes") => Ok(Command::ExpensiveMistakes(
+            parse_number(words.get(1), lang_id)?,
+            parse_number(words.get(2), lang_id)?,
+        )),
+        Some("close") => Ok(Command::Close),
+        Some("undo") => Ok(Command::Undo),
+        Some("save") => Ok(Command::Save(words.get(1).map(|path| path.to_string()))),
+        Some("quit") | Some("exit") => Ok(Command::Quit),
+        Some(word) => Err(message_with_args(
+            lang_id,
+            "cli-unknown-command",
+            &[("command", word.to_string())],
+        )),
+    }
+}
+
+fn team<'a>(game: &'a Game, side: &TeamSide) -> &'a Team {
+    match side {
+        TeamSide::First => &game.first_team,
+        TeamSide::Second => &game.second_team,
+    }
+}
+
+fn team_mut<'a>(game: &'a mut Game, side: &TeamSide) -> &'a mut Team {
+    match side {
+        TeamSide::First => &mut game.first_team,
+        TeamSide::Second => &mut game.second_team,
+    }
+}
+
+fn player_id(game: &Game, side: &TeamSide, player: &PlayerReference) -> Result<i32, Error> {
+    let team = team(game, side);
+
+    team.players
+        .iter()
+        .find(|(number, team_player)| match player {
+            PlayerReference::Number(player_number) => number.eq(player_number),
+            PlayerReference::Id(id) => team_player.id.eq(id),
+        })
+        .map(|(_, team_player)| team_player.id)
+        .ok_or(Error::PlayerNotInTeam)
+}
+
+fn number_new_players(team: &mut Team) {
+    let mut next_number = team
+        .players
+        .iter()
+        .map(|(number, _)| *number)
+        .max()
+        .unwrap_or(0);
+
+    for (number, _) in team.players.iter_mut() {
+        if (*number).eq(&0) {
+            next_number += 1;
+            *number = next_number;
+        }
+    }
+}
+
+fn other_side(side: &TeamSide) -> TeamSide {
+    match side {
+        TeamSide::First => TeamSide::Second,
+        TeamSide::Second => TeamSide::First,
+    }
+}
+
+pub fn status(game: &Game, lang_id: &str) -> String {
+    let (first_team_score, second_team_score) = game.score();
+    let (first_team_casualties, second_team_casualties) = game.casualties();
+
+    message_with_args(
+        lang_id,
+        "cli-status",
+        &[
+            ("first_team", game.first_team.name.clone()),
+            ("first_score", first_team_score.to_string()),
+            ("second_score", second_team_score.to_string()),
+            ("second_team", game.second_team.name.clone()),
+            ("first_casualties", first_team_casualties.to_string()),
+            ("second_casualties", second_team_casualties.to_string()),
+            ("status", game.status().name(lang_id)),
+        ],
+    )
+}
+
+fn pair(lang_id: &str, key: &str, first: String, second: String) -> String {
+    message_with_args(lang_id, key, &[("first", first), ("second", second)])
+}
+
+pub fn players(game: &Game, side: &TeamSide, lang_id: &str) -> String {
+    let team = team(game, side);
+
+    team.sort_players_by_number()
+        .iter()
+        .map(|(number, player)| {
+            format!(
+                "{:>3} @{:<5} {} ({})",
+                number,
+                player.id,
+                player.name(lang_id),
+                player.position.name(lang_id)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn commands(game: &Game, lang_id: &str) -> Vec<String> {
+    let keys = match game.status() {
+        GameStatus::Scheduled => vec!["cli-help-start"],
+        GameStatus::PreGameSequence => vec![
+            "cli-help-fans",
+            "cli-help-weather",
+            "cli-help-journeymen",
+            "cli-help-inducements",
+            "cli-help-induce",
+            "cli-help-pray",
+            "cli-help-toss",
+            "cli-help-kick",
+        ],
+        GameStatus::GameInProgress => vec![
+            "cli-help-td",
+            "cli-help-success",
+            "cli-help-cas",
+            "cli-help-injury",
+            "cli-help-sentoff",
+            "cli-help-halftime",
+            "cli-help-extratime",
+            "cli-help-penalties",
+            "cli-help-end",
+        ],
+        GameStatus::PostGameSequence => vec![
+            "cli-help-winnings",
+            "cli-help-dedicated",
+            "cli-help-mistakes",
+        ],
+        GameStatus::WaitingForValidation => vec!["cli-help-close"],
+        GameStatus::Closed => vec![],
+    };
+
+    keys.into_iter()
+        .chain(["cli-help-general"])
+        .map(|key| message(lang_id, key))
+        .collect()
+}
+
+pub fn help(game: &Game, lang_id: &str) -> String {
+    commands(game, lang_id)
+        .iter()
+        .map(|command| format!("  {}", command))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+pub fn execute(game: &mut Game, command: &Command, lang_id: &str) -> Result<String, Error> {
+    match command {
+        Command::Help => Ok(help(game, lang_id)),
+        Command::Status => Ok(status(game, lang_id)),
+        Command::Players(side) => Ok(players(game, side, lang_id)),
+        Command::Start => {
+            game.start();
+            Ok(status(game, lang_id))
+        }
+        Command::RollFans => game
+            .generate_fans()
+            .map(|fans| message_with_args(lang_id, "cli-fans", &[("fans", fans.to_string())])),
+        Command::Fans(first_team_fan_factor, second_team_fan_factor) => {
+            game.set_team_fan_factor(game.first_team.clone(), *first_team_fan_factor)?;
+            game.set_team_fan_factor(game.second_team.clone(), *second_team_fan_factor)?;
+            Ok(message_with_args(
+                lang_id,
+                "cli-fans",
+                &[("fans", game.fans().unwrap_or(0).to_string())],
+            ))
+        }
+        Command::RollWeather => game.generate_weather().map(|weather| weather.name(lang_id)),
+        Command::Weather(weather) => game
+            .push_weather(weather.clone())
+            .map(|weather| weather.name(lang_id)),
+        Command::Journeymen => {
+            let (first_team_journeymen, second_team_journeymen) = game.generate_journeymen()?;
+            number_new_players(&mut game.first_team);
+            number_new_players(&mut game.second_team);
+            Ok(pair(
+                lang_id,
+                "cli-journeymen",
+                first_team_journeymen.to_string(),
+                second_team_journeymen.to_string(),
+            ))
+        }
+        Command::Inducements(side) => {
+            let (first_team_inducements, second_team_inducements) =
+                game.inducements_buyable_by_teams()?;
+            let inducements = match side {
+                TeamSide::First => first_team_inducements,
+                TeamSide::Second => second_team_inducements,
+            };
+            let team = team(game, side);
+
+            Ok(inducements
+                .iter()
+                .map(|inducement| {
+                    format!(
+                        "{} ({})",
+                        inducement.name(lang_id),
+                        format_gold_pieces(lang_id, inducement.price_for_team(team).into())
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"))
+        }
+        Command::Induce(side, inducement) => {
+            let inducement = game.team_buy_inducement(team(game, side).id, inducement.clone())?;
+            number_new_players(team_mut(game, side));
+            Ok(inducement.name(lang_id))
+        }
+        Command::RollPrayer(side) => {
+            let prayer = PrayerToNuffle::roll(&game.version);
+            game.push_prayer(team(game, side).id, prayer)
+                .map(|prayer| prayer.name(lang_id))
+        }
+        Command::Pray(side, prayer) => game
+            .push_prayer(team(game, side).id, prayer.clone())
+            .map(|prayer| prayer.name(lang_id)),
+        Command::RollToss => {
+            game.generate_toss_winner()?;
+            Ok(game
+                .toss_winner()
+                .map(|team| team.name.clone())
+                .unwrap_or_default())
+        }
+        Command::Toss(side) => {
+            game.push_toss_winner(team(game, side).id)?;
+            Ok(team(game, side).name.clone())
+        }
+        Command::Kick(side) => {
+            game.push_kicking_team(team(game, side).id)?;
+            Ok(status(game, lang_id))
+        }
+        Command::Touchdown(side, player) => {
+            let player_id = player_id(game, side, player)?;
+            game.push_success(team(game, side).id, player_id, Success::Touchdown)?;
+            Ok(status(game, lang_id))
+        }
+        Command::Success(side, player, success) => {
+            let player_id = player_id(game, side, player)?;
+            game.push_success(team(game, side).id, player_id, success.clone())?;
+            Ok(success.name(lang_id))
+        }
+        Command::Casualty(side, player, inflicting_player, injury) => {
+            let opponent_side = other_side(side);
+            let player_id = player_id(game, side, player)?;
+            let inflicting_player_id = self::player_id(game, &opponent_side, inflicting_player)?;
+            let team_id = team(game, side).id;
+            let opponent_team_id = team(game, &opponent_side).id;
+
+            game.push_success(opponent_team_id, inflicting_player_id, Success::Casualty)?;
+            if let Err(error) = game.push_injury_with_cause(
+                team_id,
+                player_id,
+                injury.clone(),
+                InjuryCause::Block,
+                Some(inflicting_player_id),
+            ) {
+                game.cancel_last_event()?;
+                return Err(error);
+            }
+            Ok(status(game, lang_id))
+        }
+        Command::Injury(side, player, injury, cause) => {
+            let player_id = player_id(game, side, player)?;
+            let team_id = team(game, side).id;
+
+            match cause {
+                Some(cause) => {
+                    game.push_injury_with_cause(team_id, player_id, injury.clone(), *cause, None)?
+                }
+                None => game.push_injury(team_id, player_id, injury.clone())?,
+            }
+            Ok(injury.name(lang_id))
+        }
+        Command::SentOff(side, player) => {
+            let player_id = player_id(game, side, player)?;
+            game.push_sent_off(team(game, side).id, player_id)?;
+            Ok(status(game, lang_id))
+        }
+        Command::HalfTime => {
+            game.end_first_half()?;
+            Ok(status(game, lang_id))
+        }
+        Command::ExtraTime => {
+            game.start_extra_time()?;
+            Ok(status(game, lang_id))
+        }
+        Command::Penalties(first_team_score, second_team_score) => {
+            game.push_penalties(*first_team_score, *second_team_score)?;
+            Ok(status(game, lang_id))
+        }
+        Command::EndGame => {
+            game.end_game()?;
+            Ok(status(game, lang_id))
+        }
+        Command::RollWinnings(first_team_stalled, second_team_stalled) => {
+            game.generate_winnings(*first_team_stalled, *second_team_stalled)?;
+            let (first_team_winnings, second_team_winnings) = game.winnings();
+            Ok(pair(
+                lang_id,
+                "cli-winnings",
+                first_team_winnings.unwrap_or(0).to_string(),
+                second_team_winnings.unwrap_or(0).to_string(),
+            ))
+        }
+        Command::Winnings(first_team_winnings, second_team_winnings) => {
+            game.push_winnings(game.first_team.id, *first_team_winnings)?;
+            game.push_winnings(game.second_team.id, *second_team_winnings)?;
+            Ok(pair(
+                lang_id,
+                "cli-winnings",
+                first_team_winnings.to_string(),
+                second_team_winnings.to_string(),
+            ))
+        }
+        Command::RollDedicatedFans => {
+            game.generate_dedicated_fans_updates()
+                .map(|(first_team_delta, second_team_delta)| {
+                    pair(
+                        lang_id,
+                        "cli-dedicated-fans",
+                        format!("{:+}", first_team_delta),
+                        format!("{:+}", second_team_delta),
+                    )
+                })
+        }
+        Command::DedicatedFans(first_team_delta, second_team_delta) => {
+            game.push_dedicated_fans_update(game.first_team.id, *first_team_delta)?;
+            game.push_dedicated_fans_update(game.second_team.id, *second_team_delta)?;
+            Ok(pair(
+                lang_id,
+                "cli-dedicated-fans",
+                format!("{:+}", first_team_delta),
+                format!("{:+}", second_team_delta),
+            ))
+        }
+        Command::ExpensiveMistakes(first_team_lost_money, second_team_lost_money) => {
+            game.push_expensive_mistakes(game.first_team.id, *first_team_lost_money)?;
+            game.push_expensive_mistakes(game.second_team.id, *second_team_lost_money)?;
+            Ok(status(game, lang_id))
+        }
+        Command::Close => {
+            game.close_game()?;
+            Ok(status(game, lang_id))
+        }
+        Command::Undo => {
+            let event = game.cancel_last_event()?;
+            Ok(match event {
+                Some(event) => message_with_args(
+                    lang_id,
+                    "cli-cancelled",
+                    &[("event", format!("{:?}", event))],
+                ),
+                None => message(lang_id, "cli-nothing-to-cancel"),
+            })
+        }
+        Command::Save(_) | Command::Quit => Ok(String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{date, team};
+    use blood_bowl_rs::versions::Version;
+
+    fn run(game: &mut Game, line: &str) -> Result<String, String> {
+        let command = parse(line, game, "en")?;
+        execute(game, &command, "en").map_err(|error| error.name("en"))
+    }
+
+    #[test]
+    fn game_lifecycle() -> Result<(), String> {
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            date(),
+            &team(1, "Reds"),
+            &team(2, "Blues"),
+            false,
+        )
+        .map_err(|error| error.name("en"))?;
+
+        assert!(matches!(game.status(), GameStatus::Scheduled));
+        assert!(parse("unknown", &game, "en").is_err());
+
+        for line in [
+            "start",
+            "fans 4 3",
+            "weather perfect conditions",
+            "journeymen",
+            "toss 1",
+            "kick 2",
+        ] {
+            run(&mut game, line)?;
+        }
+        assert_eq!(game.fans(), Some(70000));
+        assert!(matches!(game.status(), GameStatus::GameInProgress));
+
+        assert_eq!(
+            run(&mut game, "td 1 3")?,
+            "Reds 1 - 0 Blues (0 - 0 CAS) : Game in progress"
+        );
+        run(&mut game, "td 2 @205")?;
+        assert_eq!(game.score(), (1, 1));
+        assert!(run(&mut game, "td 2 42").is_err());
+        run(&mut game, "undo")?;
+        assert_eq!(game.score(), (1, 0));
+
+        run(&mut game, "cas 2 7 4 badly hurt")?;
+        assert_eq!(game.casualties(), (1, 0));
+        assert_eq!(game.inflicted_casualties(1, 104).len(), 1);
+        run(&mut game, "injury 1 2 KO Crowd")?;
+        run(&mut game, "sentoff 2 8")?;
+        assert!(game.player_has_been_sent_off(2, 208));
+
+        for line in [
+            "halftime",
+            "end",
+            "winnings",
+            "dedicated 1 0",
+            "mistakes 0 0",
+        ] {
+            run(&mut game, line)?;
+        }
+        assert!(matches!(game.status(), GameStatus::WaitingForValidation));
+
+        run(&mut game, "close")?;
+        assert!(matches!(game.status(), GameStatus::Closed));
+
+        Ok(())
+    }
+}
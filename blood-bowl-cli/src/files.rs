@@ -0,0 +1,42 @@
+use blood_bowl_rs::errors::Error;
+use blood_bowl_rs::games::Game;
+use blood_bowl_rs::serialization::{from_versioned_json, to_versioned_json_pretty};
+use blood_bowl_rs::teams::Team;
+use blood_bowl_rs::translation::TranslatedName;
+use std::fs;
+
+#[derive(Debug)]
+pub enum FileError {
+    Io(String, std::io::Error),
+    Game(String, Error),
+}
+
+impl FileError {
+    pub fn message(&self, lang_id: &str) -> String {
+        match self {
+            FileError::Io(path, error) => format!("{} : {}", path, error),
+            FileError::Game(path, error) => format!("{} : {}", path, error.name(lang_id)),
+        }
+    }
+}
+
+fn read<T: serde::de::DeserializeOwned>(path: &str) -> Result<T, FileError> {
+    let json = fs::read_to_string(path).map_err(|error| FileError::Io(path.to_string(), error))?;
+
+    from_versioned_json(&json).map_err(|error| FileError::Game(path.to_string(), error))
+}
+
+pub fn load_team(path: &str) -> Result<Team, FileError> {
+    read(path)
+}
+
+pub fn load_game(path: &str) -> Result<Game, FileError> {
+    read(path)
+}
+
+pub fn save_game(game: &Game, path: &str) -> Result<(), FileError> {
+    let json =
+        to_versioned_json_pretty(game).map_err(|error| FileError::Game(path.to_string(), error))?;
+
+    fs::write(path, json).map_err(|error| FileError::Io(path.to_string(), error))
+}
@@ -0,0 +1,119 @@
+use crate::commands::{Command, execute, help, parse, status};
+use crate::files::save_game;
+use crate::messages::message_with_args;
+use blood_bowl_rs::games::Game;
+use blood_bowl_rs::translation::TranslatedName;
+use std::io::{BufRead, Write};
+
+fn error(lang_id: &str, error: String) -> String {
+    message_with_args(lang_id, "cli-error", &[("error", error)])
+}
+
+/// Applies one command line to the game, saving it after each change.
+///
+/// Returns `None` when the line asks to quit, otherwise the messages to show.
+pub fn handle_line(
+    game: &mut Game,
+    line: &str,
+    save_path: &str,
+    lang_id: &str,
+) -> Option<Vec<String>> {
+    let mut messages = Vec::new();
+    let status_before = game.status();
+
+    match parse(line, game, lang_id) {
+        Ok(Command::Quit) => return None,
+        Ok(Command::Save(path)) => {
+            let path = path.as_deref().unwrap_or(save_path);
+            messages.push(match save_game(game, path) {
+                Ok(()) => message_with_args(lang_id, "cli-saved", &[("path", path.to_string())]),
+                Err(file_error) => error(lang_id, file_error.message(lang_id)),
+            });
+        }
+        Ok(command) => match execute(game, &command, lang_id) {
+            Ok(message) => {
+                messages.push(message);
+                if let Err(file_error) = save_game(game, save_path) {
+                    messages.push(error(lang_id, file_error.message(lang_id)));
+                }
+            }
+            Err(game_error) => messages.push(error(lang_id, game_error.name(lang_id))),
+        },
+        Err(message) => messages.push(message),
+    }
+
+    if status_before.ne(&game.status()) {
+        messages.push(status(game, lang_id));
+        messages.push(help(game, lang_id));
+    }
+
+    Some(messages)
+}
+
+pub fn run<R: BufRead, W: Write>(
+    game: &mut Game,
+    save_path: &str,
+    lang_id: &str,
+    input: R,
+    output: &mut W,
+) -> std::io::Result<()> {
+    writeln!(output, "{}", status(game, lang_id))?;
+    writeln!(output, "{}", help(game, lang_id))?;
+    write!(output, "> ")?;
+    output.flush()?;
+
+    for line in input.lines() {
+        let Some(messages) = handle_line(game, &line?, save_path, lang_id) else {
+            break;
+        };
+
+        for message in messages {
+            writeln!(output, "{}", message)?;
+        }
+        write!(output, "> ")?;
+        output.flush()?;
+    }
+
+    writeln!(output)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::files::load_game;
+    use crate::test_utils::{date, team};
+    use blood_bowl_rs::games::GameStatus;
+    use blood_bowl_rs::versions::Version;
+
+    #[test]
+    fn interactive_session() {
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            date(),
+            &team(1, "Reds"),
+            &team(2, "Blues"),
+            false,
+        )
+        .unwrap();
+        let path = std::env::temp_dir().join(format!("blood-bowl-cli-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+
+        let input = "start\nfans 4 3\nweather blizzard\njourneymen\ntoss 2\nkick 1\ntd 1 2\ntd 1 3\nundo\nfoo\nquit\ntd 1 4\n";
+        let mut output = Vec::new();
+        run(&mut game, path, "en", input.as_bytes(), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Reds 1 - 0 Blues (0 - 0 CAS) : Game in progress"));
+        assert!(output.contains("unknown command: foo"));
+        assert_eq!(game.status(), GameStatus::GameInProgress);
+        assert_eq!(game.score(), (1, 0));
+
+        let saved = load_game(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(saved.events.len(), game.events.len());
+        assert_eq!(saved.score(), (1, 0));
+    }
+}
@@ -0,0 +1,200 @@
+use blood_bowl_rs::games::Game;
+use blood_bowl_rs::reports::ReportFormat;
+use blood_bowl_rs::translation::TranslatedName;
+use chrono::{Local, NaiveDateTime};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::io::{BufReader, IsTerminal, stdin, stdout};
+use std::process::ExitCode;
+
+mod commands;
+mod files;
+mod interactive;
+mod messages;
+#[cfg(test)]
+mod test_utils;
+mod tui;
+
+use commands::{Command, execute, parse, status};
+use files::{load_game, load_team, save_game};
+use messages::message;
+
+#[derive(Parser)]
+#[command(
+    name = "blood-bowl",
+    about = "Run Blood Bowl games at the table",
+    version
+)]
+struct Cli {
+    #[arg(long, global = true, default_value = "en")]
+    lang: String,
+
+    #[command(subcommand)]
+    action: Action,
+}
+
+#[derive(Subcommand)]
+enum Action {
+    #[command(about = "Create a game file from two team files")]
+    New {
+        first_team: String,
+        second_team: String,
+        #[arg(short, long, default_value = "game.json")]
+        output: String,
+        #[arg(long, default_value_t = 1)]
+        id: i32,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        game_at: Option<String>,
+        #[arg(long)]
+        needs_winner: bool,
+    },
+    #[command(about = "Play a game in the terminal UI, saving after each command")]
+    Play { game: String },
+    #[command(about = "Show the game status and the available commands")]
+    Status { game: String },
+    #[command(about = "Apply a single command to a game file")]
+    Run {
+        game: String,
+        #[arg(required = true, num_args = 1..)]
+        command: Vec<String>,
+    },
+    #[command(about = "Cancel the last event of a game file")]
+    Undo { game: String },
+    #[command(about = "Print the match report of a game file")]
+    Report {
+        game: String,
+        #[arg(short, long, value_enum, default_value = "plain-text")]
+        format: Format,
+    },
+}
+
+#[derive(Clone, ValueEnum)]
+enum Format {
+    Markdown,
+    Html,
+    PlainText,
+}
+
+impl From<Format> for ReportFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Markdown => ReportFormat::Markdown,
+            Format::Html => ReportFormat::Html,
+            Format::PlainText => ReportFormat::PlainText,
+        }
+    }
+}
+
+fn parse_game_at(text: &str, lang_id: &str) -> Result<NaiveDateTime, String> {
+    NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M")
+        .map_err(|_| message(lang_id, "cli-invalid-date"))
+}
+
+fn run_command(game: &mut Game, line: &str, lang_id: &str) -> Result<String, String> {
+    let command = parse(line, game, lang_id)?;
+
+    execute(game, &command, lang_id).map_err(|error| error.name(lang_id))
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let lang_id = cli.lang.as_str();
+
+    match cli.action {
+        Action::New {
+            first_team,
+            second_team,
+            output,
+            id,
+            title,
+            game_at,
+            needs_winner,
+        } => {
+            let first_team = load_team(&first_team).map_err(|error| error.message(lang_id))?;
+            let second_team = load_team(&second_team).map_err(|error| error.message(lang_id))?;
+            if first_team.id.eq(&second_team.id) {
+                return Err(message(lang_id, "cli-same-team"));
+            }
+            let game_at = match game_at {
+                Some(game_at) => parse_game_at(&game_at, lang_id)?,
+                None => Local::now().naive_local(),
+            };
+
+            let mut game = Game::create(
+                id,
+                None,
+                first_team.version,
+                game_at,
+                &first_team,
+                &second_team,
+                needs_winner,
+            )
+            .map_err(|error| error.name(lang_id))?;
+            game.title = title;
+
+            save_game(&game, &output).map_err(|error| error.message(lang_id))?;
+            println!("{}", status(&game, lang_id));
+        }
+        Action::Play { game: path } => {
+            let mut game = load_game(&path).map_err(|error| error.message(lang_id))?;
+
+            if stdin().is_terminal() {
+                tui::run(&mut game, &path, lang_id)
+            } else {
+                interactive::run(
+                    &mut game,
+                    &path,
+                    lang_id,
+                    BufReader::new(stdin()),
+                    &mut stdout(),
+                )
+            }
+            .map_err(|error| error.to_string())?;
+        }
+        Action::Status { game: path } => {
+            let game = load_game(&path).map_err(|error| error.message(lang_id))?;
+
+            println!("{}", status(&game, lang_id));
+            println!("{}", commands::help(&game, lang_id));
+        }
+        Action::Run {
+            game: path,
+            command,
+        } => {
+            let mut game = load_game(&path).map_err(|error| error.message(lang_id))?;
+
+            let line = command.join(" ");
+            if matches!(
+                parse(&line, &game, lang_id)?,
+                Command::Save(_) | Command::Quit
+            ) {
+                return Err(message(lang_id, "cli-only-when-playing"));
+            }
+            println!("{}", run_command(&mut game, &line, lang_id)?);
+            save_game(&game, &path).map_err(|error| error.message(lang_id))?;
+        }
+        Action::Undo { game: path } => {
+            let mut game = load_game(&path).map_err(|error| error.message(lang_id))?;
+
+            println!("{}", run_command(&mut game, "undo", lang_id)?);
+            save_game(&game, &path).map_err(|error| error.message(lang_id))?;
+        }
+        Action::Report { game: path, format } => {
+            let game = load_game(&path).map_err(|error| error.message(lang_id))?;
+
+            println!("{}", game.report(format.into(), lang_id));
+        }
+    }
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
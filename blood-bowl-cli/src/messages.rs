@@ -0,0 +1,63 @@
+use blood_bowl_rs::translation::supported_languages;
+use fluent_templates::fluent_bundle::FluentValue;
+use fluent_templates::{LanguageIdentifier, Loader, langid};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+fluent_templates::static_loader! {
+    static LOCALES = {
+        locales: "./locales",
+        fallback_language: "en",
+        customise: |bundle| bundle.set_use_isolating(false),
+    };
+}
+
+const ENGLISH: LanguageIdentifier = langid!("en");
+
+fn language_from(lang_id: &str) -> LanguageIdentifier {
+    let Ok(requested) = lang_id.parse::<LanguageIdentifier>() else {
+        return ENGLISH;
+    };
+
+    supported_languages()
+        .iter()
+        .filter_map(|language| language.parse::<LanguageIdentifier>().ok())
+        .find(|language| language.language == requested.language)
+        .unwrap_or(ENGLISH)
+}
+
+pub fn message(lang_id: &str, key: &str) -> String {
+    LOCALES.lookup(&language_from(lang_id), key)
+}
+
+pub fn message_with_args(lang_id: &str, key: &str, args: &[(&'static str, String)]) -> String {
+    let args: HashMap<Cow<'static, str>, FluentValue> = args
+        .iter()
+        .map(|(name, value)| (Cow::from(*name), FluentValue::from(value.clone())))
+        .collect();
+
+    LOCALES.lookup_with_args(&language_from(lang_id), key, &args)
+}
+
+#[cfg(test)]
+mod tests {
+    fn keys(file: &str) -> Vec<&str> {
+        file.lines()
+            .filter_map(|line| line.split_once(" = ").map(|(key, _)| key))
+            .collect()
+    }
+
+    #[test]
+    fn messages_are_translated() {
+        let english = keys(include_str!("../locales/en/cli.ftl"));
+
+        for (lang_id, file) in [
+            ("fr", include_str!("../locales/fr/cli.ftl")),
+            ("de", include_str!("../locales/de/cli.ftl")),
+            ("es", include_str!("../locales/es/cli.ftl")),
+            ("it", include_str!("../locales/it/cli.ftl")),
+        ] {
+            assert_eq!(keys(file), english, "{} messages differ", lang_id);
+        }
+    }
+}
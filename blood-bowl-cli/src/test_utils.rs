@@ -0,0 +1,43 @@
+use blood_bowl_rs::coaches::Coach;
+use blood_bowl_rs::players::Player;
+use blood_bowl_rs::positions::Position;
+use blood_bowl_rs::rosters::Roster;
+use blood_bowl_rs::teams::Team;
+use blood_bowl_rs::versions::Version;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashMap;
+
+/// Human team of eleven linemen, each player id being `id * 100 + number`.
+pub fn team(id: i32, name: &str) -> Team {
+    Team {
+        id,
+        version: Version::V5S3,
+        roster: Roster::Human,
+        name: name.to_string(),
+        coach: Coach::from_name(name),
+        treasury: 0,
+        external_logo_url: None,
+        staff: HashMap::new(),
+        players: (1..=11)
+            .map(|number| {
+                (
+                    number,
+                    Player {
+                        id: id * 100 + number,
+                        ..Player::new(Version::V5S3, Position::HumanLineman, Roster::Human)
+                    },
+                )
+            })
+            .collect(),
+        dedicated_fans: 2,
+        under_creation: false,
+        in_offseason: false,
+    }
+}
+
+/// Date of the test games, 2025-06-01 at 10:00.
+pub fn date() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 6, 1)
+        .and_then(|date| date.and_hms_opt(10, 0, 0))
+        .unwrap()
+}
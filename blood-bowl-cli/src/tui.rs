@@ -0,0 +1,238 @@
+use crate::commands::{TeamSide, commands, players, status};
+use crate::interactive::handle_line;
+use crate::messages::message;
+use blood_bowl_rs::games::Game;
+use blood_bowl_rs::reports::ReportFormat;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+
+const MESSAGE_LINES: usize = 4;
+
+pub struct App<'a> {
+    game: &'a mut Game,
+    save_path: &'a str,
+    lang_id: &'a str,
+    input: String,
+    messages: Vec<String>,
+    scroll: u16,
+    quit: bool,
+}
+
+impl<'a> App<'a> {
+    pub fn new(game: &'a mut Game, save_path: &'a str, lang_id: &'a str) -> Self {
+        App {
+            game,
+            save_path,
+            lang_id,
+            input: String::new(),
+            messages: Vec::new(),
+            scroll: 0,
+            quit: false,
+        }
+    }
+
+    fn submit(&mut self, line: &str) {
+        match handle_line(self.game, line, self.save_path, self.lang_id) {
+            Some(messages) => self.messages.extend(
+                messages
+                    .iter()
+                    .flat_map(|message| message.lines())
+                    .map(|line| line.to_string()),
+            ),
+            None => self.quit = true,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind.ne(&KeyEventKind::Press) {
+            return;
+        }
+
+        let control = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if control => self.quit = true,
+            KeyCode::Char('z') if control => self.submit("undo"),
+            KeyCode::Char('s') if control => self.submit("save"),
+            KeyCode::Char(character) if !control => self.input.push(character),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Enter => {
+                let line = std::mem::take(&mut self.input);
+                self.submit(&line);
+            }
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(5),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(5),
+            _ => {}
+        }
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let commands = commands(self.game, self.lang_id);
+        let [
+            status_area,
+            body_area,
+            commands_area,
+            messages_area,
+            input_area,
+        ] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(8),
+            Constraint::Length(commands.len() as u16 + 2),
+            Constraint::Length(MESSAGE_LINES as u16 + 2),
+            Constraint::Length(3),
+        ])
+        .areas(frame.area());
+        let [first_team_area, report_area, second_team_area] = Layout::horizontal([
+            Constraint::Percentage(25),
+            Constraint::Percentage(50),
+            Constraint::Percentage(25),
+        ])
+        .areas(body_area);
+
+        frame.render_widget(
+            Paragraph::new(status(self.game, self.lang_id))
+                .style(Style::default().add_modifier(Modifier::BOLD))
+                .block(Block::bordered()),
+            status_area,
+        );
+
+        for (side, team, area) in [
+            (TeamSide::First, &self.game.first_team, first_team_area),
+            (TeamSide::Second, &self.game.second_team, second_team_area),
+        ] {
+            frame.render_widget(
+                Paragraph::new(players(self.game, &side, self.lang_id))
+                    .block(Block::bordered().title(team.name.as_str())),
+                area,
+            );
+        }
+
+        frame.render_widget(
+            Paragraph::new(self.game.report(ReportFormat::PlainText, self.lang_id))
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0))
+                .block(Block::bordered()),
+            report_area,
+        );
+
+        frame.render_widget(
+            Paragraph::new(commands.join("\n"))
+                .block(Block::bordered().title(message(self.lang_id, "cli-tui-commands"))),
+            commands_area,
+        );
+
+        let skipped = self.messages.len().saturating_sub(MESSAGE_LINES);
+        frame.render_widget(
+            Paragraph::new(self.messages[skipped..].join("\n")).block(Block::bordered()),
+            messages_area,
+        );
+
+        frame.render_widget(
+            Paragraph::new(format!("> {}", self.input)).block(
+                Block::bordered()
+                    .title(message(self.lang_id, "cli-tui-command"))
+                    .title_bottom(message(self.lang_id, "cli-tui-keys")),
+            ),
+            input_area,
+        );
+        frame.set_cursor_position((
+            input_area.x + 3 + self.input.chars().count() as u16,
+            input_area.y + 1,
+        ));
+    }
+}
+
+pub fn run(game: &mut Game, save_path: &str, lang_id: &str) -> std::io::Result<()> {
+    let mut app = App::new(game, save_path, lang_id);
+    let mut terminal = ratatui::init();
+
+    let result = (|| {
+        while !app.quit {
+            terminal.draw(|frame| app.draw(frame))?;
+            if let Event::Key(key) = event::read()? {
+                app.handle_key(key);
+            }
+        }
+        Ok(())
+    })();
+
+    ratatui::restore();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{date, team};
+    use blood_bowl_rs::games::GameStatus;
+    use blood_bowl_rs::versions::Version;
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    fn type_line(app: &mut App, line: &str) {
+        for character in line.chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(character)));
+        }
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+    }
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(140, 50)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|line| line.iter().map(|cell| cell.symbol()).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn terminal_session() {
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            date(),
+            &team(1, "Reds"),
+            &team(2, "Blues"),
+            false,
+        )
+        .unwrap();
+        let path = std::env::temp_dir().join(format!("blood-bowl-tui-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let mut app = App::new(&mut game, path, "en");
+
+        for line in ["start", "fans 4 3", "weather blizzard", "journeymen"] {
+            type_line(&mut app, line);
+        }
+        assert!(screen(&app).contains("toss [roll | <team>]"));
+
+        for line in ["toss 2", "kick 1", "td 1 2"] {
+            type_line(&mut app, line);
+        }
+        let screen_in_game = screen(&app);
+        assert!(screen_in_game.contains("Reds 1 - 0 Blues (0 - 0 CAS) : Game in progress"));
+        assert!(screen_in_game.contains("td <team> <player>"));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('z'), KeyModifiers::CONTROL));
+        assert!(screen(&app).contains("Reds 0 - 0 Blues"));
+
+        type_line(&mut app, "foo");
+        assert!(screen(&app).contains("unknown command: foo"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert!(app.quit);
+
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(game.status(), GameStatus::GameInProgress);
+        assert_eq!(game.score(), (0, 0));
+    }
+}
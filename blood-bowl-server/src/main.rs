@@ -0,0 +1,53 @@
+use clap::Parser;
+use std::process::ExitCode;
+
+mod routes;
+mod storage;
+
+use storage::Storage;
+
+#[derive(Parser)]
+#[command(
+    name = "server",
+    about = "Serve Blood Bowl teams and games over HTTP",
+    version
+)]
+struct Cli {
+    #[arg(long, default_value = "127.0.0.1:3000")]
+    address: String,
+
+    #[arg(
+        long,
+        help = "Database URL, like sqlite://league.db?mode=rwc (in memory if absent)"
+    )]
+    database: Option<String>,
+}
+
+async fn run(cli: Cli) -> Result<(), String> {
+    let storage = match &cli.database {
+        Some(url) => Storage::database(url)
+            .await
+            .map_err(|_| format!("cannot open database {}", url))?,
+        None => Storage::memory(),
+    };
+
+    let listener = tokio::net::TcpListener::bind(&cli.address)
+        .await
+        .map_err(|error| format!("cannot listen on {} : {}", cli.address, error))?;
+    println!("Listening on {}", cli.address);
+
+    axum::serve(listener, routes::router(storage))
+        .await
+        .map_err(|error| error.to_string())
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    match run(Cli::parse()).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,774 @@
+use crate::storage::Storage;
+use axum::extract::{Path, Query, Request, State};
+use axum::http::StatusCode;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use blood_bowl_rs::coaches::Coach;
+use blood_bowl_rs::errors::Error;
+use blood_bowl_rs::events::GameEvent;
+use blood_bowl_rs::games::{Game, GameAction, GameStatus};
+use blood_bowl_rs::positions::{Position, PositionDefinition};
+use blood_bowl_rs::reports::ReportFormat;
+use blood_bowl_rs::rosters::{Roster, RosterDefinition};
+use blood_bowl_rs::staffs::Staff;
+use blood_bowl_rs::teams::Team;
+use blood_bowl_rs::translation::{TranslatedName, TypeName};
+use blood_bowl_rs::versions::Version;
+use chrono::{Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct AppState {
+    storage: Storage,
+    write_lock: Mutex<()>,
+}
+
+pub enum ApiError {
+    NotFound,
+    Rules(Error),
+}
+
+impl From<Error> for ApiError {
+    fn from(error: Error) -> Self {
+        ApiError::Rules(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match self {
+            ApiError::NotFound => (
+                StatusCode::NOT_FOUND,
+                Json(json!({"error": "NotFound", "message": "Not found"})),
+            )
+                .into_response(),
+            ApiError::Rules(error) => {
+                let status = match error {
+                    Error::DatabaseConstraintViolation => StatusCode::CONFLICT,
+                    Error::DatabaseError
+                    | Error::DatabaseUnavailable
+                    | Error::DatabaseMigrationFailed
+                    | Error::InvalidDatabaseData
+                    | Error::UnsupportedDatabase => StatusCode::INTERNAL_SERVER_ERROR,
+                    _ => StatusCode::UNPROCESSABLE_ENTITY,
+                };
+
+                let mut response = (status, error_body(&error, &default_lang())).into_response();
+                response.extensions_mut().insert(error);
+                response
+            }
+        }
+    }
+}
+
+fn error_body(error: &Error, lang_id: &str) -> Json<serde_json::Value> {
+    Json(json!({"error": error.type_name(), "message": error.name(lang_id)}))
+}
+
+/// Translates rules errors into the language asked with the `lang` query parameter.
+async fn translate_errors(
+    Query(language): Query<Language>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+
+    match response.extensions().get::<Error>() {
+        Some(error) => {
+            let mut translated =
+                (response.status(), error_body(error, &language.lang)).into_response();
+            translated.extensions_mut().insert(error.clone());
+            translated
+        }
+        None => response,
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+#[derive(Deserialize)]
+struct Language {
+    #[serde(default = "default_lang")]
+    lang: String,
+}
+
+fn default_lang() -> String {
+    "en".to_string()
+}
+
+#[derive(Deserialize)]
+struct ReportQuery {
+    #[serde(default = "default_lang")]
+    lang: String,
+    #[serde(default = "default_report_format")]
+    format: ReportFormat,
+}
+
+fn default_report_format() -> ReportFormat {
+    ReportFormat::Markdown
+}
+
+#[derive(Serialize)]
+struct RosterEntry {
+    roster: Roster,
+    name: String,
+    definition: RosterDefinition,
+}
+
+#[derive(Serialize)]
+struct PositionEntry {
+    position: Position,
+    name: String,
+    definition: PositionDefinition,
+}
+
+#[derive(Deserialize)]
+struct NewTeam {
+    version: Version,
+    roster: Roster,
+    name: String,
+    coach: String,
+}
+
+#[derive(Deserialize)]
+struct NewPlayer {
+    position: Position,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    number: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct NewStaff {
+    staff: Staff,
+}
+
+#[derive(Deserialize)]
+struct NewCaptain {
+    player_id: i32,
+}
+
+#[derive(Serialize)]
+struct TeamValidation {
+    compliant: bool,
+    error: Option<String>,
+    message: Option<String>,
+    value: u32,
+    current_value: u32,
+}
+
+#[derive(Deserialize)]
+struct NewGame {
+    first_team_id: i32,
+    second_team_id: i32,
+    #[serde(default)]
+    title: Option<String>,
+    #[serde(default)]
+    game_at: Option<NaiveDateTime>,
+    #[serde(default)]
+    needs_winner: bool,
+}
+
+#[derive(Serialize)]
+struct GameSummary {
+    id: i32,
+    status: GameStatus,
+    status_name: String,
+    first_team: String,
+    second_team: String,
+    score: (usize, usize),
+    casualties: (usize, usize),
+    events: usize,
+}
+
+impl GameSummary {
+    fn from_game(game: &Game, lang_id: &str) -> Self {
+        Self {
+            id: game.id,
+            status: game.status(),
+            status_name: game.status().name(lang_id),
+            first_team: game.first_team.name.clone(),
+            second_team: game.second_team.name.clone(),
+            score: game.score(),
+            casualties: game.casualties(),
+            events: game.events.len(),
+        }
+    }
+}
+
+pub fn router(storage: Storage) -> Router {
+    let state = Arc::new(AppState {
+        storage,
+        write_lock: Mutex::new(()),
+    });
+
+    Router::new()
+        .route("/versions/{version}/rosters", get(list_rosters))
+        .route(
+            "/versions/{version}/rosters/{roster}/positions",
+            get(list_positions),
+        )
+        .route("/teams", get(list_teams).post(create_team))
+        .route(
+            "/teams/{id}",
+            get(find_team).put(update_team).delete(delete_team),
+        )
+        .route("/teams/{id}/players", post(buy_player))
+        .route("/teams/{id}/staff", post(buy_staff))
+        .route("/teams/{id}/captain", post(name_captain))
+        .route("/teams/{id}/validation", get(validate_team))
+        .route("/games", get(list_games).post(create_game))
+        .route("/games/{id}", get(find_game).delete(delete_game))
+        .route("/games/{id}/actions", post(push_action))
+        .route("/games/{id}/undo", post(undo))
+        .route("/games/{id}/status", get(game_status))
+        .route("/games/{id}/report", get(game_report))
+        .layer(middleware::from_fn(translate_errors))
+        .with_state(state)
+}
+
+async fn list_rosters(
+    Path(version): Path<Version>,
+    Query(language): Query<Language>,
+) -> ApiResult<Vec<RosterEntry>> {
+    Ok(Json(
+        Roster::list(version)
+            .into_iter()
+            .filter_map(|roster| {
+                roster.definition(version).map(|definition| RosterEntry {
+                    roster,
+                    name: roster.name(&language.lang),
+                    definition,
+                })
+            })
+            .collect(),
+    ))
+}
+
+async fn list_positions(
+    Path((version, roster)): Path<(Version, Roster)>,
+    Query(language): Query<Language>,
+) -> ApiResult<Vec<PositionEntry>> {
+    let roster_definition = roster.definition(version).ok_or(ApiError::NotFound)?;
+
+    Ok(Json(
+        roster_definition
+            .positions
+            .into_iter()
+            .filter_map(|position| {
+                position
+                    .definition(version, roster)
+                    .map(|definition| PositionEntry {
+                        position,
+                        name: position.name(&language.lang),
+                        definition,
+                    })
+            })
+            .collect(),
+    ))
+}
+
+async fn load_team(state: &AppState, id: i32) -> Result<Team, ApiError> {
+    state.storage.find_team(id).await?.ok_or(ApiError::NotFound)
+}
+
+async fn load_game(state: &AppState, id: i32) -> Result<Game, ApiError> {
+    state.storage.find_game(id).await?.ok_or(ApiError::NotFound)
+}
+
+async fn list_teams(State(state): State<Arc<AppState>>) -> ApiResult<Vec<Team>> {
+    Ok(Json(state.storage.list_teams().await?))
+}
+
+async fn create_team(
+    State(state): State<Arc<AppState>>,
+    Json(new_team): Json<NewTeam>,
+) -> Result<(StatusCode, Json<Team>), ApiError> {
+    new_team
+        .roster
+        .definition(new_team.version)
+        .ok_or(Error::RosterNotExist)?;
+
+    let _guard = state.write_lock.lock().await;
+    let mut team = Team {
+        id: state.storage.next_team_id().await?,
+        version: new_team.version,
+        roster: new_team.roster,
+        name: new_team.name,
+        coach: Coach::from_name(&new_team.coach),
+        treasury: Team::initial_treasury(&new_team.version),
+        external_logo_url: None,
+        staff: HashMap::new(),
+        players: Vec::new(),
+        dedicated_fans: 1,
+        under_creation: true,
+        in_offseason: false,
+    };
+    state.storage.save_team(&mut team).await?;
+
+    Ok((StatusCode::CREATED, Json(team)))
+}
+
+async fn find_team(State(state): State<Arc<AppState>>, Path(id): Path<i32>) -> ApiResult<Team> {
+    Ok(Json(load_team(&state, id).await?))
+}
+
+async fn update_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Json(mut team): Json<Team>,
+) -> ApiResult<Team> {
+    let _guard = state.write_lock.lock().await;
+    load_team(&state, id).await?;
+
+    team.id = id;
+    state.storage.save_team(&mut team).await?;
+
+    Ok(Json(team))
+}
+
+async fn delete_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    let _guard = state.write_lock.lock().await;
+
+    match state.storage.delete_team(id).await? {
+        true => Ok(StatusCode::NO_CONTENT),
+        false => Err(ApiError::NotFound),
+    }
+}
+
+async fn buy_player(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Json(new_player): Json<NewPlayer>,
+) -> ApiResult<Team> {
+    let _guard = state.write_lock.lock().await;
+    let mut team = load_team(&state, id).await?;
+    let next_id = team.players.iter().map(|(_, player)| player.id).max();
+    let next_number = team.players.iter().map(|(number, _)| *number).max();
+    if let Some(number) = new_player.number
+        && team
+            .players
+            .iter()
+            .any(|(team_number, _)| team_number.eq(&number))
+    {
+        return Err(ApiError::Rules(Error::PlayerNumberAlreadyUsed));
+    }
+
+    team.buy_position(&new_player.position)?;
+    if let Some((number, player)) = team.players.last_mut() {
+        *number = new_player.number.unwrap_or(next_number.unwrap_or(0) + 1);
+        player.id = next_id.unwrap_or(0) + 1;
+        if let Some(name) = new_player.name {
+            player.name = name;
+        }
+    }
+    state.storage.save_team(&mut team).await?;
+
+    Ok(Json(team))
+}
+
+async fn buy_staff(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Json(new_staff): Json<NewStaff>,
+) -> ApiResult<Team> {
+    let _guard = state.write_lock.lock().await;
+    let mut team = load_team(&state, id).await?;
+
+    team.buy_staff(&new_staff.staff)?;
+    state.storage.save_team(&mut team).await?;
+
+    Ok(Json(team))
+}
+
+async fn name_captain(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Json(new_captain): Json<NewCaptain>,
+) -> ApiResult<Team> {
+    let _guard = state.write_lock.lock().await;
+    let mut team = load_team(&state, id).await?;
+
+    let (_, captain) = team
+        .players
+        .iter()
+        .find(|(_, player)| player.id.eq(&new_captain.player_id))
+        .ok_or(Error::PlayerNotInTeam)?;
+    if !team.can_player_be_captain(captain) {
+        return Err(ApiError::Rules(Error::CaptainMissing));
+    }
+
+    for (_, player) in team.players.iter_mut() {
+        player.is_captain = player.id.eq(&new_captain.player_id);
+    }
+    state.storage.save_team(&mut team).await?;
+
+    Ok(Json(team))
+}
+
+async fn validate_team(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(language): Query<Language>,
+) -> ApiResult<TeamValidation> {
+    let team = load_team(&state, id).await?;
+    let (compliant, error, message) = match team.check_if_rules_compliant() {
+        Ok(()) => (true, None, None),
+        Err(error) => (
+            false,
+            Some(error.type_name()),
+            Some(error.name(&language.lang)),
+        ),
+    };
+
+    Ok(Json(TeamValidation {
+        compliant,
+        error,
+        message,
+        value: team.value()?,
+        current_value: team.current_value()?,
+    }))
+}
+
+async fn list_games(
+    State(state): State<Arc<AppState>>,
+    Query(language): Query<Language>,
+) -> ApiResult<Vec<GameSummary>> {
+    Ok(Json(
+        state
+            .storage
+            .list_games()
+            .await?
+            .iter()
+            .map(|game| GameSummary::from_game(game, &language.lang))
+            .collect(),
+    ))
+}
+
+async fn create_game(
+    State(state): State<Arc<AppState>>,
+    Json(new_game): Json<NewGame>,
+) -> Result<(StatusCode, Json<Game>), ApiError> {
+    if new_game.first_team_id.eq(&new_game.second_team_id) {
+        return Err(ApiError::Rules(Error::NotAPlayingTeam));
+    }
+
+    let _guard = state.write_lock.lock().await;
+    let first_team = load_team(&state, new_game.first_team_id).await?;
+    let second_team = load_team(&state, new_game.second_team_id).await?;
+
+    let mut game = Game::create(
+        state.storage.next_game_id().await?,
+        None,
+        first_team.version,
+        new_game
+            .game_at
+            .unwrap_or_else(|| Local::now().naive_local()),
+        &first_team,
+        &second_team,
+        new_game.needs_winner,
+    )?;
+    game.title = new_game.title;
+    game.check_if_rules_compliant()?;
+    state.storage.save_game(&game).await?;
+
+    Ok((StatusCode::CREATED, Json(game)))
+}
+
+async fn find_game(State(state): State<Arc<AppState>>, Path(id): Path<i32>) -> ApiResult<Game> {
+    Ok(Json(load_game(&state, id).await?))
+}
+
+async fn delete_game(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<StatusCode, ApiError> {
+    let _guard = state.write_lock.lock().await;
+
+    match state.storage.delete_game(id).await? {
+        true => Ok(StatusCode::NO_CONTENT),
+        false => Err(ApiError::NotFound),
+    }
+}
+
+async fn push_action(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(language): Query<Language>,
+    Json(action): Json<GameAction>,
+) -> ApiResult<GameSummary> {
+    let _guard = state.write_lock.lock().await;
+    let mut game = load_game(&state, id).await?;
+
+    action.apply(&mut game)?;
+    state.storage.save_game(&game).await?;
+
+    Ok(Json(GameSummary::from_game(&game, &language.lang)))
+}
+
+async fn undo(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> ApiResult<Option<GameEvent>> {
+    let _guard = state.write_lock.lock().await;
+    let mut game = load_game(&state, id).await?;
+
+    let event = game.cancel_last_event()?;
+    state.storage.save_game(&game).await?;
+
+    Ok(Json(event))
+}
+
+async fn game_status(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(language): Query<Language>,
+) -> ApiResult<GameSummary> {
+    let game = load_game(&state, id).await?;
+
+    Ok(Json(GameSummary::from_game(&game, &language.lang)))
+}
+
+async fn game_report(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Query(query): Query<ReportQuery>,
+) -> Result<String, ApiError> {
+    let game = load_game(&state, id).await?;
+
+    Ok(game.report(query.format, &query.lang))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use serde_json::Value;
+    use tower::ServiceExt;
+
+    async fn call(router: &Router, method: &str, uri: &str, body: Value) -> (StatusCode, Value) {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(match body {
+                Value::Null => Body::empty(),
+                body => Body::from(body.to_string()),
+            })
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+
+        (
+            status,
+            serde_json::from_slice(&bytes)
+                .unwrap_or(Value::String(String::from_utf8_lossy(&bytes).to_string())),
+        )
+    }
+
+    async fn draft_team(router: &Router, name: &str) -> i64 {
+        let (status, team) = call(
+            router,
+            "POST",
+            "/teams",
+            json!({"version": "V5S3", "roster": "Human", "name": name, "coach": name}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let id = team["id"].as_i64().unwrap();
+
+        for _ in 0..11 {
+            let (status, _) = call(
+                router,
+                "POST",
+                &format!("/teams/{}/players", id),
+                json!({"position": "HumanLineman"}),
+            )
+            .await;
+            assert_eq!(status, StatusCode::OK);
+        }
+        let (status, team) = call(
+            router,
+            "POST",
+            &format!("/teams/{}/captain", id),
+            json!({"player_id": 1}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(team["players"][10][0], 11);
+
+        id
+    }
+
+    async fn play_game(router: Router) {
+        let (status, rosters) = call(
+            &router,
+            "GET",
+            "/versions/V5S3/rosters?lang=fr",
+            Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(
+            rosters
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|roster| roster["roster"].eq("Human"))
+        );
+        let (status, positions) = call(
+            &router,
+            "GET",
+            "/versions/V5S3/rosters/Human/positions",
+            Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(positions.as_array().unwrap().len() > 3);
+
+        let reds = draft_team(&router, "Reds").await;
+        let blues = draft_team(&router, "Blues").await;
+        let (_, validation) = call(&router, "GET", "/teams/1/validation", Value::Null).await;
+        assert_eq!(validation["compliant"], true);
+        let (status, error) = call(
+            &router,
+            "POST",
+            "/teams/1/staff",
+            json!({"staff": "ReRoll"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{}", error);
+
+        let (status, game) = call(
+            &router,
+            "POST",
+            "/games",
+            json!({"first_team_id": reds, "second_team_id": blues, "game_at": "2025-06-01T10:00:00"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let id = game["id"].as_i64().unwrap();
+        let actions = format!("/games/{}/actions", id);
+
+        for action in [
+            json!({"action": "Start"}),
+            json!({"action": "FanFactor", "team_id": reds, "fan_factor": 4}),
+            json!({"action": "FanFactor", "team_id": blues, "fan_factor": 3}),
+            json!({"action": "Weather", "weather": "PerfectConditions"}),
+            json!({"action": "GenerateJourneymen"}),
+            json!({"action": "TossWinner", "team_id": reds}),
+            json!({"action": "KickingTeam", "team_id": blues}),
+            json!({"action": "Success", "team_id": reds, "player_id": 2, "success": "Touchdown"}),
+            json!({"action": "Success", "team_id": blues, "player_id": 3, "success": "Touchdown"}),
+        ] {
+            let (status, summary) = call(&router, "POST", &actions, action).await;
+            assert_eq!(status, StatusCode::OK, "{}", summary);
+        }
+
+        let (status, event) =
+            call(&router, "POST", &format!("/games/{}/undo", id), Value::Null).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(event["Success"].is_object());
+
+        let (status, error) = call(
+            &router,
+            "POST",
+            &actions,
+            json!({"action": "Success", "team_id": 42, "player_id": 1, "success": "Touchdown"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(error["error"].is_string());
+        let (status, translated) = call(
+            &router,
+            "POST",
+            &format!("{}?lang=fr", actions),
+            json!({"action": "Success", "team_id": 42, "player_id": 1, "success": "Touchdown"}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(translated["error"], error["error"]);
+        assert_ne!(translated["message"], error["message"]);
+
+        let (status, summary) = call(
+            &router,
+            "GET",
+            &format!("/games/{}/status", id),
+            Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(summary["status"], "GameInProgress");
+        assert_eq!(summary["score"], json!([1, 0]));
+
+        let (status, report) = call(
+            &router,
+            "GET",
+            &format!("/games/{}/report?format=PlainText", id),
+            Value::Null,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(report.as_str().unwrap().contains("Reds"));
+
+        let (status, _) = call(&router, "GET", "/games/42", Value::Null).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = call(&router, "DELETE", &format!("/games/{}", id), Value::Null).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (_, games) = call(&router, "GET", "/games", Value::Null).await;
+        assert!(games.as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn memory_storage() {
+        play_game(router(Storage::memory())).await;
+    }
+
+    #[tokio::test]
+    async fn duplicate_player_number() {
+        let router = router(Storage::memory());
+        let id = draft_team(&router, "Reds").await;
+
+        let (status, error) = call(
+            &router,
+            "POST",
+            &format!("/teams/{}/players", id),
+            json!({"position": "HumanLineman", "number": 11}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(error["error"], "PlayerNumberAlreadyUsed");
+
+        let (status, team) = call(
+            &router,
+            "POST",
+            &format!("/teams/{}/players", id),
+            json!({"position": "HumanLineman", "number": 16}),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(team["players"][11][0], 16);
+    }
+
+    #[tokio::test]
+    async fn sqlite_storage() {
+        let path =
+            std::env::temp_dir().join(format!("blood-bowl-server-{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let url = format!("sqlite://{}?mode=rwc", path.display());
+
+        play_game(router(Storage::database(&url).await.unwrap())).await;
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,131 @@
+use blood_bowl_rs::db::{GameRepository, TeamRepository, connect};
+use blood_bowl_rs::errors::Error;
+use blood_bowl_rs::games::Game;
+use blood_bowl_rs::teams::Team;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+#[derive(Default)]
+pub struct MemoryStorage {
+    teams: BTreeMap<i32, Team>,
+    games: BTreeMap<i32, Game>,
+}
+
+pub enum Storage {
+    Memory(Mutex<MemoryStorage>),
+    Database {
+        teams: TeamRepository,
+        games: GameRepository,
+    },
+}
+
+impl Storage {
+    pub fn memory() -> Self {
+        Storage::Memory(Mutex::new(MemoryStorage::default()))
+    }
+
+    pub async fn database(url: &str) -> Result<Self, Error> {
+        let pool = connect(url).await?;
+
+        Ok(Storage::Database {
+            teams: TeamRepository::new(pool.clone()),
+            games: GameRepository::new(pool),
+        })
+    }
+
+    fn memory_storage(storage: &Mutex<MemoryStorage>) -> std::sync::MutexGuard<'_, MemoryStorage> {
+        storage
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub async fn list_teams(&self) -> Result<Vec<Team>, Error> {
+        match self {
+            Storage::Memory(storage) => Ok(Self::memory_storage(storage)
+                .teams
+                .values()
+                .cloned()
+                .collect()),
+            Storage::Database { teams, .. } => teams.list().await,
+        }
+    }
+
+    pub async fn find_team(&self, id: i32) -> Result<Option<Team>, Error> {
+        match self {
+            Storage::Memory(storage) => Ok(Self::memory_storage(storage).teams.get(&id).cloned()),
+            Storage::Database { teams, .. } => teams.find(id).await,
+        }
+    }
+
+    pub async fn next_team_id(&self) -> Result<i32, Error> {
+        let teams = self.list_teams().await?;
+
+        Ok(teams.iter().map(|team| team.id).max().unwrap_or(0) + 1)
+    }
+
+    pub async fn save_team(&self, team: &mut Team) -> Result<(), Error> {
+        match self {
+            Storage::Memory(storage) => {
+                Self::memory_storage(storage)
+                    .teams
+                    .insert(team.id, team.clone());
+                Ok(())
+            }
+            Storage::Database { teams, .. } => teams.save(team).await,
+        }
+    }
+
+    pub async fn delete_team(&self, id: i32) -> Result<bool, Error> {
+        match self {
+            Storage::Memory(storage) => {
+                Ok(Self::memory_storage(storage).teams.remove(&id).is_some())
+            }
+            Storage::Database { teams, .. } => teams.delete(id).await,
+        }
+    }
+
+    pub async fn list_games(&self) -> Result<Vec<Game>, Error> {
+        match self {
+            Storage::Memory(storage) => Ok(Self::memory_storage(storage)
+                .games
+                .values()
+                .cloned()
+                .collect()),
+            Storage::Database { games, .. } => games.list().await,
+        }
+    }
+
+    pub async fn find_game(&self, id: i32) -> Result<Option<Game>, Error> {
+        match self {
+            Storage::Memory(storage) => Ok(Self::memory_storage(storage).games.get(&id).cloned()),
+            Storage::Database { games, .. } => games.find(id).await,
+        }
+    }
+
+    pub async fn next_game_id(&self) -> Result<i32, Error> {
+        let games = self.list_games().await?;
+
+        Ok(games.iter().map(|game| game.id).max().unwrap_or(0) + 1)
+    }
+
+    pub async fn save_game(&self, game: &Game) -> Result<(), Error> {
+        match self {
+            Storage::Memory(storage) => {
+                Self::memory_storage(storage)
+                    .games
+                    .insert(game.id, game.clone());
+                Ok(())
+            }
+            Storage::Database { games, .. } => games.save(game).await,
+        }
+    }
+
+    pub async fn delete_game(&self, id: i32) -> Result<bool, Error> {
+        match self {
+            Storage::Memory(storage) => {
+                Ok(Self::memory_storage(storage).games.remove(&id).is_some())
+            }
+            Storage::Database { games, .. } => games.delete(id).await,
+        }
+    }
+}
@@ -0,0 +1,476 @@
+use blood_bowl_rs::advancements::Advancement;
+use blood_bowl_rs::coaches::Coach;
+use blood_bowl_rs::errors::Error;
+use blood_bowl_rs::games::{Game, GameAction};
+use blood_bowl_rs::inducements::Inducement;
+use blood_bowl_rs::injuries::Injury;
+use blood_bowl_rs::positions::{Keyword, Position, PositionDefinition};
+use blood_bowl_rs::prayers::PrayerToNuffle;
+use blood_bowl_rs::rosters::{Roster, RosterDefinition, SpecialRule};
+use blood_bowl_rs::skills::{Skill, SkillCategory};
+use blood_bowl_rs::staffs::Staff;
+use blood_bowl_rs::teams::Team;
+use blood_bowl_rs::translation::{
+    TranslatedName, TypeName, load_translations, supported_languages,
+};
+use blood_bowl_rs::versions::Version;
+use blood_bowl_rs::weather::Weather;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+#[derive(Serialize)]
+struct RosterEntry {
+    roster: Roster,
+    name: String,
+    definition: RosterDefinition,
+}
+
+#[derive(Serialize)]
+struct PositionEntry {
+    position: Position,
+    name: String,
+    skills_names: Vec<String>,
+    definition: PositionDefinition,
+}
+
+#[derive(Serialize)]
+struct BuyablePosition {
+    position: Position,
+    name: String,
+    cost: u32,
+    buyable: bool,
+}
+
+#[derive(Serialize)]
+struct TeamValues {
+    value: u32,
+    current_value: u32,
+    treasury: i32,
+    formatted_value: String,
+    formatted_current_value: String,
+    formatted_treasury: String,
+}
+
+#[derive(Serialize)]
+struct TeamValidation {
+    compliant: bool,
+    error: Option<String>,
+    message: Option<String>,
+}
+
+fn error_json(error: Error, lang_id: &str) -> String {
+    json!({
+        "error": error.type_name(),
+        "message": error.name(lang_id),
+    })
+    .to_string()
+}
+
+fn parse<T: DeserializeOwned>(json: &str) -> Result<T, Error> {
+    serde_json::from_str(json).map_err(|_| Error::InvalidSerializedData)
+}
+
+fn parse_name<T: DeserializeOwned>(name: &str) -> Result<T, Error> {
+    serde_json::from_value(Value::String(name.to_string()))
+        .or_else(|_| serde_json::from_str(name))
+        .map_err(|_| Error::InvalidSerializedData)
+}
+
+fn to_json<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(value).map_err(|_| Error::InvalidSerializedData)
+}
+
+fn respond<T: Serialize>(result: Result<T, Error>, lang_id: &str) -> Result<String, String> {
+    result
+        .and_then(|value| to_json(&value))
+        .map_err(|error| error_json(error, lang_id))
+}
+
+#[wasm_bindgen(js_name = listRosters)]
+pub fn list_rosters(version: &str, lang_id: &str) -> Result<String, String> {
+    let result = parse_name::<Version>(version).map(|version| {
+        Roster::list(version)
+            .into_iter()
+            .filter_map(|roster| {
+                roster.definition(version).map(|definition| RosterEntry {
+                    roster,
+                    name: roster.name(lang_id),
+                    definition,
+                })
+            })
+            .collect::<Vec<RosterEntry>>()
+    });
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = positionDefinitions)]
+pub fn position_definitions(version: &str, roster: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let version: Version = parse_name(version)?;
+        let roster: Roster = parse_name(roster)?;
+        let roster_definition = roster.definition(version).ok_or(Error::RosterNotExist)?;
+
+        Ok(roster_definition
+            .positions
+            .into_iter()
+            .filter_map(|position| {
+                position
+                    .definition(version, roster)
+                    .map(|definition| PositionEntry {
+                        position,
+                        name: position.name(lang_id),
+                        skills_names: definition
+                            .skills
+                            .iter()
+                            .map(|skill| skill.name(lang_id))
+                            .collect(),
+                        definition,
+                    })
+            })
+            .collect::<Vec<PositionEntry>>())
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = newTeam)]
+pub fn new_team(
+    version: &str,
+    roster: &str,
+    name: &str,
+    coach_name: &str,
+    lang_id: &str,
+) -> Result<String, String> {
+    let result = (|| {
+        let version: Version = parse_name(version)?;
+        let roster: Roster = parse_name(roster)?;
+        roster.definition(version).ok_or(Error::RosterNotExist)?;
+
+        Ok(Team {
+            id: -1,
+            version,
+            roster,
+            name: name.to_string(),
+            coach: Coach::from_name(coach_name),
+            treasury: Team::initial_treasury(&version),
+            external_logo_url: None,
+            staff: HashMap::new(),
+            players: Vec::new(),
+            dedicated_fans: 1,
+            under_creation: true,
+            in_offseason: false,
+        })
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = positionsBuyable)]
+pub fn positions_buyable(team: &str, lang_id: &str) -> Result<String, String> {
+    let result = parse::<Team>(team).map(|team| {
+        team.positions_buyable()
+            .into_iter()
+            .map(|(position, cost, buyable)| BuyablePosition {
+                position,
+                name: position.name(lang_id),
+                cost,
+                buyable,
+            })
+            .collect::<Vec<BuyablePosition>>()
+    });
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = buyPosition)]
+pub fn buy_position(team: &str, position: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let mut team: Team = parse(team)?;
+        let position: Position = parse_name(position)?;
+        // The core leaves bought players unnumbered, the builder numbers them after the others.
+        let number = team
+            .players
+            .iter()
+            .map(|(number, _)| *number)
+            .max()
+            .unwrap_or(0)
+            + 1;
+
+        team.buy_position(&position)?;
+        if let Some((bought_number, _)) = team.players.last_mut() {
+            *bought_number = number;
+        }
+
+        Ok(team)
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = buyStaff)]
+pub fn buy_staff(team: &str, staff: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let mut team: Team = parse(team)?;
+        let staff: Staff = parse_name(staff)?;
+
+        team.buy_staff(&staff)?;
+
+        Ok(team)
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = nameCaptain)]
+pub fn name_captain(team: &str, number: i32, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let mut team: Team = parse(team)?;
+        for (_, player) in team.players.iter_mut() {
+            player.is_captain = false;
+        }
+
+        let (_, captain) = team
+            .players
+            .iter()
+            .find(|(player_number, _)| player_number.eq(&number))
+            .ok_or(Error::PlayerNotInTeam)?;
+        if !team.can_player_be_captain(captain) {
+            return Err(Error::PlayerCanNotBeCaptain);
+        }
+
+        for (player_number, player) in team.players.iter_mut() {
+            player.is_captain = number.eq(player_number);
+        }
+
+        Ok(team)
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = teamValues)]
+pub fn team_values(team: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let team: Team = parse(team)?;
+
+        Ok(TeamValues {
+            value: team.value()?,
+            current_value: team.current_value()?,
+            treasury: team.treasury,
+            formatted_value: team.formatted_value(lang_id)?,
+            formatted_current_value: team.formatted_current_value(lang_id)?,
+            formatted_treasury: team.formatted_treasury(lang_id),
+        })
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = checkTeam)]
+pub fn check_team(team: &str, lang_id: &str) -> Result<String, String> {
+    let result = parse::<Team>(team).map(|team| match team.check_if_rules_compliant() {
+        Ok(()) => TeamValidation {
+            compliant: true,
+            error: None,
+            message: None,
+        },
+        Err(error) => TeamValidation {
+            compliant: false,
+            error: Some(error.type_name()),
+            message: Some(error.name(lang_id)),
+        },
+    });
+
+    respond(result, lang_id)
+}
+
+fn translated<T: DeserializeOwned + TranslatedName>(
+    value: &str,
+    lang_id: &str,
+) -> Result<String, Error> {
+    parse_name::<T>(value).map(|value| value.name(lang_id))
+}
+
+#[wasm_bindgen(js_name = localizedName)]
+pub fn localized_name(kind: &str, value: &str, lang_id: &str) -> Result<String, String> {
+    let result = match kind {
+        "Advancement" => translated::<Advancement>(value, lang_id),
+        "Inducement" => translated::<Inducement>(value, lang_id),
+        "Injury" => translated::<Injury>(value, lang_id),
+        "Keyword" => translated::<Keyword>(value, lang_id),
+        "Position" => translated::<Position>(value, lang_id),
+        "PrayerToNuffle" => translated::<PrayerToNuffle>(value, lang_id),
+        "Roster" => translated::<Roster>(value, lang_id),
+        "Skill" => translated::<Skill>(value, lang_id),
+        "SkillCategory" => translated::<SkillCategory>(value, lang_id),
+        "SpecialRule" => translated::<SpecialRule>(value, lang_id),
+        "Staff" => translated::<Staff>(value, lang_id),
+        "Version" => translated::<Version>(value, lang_id),
+        "Weather" => translated::<Weather>(value, lang_id),
+        _ => Err(Error::InvalidSerializedData),
+    };
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = supportedLanguages)]
+pub fn supported_languages_list(lang_id: &str) -> Result<String, String> {
+    respond(Ok(supported_languages()), lang_id)
+}
+
+#[wasm_bindgen(js_name = loadTranslations)]
+pub fn load_runtime_translations(lang_id: &str, source: &str) -> Result<String, String> {
+    respond(load_translations(lang_id, source), lang_id)
+}
+
+#[wasm_bindgen(js_name = pushGameAction)]
+pub fn push_game_action(game: &str, action: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let mut game: Game = parse(game)?;
+        let action: GameAction = parse(action)?;
+
+        action.apply(&mut game)?;
+
+        Ok(game)
+    })();
+
+    respond(result, lang_id)
+}
+
+#[wasm_bindgen(js_name = cancelLastGameEvent)]
+pub fn cancel_last_game_event(game: &str, lang_id: &str) -> Result<String, String> {
+    let result = (|| {
+        let mut game: Game = parse(game)?;
+
+        game.cancel_last_event()?;
+
+        Ok(game)
+    })();
+
+    respond(result, lang_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDateTime;
+
+    #[test]
+    fn team_drafting() {
+        let rosters: Value = serde_json::from_str(&list_rosters("V5S3", "en").unwrap()).unwrap();
+        assert!(
+            rosters
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|roster| roster["roster"].eq("Human") && roster["name"].eq("Human"))
+        );
+
+        let positions: Value =
+            serde_json::from_str(&position_definitions("V5S3", "Human", "fr").unwrap()).unwrap();
+        assert!(positions.as_array().unwrap().len() > 3);
+
+        let mut team = new_team("V5S3", "Human", "Reds", "Alice", "en").unwrap();
+        for _ in 0..11 {
+            team = buy_position(&team, "HumanLineman", "en").unwrap();
+        }
+        team = buy_staff(&team, "ReRoll", "en").unwrap();
+        team = name_captain(&team, 1, "en").unwrap();
+
+        let values: Value = serde_json::from_str(&team_values(&team, "en").unwrap()).unwrap();
+        assert_eq!(values["value"], 11 * 50000 + 50000);
+        assert_eq!(values["treasury"], 1000000 - 11 * 50000 - 50000);
+        assert_eq!(values["formatted_value"], "600k");
+
+        let validation: Value = serde_json::from_str(&check_team(&team, "en").unwrap()).unwrap();
+        assert_eq!(validation["compliant"], true);
+
+        let buyable: Value =
+            serde_json::from_str(&positions_buyable(&team, "en").unwrap()).unwrap();
+        assert!(buyable.as_array().unwrap().iter().any(|position| {
+            position["position"].eq("HumanLineman") && position["buyable"].eq(&true)
+        }));
+
+        let numbers: Vec<i32> = parse::<Team>(&team)
+            .unwrap()
+            .players
+            .iter()
+            .map(|(number, _)| *number)
+            .collect();
+        assert_eq!(numbers, (1..=11).collect::<Vec<i32>>());
+
+        team = buy_position(&team, "Ogre", "en").unwrap();
+        let error: Value =
+            serde_json::from_str(&name_captain(&team, 12, "en").unwrap_err()).unwrap();
+        assert_eq!(error["error"], "PlayerCanNotBeCaptain");
+
+        for _ in 0..4 {
+            team = buy_position(&team, "HumanLineman", "en").unwrap();
+        }
+        let error: Value =
+            serde_json::from_str(&buy_position(&team, "HumanLineman", "fr").unwrap_err()).unwrap();
+        assert_eq!(error["error"], "TooMuchPlayers");
+
+        assert_eq!(
+            localized_name("Skill", "Block", "fr").unwrap(),
+            "\"Blocage\"".to_string()
+        );
+        assert!(localized_name("Unknown", "Block", "en").is_err());
+
+        let languages: Value =
+            serde_json::from_str(&supported_languages_list("en").unwrap()).unwrap();
+        assert!(
+            languages
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|lang| lang.eq("de"))
+        );
+        let error: Value =
+            serde_json::from_str(&load_runtime_translations("pt", "Block = Bloqueio").unwrap_err())
+                .unwrap();
+        assert_eq!(error["error"], "UnsupportedLanguage");
+    }
+
+    #[test]
+    fn game_actions() {
+        let mut first_team: Team =
+            parse(&new_team("V5S3", "Human", "Reds", "Alice", "en").unwrap()).unwrap();
+        first_team.id = 1;
+        let mut second_team = first_team.clone();
+        second_team.id = 2;
+        second_team.coach = Coach::from_name("Bob");
+
+        let game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            NaiveDateTime::parse_from_str("2025-06-01 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap(),
+            &first_team,
+            &second_team,
+            false,
+        )
+        .unwrap();
+        let mut game = to_json(&game).unwrap();
+
+        game = push_game_action(&game, r#"{"action": "Start"}"#, "en").unwrap();
+        game = push_game_action(&game, r#"{"action": "KickingTeam", "team_id": 1}"#, "en").unwrap();
+        assert_eq!(parse::<Game>(&game).unwrap().events.len(), 1);
+
+        let error: Value = serde_json::from_str(
+            &push_game_action(
+                &game,
+                r#"{"action": "Penalties", "first_team_score": 3, "second_team_score": 3}"#,
+                "en",
+            )
+            .unwrap_err(),
+        )
+        .unwrap();
+        assert_eq!(error["error"], "GamePenaltiesShouldHaveAWinner");
+        assert!(push_game_action(&game, r#"{"action": "Unknown"}"#, "en").is_err());
+    }
+}
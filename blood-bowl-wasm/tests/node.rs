@@ -0,0 +1,17 @@
+use blood_bowl_wasm::{buy_position, check_team, name_captain, new_team};
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn drafting_from_javascript() {
+    let mut team = new_team("V5S3", "Human", "Reds", "Alice", "en").unwrap();
+    for _ in 0..11 {
+        team = buy_position(&team, "HumanLineman", "en").unwrap();
+    }
+    team = name_captain(&team, 1, "en").unwrap();
+
+    assert!(
+        check_team(&team, "en")
+            .unwrap()
+            .contains("\"compliant\":true")
+    );
+}
@@ -0,0 +1,1259 @@
+###############################################################
+# Errors
+###############################################################
+
+UnsupportedVersion = Nicht unterstützte Version
+NotEnoughPlayers = Nicht genug Spieler (mindestens 11)
+TooMuchPlayers = Zu viele Spieler (höchstens 16)
+TreasuryExceeded = Schatzkammer überschritten
+RosterNotExist = Dieser Kader existiert nicht
+NotEnoughFans = Nicht genug treue Fans
+TooMuchFans = Zu viele treue Fans
+PositionNotInRoster = Position in diesem Kader nicht verfügbar
+PositionNotDefined = Position nicht definiert
+PositionMaxExceeded = Höchstzahl für diese Position überschritten
+TooMuchBigMen = Zu viele Big Guys
+StaffNotInRoster = Dieses Personal existiert für diesen Kader nicht
+StaffExceededMaximum = Höchstzahl für dieses Personal überschritten
+IncorrectTreasury = Ungültige Schatzkammer
+PlayersMustMatchTeamVersion = Die Spieler müssen zur Version des Teams passen
+TeamsMustMatchGameVersion = Die Teams müssen zur Version des Spiels passen
+CanNotCreateGameBeforeAnotherAlreadyPlayed = Ein Spiel kann nicht vor einem bereits gespielten Spiel erstellt werden
+SameCoachForBothTeams = Beide Teams haben denselben Coach
+GameShouldHaveTwoTeams = An einem Spiel müssen zwei Teams teilnehmen
+TeamAlreadyPlayingGame = Ein Team spielt bereits ein Spiel
+StartGameBeforeAddingEvents = Ein Spiel muss gestartet werden, bevor Ereignisse hinzugefügt werden
+GameNeedsAWinner = Dieses Spiel braucht einen Sieger
+GamePenaltiesShouldHaveAWinner = Das Elfmeterschießen braucht einen Sieger
+GameAlreadyClosed = Das Spiel ist bereits abgeschlossen
+GameNotClosed = Das Spiel muss beendet und abgeschlossen sein
+JourneymanPositionNotFound = Position für Aushilfsspieler nicht gefunden
+NotAPlayingTeam = Dieses Team spielt nicht
+JourneymenShouldBeOkBeforeBuyingInducements = Aushilfsspieler müssen vor dem Kauf von Anreizen geregelt werden
+SkillNotAvailableForPlayer = Fertigkeit für diesen Spieler nicht verfügbar
+CaptainMissing = Das Team hat keinen Kapitän, bitte ernenne einen
+TooMuchCaptains = Das Team hat zu viele Kapitäne
+PlayerCanNotBeCaptain = Dieser Spieler kann nicht Kapitän des Teams sein
+NotEnoughTeamsForBracket = Nicht genug Teams, um einen Turnierbaum zu erstellen
+GameNotInBracket = Dieses Spiel gehört nicht zum Turnierbaum
+BracketMatchAlreadyFollowedUp = Die Folgespiele dieser Turnierbaum-Partie wurden bereits gespielt
+PlayerNotInTeam = Dieser Spieler gehört nicht zum Team
+PlayerNumberAlreadyUsed = Ein anderer Spieler des Teams trägt bereits diese Nummer
+TierNotAllowedInTournament = Diese Kaderstufe ist im Turnier nicht erlaubt
+TournamentBudgetExceeded = Turnierbudget überschritten
+TournamentSkillsExceeded = Zu viele Fertigkeiten für das Turnier
+TournamentSameSkillMaximumExceeded = Dieselbe Fertigkeit wurde für das Turnier zu oft gewählt
+TournamentEliteSkillNotAllowed = Elite-Fertigkeiten sind im Turnier nicht erlaubt
+TournamentSkillNotAllowed = Diese Fertigkeit ist im Turnier nicht erlaubt
+InvalidTeamFile = Ungültige Teamdatei
+InvalidTeamFileLine = Ungültige Zeile in der Teamdatei
+UnknownRoster = Unbekannter Kader
+UnknownPosition = Unbekannte Position
+UnknownSkill = Unbekannte Fertigkeit
+UnknownAdvancement = Unbekannter Aufstieg
+UnknownInjury = Unbekannte Verletzung
+UnknownStaff = Unbekanntes Personal
+InvalidSerializedData = Ungültige serialisierte Daten
+UnsupportedFormat = Nicht unterstütztes Serialisierungsformat
+DatabaseError = Datenbankfehler
+DatabaseUnavailable = Datenbank nicht erreichbar
+DatabaseConstraintViolation = Datenbankeinschränkung verletzt
+DatabaseMigrationFailed = Datenbankmigration fehlgeschlagen
+InvalidDatabaseData = Ungültige Daten in der Datenbank
+UnsupportedDatabase = Nicht unterstützte Datenbank
+SquareOutOfPitch = Das Feld liegt außerhalb des Spielfelds
+SquareNotInOwnHalf = Das Feld liegt nicht in der eigenen Hälfte
+SquareAlreadyOccupied = Das Feld ist bereits besetzt
+PlayerAlreadyOnPitch = Der Spieler ist bereits auf dem Spielfeld
+PlayerNotAvailable = Der Spieler ist nicht verfügbar
+TooMuchPlayersOnPitch = Zu viele Spieler auf dem Spielfeld
+NotEnoughPlayersOnPitch = Nicht genug Spieler auf dem Spielfeld
+NotEnoughPlayersOnLineOfScrimmage = Nicht genug Spieler an der Anspiellinie
+TooMuchPlayersInWideZone = Zu viele Spieler in einer Flügelzone
+GameNotInProgress = Das Spiel läuft nicht
+NotTeamTurn = Dieses Team ist nicht am Zug
+PlayerCanNotAct = Dieser Spieler kann jetzt nicht handeln
+InvalidPath = Ungültiger Bewegungspfad
+InvalidTarget = Ungültiges Ziel
+ActionAlreadyUsed = Diese Aktion wurde in diesem Zug bereits genutzt
+UnsupportedLanguage = Diese Sprache wird nicht unterstützt
+InvalidTranslations = Diese Übersetzungen sind kein gültiges Fluent
+TranslationsFileNotReadable = Die Übersetzungsdatei kann nicht gelesen werden
+TranslationsNotEnabled = Übersetzungen können ohne das Feature i18n nicht geladen werden
+
+###############################################################
+# Game status
+###############################################################
+
+Scheduled = Geplant
+PreGameSequence = Vorspielphase läuft
+GameInProgress = Spiel läuft
+PostGameSequence = Nachspielphase läuft
+WaitingForValidation = Wartet auf Bestätigung
+Closed = Abgeschlossen
+
+###############################################################
+# Versions
+###############################################################
+
+V1 = V1 - 1987
+V2 = V2 - 1991
+V3 = V3 - 1994
+V4 = V4 LRB - 2000
+V5 = V5 Saison 2 - 2020
+V5S3 = V5 Saison 3 - 2025
+
+###############################################################
+# Weather
+###############################################################
+
+SwelteringHeat = Brütende Hitze
+SwelteringHeat_description = Einige Spieler fallen in der unerträglichen Hitze in Ohnmacht! W3 zufällig bestimmte Spieler jedes Teams, die sich am Ende eines Drives auf dem Spielfeld befinden, werden in die Reserve gestellt. Sie müssen den nächsten Drive aussetzen.
+VerySunny = Sehr sonnig
+VerySunny_description = Ein herrlicher Tag, doch der klare Himmel und das grelle Sonnenlicht stören das Passspiel! Wende jedes Mal einen Modifikator von -1 an, wenn ein Spieler einen Test auf seine Passfähigkeit ablegt.
+PerfectConditions = Perfekte Bedingungen
+PerfectConditions_description = Weder zu kalt noch zu heiß. Ein warmer, trockener und leicht bewölkter Tag bietet perfekte Bedingungen für Blood Bowl.
+PouringRain = Strömender Regen
+PouringRain_description = Ein sintflutartiger Regenguss durchnässt die Spieler und macht den Ball sehr rutschig! Wende jedes Mal einen Modifikator von -1 an, wenn ein Spieler einen Geschicklichkeitstest ablegt, um den Ball zu fangen oder aufzunehmen oder einen Pass zu stören.
+Blizzard = Schneesturm
+Blizzard_description = Eisige Temperaturen und heftiger Schneefall machen den Boden tückisch. Wende jedes Mal einen Modifikator von -1 an, wenn ein Spieler versucht, ein zusätzliches Feld zu sprinten. Zudem können wegen der schlechten Sicht nur Kurze und Schnelle Pässe versucht werden.
+
+###############################################################
+# Inducements
+###############################################################
+
+PrayersToNuffle = Gebete an Nuffle
+PrayersToNuffle_description = Jedes Gebet an Nuffle erlaubt deinem Team vor dem Spiel einen Wurf auf der Tabelle der Gebete an Nuffle.
+PartTimeAssistantCoaches = Aushilfs-Co-Trainer
+PartTimeAssistantCoaches_description = Jeder Aushilfs-Co-Trainer addiert für die Dauer des Spiels 1 zu den Würfen für Brillantes Coaching deines Teams.
+TempAgencyCheerleaders = Leih-Cheerleader
+TempAgencyCheerleaders_description = Jeder Leih-Cheerleader addiert für die Dauer des Spiels 1 zu den Jubelnden Fans deines Teams.
+TeamMascot = Teammaskottchen
+TeamMascot_description = Einmal pro Halbzeit erlaubt dir das Maskottchen, einen W6 zu werfen, wenn ein Team-Wiederholungswurf benötigt wird: Bei 5+ erhältst du einen kostenlosen Wiederholungswurf.
+WeatherMage = Wettermagier
+WeatherMage_description = Einmal pro Spiel kann der Wettermagier zu Beginn eines deiner Spielzüge oder eines Drives das Wetter ändern, indem er auf der Wettertabelle würfelt; das Ergebnis darfst du wiederholen.
+BlitzersBestKegs = Blitzer's Best-Fässer
+BlitzersBestKegs_description = Jedes Blitzer's Best-Fass addiert 1 zum Wurf, mit dem am Ende jedes Drives geprüft wird, ob bewusstlose Spieler wieder zu sich kommen.
+Bribes = Bestechungsgelder
+Bribes_description = Wird ein Spieler deines Teams vom Schiedsrichter des Feldes verwiesen, kannst du ein Bestechungsgeld einsetzen. Wirf einen W6: Bei 2+ wird der Spieler nicht verwiesen und es kommt zu keinem Ballverlust. Bei 1 ist das Bestechungsgeld verloren.
+ExtraTeamTraining = Zusätzliches Teamtraining
+ExtraTeamTraining_description = Jedes zusätzliche Teamtraining gewährt deinem Team einen zusätzlichen Team-Wiederholungswurf, der in einer beliebigen Halbzeit eingesetzt werden kann.
+MortuaryAssistant = Leichenbestatter-Gehilfe
+MortuaryAssistant_description = Einmal pro Spiel kann der Leichenbestatter-Gehilfe einen misslungenen Regenerationswurf eines Spielers deines Teams mit dem Merkmal Regeneration wiederholen.
+PlagueDoctor = Pestdoktor
+PlagueDoctor_description = Einmal pro Spiel kann der Pestdoktor entweder einen misslungenen Regenerationswurf wiederholen oder einen bewusstlosen Spieler heilen und ihn in die Reserve stellen.
+RiotousRookies = Randalierende Neulinge
+RiotousRookies_description = Vor dem Spiel erhält dein Team für die Dauer des Spiels 2W3+1 Aushilfsspieler.
+WanderingApothecaries = Wandernde Apotheker
+WanderingApothecaries_description = Jeder wandernde Apotheker kann einmal pro Spiel genau wie der Apotheker des Teams eingesetzt werden.
+HalflingMasterChef = Halblings-Meisterkoch
+HalflingMasterChef_description = Wirf zu Beginn jeder Halbzeit drei W6. Für jede 4+ erhält dein Team einen Team-Wiederholungswurf und das gegnerische Team verliert einen.
+BiasedRefereeDodgyLeagueRep = Parteiischer Schiedsrichter: Zwielichtiger Ligavertreter
+BiasedRefereeDodgyLeagueRep_description = Immer wenn ein gegnerischer Spieler ein Foul begeht und vom Schiedsrichter nicht gesehen wird, wirf einen W6. Bei 5+ wird der Spieler trotzdem des Feldes verwiesen.
+WizardSportsWizard = Zauberer: Sportzauberer
+WizardSportsWizard_description = Einmal pro Spiel kann der Sportzauberer einen Zauber wie einen Feuerball oder Zap! gegen gegnerische Spieler wirken.
+StarPlayer = Starspieler
+StarPlayer_description = Starspieler sind berühmte Spieler, die als Anreiz für ein einzelnes Spiel angeheuert werden können. Sie spielen in deinem Team und gelten als Einzelgänger.
+MegaStarPlayer = Mega-Starspieler
+MegaStarPlayer_description = Mega-Stars sind die berühmtesten aller Starspieler. Sie können als Anreiz für ein einzelnes Spiel angeheuert werden und folgen denselben Regeln wie andere Starspieler.
+FamousCoachingStaff = Berühmtes Trainerteam
+FamousCoachingStaff_description = Berühmte Trainer, Apotheker und Spezialisten können als Anreiz für ein einzelnes Spiel angeheuert werden und bringen jeweils eine einzigartige Sonderregel mit.
+
+# Old
+
+BloodweiserKegs = Bloodweiser-Fässer
+BloodweiserKegs_description = Jedes Bloodweiser-Fass addiert 1 zum Wurf, mit dem am Ende jedes Drives geprüft wird, ob bewusstlose Spieler wieder zu sich kommen.
+SpecialPlays = Spezialspielzüge
+SpecialPlays_description = Jede Spezialspielzug-Karte erlaubt deinem Team, während des Spiels einen Spezialspielzug zu ziehen und einzusetzen.
+
+###############################################################
+# Prayers to Nuffle
+###############################################################
+
+TreacherousTrapdoor = Tückische Falltür
+TreacherousTrapdoor_description = Wirf bis zum Ende dieser Halbzeit jedes Mal einen W6, wenn ein Spieler ein Falltürfeld betritt. Bei 1 öffnet sich die Falltür, der Spieler fällt hindurch und wird in die Reserve gestellt. Hatte er den Ball, springt dieser vom Falltürfeld ab.
+FriendsWithTheRef = Freund des Schiris
+FriendsWithTheRef_description = Behandle bis zum Ende dieses Drives beim Protestieren gegen eine Entscheidung einen Wurf von 5-6 als Na, wenn du es so sagst und einen Wurf von 2-4 als Verschwinde.
+Stiletto = Stilett
+Stiletto_description = Bestimme zufällig einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Ende dieses Drives erhält er das Merkmal Erdolchen.
+IronMan = Eiserner Mann
+IronMan_description = Wähle einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Spielende verbessert sich sein Rüstungswert um 1, bis höchstens 11+.
+KnuckleDusters = Schlagringe
+KnuckleDusters_description = Wähle einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Ende dieses Drives erhält er die Fertigkeit Mächtiger Hieb (+1).
+BadHabits = Schlechte Angewohnheiten
+BadHabits_description = Bestimme zufällig W3 gegnerische Spieler, die für diesen Drive verfügbar sind und nicht das Merkmal Einzelgänger haben. Bis zum Ende dieses Drives erhalten sie das Merkmal Einzelgänger (2+).
+GreasyCleats = Schmierige Stollen
+GreasyCleats_description = Bestimme zufällig einen gegnerischen Spieler, der für diesen Drive verfügbar ist. Seine Schuhe wurden manipuliert: Bis zum Ende dieses Drives sinkt seine Bewegungsreichweite um 1.
+BlessingsOfNuffle = Nuffles Segen
+BlessingsOfNuffle_description = Wähle einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Spielende erhält er die Fertigkeit Profi.
+MolesUnderThePitch = Maulwürfe unter dem Spielfeld
+MolesUnderThePitch_description = Wende bis zum Ende dieser Halbzeit jedes Mal einen Modifikator von -1 an, wenn ein Spieler versucht, ein zusätzliches Feld zu sprinten, oder -2, wenn beide Coaches dieses Ergebnis erzielt haben.
+PerfectPassing = Perfektes Passspiel
+PerfectPassing_description = Bis zum Spielende erhält jeder Spieler deines Teams, der einen Pass vollendet, 2 SSP statt der üblichen 1 SSP.
+DazzlingCatching = Blendendes Fangen
+DazzlingCatching_description = Bis zum Spielende erhält jeder Spieler deines Teams, der einen Pass fängt, 1 SSP.
+FanInteraction = Fan-Interaktion
+FanInteraction_description = Erleidet bis zum Ende dieses Drives ein gegnerischer Spieler eine Verletzung, weil er in die Zuschauer gestoßen wurde, erhält dein Team dafür 2 SSP, als wäre er durch einen Block verletzt worden.
+FoulingFrenzy = Foulrausch
+FoulingFrenzy_description = Bis zum Spielende erhält jeder Spieler deines Teams, der mit einem Foul eine Verletzung verursacht, 2 SSP, als hätte er sie mit einem Block verursacht.
+ThrowARock = Steinwurf
+ThrowARock_description = Verzögert ein gegnerischer Spieler bis zum Ende dieses Drives das Spiel, kannst du am Ende seines Spielzugs einen W6 werfen. Bei 5+ wirft ein wütender Fan einen Stein auf ihn und er wird sofort niedergeschlagen.
+UnderScrutiny = Unter Beobachtung
+UnderScrutiny_description = Bis zum Ende dieser Halbzeit wird jeder gegnerische Spieler, der ein Foul begeht, automatisch vom Schiedsrichter gesehen, auch ohne Pasch.
+IntensiveTraining = Intensivtraining
+IntensiveTraining_description = Bestimme zufällig einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Spielende erhält er eine primäre Fertigkeit deiner Wahl.
+
+# Old
+
+BlessedStatueOfNuffle = Gesegnete Nuffle-Statue
+BlessedStatueOfNuffle_description = Wähle einen Spieler deines Teams, der für diesen Drive verfügbar ist und nicht das Merkmal Einzelgänger hat. Bis zum Spielende erhält er die Fertigkeit Profi.
+NecessaryViolence = Notwendige Gewalt
+NecessaryViolence_description = Bis zum Spielende erhält jeder Spieler deines Teams, der eine Verletzung verursacht, 3 SSP statt der üblichen 2 SSP.
+
+###############################################################
+# Roster names
+###############################################################
+
+AmazonRoster = Amazonen
+BlackOrcRoster = Schwarzorks
+BretonnianRoster = Bretonen
+ChaosChosenRoster = Chaos-Auserkorene
+ChaosDwarfRoster = Chaoszwerge
+ChaosRenegadeRoster = Chaos-Abtrünnige
+DarkElfRoster = Dunkelelfen
+DwarfRoster = Zwerge
+ElvenUnionRoster = Elfenunion
+GnomeRoster = Gnome
+GoblinRoster = Goblins
+HalflingRoster = Halblinge
+HighElfRoster = Hochelfen
+HumanRoster = Menschen
+ImperialNobilityRoster = Imperialer Adel
+KhorneRoster = Khorne
+LizardmenRoster = Echsenmenschen
+NecromanticHorrorRoster = Nekromantischer Schrecken
+NorseRoster = Nordmänner
+NurgleRoster = Nurgle
+OgreRoster = Oger
+OldWorldAllianceRoster = Allianz der Alten Welt
+OrcRoster = Orks
+ShamblingUndeadRoster = Schlurfende Untote
+SkavenRoster = Skaven
+SnotlingRoster = Snotlinge
+TombKingsRoster = Gruftkönige
+UnderworldDenizensRoster = Unterweltbewohner
+VampireRoster = Vampire
+WoodElfRoster = Waldelfen
+
+###############################################################
+# Special leagues and rules
+###############################################################
+
+# Special leagues
+
+BadlandsBrawl = Badlands-Keilerei
+BadlandsBrawl_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams der Badlands-Keilerei zur Verfügung stehen.
+ChaosClash = Chaos-Zusammenstoß
+ElvenKingdomsLeague = Liga der Elfenkönigreiche
+ElvenKingdomsLeague_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams der Liga der Elfenkönigreiche zur Verfügung stehen.
+HalflingThimbleCup = Halblings-Fingerhutpokal
+HalflingThimbleCup_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams des Halblings-Fingerhutpokals zur Verfügung stehen.
+LustrianSuperleague = Lustrianische Superliga
+LustrianSuperleague_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams der Lustrianischen Superliga zur Verfügung stehen.
+OldWorldClassic = Klassiker der Alten Welt
+OldWorldClassic_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams des Klassikers der Alten Welt zur Verfügung stehen.
+SylvanianSpotlight = Sylvanisches Rampenlicht
+SylvanianSpotlight_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams des Sylvanischen Rampenlichts zur Verfügung stehen.
+UnderworldChallenge = Unterwelt-Herausforderung
+UnderworldChallenge_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams der Unterwelt-Herausforderung zur Verfügung stehen.
+WoodlandLeague = Waldlandliga
+WorldsEdgeSuperleague = Weltrand-Superliga
+WorldsEdgeSuperleague_description = Dieses Team kann Starspieler und Anreize anheuern, die Teams der Weltrand-Superliga zur Verfügung stehen.
+
+# Special rules
+
+BrawlingBrutes = Raufende Rohlinge
+BrawlingBrutes_description = Dieses Team kann Spieler mit dem Schlüsselwort Big Guy anheuern, ohne dass sie auf die übliche Grenze angerechnet werden, und kann einmal pro Spiel ein einzelnes Ergebnis Beide Umgehauen wiederholen.
+BriberyAndCorruption = Bestechung und Korruption
+BriberyAndCorruption_description = Würfelt dieses Team beim Einsatz eines Bestechungsgeldes eine natürliche 1, darf der Wurf einmal pro Spiel wiederholt werden. Zudem kosten Bestechungsgelder dieses Team als Anreiz weniger.
+FavouredOf = Begünstigt von...
+FavouredOf_description = Bei der Erstellung dieses Teams muss der Coach wählen, welcher Chaosgott es begünstigt. Diese Wahl bestimmt, welche Starspieler und Anreize das Team anheuern kann.
+FavouredOfHashut = Begünstigt von Hashut
+FavouredOfHashut_description = Dieses Team wird von Hashut begünstigt und kann Starspieler und Anreize anheuern, die Teams mit dieser Sonderregel zur Verfügung stehen.
+FavouredOfKhorne = Begünstigt von Khorne
+FavouredOfKhorne_description = Dieses Team wird von Khorne begünstigt und kann Starspieler und Anreize anheuern, die Teams mit dieser Sonderregel zur Verfügung stehen.
+FavouredOfNurgle = Begünstigt von Nurgle
+FavouredOfNurgle_description = Dieses Team wird von Nurgle begünstigt und kann Starspieler und Anreize anheuern, die Teams mit dieser Sonderregel zur Verfügung stehen.
+LowCostLinemen = Billige Linienspieler
+LowCostLinemen_description = Die Linienspieler dieses Teams zählen bei der Berechnung der Anreize und des verfügbaren Kleingelds nicht zu seinem aktuellen Wert.
+MastersOfUndeath = Meister des Untodes
+MastersOfUndeath_description = Einmal pro Spiel kann der Cheftrainer dieses Teams einen Toten erwecken: Erleidet ein gegnerischer Spieler das Ergebnis TOT, kann er sich dem Team für den Rest des Spiels als neuer Zombie-Linienspieler anschließen.
+Swarming = Schwärmen
+Swarming_description = Während jeder Sequenz zu Beginn eines Drives kannst du nach Schritt 2 und vor Schritt 3 W3 Spieler mit diesem Merkmal aus der Reserve nehmen und auf dem Spielfeld aufstellen, sofern sie weder in einer Flügelzone noch an der Anspiellinie stehen.
+TeamCaptain = Mannschaftskapitän
+TeamCaptain_description = Bei der Erstellung dieses Teams muss ein Spieler zum Mannschaftskapitän ernannt werden. Solange der Kapitän auf dem Spielfeld ist, kannst du bei jedem Einsatz eines Team-Wiederholungswurfs einen W6 werfen: Bei 6 geht der Wiederholungswurf nicht verloren.
+VampireLord = Vampirfürst
+VampireLord_description = Dieses Team kann einen Pestdoktor oder einen Leichenbestatter-Gehilfen als Anreiz anheuern, und seine Vampire können sich von Leibeigenen nähren, um ihren Blutdurst zu stillen.
+
+###############################################################
+# Staff
+###############################################################
+
+Cheerleader = Cheerleader
+AssistantCoach = Co-Trainer
+Apothecary = Apotheker
+ReRoll = Wiederholungswürfe
+
+###############################################################
+# Famous Coaching Staff
+###############################################################
+
+AyleenAndar = Ayleen Andar
+FinkDaFixer = Fink da Fixer
+GalandrilSilverwater = Galandril Silverwater
+JosefBugman = Josef Bugman
+KariColdsteel = Kari Coldsteel
+KrotShockwhisker = Krot Shockwhisker
+MungoSpinecracker = Mungo Spinecracker
+PapaSkullbones = Papa Skullbones
+ProfessorFronkelheim = Professor Fronkelheim
+SchielundScharlitan = Schielund Scharlitan
+
+###############################################################
+# Position names
+###############################################################
+
+# Keywords & Common
+
+All = Alle
+
+Animal = Tier
+Animal_description = Tiere sind Bestien, die zusammen mit ihren Führern aufs Feld kommen, wie der Waldfuchs der Gnome oder der nordische Bierkeiler.
+Beastman = Tiermensch
+Beastman_description = Tiermenschen sind gehörnte Anhänger der Dunklen Götter. Sie bilden den Kern der Chaos-Auserkorenen und spielen als Khorngors und Pestigors für Khorne und Nurgle.
+Construct = Konstrukt
+Construct_description = Konstrukte sind künstliche, von dunkler Magie belebte Körper, wie die Fleischgolems der Nekromantischen Schrecken.
+Dryad = Dryade
+Dryad_description = Dryaden sind Baumgeister der tiefen Wälder. Nur der Starspieler Willow Rosebark hat dieses Schlüsselwort.
+Dwarf = Zwerg
+Dwarf_description = Zwerge sind kleine, sture und schwer gepanzerte Spieler der Zwergen-, Chaoszwergen- und Allianz-der-Alten-Welt-Teams. Ihr alter Groll hat ihnen mehr als einen Hass eingebracht.
+Dwarfs = Zwerge
+Elf = Elf
+Elf_description = Elfen sind wendige, anmutige Spieler, die auf Tempo und Pässe setzen, aufgestellt von Dunkelelfen, Hochelfen, Waldelfen und der Elfischen Union.
+Ghoul = Ghul
+Ghoul_description = Ghule sind schnelle, wilde Aasfresser, die als Läufer für Untote und Nekromantische Schrecken spielen.
+Gnoblar = Gnoblar
+Gnoblar_description = Gnoblars sind winzige, feige Grünhäute, die die Linie der Oger-Teams auffüllen und den Großen aus dem Weg gehen.
+Gnome = Gnom
+Gnome_description = Gnome sind kleine, gerissene Trickser und Illusionisten und bilden den Kern der Gnomen-Teams.
+Goblin = Goblin
+Goblin_description = Goblins sind kleine, hinterhältige Grünhäute. Sie stellen eigene Teams und spielen neben Orks, Chaoszwergen und Chaos-Renegaten, oft mit Geheimwaffen.
+Halfling = Halbling
+Halfling_description = Halblinge sind kleine Leute, denen Essen weit wichtiger ist als Football. Sie bilden Halblings-Teams und können als Fänger in Menschen-Teams spielen.
+Halflings = Halblinge
+Human = Mensch
+Human_description = Menschen sind die verbreitetsten Spieler und stellen unter anderem Menschen-, Amazonen-, Bretonen-, Imperialer-Adel-, Nordmänner- und Allianz-der-Alten-Welt-Teams.
+Humans = Menschen
+Lizardman = Echsenmensch
+Lizardman_description = Echsenmenschen sind die kaltblütigen Diener der Alten: flinke Skinks, zähe Saurus und mächtige Kroxigors.
+Minotaur = Minotaurus
+Minotaur_description = Minotauren sind riesige, stierköpfige Große Kerle voller Ungezügelter Wut, angeheuert von Chaos-Auserkorenen, Chaoszwergen und Chaos-Renegaten.
+Ogre = Oger
+Ogre_description = Oger sind massige, hungrige Große Kerle, die eigene Teams stellen und sich an Chaos-, Menschen- und Allianz-der-Alten-Welt-Teams verdingen.
+Orc = Ork
+Orc_description = Orks sind zähe, aggressive Grünhäute, die für Ork-, Schwarzork- und Chaos-Renegaten-Teams ein brutales Laufspiel spielen.
+Skaven = Skaven
+Skaven_description = Skaven sind schnelle, verräterische Rattenmenschen. Gossenläufer, Klanratten und Rattenoger spielen für Skaven, Unterweltbewohner und Chaos-Renegaten.
+Skeleton = Skelett
+Skeleton_description = Skelette sind die wiederbelebten Gebeine lange Verstorbener, langsam aber schwer kleinzukriegen, aufgestellt von Untoten und Gruftkönigen.
+Snakeman = Schlangenmensch
+Snakeman_description = Schlangenmenschen sind schlangenartige Wesen. Nur der Starspieler Boa Kon'ssstriktr hat dieses Schlüsselwort.
+Snotling = Snotling
+Snotling_description = Snotlinge sind die kleinsten aller Grünhäute und überschwemmen das Feld für Snotling- und Unterwelt-Teams, zusammen mit ihren Hüpfern, Pilzschleudern und Pumpwagen.
+Spawn = Brut
+Spawn_description = Brut sind mutierte, hirnlose Schrecken, Geschenke der Dunklen Götter, wie die Blutbrut des Khorne und die Seuchenbrut des Nurgle.
+Spite = Spite
+Spite_description = Spites sind winzige Waldgeister. Nur der Starspieler Swiftvine Glimmershard hat dieses Schlüsselwort.
+Squirrel = Eichhörnchen
+Squirrel_description = Dieses Schlüsselwort gehört einem einzigen pelzigen Starspieler: Akhorne dem Eichhörnchen.
+Thrall = Leibeigener
+Thrall_description = Leibeigene sind die menschlichen Diener der Vampir-Teams, von denen sich die Vampire nähren, wenn sie der Blutdurst packt.
+Treeman = Baummensch
+Treeman_description = Baummenschen sind uralte, langsame Baumgeister, die als Große Kerle für Waldelfen, Halblinge, Gnome und die Allianz der Alten Welt spielen.
+Troll = Troll
+Troll_description = Trolle sind dumme, regenerierende Große Kerle, die für Grünhaut-, Chaos- und Unterwelt-Teams spielen. Manche Spieler hegen Hass auf Trolle.
+Undead = Untoter
+Undead_description = Untote Spieler wurden durch dunkle Magie aus dem Grab geholt und spielen für Untote, Nekromantische Schrecken, Gruftkönige und Vampire. Manche Spieler hegen Hass auf Untote.
+Vampire = Vampir
+Vampire_description = Vampire sind unsterbliche Adlige, schnell und stark, aber vom Blutdurst getrieben, sich an ihren Leibeigenen zu nähren.
+Werewolf = Werwolf
+Werewolf_description = Werwölfe sind wilde Gestaltwandler, aufgestellt von Nekromantischen Schrecken.
+Wraith = Schemen
+Wraith_description = Schemen sind geisterhafte Untote, die für Nekromantische Schrecken über das Feld gleiten.
+Yhetee = Yhetee
+Yhetee_description = Yhetees sind wilde Große Kerle aus dem eisigen Norden, die für Nordmänner-Teams spielen.
+Zoat = Zoat
+Zoat_description = Zoats sind seltene, scheue Waldwesen. Nur der Starspieler Zolcath der Zoat hat dieses Schlüsselwort.
+Zombie = Zombie
+Zombie_description = Zombies sind langsame, schlurfende Leichen und die billigen Linienspieler von Untoten und Nekromantischen Schrecken.
+
+Lineman = Linienspieler
+Lineman_description = Linienspieler sind das Rückgrat eines Teams. Sie können in großer Zahl angeheuert werden und haben Zugang zu den grundlegenden Fertigkeitskategorien des Teams.
+Thrower = Werfer
+Thrower_description = Werfer sind die Spielmacher des Teams und auf das Passen des Balls spezialisiert.
+Catcher = Fänger
+Catcher_description = Fänger sind darauf spezialisiert, Pässe zu fangen und den Ball in die Endzone zu tragen.
+Blitzer = Blitzer
+Blitzer_description = Blitzer verbinden Schnelligkeit und Stärke, um die gegnerische Linie zu durchbrechen und den Ballträger zu jagen.
+Blocker = Blocker
+Blocker_description = Blocker sind starke, zähe Spieler, die die Anspiellinie halten und Gegner umhauen.
+Runner = Läufer
+Runner_description = Läufer sind schnelle, gewandte Spieler, die den Ball übers Spielfeld bringen.
+BigGuy = Big Guy
+BigGuy_description = Big Guys sind riesige, mächtige und oft unzuverlässige Spieler. Ein Team darf nur eine begrenzte Anzahl davon aufnehmen.
+Special = Spezial
+Special_description = Spezialspieler erfüllen in ihrem Team eine einzigartige Rolle, die zu keinem anderen Positions-Schlüsselwort passt.
+
+# Amazon
+
+EagleWarriorLinewoman = Adlerkriegerin-Linienspielerin
+PiranhaWarriorBlitzer = Piranhakriegerin-Blitzerin
+PythonWarriorThrower = Pythonkriegerin-Werferin
+JaguarWarriorBlocker = Jaguarkriegerin-Blockerin
+
+# Black Orc
+
+GoblinBruiserLineman = Goblin-Schläger-Linienspieler
+BlackOrc = Schwarzork
+TrainedTroll = Abgerichteter Troll
+
+# Bretonnian
+
+Squires = Knappe
+KnightCatcher = Ritter-Fänger
+KnightThrower = Ritter-Werfer
+GrailKnight = Gralsritter
+
+# Chaos Chosen
+
+BeastmanRunnerLineman = Tiermensch-Läufer-Linienspieler
+ChosenBlocker = Auserkorener Blocker
+ChaosTroll = Chaostroll
+ChaosOgre = Chaosoger
+
+# Chaos Dwarf
+
+HobgoblinLineman = Hobgoblin-Linienspieler
+HobgoblinSneakyStabba = Hinterhältiger Hobgoblin-Stecher
+ChaosDwarfBlocker = Chaoszwerg-Blocker
+ChaosDwarfFlamesmith = Chaoszwerg-Flammenschmied
+BullCentaurBlitzer = Stierzentaur-Blitzer
+
+# Chaos Renegade
+
+RenegadeHumanLineman = Abtrünniger menschlicher Linienspieler
+RenegadeHumanThrower = Abtrünniger menschlicher Werfer
+RenegadeGoblin = Abtrünniger Goblin
+RenegadeOrc = Abtrünniger Ork
+RenegadeSkaven = Abtrünniger Skaven
+RenegadeDarkElf = Abtrünniger Dunkelelf
+RenegadeTroll = Abtrünniger Troll
+RenegadeOgre = Abtrünniger Oger
+RenegadeMinotaur = Abtrünniger Minotaurus
+RenegadeRatOgre = Abtrünniger Rattenoger
+
+# Dark Elf
+
+DarkElfLineman = Dunkelelf-Linienspieler
+Assassin = Assassine
+WitchElf = Hexenkriegerin
+
+# Dwarf
+
+DwarfBlockerLineman = Zwerg-Linienspieler
+TrollSlayer = Trollslayer
+Deathroller = Todeswalze
+
+# Gnome
+
+GnomeLineman = Gnom-Linienspieler
+GnomeBeastmaster = Gnom-Bestienmeister
+GnomeIllusionist = Gnom-Illusionist
+WoodlandFox = Waldfuchs
+AlternForestTreeman = Baummensch aus dem Alternwald
+
+# Goblin
+
+GoblinLineman = Goblin-Linienspieler
+Bomma = Bomma
+Looney = Irrer
+Fanatic = Fanatiker
+Pogoer = Pogo-Springer
+Ooligan = 'Ooligan
+DoomDiver = Todesflieger
+
+# Halfling
+
+HalflingHopefulLineman = Hoffnungsvoller Halbling-Linienspieler
+HalflingHefty = Kräftiger Halbling
+HalflingCatcher = Halbling-Fänger
+
+# High Elf
+
+HighElfLineman = Hochelf-Linienspieler
+PhoenixWarrior = Phönixkrieger
+DragonPrince = Drachenprinz
+WhiteLion = Weißer Löwe
+
+# Human
+
+HumanLineman = Menschlicher Linienspieler
+HalflingHopeful = Hoffnungsvoller Halbling
+
+# Imperial Nobility
+
+ImperialRetainerLineman = Imperialer Gefolgsmann-Linienspieler
+ImperialThrower = Imperialer Werfer
+NobleBlitzer = Adliger Blitzer
+Bodyguard = Leibwächter
+
+# Khorne
+
+BloodbornMarauderLineman = Blutgeborener Plünderer-Linienspieler
+Khorngor = Khorngor
+Bloodseeker = Blutsucher
+Bloodspawn = Blutbrut
+
+# Lizardmen
+
+SkinkRunnerLineman = Skink-Läufer-Linienspieler
+ChameleonSkink = Chamäleon-Skink
+SaurusBlocker = Saurus-Blocker
+Kroxigor = Kroxigor
+
+# Necromantic Horror
+
+ZombieLineman = Zombie-Linienspieler
+GhoulRunner = Ghul-Läufer
+FleshGolem = Fleischgolem
+
+# Norse
+
+NorseRaiderLineman = Nordmann-Plünderer-Linienspieler
+BeerBoar = Bierkeiler
+NorseBerzerker = Nordmann-Berserker
+Valkyrie = Walküre
+Ulfwerener = Ulfwerener
+
+# Nurgle
+
+RotterLineman = Fäulnis-Linienspieler
+Pestigor = Pestigor
+Bloater = Aufgedunsener
+Rotspawn = Fäulnisbrut
+
+# Ogre
+
+GnoblarLineman = Gnoblar-Linienspieler
+OgreRuntPunter = Oger-Kümmerling-Kicker
+OgreBlocker = Oger-Blocker
+
+# Old World Alliance
+
+OldWorldHumanLineman = Menschlicher Linienspieler der Alten Welt
+OldWorldHumanThrower = Menschlicher Werfer der Alten Welt
+OldWorldHumanCatcher = Menschlicher Fänger der Alten Welt
+OldWorldDwarfLineman = Zwerg-Linienspieler der Alten Welt
+OldWorldDwarfBlitzer = Zwerg-Blitzer der Alten Welt
+OldWorldDwarfBlocker = Zwerg-Blocker der Alten Welt
+OldWorldDwarfRunner = Zwerg-Läufer der Alten Welt
+OldWorldHumanBlitzer = Menschlicher Blitzer der Alten Welt
+OldWorldDwarfTrollSlayer = Zwergischer Trollslayer der Alten Welt
+OldWorldHalflingHopefuls = Hoffnungsvolle Halblinge der Alten Welt
+
+# Orc
+
+OrcLineman = Ork-Linienspieler
+BigUn = Großer
+UntrainedTroll = Ungezähmter Troll
+
+# Shambling Undead
+
+SkeletonLineman = Skelett-Linienspieler
+WightBlitzer = Grabunhold-Blitzer
+Mummy = Mumie
+
+# Skaven
+
+SkavenClanratLineman = Skaven-Klanratten-Linienspieler
+GutterRunner = Gossenläufer
+RatOgre = Rattenoger
+
+# Snotling
+
+SnotlingLineman = Snotling-Linienspieler
+FungusFlinga = Pilzschleuderer
+FunHoppa = Spaßhüpfer
+StiltyRunna = Stelzenläufer
+PumpWagon = Pumpwagen
+
+# Tomb Kings
+
+AnointedThrower = Gesalbter Werfer
+AnointedBlitzer = Gesalbter Blitzer
+TombGuardian = Gruftwächter
+
+# Underworld Denizens
+
+UnderworldGoblinLineman = Unterwelt-Goblin-Linienspieler
+UnderworldSnotling = Unterwelt-Snotling
+SkavenClanrat = Skaven-Klanratte
+SkavenThrower = Skaven-Werfer
+SkavenBlitzer = Skaven-Blitzer
+UnderworldTroll = Unterwelt-Troll
+MutantRatOgre = Mutierter Rattenoger
+
+# Vampire
+
+ThrallLineman = Leibeigener Linienspieler
+VampireRunner = Vampir-Läufer
+VampireBlitzer = Vampir-Blitzer
+VampireThrower = Vampir-Werfer
+Vargheist = Vargheist
+
+# Wood Elf
+
+WoodElfLineman = Waldelf-Linienspieler
+Wardancer = Kriegstänzer
+LorenForestTreeman = Baummensch aus dem Lorenwald
+
+# Others
+
+Journeyman = Aushilfsspieler
+
+# Stars
+
+AkhorneTheSquirrel = Akhorne The Squirrel
+AnqiPanqi = Anqi Panqi
+BarikFarblast = Barik Farblast
+BilerotVomitflesh = Bilerot Vomitflesh
+BoaKonSsstriktr = Boa Kon'ssstriktr
+BomberDribblesnot = Bomber Dribblesnot
+BryceTheSliceCambuel = Bryce 'The Slice' Cambuel
+CaptainKarinaVonRiesz = Captain Karina Von Riesz
+CindyPiewhistle = Cindy Piewhistle
+CountLuthorVonDrakenborg = Count Luthor von Drakenborg
+DeeprootStrongbranch = Deeproot Strongbranch
+DriblAndDrull = Dribl and Drull
+Dribl = Dribl
+Drull = Drull
+EldrilSidewinder = Eldril Sidewinder
+EstelleLaVeneaux = Estelle la Veneaux
+FrankNStein = Frank 'n' Stein
+FungusTheLoon = Fungus the Loon
+GlartSmashrip = Glart Smashrip
+GlorielSummerbloom = Gloriel Summerbloom
+GlotlStop = Glotl Stop
+GrakAndCrumbleberry = Grak and Crumbleberry
+Grak = Grak
+Crumbleberry = Crumbleberry
+GrashnakBlackhoof = Grashnak Blackhoof
+GretchenWachterTheBloodBowlWidow = Gretchen Wächter 'The Blood Bowl Widow'
+GriffOberwald = Griff Oberwald
+GrimIronjaw = Grim Ironjaw
+GrombrindalTheWhiteDwarf = Grombrindal, The White Dwarf
+GufflePussmaw = Guffle Pussmaw
+HakflemSkuttlespike = Hakflem Skuttlespike
+HelmutWulf = Helmut Wulf
+HTharkTheUnstoppable = H'thark the Unstoppable
+IvanTAnimalDeathshroud = Ivan 'the Animal' Deathshroud
+IvarEriksson = Ivar Eriksson
+JeremiahKool = Jeremiah Kool
+JordellFreshbreeze = Jordell Freshbreeze
+KarlaVonKill = Karla Von Kill
+KirothKrakeneye = Kiroth Krakeneye
+KreekTheVerminatorRustgouger = Kreek 'the Verminator' Rustgouger
+LordBorakTheDespoiler = Lord Borak the Despoiler
+MapleHighgrove = Maple Highgrove
+MaxSpleenripper = Max Spleenripper
+MightyZug = Mighty Zug
+MorgNThorg = Morg 'n' Thorg
+NobblaBlackwart = Nobbla Blackwart
+PuggyBaconbreath = Puggy Baconbreath
+RashnakBackstabber = Rashnak Backstabber
+RipperBolgrot = Ripper Bolgrot
+RodneyRoachbait = Rodney Roachbait
+RowanaForestfoot = Rowana Forestfoot
+RoxannaDarknail = Roxanna Darknail
+RumbelowSheepskin = Rumbelow Sheepskin
+ScrappaSorehead = Scrappa Sorehead
+ScylaAnfingrimm = Scyla Anfingrimm
+SkitterStabStab = Skitter Stab-stab
+SkrorgSnowpelt = Skrorg Snowpelt
+SkrullHalfheight = Skrull Halfheight
+SwiftvineGlimmershard = Swiftvine Glimmershard
+TheBlackGobbo = The Black Gobbo
+TheSwiftTwins = The Swift Twins
+LucienSwift = Lucien Swift
+ValenSwift = Valen Swift
+ThorssonStoutmead = Thorsson Stoutmead
+VaragGhoulChewer = Varag Ghoul-Chewer
+WilhelmChaney = Wilhelm Chaney
+WillowRosebark = Willow Rosebark
+WithergraspDoubledrool = Withergrasp Doubledrool
+ZolcathTheZoat = Zolcath the Zoat
+ZzhargMadeye = Zzharg Madeye
+
+###############################################################
+# Injuries
+###############################################################
+
+Stunned = Benommen
+KO = K.O.
+BadlyHurt = Schwer verletzt
+SeriouslyHurt = Ernsthaft verletzt
+SeriousInjury = Ernsthafte Verletzung
+HeadInjury = Kopfverletzung
+SmashedKnee = Zertrümmertes Knie
+BrokenArm = Gebrochener Arm
+DislocatedHip = Ausgerenkte Hüfte
+NeckInjury = Nackenverletzung
+Dead = Tot
+
+# Old
+
+DislocatedShoulder = Ausgerenkte Schulter
+
+###############################################################
+# Successes
+###############################################################
+
+PassingCompletion = Vollendeter Pass
+ThrowingCompletion = Vollendeter Wurf
+LandingOnFeet = Auf den Füßen gelandet
+Deflection = Ablenkung
+Interception = Abfangen
+Casualty = Verletzung
+Touchdown = Touchdown
+MostValuablePlayer = Wertvollster Spieler
+StarPlayerPoint = Starspielerpunkt
+
+###############################################################
+# Advancement choices
+###############################################################
+
+RandomPrimarySkill = Zufällige primäre Fertigkeit ({ $skill_category })
+RandomSecondarySkill = Zufällige sekundäre Fertigkeit ({ $skill_category })
+ChosenPrimarySkill = Gewählte primäre Fertigkeit
+ChosenSecondarySkill = Gewählte sekundäre Fertigkeit
+RandomCharacteristic = Zufälliges Attribut
+
+###############################################################
+# Advancements
+###############################################################
+
+ChosenSkill = Gewählte Fertigkeit ({ $skill })
+RandomSkill = Zufällige Fertigkeit ({ $skill })
+MovementAllowance = Bewegungsreichweite
+Strength = Stärke
+Agility = Geschicklichkeit
+PassingAbility = Passfähigkeit
+ArmourValue = Rüstungswert
+
+###############################################################
+# Skill Categories
+###############################################################
+
+General = Allgemein
+Mutation = Mutation
+Pass = Passen
+Pass_description = Dieser Spieler darf einen misslungenen Passfähigkeitstest bei einer Passaktion wiederholen.
+Devious = Hinterlist
+Trait = Merkmal
+
+###############################################################
+# Skills & Traits
+###############################################################
+
+# General
+
+Block = Abblocken
+Block_description = Wird bei einer Blockaktion das Ergebnis Beide Umgehauen angewendet, kann dieser Spieler es ignorieren und wird nicht niedergeschlagen.
+Dauntless = Unerschrocken
+Dauntless_description = Blockt dieser Spieler ein Ziel mit höherer Stärke, wirf einen W6 und addiere die Stärke dieses Spielers. Übersteigt die Summe die Stärke des Ziels, steigt die Stärke dieses Spielers für die Dauer des Blocks auf dessen Wert.
+Fend = Abwehr
+Fend_description = Wird dieser Spieler durch ein Blockwürfelergebnis zurückgestoßen, kann er verhindern, dass der Spieler, der ihn zurückgestoßen hat, nachsetzt. Abwehr kann bei einer Blitzaktion nicht gegen einen Spieler mit Juggernaut eingesetzt werden.
+Frenzy = Raserei
+Frenzy_description = Bei jeder Blockaktion muss dieser Spieler nachsetzen, wenn das Ziel zurückgestoßen wird. Steht das Ziel danach noch und konnte dieser Spieler nachsetzen, muss er einen zweiten Block gegen dasselbe Ziel ausführen.
+Kick = Präziser Schuss
+Kick_description = Wird dieser Spieler beim Anstoß als Kicker bestimmt, kannst du das Ergebnis des W6, der die Abweichung des Balls bestimmt, halbieren (abgerundet).
+Pro = Profi
+Pro_description = Einmal pro Spielzug kann dieser Spieler versuchen, einen für ihn geworfenen Würfel zu wiederholen. Wirf einen W6: Bei 3+ darf der Würfel wiederholt werden, bei 1-2 bleibt das ursprüngliche Ergebnis. Für diesen Würfel kann danach kein Team-Wiederholungswurf genutzt werden.
+SteadyFooting = Sicherer Stand
+SteadyFooting_description = Würde dieser Spieler niedergeschlagen oder hingelegt, wirf einen W6. Bei 6 behält er das Gleichgewicht und bleibt stehen.
+StripBall = Ball abnehmen
+StripBall_description = Blockt dieser Spieler einen gegnerischen Ballträger, lässt dieser bei einem Ergebnis Zurückgestoßen den Ball in dem Feld fallen, in das er gestoßen wird.
+SureHands = Sichere Hände
+SureHands_description = Dieser Spieler darf jeden misslungenen Versuch, den Ball aufzunehmen, wiederholen. Zudem kann Ball abnehmen nicht gegen ihn eingesetzt werden.
+Tackle = Tackle
+Tackle_description = Versucht ein aktiver gegnerischer Spieler, aus einem Feld auszuweichen, in dem er von Spielern mit dieser Fertigkeit markiert wird, kann er die Fertigkeit Ausweichen nicht einsetzen.
+Taunt = Provozieren
+Taunt_description = Blockt ein Gegner diesen Spieler und wird dieser zurückgestoßen, kannst du den Angreifer zum Nachsetzen zwingen.
+Wrestle = Ringen
+Wrestle_description = Dieser Spieler kann diese Fertigkeit einsetzen, wenn das Ergebnis Beide Umgehauen angewendet wird, ob er blockt oder geblockt wird. Statt des normalen Ergebnisses werden beide Spieler hingelegt und es wird kein Rüstungswurf durchgeführt.
+
+# Agility
+
+Catch = Fangen
+Catch_description = Dieser Spieler darf einen misslungenen Geschicklichkeitstest beim Fangen des Balls wiederholen.
+Defensive = Defensiv
+Defensive_description = Während des gegnerischen Spielzugs können von diesem Spieler markierte gegnerische Spieler die Fertigkeit Schutz nicht einsetzen.
+DivingCatch = Hechtfang
+DivingCatch_description = Dieser Spieler kann versuchen, den Ball zu fangen, wenn er nach einer Streuung oder Abweichung in einem Feld seiner Tacklezone landet. Zudem erhält er +1 beim Fangen eines präzisen Passes, wenn er im Zielfeld steht.
+DivingTackle = Hechttackle
+DivingTackle_description = Besteht ein aktiver Gegner seinen Geschicklichkeitstest, um aus einem von diesem Spieler markierten Feld auszuweichen oder zu springen, kannst du diese Fertigkeit einsetzen. Der Gegner zieht 2 vom Ergebnis ab, dann wird dieser Spieler im verlassenen Feld hingelegt.
+Dodge = Ausweichen
+Dodge_description = Einmal pro Spielzug darf dieser Spieler während seiner Aktivierung einen misslungenen Geschicklichkeitstest beim Ausweichen wiederholen. Zudem kann er diese Fertigkeit einsetzen, wenn er geblockt wird und das Ergebnis Stolpern angewendet wird.
+HitAndRun = Zuschlagen und Weglaufen
+HitAndRun_description = Nach einer Blockaktion darf dieser Spieler, sofern er noch steht, sofort ein Feld kostenlos ziehen und dabei Tacklezonen ignorieren. Danach darf er weder einen Gegner markieren noch von einem markiert werden.
+JumpUp = Aufspringen
+JumpUp_description = Liegt dieser Spieler am Boden, darf er kostenlos aufstehen. Liegt er bei seiner Aktivierung, kann er zudem versuchen aufzuspringen und eine Blockaktion auszuführen, indem er einen Geschicklichkeitstest mit +1 besteht.
+Leap = Weiter Sprung
+Leap_description = Statt über ein Feld mit einem liegenden oder benommenen Spieler zu springen, kann dieser Spieler über ein beliebiges angrenzendes Feld springen, auch wenn es leer ist oder ein stehender Spieler darin steht. Zudem verringert er negative Modifikatoren auf den Geschicklichkeitstest beim Springen um 1.
+SideStep = Seitschritt
+SideStep_description = Wird dieser Spieler zurückgestoßen, wählt nicht der gegnerische Coach das Feld, sondern sein eigener Coach wählt ein beliebiges freies angrenzendes Feld.
+SafePairOfHands = Sicheres Händchen
+SafePairOfHands_description = Wird dieser Spieler mit dem Ball niedergeschlagen oder hingelegt, springt der Ball nicht ab. Stattdessen kannst du ihn in ein freies Feld neben diesem Spieler legen.
+Sprint = Sprinten
+Sprint_description = Bei jeder Aktion mit Bewegung darf dieser Spieler dreimal statt der üblichen zweimal sprinten.
+SureFeet = Sicherer Tritt
+SureFeet_description = Einmal pro Spielzug darf dieser Spieler während seiner Aktivierung den W6 beim Sprinten wiederholen.
+
+# Devious
+
+DirtyPlayer = Schmutziger Spieler
+DirtyPlayer_description = Begeht dieser Spieler ein Foul, kannst du auf den Rüstungs- oder Verletzungswurf gegen das Opfer +1 anwenden.
+EyeGouge = Augenstechen
+EyeGouge_description = Blockt dieser Spieler und wird das Ziel zurückgestoßen, verliert es bis zu seiner nächsten Aktivierung seine Tacklezone.
+Fumblerooski = Fumblerooski
+Fumblerooski_description = Führt dieser Spieler mit dem Ball eine Bewegungs- oder Blitzaktion aus, kann er den Ball ohne Geschicklichkeitstest in einem beliebigen Feld fallen lassen, das er während seiner Bewegung verlässt.
+LethalFlight = Tödlicher Flug
+LethalFlight_description = Wird dieser Spieler von einem Mitspieler geworfen und landet auf einem Feld mit einem Gegner, kannst du auf den Rüstungs- oder Verletzungswurf gegen diesen +1 anwenden.
+LoneFouler = Einsamer Foulspieler
+LoneFouler_description = Begeht dieser Spieler ein Foul ohne offensive Unterstützung, darf er den Rüstungswurf wiederholen.
+PileDriver = Rammbock
+PileDriver_description = Schlägt dieser Spieler einen Gegner durch einen Block nieder, kann er sofort ein kostenloses Foul gegen ihn begehen. Er muss nach dem Block stehen und darf sich nicht bewegt haben. Danach endet seine Aktivierung.
+PutTheBootIn = Nachtreten
+PutTheBootIn_description = Unterstützt dieser Spieler ein Foul, addiert er +2 statt +1 zum Rüstungswurf.
+QuickFoul = Schnelles Foul
+QuickFoul_description = Begeht dieser Spieler ein Foul, muss seine Aktivierung danach nicht enden. Er darf sich weiterbewegen, wenn er noch Bewegung übrig hat.
+Saboteur = Saboteur
+Saboteur_description = Wird dieser Spieler niedergeschlagen, wirf einen W6. Bei 4+ werden auch alle angrenzenden Gegner niedergeschlagen.
+Shadowing = Beschatten
+Shadowing_description = Verlässt ein Gegner freiwillig die Tacklezone dieses Spielers, kann dieser ihm folgen. Wirf einen W6 und addiere die Bewegungsreichweite dieses Spielers abzüglich der des Gegners: Bei 6 oder mehr zieht dieser Spieler in das verlassene Feld.
+SneakyGit = Hinterhältiger Mistkerl
+SneakyGit_description = Begeht dieser Spieler ein Foul, wird er bei einem natürlichen Pasch auf dem Rüstungswurf nicht des Feldes verwiesen. Zudem muss seine Aktivierung nach dem Foul nicht enden.
+ViolentInnovator = Gewalttätiger Erfinder
+ViolentInnovator_description = Verursacht dieser Spieler eine Verletzung mit einer Spezialaktion aus einer Fertigkeit oder einem Merkmal, erhält er 2 SSP, als hätte er sie mit einem Block verursacht.
+
+# Strength
+
+ArmBar = Armhebel
+ArmBar_description = Stürzt ein Gegner, weil er seinen Geschicklichkeitstest zum Ausweichen oder Springen aus einem von diesem Spieler markierten Feld nicht besteht, kannst du auf den Rüstungs- oder Verletzungswurf +1 anwenden.
+Brawler = Raufbold
+Brawler_description = Führt dieser Spieler eine eigenständige Blockaktion aus (nicht als Teil eines Blitzes), darf er ein einzelnes Ergebnis Beide Umgehauen wiederholen.
+BreakTackle = Tackle durchbrechen
+BreakTackle_description = Einmal während seiner Aktivierung kann dieser Spieler nach einem Geschicklichkeitstest zum Ausweichen den Wurf um +1 modifizieren, wenn seine Stärke 4 oder weniger beträgt, oder um +2 bei Stärke 5 oder mehr.
+BullsEye = Volltreffer
+BullsEye_description = Gelingt diesem Spieler ein hervorragender Wurf beim Werfen eines Mitspielers, landet der geworfene Spieler ohne Streuung im Zielfeld.
+Grab = Packen
+Grab_description = Bei einer eigenständigen Blockaktion kann das Ziel Seitschritt nicht einsetzen. Zudem kann dieser Spieler bei einem Ergebnis Zurückgestoßen ein beliebiges freies Feld neben dem Ziel wählen.
+Guard = Schutz
+Guard_description = Ein Spieler mit dieser Fertigkeit kann offensive und defensive Unterstützung leisten, egal wie viele Gegner ihn markieren.
+Juggernaut = Juggernaut
+Juggernaut_description = Bei einer Blitzaktion kann das Ziel des Blocks weder Abwehr noch Standfest noch Ringen einsetzen, und dieser Spieler kann ein Ergebnis Beide Umgehauen als Zurückgestoßen behandeln.
+MightyBlow = Mächtiger Hieb
+MightyBlow_description = Wird ein Gegner durch einen Block dieses Spielers niedergeschlagen, kannst du auf den Rüstungs- oder Verletzungswurf +1 anwenden. Diese Fertigkeit kann nicht mit Erdolchen oder Kettensäge kombiniert werden.
+MultipleBlock = Mehrfachblock
+MultipleBlock_description = Bei einer eigenständigen Blockaktion kann dieser Spieler zwei verschiedene Gegner blocken, die er markiert, mit einem Modifikator von -2 auf seine Stärke bei beiden Blocks. Keines der Ziele darf offensive Unterstützung erhalten.
+StandFirm = Standfest
+StandFirm_description = Dieser Spieler kann wählen, nicht zurückgestoßen zu werden, ob durch einen Block gegen ihn oder durch eine Kettenreaktion.
+StrongArm = Starker Arm
+StrongArm_description = Dieser Spieler erhält +1 auf seinen Passfähigkeitstest beim Werfen eines Mitspielers.
+ThickSkull = Dickschädel
+ThickSkull_description = Bei einem Verletzungswurf gegen diesen Spieler ist er nur bei 9 K.O. und behandelt eine 8 als Benommen. Hat er auch Zwergenhaft, ist er nur bei 8 K.O. und behandelt eine 7 als Benommen.
+
+# Passing
+
+Accurate = Präzise
+Accurate_description = Bei einem Schnellen oder Kurzen Pass kannst du einen zusätzlichen Modifikator von +1 auf den Passfähigkeitstest anwenden.
+Cannoneer = Kanonier
+Cannoneer_description = Bei einem Langen Pass oder einer Langen Bombe kannst du einen zusätzlichen Modifikator von +1 auf den Passfähigkeitstest anwenden.
+CloudBurster = Wolkenbrecher
+CloudBurster_description = Bei einem Langen Pass oder einer Langen Bombe kannst du den gegnerischen Coach zwingen, einen erfolgreichen Versuch, den Pass zu stören, zu wiederholen.
+DumpOff = Abladen
+DumpOff_description = Wird dieser Spieler als Ziel eines Blocks bestimmt, während er den Ball hat, kann er sofort einen Schnellen Pass ausführen und die Aktivierung des Gegners unterbrechen. Dieser Pass kann keinen Ballverlust verursachen.
+GiveAndGo = Doppelpass
+GiveAndGo_description = Führt dieser Spieler eine Übergabe aus, muss seine Aktivierung danach nicht enden. Er darf sich weiterbewegen, wenn er noch Bewegung übrig hat.
+HailMaryPass = Hail-Mary-Pass
+HailMaryPass_description = Bei einer Passaktion kann das Zielfeld überall auf dem Spielfeld liegen. Ein Hail-Mary-Pass ist nie präzise, streut immer dreimal und kann nicht gestört werden. Eine natürliche 1 ist immer ein Patzer.
+Leader = Anführer
+Leader_description = Ein Team mit einem oder mehreren Spielern mit dieser Fertigkeit erhält einen zusätzlichen Team-Wiederholungswurf, den Anführer-Wiederholungswurf, der nur genutzt werden kann, wenn mindestens einer dieser Spieler auf dem Spielfeld ist.
+NervesOfSteel = Nerven wie Drahtseile
+NervesOfSteel_description = Dieser Spieler kann Modifikatoren für Markierung ignorieren, wenn er passt, den Ball fängt oder einen Pass zu stören versucht.
+OnTheBall = Am Ball
+OnTheBall_description = Dieser Spieler darf sich bis zu drei Felder bewegen, wenn der Gegner einen Pass ansagt, oder ein Feld, wenn der Ball beim Anstoß gekickt wird. Dabei ignoriert er Tacklezonen und muss nicht ausweichen.
+Punt = Befreiungsschlag
+Punt_description = Statt eines Passes kann dieser Spieler den Ball in eine beliebige Richtung kicken. Der Ball weicht von seinem Feld um W6 Felder ab und kann nicht gestört werden.
+SafePass = Sicherer Pass
+SafePass_description = Patzt dieser Spieler bei einem Pass, fällt der Ball nicht, springt nicht von seinem Feld ab und es kommt zu keinem Ballverlust.
+
+# Mutation
+
+BigHand = Große Hand
+BigHand_description = Dieser Spieler kann Modifikatoren für Markierung oder Strömenden Regen ignorieren, wenn er versucht, den Ball aufzunehmen.
+Claws = Klauen
+Claws_description = Bei einem Rüstungswurf gegen einen Gegner, den dieser Spieler durch einen Block niedergeschlagen hat, durchschlägt eine unmodifizierte 8+ die Rüstung, unabhängig vom Rüstungswert.
+DisturbingPresence = Verstörende Präsenz
+DisturbingPresence_description = Passt, fängt oder stört ein Gegner einen Pass, erhält er -1 für jeden Spieler deines Teams mit dieser Fertigkeit im Umkreis von drei Feldern.
+ExtraArms = Zusätzliche Arme
+ExtraArms_description = Dieser Spieler erhält +1, wenn er den Ball aufnehmen oder fangen oder einen Pass stören will.
+FoulAppearance = Abstoßendes Äußeres
+FoulAppearance_description = Sagt ein Gegner einen Block oder eine Spezialaktion gegen diesen Spieler an, muss er zuerst einen W6 werfen. Bei 1 kann er die angesagte Aktion nicht ausführen und sie ist verloren.
+Horns = Hörner
+Horns_description = Blockt dieser Spieler im Rahmen eines Blitzes, kannst du +1 auf seine Stärke anwenden.
+IronHardSkin = Eisenharte Haut
+IronHardSkin_description = Klauen kann bei Rüstungswürfen gegen diesen Spieler nicht eingesetzt werden. Zudem kann der Gegner keine Modifikatoren auf Rüstungswürfe gegen ihn anwenden.
+MonstrousMouth = Monströses Maul
+MonstrousMouth_description = Dieser Spieler darf jeden misslungenen Fangversuch wiederholen. Zudem kann Ball abnehmen nicht gegen ihn eingesetzt werden.
+PrehensileTail = Greifschwanz
+PrehensileTail_description = Versucht ein aktiver Gegner, aus einem von diesem Spieler markierten Feld auszuweichen oder zu springen, erhält er einen zusätzlichen Modifikator von -1 auf seinen Geschicklichkeitstest.
+Tentacles = Tentakel
+Tentacles_description = Verlässt ein von diesem Spieler markierter Gegner freiwillig seine Tacklezone, wirf einen W6, addiere die Stärke dieses Spielers und ziehe die des Gegners ab: Bei 6 oder mehr wird der Gegner festgehalten und seine Bewegung endet.
+TwoHeads = Zwei Köpfe
+TwoHeads_description = Dieser Spieler erhält +1 auf den Geschicklichkeitstest beim Ausweichen.
+VeryLongLegs = Sehr lange Beine
+VeryLongLegs_description = Dieser Spieler verringert negative Modifikatoren beim Springen um 1. Zudem erhält er +2 beim Stören eines Passes und ignoriert die Fertigkeit Wolkenbrecher.
+
+# Trait
+
+AlwaysHungry = Immer hungrig
+AlwaysHungry_description = Will dieser Spieler einen Mitspieler werfen, wirf nach seiner Bewegung, aber vor dem Wurf einen W6. Bei 2+ wird normal geworfen. Bei 1 kann dieser Spieler versuchen, seinen Mitspieler zu fressen.
+Animosity = Feindseligkeit ({ $position })
+Animosity_description = Dieser Spieler ist neidisch auf bestimmte Mitspieler, die in Klammern angegeben sind. Will er einem solchen Mitspieler den Ball übergeben oder zupassen, wirf einen W6. Bei 1 weigert er sich und seine Aktivierung endet.
+AnimalSavagery = Tierische Wildheit
+AnimalSavagery_description = Wirf bei der Aktivierung dieses Spielers nach dem Ansagen seiner Aktion einen W6, mit +2 bei einem Block oder Blitz. Bei 1-3 schlägt er auf einen angrenzenden stehenden Mitspieler ein, der niedergeschlagen wird; gibt es keinen, endet seine Aktivierung sofort.
+BallChain = Kugel und Kette
+BallChain_description = Bei seiner Aktivierung kann dieser Spieler nur eine Kugel-und-Kette-Bewegung ausführen. Er bewegt sich zufällig mithilfe der Einwurfschablone und blockt automatisch jeden Spieler in dem Feld, das er betritt.
+BloodLust = Blutdurst ({ $value })
+BloodLust_description = Wirf bei der Aktivierung dieses Spielers nach dem Ansagen seiner Aktion einen W6. Ist das Ergebnis mindestens so hoch wie der Wert in Klammern, handelt er normal. Sonst muss er sich von einem leibeigenen Mitspieler nähren oder am Ende seiner Aktivierung das Spielfeld verlassen.
+Bombardier = Bombenwerfer
+Bombardier_description = Ist dieser Spieler bei seiner Aktivierung auf den Beinen, kann er eine Bombe werfen. Diese Spezialaktion wird wie ein Pass ausgeführt, nur dass statt des Balls eine Bombe geworfen wird, die dort explodiert, wo sie landet.
+BoneHead = Holzkopf
+BoneHead_description = Wirf bei der Aktivierung dieses Spielers nach dem Ansagen seiner Aktion einen W6. Bei 1 vergisst er, was er tun wollte, und seine Aktivierung endet sofort. Zudem verliert er bis zu seiner nächsten Aktivierung seine Tacklezone.
+BreatheFire = Feueratem
+BreatheFire_description = Einmal pro Aktivierung kann dieser Spieler statt eines Blocks Feuer auf einen von ihm markierten Gegner speien. Wirf einen W6: Bei 6 wird das Ziel niedergeschlagen, bei 4-5 hingelegt, bei 2-3 passiert nichts und bei 1 wird dieser Spieler hingelegt.
+Chainsaw = Kettensäge
+Chainsaw_description = Statt eines Blocks kann dieser Spieler einen Kettensägenangriff ausführen. Wirf einen W6: Bei 2+ wird das Ziel getroffen und es wird ein Rüstungswurf mit +3 gegen es durchgeführt. Bei 1 schlägt die Kettensäge zurück und trifft stattdessen diesen Spieler.
+Decay = Verwesung
+Decay_description = Erleidet dieser Spieler auf der Verletzungstabelle eine Verletzung, wird auf alle Würfe für ihn auf der Verletzungsfolgentabelle ein Modifikator von +1 angewendet.
+Drunkard = Trunkenbold
+Drunkard_description = Dieser Spieler erhält -1 auf den Wurf beim Sprinten.
+Hatred = Hass ({ $keyword })
+Hatred_description = Blockt dieser Spieler einen Gegner mit dem in Klammern angegebenen Schlüsselwort, darf er einen einzelnen Blockwürfel wiederholen.
+HypnoticGaze = Hypnotischer Blick
+HypnoticGaze_description = Während seiner Aktivierung kann dieser Spieler einen von ihm markierten Gegner hypnotisieren. Lege einen Geschicklichkeitstest mit -1 für jeden weiteren Spieler ab, der ihn markiert. Bei Erfolg verliert das Ziel seine Tacklezone und kann bis zu seiner nächsten Aktivierung weder fangen, abfangen, passen, unterstützen noch sich freiwillig bewegen.
+Insignificant = Unbedeutend
+Insignificant_description = Beim Erstellen eines Teams darf höchstens die Hälfte seiner Spieler dieses Merkmal haben. Dieser Spieler erhält keine SSP, wenn er von einem Mitspieler geworfen oder gekickt wird.
+KickTeamMate = Mitspieler kicken
+KickTeamMate_description = Einmal pro Spielzug kann zusätzlich zu einem Pass oder Mitspielerwurf ein Spieler mit diesem Merkmal einen stehenden, angrenzenden Mitspieler mit dem Merkmal Richtiges Zeug kicken.
+Loner = Einzelgänger ({ $value }+)
+Loner_description = Will dieser Spieler einen Team-Wiederholungswurf nutzen, wirf einen W6. Ist das Ergebnis mindestens so hoch wie der Wert in Klammern, wird der Wiederholungswurf normal genutzt. Sonst bleibt das ursprüngliche Ergebnis und der Wiederholungswurf ist verloren.
+MyBall = Mein Ball
+MyBall_description = Ein Spieler mit diesem Merkmal gibt den Ball nicht freiwillig ab: Er kann weder passen noch übergeben noch eine Fertigkeit einsetzen, mit der er den Ball abgeben würde.
+NoBall = Kein Ball
+NoBall_description = Dieser Spieler kann den Ball weder aufnehmen, fangen noch abfangen und kann weder passen noch übergeben.
+PickMeUp = Hilf mir auf
+PickMeUp_description = Wirf am Ende des gegnerischen Spielzugs einen W6 für jeden liegenden, nicht benommenen Mitspieler im Umkreis von drei Feldern um einen stehenden Spieler mit diesem Merkmal. Bei 5+ darf dieser Mitspieler sofort aufstehen.
+PlagueRidden = Verseucht
+PlagueRidden_description = Einmal pro Spiel kann ein Gegner mit Stärke 4 oder weniger ohne Verwesung, Regeneration oder Zwergenhaft, der durch einen Block oder ein Foul dieses Spielers getötet wird, als neuer Fäulnis-Linienspieler deinem Team beitreten.
+PogoStick = Pogostab
+PogoStick_description = Statt über einen liegenden oder benommenen Spieler zu springen, kann dieser Spieler über ein beliebiges angrenzendes Feld springen. Zudem ignoriert er negative Modifikatoren auf den Geschicklichkeitstest beim Springen.
+ProjectileVomit = Projektilkotzen
+ProjectileVomit_description = Statt eines Blocks kann dieser Spieler kotzen. Wirf einen W6: Bei 2+ wird das Ziel getroffen und es wird ein Rüstungswurf gegen es durchgeführt. Bei 1 bekotzt er sich selbst und es wird ein Rüstungswurf gegen ihn durchgeführt.
+ReallyStupid = Strohdumm
+ReallyStupid_description = Wirf bei der Aktivierung dieses Spielers einen W6, mit +2, wenn er neben einem oder mehreren stehenden Mitspielern ohne dieses Merkmal steht. Bei 1-3 vergisst er, was er tun wollte, seine Aktivierung endet sofort und er verliert seine Tacklezone.
+Regeneration = Regeneration
+Regeneration_description = Wirf nach einem Wurf auf der Verletzungsfolgentabelle gegen diesen Spieler einen W6. Bei 4+ wird der Wurf ohne Wirkung verworfen und der Spieler kommt in die Reserve statt zu den Verletzten.
+RightStuff = Richtiges Zeug
+RightStuff_description = Hat dieser Spieler auch eine Stärke von 3 oder weniger, kann er von einem Mitspieler mit Mitspieler werfen geworfen werden.
+SecretWeapon = Geheimwaffe
+SecretWeapon_description = Endet ein Drive, an dem dieser Spieler teilgenommen hat, wird er wegen eines Fouls des Feldes verwiesen, selbst wenn er am Ende des Drives nicht mehr auf dem Spielfeld war.
+Stab = Erdolchen
+Stab_description = Statt eines Blocks kann dieser Spieler einen von ihm markierten Gegner erdolchen. Führe einen unmodifizierten Rüstungswurf gegen das Opfer durch: Wird die Rüstung durchschlagen, folgt ein Verletzungswurf, sonst passiert nichts.
+Stunty = Zwergenhaft
+Stunty_description = Bei einem Geschicklichkeitstest zum Ausweichen ignoriert dieser Spieler die -1-Modifikatoren für Markierung im Zielfeld. Stört jedoch ein Gegner seinen Pass, erhält dieser +1. Zudem werden Verletzungswürfe gegen ihn auf der Verletzungstabelle für Zwergenhafte durchgeführt.
+Swoop = Sturzflug
+Swoop_description = Wird dieser Spieler von einem Mitspieler geworfen, streut er vor der Landung nicht wie üblich. Stattdessen bestimmt die Einwurfschablone seine Richtung und er bewegt sich bis zu W6 Felder in diese Richtung.
+TakeRoots = Wurzeln schlagen
+TakeRoots_description = Wirf bei der Aktivierung dieses Spielers nach dem Ansagen seiner Aktion einen W6. Bei 1 schlägt er Wurzeln: Er kann sein Feld bis zum Ende des Drives nicht mehr verlassen, es sei denn, er wird niedergeschlagen oder hingelegt.
+Timmmber = Baum fällt!
+Timmmber_description = Hat dieser Spieler eine Bewegungsreichweite von 2 oder weniger, erhält er beim Aufstehen +1 für jeden angrenzenden stehenden, nicht markierten Mitspieler. Eine natürliche 1 ist immer ein Fehlschlag.
+Trickster = Trickser
+Trickster_description = Soll dieser Spieler von einem Block oder einer blockersetzenden Spezialaktion getroffen werden, kann er vor dem Bestimmen der Würfelanzahl vom Feld genommen und in ein anderes freies Feld neben dem blockenden Spieler gestellt werden.
+ThrowTeamMate = Mitspieler werfen
+ThrowTeamMate_description = Hat dieser Spieler eine Stärke von 5 oder mehr, kann er einen stehenden, angrenzenden Mitspieler mit dem Merkmal Richtiges Zeug werfen.
+Titchy = Winzig
+Titchy_description = Dieser Spieler erhält +1 auf Geschicklichkeitstests zum Ausweichen. Weicht jedoch ein Gegner in seine Tacklezone aus, gilt dieser Spieler nicht als markierend.
+UnchannelledFury = Ungezügelte Wut
+UnchannelledFury_description = Wirf bei der Aktivierung dieses Spielers nach dem Ansagen seiner Aktion einen W6, mit +2 bei einem Block oder Blitz. Bei 1-3 tobt er zusammenhanglos und seine Aktivierung endet sofort.
+Unsteady = Wackelig
+Unsteady_description = Dieser Spieler kann beim Ausweichen oder Sprinten keine Team-Wiederholungswürfe nutzen.
+
+# Special Skills
+BlindRage = Blinde Wut
+BlindRage_description = Akhorne darf den W6 wiederholen, wenn er die Fertigkeit Unerschrocken einsetzt.
+SavageBlow = Wilder Schlag
+SavageBlow_description = Einmal pro Spiel darf Barik bei einem Block beliebig viele Blockwürfel wiederholen.
+BlastIt = Spreng es!
+BlastIt_description = Einmal pro Spiel darf Barik einen misslungenen Passtest beim Bombenwurf wiederholen.
+PutridRegurgitation = Fauliges Hochwürgen
+PutridRegurgitation_description = Einmal pro Spiel darf Bilerot zusätzlich zu einer anderen Aktion Projektilkotzen einsetzen und den W6 für den Treffer wiederholen.
+LookIntoMyEyes = Schau mir in die Augen
+LookIntoMyEyes_description = Einmal pro Spiel darf Boa zu Beginn einer seiner Aktivierungen einen angrenzenden Gegner wählen und einen W6 werfen. Bei 1 oder 2 passiert nichts; bei 3+ lässt dieser Spieler den Ball fallen, falls er ihn trägt, und Boa nimmt ihn an sich.
+GhostlyFlames = Geisterflammen
+GhostlyFlames_description = Blockt Bryce und wird das Ziel niedergeschlagen, darfst du einen zusätzlichen Modifikator auf den Rüstungs- oder Verletzungswurf anwenden, der das Geisterfeuer seiner Schläge darstellt.
+TastyMorsel = Leckerbissen
+TastyMorsel_description = Wird Bryce zurückgestoßen oder niedergeschlagen, wollen ihn die Gegner fressen: Wirf einen W6, bei 1 wird er aus dem Spiel genommen und zu den Verletzten gestellt.
+StarOfTheShow = Star der Show
+StarOfTheShow_description = Einmal pro Spiel darf dieser Spieler einen Team-Wiederholungswurf nutzen, selbst wenn Einzelgänger das normalerweise verhindern würde, ohne für Einzelgänger zu würfeln.
+ASneakyPair = Ein hinterhältiges Paar
+MesmerizingGaze = Betörender Blick
+MesmerizingGaze_description = Einmal pro Spiel darf Eldril einen misslungenen Geschicklichkeitstest beim Hypnotischen Blick wiederholen.
+BalefulHex = Unheilvoller Fluch
+BalefulHex_description = Einmal pro Spiel darf Estelle zu Beginn einer ihrer Aktivierungen einen Gegner im Umkreis von fünf Feldern wählen und einen W6 werfen. Bei 2+ ist dieser Spieler abgelenkt und verliert bis zum Ende des nächsten Spielzugs seine Tacklezone.
+BrutalBlock = Brutaler Block
+BrutalBlock_description = Einmal pro Spiel darfst du, wenn ein Gegner durch einen Block dieses Spielers niedergeschlagen wird, einen zusätzlichen Modifikator von +1 auf den Verletzungsfolgenwurf anwenden.
+WhirlingDervish = Wirbelnder Derwisch
+WhirlingDervish_description = Führt dieser Spieler einen Blitz aus, darf er zwei Blocks gegen verschiedene Ziele ausführen und sich normal zwischen ihnen bewegen.
+FrenziedRush = Rasender Ansturm
+FrenziedRush_description = Einmal pro Spiel darf dieser Spieler bei einem Blitz für die Dauer dieser Aktion die Fertigkeit Raserei und einen zusätzlichen Bewegungspunkt erhalten.
+ShotToNothing = Nichts zu verlieren
+ShotToNothing_description = Einmal pro Spiel darf dieser Spieler einen Pass über die Reichweite Langer Bombenpass ohne den üblichen Malus ausführen, und der Pass kann nicht gestört werden.
+PrimalSavagery = Urwüchsige Wildheit
+PrimalSavagery_description = Einmal pro Spiel darf dieser Spieler beim Wurf für Tierische Wildheit statt auf einen Mitspieler auf einen angrenzenden Gegner einschlagen.
+TwoForOne = Zwei für Einen
+GoredByTheBull = Vom Stier aufgespießt
+GoredByTheBull_description = Einmal pro Spiel darfst du bei einem Blitz dieses Spielers einen zusätzlichen Modifikator von +1 auf die durch Hörner gewährte Stärke anwenden.
+Incorporeal = Körperlos
+Incorporeal_description = Dieser Spieler darf +1 auf jeden Geschicklichkeitstest zum Ausweichen anwenden und wird nie von dem Gegner markiert, von dem er wegweicht.
+Slayer = Slayer
+Slayer_description = Blockt dieser Spieler einen Gegner mit Stärke 5 oder mehr, darf er +1 auf seine eigene Stärke anwenden.
+WisdomOfTheWhiteDwarf = Weisheit des Weißen Zwergs
+WisdomOfTheWhiteDwarf_description = Einmal pro Spielzug darf Grim einem angrenzenden Mitspieler bis zum Ende von dessen Aktivierung eine der folgenden Fertigkeiten verleihen: Blocken, Tackle brechen, Unerschrocken oder Standfest.
+QuickBite = Schneller Biss
+QuickBite_description = Wurde das Ziel eines Blocks dieses Spielers niedergeschlagen, darf er sofort einen zusätzlichen Rüstungswurf gegen einen anderen angrenzenden Gegner durchführen.
+OldPro = Alter Profi
+OldPro_description = Einmal pro Spiel darf Helmut seine Fertigkeit Profi nutzen, um einen einzelnen Würfel eines Rüstungswurfs gegen ihn zu wiederholen.
+UnstoppableMomentum = Unaufhaltsamer Schwung
+UnstoppableMomentum_description = Führt dieser Spieler einen Blitz aus, darfst du einen einzelnen Blockwürfel wiederholen.
+DwarvenScourge = Geißel der Zwerge
+DwarvenScourge_description = Einmal pro Spiel darfst du, wenn dieser Spieler einen Gegner mit dem Schlüsselwort Zwerg niederschlägt, einen zusätzlichen Modifikator von +1 auf den Verletzungswurf anwenden.
+RaidingParty = Raubzug
+RaidingParty_description = Einmal pro Spiel darf dieser Spieler einen angrenzenden, nicht markierten Mitspieler ein Feld in beliebige Richtung bewegen, sofern dieser dadurch nicht markiert wird.
+TheFlashingBlade = Die blitzende Klinge
+TheFlashingBlade_description = Setzt dieser Spieler Erdolchen ein, darfst du einen zusätzlichen Modifikator von +1 auf den Rüstungswurf anwenden.
+SwiftAsTheBreeze = Schnell wie der Wind
+SwiftAsTheBreeze_description = Einmal pro Spiel darf dieser Spieler einen einzelnen misslungenen Geschicklichkeitstest zum Ausweichen, Springen oder Hechtsprung wiederholen, unabhängig von anderen eingesetzten Fertigkeiten.
+DwarfenGrit = Zwergische Zähigkeit
+DwarfenGrit_description = Einmal pro Spiel darfst du, wenn ein Spieler dieses Teams niedergeschlagen wird, den Rüstungswurf gegen ihn wiederholen.
+Indomitable = Unbezwingbar
+Indomitable_description = Einmal pro Spiel darf dieser Spieler bei einem Block ein Ergebnis Zurückgestoßen als Umgehauen gegen das Ziel behandeln.
+BlackInk = Schwarze Tinte
+BlackInk_description = Einmal pro Spiel darf dieser Spieler den Hypnotischen Blick gegen einen Gegner im Umkreis von drei Feldern einsetzen statt gegen einen, den er markiert.
+LordOfChaos = Herr des Chaos
+LordOfChaos_description = Ein Team mit diesem Spieler erhält einen zusätzlichen Team-Wiederholungswurf für die erste Halbzeit. Wird er dort nicht genutzt, darf er in der zweiten Halbzeit eingesetzt werden.
+ViciousVines = Bösartige Ranken
+ViciousVines_description = Einmal pro Spiel darf dieser Spieler, ohne sich zu bewegen, einen Block gegen einen beliebigen stehenden Gegner im Umkreis von zwei Feldern ausführen.
+MaximumCarnage = Maximales Gemetzel
+MaximumCarnage_description = Einmal pro Spiel darf dieser Spieler bei einem Blitz nacheinander einen Block gegen jeden angrenzenden Gegner ausführen.
+CrushingBlow = Vernichtender Schlag
+CrushingBlow_description = Einmal pro Spiel darfst du, wenn ein Gegner durch einen Block dieses Spielers niedergeschlagen wird, einen zusätzlichen Modifikator von +1 auf den Rüstungswurf anwenden.
+KickThemWhileTheyAreDown = Tritt nach, wenn sie liegen!
+KickThemWhileTheyAreDown_description = Einmal pro Spiel darf dieser Spieler ein Foul begehen, ohne dass es als Foul des Teams in diesem Spielzug zählt, und erhält einen zusätzlichen Modifikator von +1 auf den Rüstungswurf.
+HalflingLuck = Halblingsglück
+HalflingLuck_description = Einmal pro Spiel darf dieser Spieler einen beliebigen einzelnen eigenen Würfelwurf außer Rüstungs-, Verletzungs- oder Verletzungsfolgenwürfen wiederholen, selbst wenn er ein Einzelgänger ist.
+ToxinConnoisseur = Giftkenner
+ToxinConnoisseur_description = Einmal pro Spiel darf dieser Spieler Erdolchen mit einem zusätzlichen Modifikator von +1 auf den Verletzungswurf einsetzen.
+ThinkingManTroll = Denkender Troll
+ThinkingManTroll_description = Einmal pro Spielzug darf dieser Spieler den W6 für Strohdumm oder Holzkopf wiederholen.
+CatchOfTheDay = Fang des Tages
+CatchOfTheDay_description = Beendet dieser Spieler seine Aktivierung in der gegnerischen Hälfte mit einem freien angrenzenden Feld, darf der Ball dort platziert werden, wenn er in der Nähe aufspringt, als hätte er ihn gefangen.
+BoundingLeap = Weiter Satz
+BoundingLeap_description = Einmal pro Spiel darf dieser Spieler +1 auf den Geschicklichkeitstest beim Hechtsprung anwenden und über zwei Felder statt einem springen.
+SlashingNails = Reißende Nägel
+SlashingNails_description = Blockt dieser Spieler und wird das Ziel niedergeschlagen, darfst du die Fertigkeit Klauen auf den Rüstungswurf anwenden.
+Ram = Rammbock
+Ram_description = Wird das Ziel eines Blitzes dieses Spielers niedergeschlagen, darfst du +1 auf den Rüstungswurf anwenden.
+Yoink = Meins!
+Yoink_description = Einmal pro Spiel darf dieser Spieler statt eines Blocks versuchen, einem angrenzenden Gegner den Ball abzunehmen.
+FuryOfTheBloodGod = Zorn des Blutgottes
+FuryOfTheBloodGod_description = Einmal pro Spiel darf dieser Spieler bei einem Block ein beliebiges Blockwürfelergebnis als Umgehauen behandeln.
+MasterAssassin = Meisterassassine
+MasterAssassin_description = Setzt dieser Spieler Erdolchen ein, darfst du den Rüstungswurf wiederholen.
+PumpUpTheCrowd = Heiz die Menge an
+PumpUpTheCrowd_description = Einmal pro Spiel erhält dein Team sofort einen Team-Wiederholungswurf, wenn dieser Spieler einen Touchdown erzielt.
+StrongPassingGame = Starkes Passspiel
+StrongPassingGame_description = Einmal pro Spiel darf dieser Spieler einen misslungenen Passtest beim Mitspielerwurf wiederholen.
+FuriousOutburst = Wütender Ausbruch
+FuriousOutburst_description = Einmal pro Spiel darf sich dieser Spieler statt eines Blocks in ein beliebiges freies Feld im Umkreis von drei Feldern teleportieren und einen Block gegen einen angrenzenden Gegner ausführen.
+SneakiestOfTheLot = Der Hinterhältigste von allen
+SneakiestOfTheLot_description = Ein Team mit diesem Spieler darf pro Spielzug zwei Fouls begehen, sofern dieser Spieler eines davon begeht.
+WorkingInTandem = Im Tandem
+BeerBarrelBash = Bierfass-Wurf!
+BeerBarrelBash_description = Einmal pro Spiel darf dieser Spieler ein Fass auf einen Gegner bis zur Reichweite eines Kurzen Passes werfen. Trifft es, wird das Ziel niedergeschlagen.
+KrumpAndSmash = Krump und Zerschmetter
+KrumpAndSmash_description = Einmal pro Spiel darfst du, wenn ein großer Kerl dieses Teams blockt, einen einzelnen Blockwürfel wiederholen.
+SavageMauling = Wildes Zerfleischen
+SavageMauling_description = Einmal pro Spiel darfst du einen Verletzungswurf dieses Spielers gegen einen Gegner wiederholen.
+WoodlandFury = Zorn des Waldes
+WoodlandFury_description = Einmal pro Spiel darf ein Spieler dieses Teams bei einem Blitz bis zum Ende seiner Aktivierung die Fertigkeit Raserei erhalten.
+WatchOut = Pass auf!
+WatchOut_description = Einmal pro Spiel darf dieser Spieler, wenn ein Gegner einen ihm angrenzenden Mitspieler blockt, diesen Gegner die Blockwürfel wiederholen lassen.
+ExcuseMeAreYouAZoat = Entschuldigung, sind Sie ein Zoat?
+ExcuseMeAreYouAZoat_description = Einmal pro Spiel darf dieser Spieler den Hypnotischen Blick einsetzen und einen misslungenen Geschicklichkeitstest wiederholen.
+BlastingSolvesEverything = Ballern löst alles
+BlastingSolvesEverything_description = Dieses Team darf Bombenwerfer und andere Geheimwaffen anheuern, ohne dass sie auf das übliche Limit angerechnet werden, und darf Bestechungen zu reduzierten Kosten kaufen.
+Kaboom = Kawumm!
+Kaboom_description = Einmal pro Spiel betrifft die Explosion einer von diesem Spieler geworfenen Bombe jedes Feld neben dem Landefeld, und alle Spieler dort werden bei 4+ niedergeschlagen.
+AllYouCanEat = Alles, was du essen kannst
+AllYouCanEat_description = Einmal pro Spiel darf dieser Spieler den W6 für Immer hungrig wiederholen.
+Reliable = Zuverlässig
+Reliable_description = Patzt dieser Spieler beim Mitspielerwurf, streut der geworfene Spieler vom Feld des Werfers aus, und der Wurf verursacht keinen Ballverlust, es sei denn, der geworfene Spieler wird verletzt.
+ConsummateProfessional = Vollendeter Profi
+ConsummateProfessional_description = Einmal pro Spiel darf dieser Spieler einen einzelnen Würfel eines Passtests, Geschicklichkeitstests, Rüstungs- oder Verletzungswurfs wiederholen.
+Treacherous = Verräterisch
+Treacherous_description = Einmal pro Spiel darf dieser Spieler einem angrenzenden Mitspieler, der den Ball trägt, diesen ohne Wurf abnehmen.
+IllBeBack = Ich komme wieder!
+IllBeBack_description = Wird dieser Spieler einmal pro Spiel wegen eines Fouls des Feldes verwiesen, kommt er stattdessen in die Reserve.
+TheBallista = Die Balliste
+TheBallista_description = Einmal pro Spiel darf dieser Spieler einen misslungenen Passtest beim Passen wiederholen.
+
+# V5
+
+BurstOfSpeed = Tempoausbruch
+BurstOfSpeed_description = Einmal pro Spiel darf dieser Spieler während seiner Aktivierung dreimal zusätzlich sprinten.
+DirtyPlayerNumber = Schmutziger Spieler (+{ $value })
+DirtyPlayerNumber_description = Begeht dieser Spieler ein Foul, kann der Rüstungs- oder Verletzungswurf gegen das Opfer um den in Klammern angegebenen Wert modifiziert werden.
+MightyBlowNumber = Mächtiger Hieb (+{ $value })
+MightyBlowNumber_description = Wird ein Gegner durch einen Block dieses Spielers niedergeschlagen, kannst du den Rüstungs- oder Verletzungswurf um den in Klammern angegebenen Wert modifizieren. Diese Fertigkeit kann nicht mit Erdolchen oder Kettensäge kombiniert werden.
+NoHands = Keine Hände
+NoHands_description = Dieser Spieler kann den Ball nicht in Besitz nehmen. Er kann ihn weder aufnehmen noch fangen noch einen Pass stören.
+RunningPass = Pass im Lauf
+RunningPass_description = Führt dieser Spieler einen Schnellen Pass aus, muss seine Aktivierung danach nicht enden. Er darf sich nach dem Pass weiterbewegen, wenn er noch Bewegung übrig hat.
+ThenIStartedBlastin = Dann fing ich an zu ballern!
+ThenIStartedBlastin_description = Einmal pro Spiel kann dieser Spieler auf einen Gegner im Umkreis von drei Feldern schießen: Wirf einen W6 und bei 4+ wird das Ziel niedergeschlagen.
+
+###############################################################
+# Brackets
+###############################################################
+
+SingleElimination = K.-o.-System
+DoubleElimination = Doppel-K.-o.-System
+Standings = Tabelle
+NafElo = NAF-Elo
+Winners = Gewinnerrunde
+Losers = Verliererrunde
+GrandFinal = Großes Finale
+GrandFinalReset = Entscheidungsspiel des Finales
+BracketRound = { $side }, Runde { $round }
+
+###############################################################
+# Ratings
+###############################################################
+
+Glicko2 = Glicko-2
+TrueSkill = TrueSkill
+
+###############################################################
+# Injury causes
+###############################################################
+
+Foul = Foul
+Crowd = Publikum
+FailedDodge = Misslungenes Ausweichen
+FailedRush = Misslungenes Sprinten
+Other = Sonstiges
+
+###############################################################
+# Reports
+###############################################################
+
+MatchReport = Spielbericht
+Team = Team
+Coach = Trainer
+CurrentTeamValue = Aktueller Teamwert
+Weather = Wetter
+Fans = Fans
+Date = Datum
+PreGame = Vorspiel
+Inducements = Anreize
+FirstHalf = Erste Halbzeit
+SecondHalf = Zweite Halbzeit
+ExtraTime = Verlängerung
+FinalScore = Endstand
+Penalties = Elfmeterschießen
+StarPlayerPoints = Star-Spieler-Punkte
+Player = Spieler
+Injuries = Verletzungen
+Cause = Ursache
+InflictedBy = Verursacht durch
+Winnings = Einnahmen
+DedicatedFans = Treue Fans
+ExpensiveMistakes = Teure Fehler
+MostValuablePlayers = Wertvollste Spieler
+SentOff = Des Feldes verwiesen
+PushedIntoCrowd = Ins Publikum gestoßen
+NothingToReport = Nichts zu berichten
+Roster = Kader
+Players = Spieler
+Number = #
+Name = Name
+Position = Position
+ShortMovementAllowance = BW
+ShortStrength = ST
+ShortAgility = GE
+ShortPassingAbility = PF
+ShortArmourValue = RW
+Skills = Fertigkeiten
+HiringValue = Anwerbewert
+CurrentValue = Aktueller Wert
+Staff = Betreuer
+Quantity = Anzahl
+Treasury = Kasse
+TeamValue = Teamwert
+SpecialRules = Sonderregeln
+ModifiedCharacteristic = Hervorgehobene Werte weichen vom Positionsprofil ab.
+
+###############################################################
+# Pitch formats
+###############################################################
+
+Standard = Standard
+Sevens = Sevens
+
+###############################################################
+# Formatting
+###############################################################
+
+GoldPieces = { $value }k GM
+PlayersCount =
+    { $count ->
+        [one] { $count } Spieler
+       *[other] { $count } Spieler
+    }
+StarPlayerPointsCount =
+    { $count ->
+        [one] { $count } Star-Spieler-Punkt
+       *[other] { $count } Star-Spieler-Punkte
+    }
@@ -26,12 +26,60 @@ StartGameBeforeAddingEvents = A game should be started before adding events
 GameNeedsAWinner = This game needs a winning team
 GamePenaltiesShouldHaveAWinner = Game penalties should have a winner
 GameAlreadyClosed = Game is already closed
+GameNotClosed = Game should be finished and closed
 JourneymanPositionNotFound = Journeyman position not found
 NotAPlayingTeam = Not a playing team
 JourneymenShouldBeOkBeforeBuyingInducements = Journeymen should be resolved before buying any inducements
 SkillNotAvailableForPlayer = Skill not available for player
 CaptainMissing = There is no captain in the team, please name one
 TooMuchCaptains = There are too much captains in the team
+PlayerCanNotBeCaptain = This player can not be the captain of the team
+NotEnoughTeamsForBracket = Not enough teams to create a bracket
+GameNotInBracket = This game is not part of the bracket
+BracketMatchAlreadyFollowedUp = The next matches of this bracket match have already been played
+PlayerNotInTeam = This player is not in the team
+PlayerNumberAlreadyUsed = Another player of the team already has this number
+TierNotAllowedInTournament = This roster tier is not allowed in the tournament
+TournamentBudgetExceeded = Tournament budget exceeded
+TournamentSkillsExceeded = Too much skills for the tournament
+TournamentSameSkillMaximumExceeded = The same skill is taken too many times for the tournament
+TournamentEliteSkillNotAllowed = Elite skills are not allowed in the tournament
+TournamentSkillNotAllowed = This skill is not allowed in the tournament
+InvalidTeamFile = Invalid team file
+InvalidTeamFileLine = Invalid line in team file
+UnknownRoster = Unknown roster
+UnknownPosition = Unknown position
+UnknownSkill = Unknown skill
+UnknownAdvancement = Unknown advancement
+UnknownInjury = Unknown injury
+UnknownStaff = Unknown staff
+InvalidSerializedData = Invalid serialized data
+UnsupportedFormat = Unsupported serialization format
+DatabaseError = Database error
+DatabaseUnavailable = Database unavailable
+DatabaseConstraintViolation = Database constraint violated
+DatabaseMigrationFailed = Database migration failed
+InvalidDatabaseData = Invalid data in database
+UnsupportedDatabase = Unsupported database
+SquareOutOfPitch = Square is out of the pitch
+SquareNotInOwnHalf = Square is not in the team half
+SquareAlreadyOccupied = Square is already occupied
+PlayerAlreadyOnPitch = Player is already on the pitch
+PlayerNotAvailable = Player is not available
+TooMuchPlayersOnPitch = Too much players on the pitch
+NotEnoughPlayersOnPitch = Not enough players on the pitch
+NotEnoughPlayersOnLineOfScrimmage = Not enough players on the line of scrimmage
+TooMuchPlayersInWideZone = Too much players in a wide zone
+GameNotInProgress = Game is not in progress
+NotTeamTurn = It is not this team's turn
+PlayerCanNotAct = This player can not act now
+InvalidPath = Invalid movement path
+InvalidTarget = Invalid target
+ActionAlreadyUsed = This action has already been used this turn
+UnsupportedLanguage = This language is not supported
+InvalidTranslations = These translations are not valid Fluent
+TranslationsFileNotReadable = The translations file can not be read
+TranslationsNotEnabled = Translations can not be loaded without the i18n feature
 
 ###############################################################
 # Game status
@@ -75,51 +123,92 @@ Blizzard_description = Freezing conditions and heavy falls of snow make the foot
 ###############################################################
 
 PrayersToNuffle = Prayers to Nuffle
+PrayersToNuffle_description = Each Prayer to Nuffle lets your team roll once on the Prayers to Nuffle table before the game.
 PartTimeAssistantCoaches = Part-time Assistant Coaches
+PartTimeAssistantCoaches_description = Each Part-time Assistant Coach adds 1 to your team's Brilliant Coaching rolls for the duration of the game.
 TempAgencyCheerleaders = Temp Agency Cheerleaders
+TempAgencyCheerleaders_description = Each Temp Agency Cheerleader adds 1 to your team's Cheering Fans for the duration of the game.
 TeamMascot = Team Mascot
+TeamMascot_description = Once per half, the Team Mascot lets you roll a D6 when a team re-roll is needed: on a 5+ you gain a free re-roll.
 WeatherMage = Weather Mage
+WeatherMage_description = Once per game, at the start of any of your team turns or at the start of a drive, the Weather Mage may change the weather by rolling on the Weather table, re-rolling the result if you wish.
 BlitzersBestKegs = Blitzer's Best Kegs
+BlitzersBestKegs_description = Each Blitzer's Best Keg adds 1 to the roll made to see if Knocked-out players recover at the end of each drive.
 Bribes = Bribes
-ExtraTeamTraining = Entraînement Supplémentaire
+Bribes_description = When a player on your team is Sent-off by the referee, you may use a Bribe. Roll a D6: on a 2+ the player is not Sent-off and no turnover is caused. On a 1, the Bribe is wasted.
+ExtraTeamTraining = Extra Team Training
+ExtraTeamTraining_description = Each Extra Team Training grants your team one additional team re-roll that may be used during either half of the game.
 MortuaryAssistant = Mortuary Assistant
+MortuaryAssistant_description = Once per game, when a player on your team with the Regeneration trait fails their Regeneration roll, the Mortuary Assistant may re-roll it.
 PlagueDoctor = Plague Doctor
+PlagueDoctor_description = Once per game, the Plague Doctor may be used either to re-roll a failed Regeneration roll, or to heal a player that has been KO'd, placing them in the Reserves box.
 RiotousRookies = Riotous Rookies
+RiotousRookies_description = Before the game, your team gains 2D3+1 Journeymen linemen for the duration of the game.
 WanderingApothecaries = Wandering Apothecaries
+WanderingApothecaries_description = Each Wandering Apothecary may be used once per game exactly as a team apothecary would be.
 HalflingMasterChef = Halfling Master Chef
+HalflingMasterChef_description = At the start of each half, roll three D6. For each roll of 4+, your team gains a team re-roll and the opposing team loses one.
 BiasedRefereeDodgyLeagueRep = Biased Referee: Dodgy League Rep
+BiasedRefereeDodgyLeagueRep_description = Whenever an opposing player commits a Foul action and is not seen by the referee, roll a D6. On a 5+, the player is Sent-off anyway.
 WizardSportsWizard = Wizard: Sports Wizard
+WizardSportsWizard_description = Once per game, the Sports Wizard may cast one spell, such as a Fireball or Zap!, against opposing players.
+StarPlayer = Star Player
+StarPlayer_description = Star Players are famous players who may be hired as an inducement for a single game. They play as part of your team and count as Loners.
+MegaStarPlayer = Mega Star Player
+MegaStarPlayer_description = Mega Stars are the most famous Star Players of all. They may be hired as an inducement for a single game, following the same rules as other Star Players.
+FamousCoachingStaff = Famous Coaching Staff
+FamousCoachingStaff_description = Famous Coaching Staff are renowned coaches, apothecaries and specialists who may be hired as an inducement for a single game, each bringing a unique special rule.
 
 # Old
 
 BloodweiserKegs = Bloodweiser Kegs
+BloodweiserKegs_description = Each Bloodweiser Keg adds 1 to the roll made to see if Knocked-out players recover at the end of each drive.
 SpecialPlays = Special Plays
+SpecialPlays_description = Each Special Play card lets your team draw and use one Special Play during the game.
 
 ###############################################################
 # Prayers to Nuffle
 ###############################################################
 
 TreacherousTrapdoor = Treacherous Trapdoor
+TreacherousTrapdoor_description = Until the end of this half, every time any player enters a trapdoor square, roll a D6. On a 1, the trapdoor opens and the player falls through it, being placed in the Reserves box. If they were holding the ball, it bounces from the trapdoor square.
 FriendsWithTheRef = Friends with the Ref
+FriendsWithTheRef_description = Until the end of this drive, when rolling for Argue the Call, treat a roll of 5-6 as Well, When You Put It Like That and a roll of 2-4 as Get Out of Here.
 Stiletto = Stiletto
+Stiletto_description = Randomly select one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of this drive, that player gains the Stab trait.
 IronMan = Iron Man
+IronMan_description = Choose one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of the game, that player improves their Armour Value by 1, to a maximum of 11+.
 KnuckleDusters = Knuckle Dusters
+KnuckleDusters_description = Choose one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of this drive, that player gains the Mighty Blow (+1) skill.
 BadHabits = Bad Habits
+BadHabits_description = Randomly select D3 opposition players that are available to play during this drive and that do not have the Loner trait. Until the end of this drive, those players gain the Loner (2+) trait.
 GreasyCleats = Greasy Cleats
+GreasyCleats_description = Randomly select one opposition player that is available to play during this drive. That player has had their boots tampered with: until the end of this drive, their Movement Allowance is reduced by 1.
 BlessingsOfNuffle = Blessings of Nuffle
+BlessingsOfNuffle_description = Choose one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of the game, that player gains the Pro skill.
 MolesUnderThePitch = Moles under the Pitch
+MolesUnderThePitch_description = Until the end of this half, apply a -1 modifier every time any player attempts to Rush an extra square, or -2 if both coaches rolled this result.
 PerfectPassing = Perfect Passing
+PerfectPassing_description = Until the end of the game, any player on your team that makes a Completion earns 2 SPP, rather than the usual 1 SPP.
 DazzlingCatching = Dazzling Catching
+DazzlingCatching_description = Until the end of the game, any player on your team that catches a pass earns 1 SPP.
 FanInteraction = Fan Interaction
+FanInteraction_description = Until the end of this drive, if a player on the opposing team suffers a Casualty as a result of being pushed into the crowd, that player will earn your team 2 SPP as though they had been hurt by a Block action.
 FoulingFrenzy = Fouling Frenzy
+FoulingFrenzy_description = Until the end of the game, any player on your team that causes a Casualty with a Foul action earns 2 SPP, as though they had caused it with a Block action.
 ThrowARock = Throw a Rock
+ThrowARock_description = Until the end of this drive, should an opposing player Stall, at the end of their team turn you may roll a D6. On a 5+, an angry fan throws a rock at that player, who is immediately Knocked Down.
 UnderScrutiny = Under Scrutiny
+UnderScrutiny_description = Until the end of this half, any player on the opposing team that commits a Foul action is automatically seen by the referee, even if a double is not rolled.
 IntensiveTraining = Intensive Training
+IntensiveTraining_description = Randomly select one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of this game, that player gains a single Primary skill of your choice.
 
 # Old
 
 BlessedStatueOfNuffle = Blessed Statue of Nuffle
+BlessedStatueOfNuffle_description = Choose one player on your team that is available to play during this drive and that does not have the Loner trait. Until the end of the game, that player gains the Pro skill.
 NecessaryViolence = Necessary Violence
+NecessaryViolence_description = Until the end of the game, any player on your team that causes a Casualty earns 3 SPP, rather than the usual 2 SPP.
 
 ###############################################################
 # Roster names
@@ -163,29 +252,48 @@ WoodElfRoster = Wood Elf
 # Special leagues
 
 BadlandsBrawl = Badlands Brawl
+BadlandsBrawl_description = This team may hire Star Players and inducements available to teams playing in the Badlands Brawl.
 ChaosClash = Chaos Clash
 ElvenKingdomsLeague = Elven Kingdoms League
+ElvenKingdomsLeague_description = This team may hire Star Players and inducements available to teams playing in the Elven Kingdoms League.
 HalflingThimbleCup = Halfling Thimble Cup
+HalflingThimbleCup_description = This team may hire Star Players and inducements available to teams playing in the Halfling Thimble Cup.
 LustrianSuperleague = Lustrian Superleague
+LustrianSuperleague_description = This team may hire Star Players and inducements available to teams playing in the Lustrian Superleague.
 OldWorldClassic = Old World Classic
+OldWorldClassic_description = This team may hire Star Players and inducements available to teams playing in the Old World Classic.
 SylvanianSpotlight = Sylvanian Spotlight
+SylvanianSpotlight_description = This team may hire Star Players and inducements available to teams playing in the Sylvanian Spotlight.
 UnderworldChallenge = Underworld Challenge
+UnderworldChallenge_description = This team may hire Star Players and inducements available to teams playing in the Underworld Challenge.
 WoodlandLeague = Woodland League
 WorldsEdgeSuperleague = Worlds Edge Superleague
+WorldsEdgeSuperleague_description = This team may hire Star Players and inducements available to teams playing in the Worlds Edge Superleague.
 
 # Special rules
 
 BrawlingBrutes = Brawlin Brutes
+BrawlingBrutes_description = This team may hire players with the Big Guy keyword without them counting against the usual limit, and may re-roll a single Both Down result once per game.
 BriberyAndCorruption = Bribery and Corruption
+BriberyAndCorruption_description = Once per game, if this team rolls a natural 1 when using a Bribe, the Bribe may be re-rolled. Additionally, Bribes cost this team less to purchase as inducements.
 FavouredOf = Favoured of...
+FavouredOf_description = When this team is created, the coach must choose which Chaos god it is favoured by. This choice determines which Star Players and inducements the team may hire.
 FavouredOfHashut = Favoured of Hashut
+FavouredOfHashut_description = This team is favoured by Hashut and may hire Star Players and inducements available to teams with this special rule.
 FavouredOfKhorne = Favoured of Khorne
+FavouredOfKhorne_description = This team is favoured by Khorne and may hire Star Players and inducements available to teams with this special rule.
 FavouredOfNurgle = Favoured of Nurgle
+FavouredOfNurgle_description = This team is favoured by Nurgle and may hire Star Players and inducements available to teams with this special rule.
 LowCostLinemen = Low Cost Linemen
+LowCostLinemen_description = The linemen of this team do not count towards its Current Value when calculating inducements and the petty cash available.
 MastersOfUndeath = Masters of Undeath
+MastersOfUndeath_description = Once per game, the head coach of this team may raise a dead player: when an opposing player suffers a DEAD result, they may join this team as a new Zombie Lineman for the rest of the game.
 Swarming = Swarming
+Swarming_description = During each Start of Drive sequence, after Step 2 but before Step 3, you may remove D3 players with this trait from the Reserves box and set them up on the pitch, provided they are not placed in either Wide Zone or on the Line of Scrimmage.
 TeamCaptain = Team Captain
+TeamCaptain_description = When this team is created, one player must be nominated as Team Captain. While the captain is on the pitch, you may roll a D6 each time a team re-roll is used: on a 6, the re-roll is not lost.
 VampireLord = Vampire Lord
+VampireLord_description = This team may hire a Plague Doctor or Mortuary Assistant as an inducement and its Vampire players may feed on Thralls to satisfy their Blood Lust.
 
 ###############################################################
 # Staff
@@ -220,50 +328,91 @@ SchielundScharlitan = Schielund Scharlitan
 All = All
 
 Animal = Animal
+Animal_description = Animals are beasts that take the field alongside their handlers, such as the Woodland Fox of Gnome teams and the Norse Beer Boar.
 Beastman = Beastman
+Beastman_description = Beastmen are horned followers of the Dark Gods who make up the rank and file of Chaos Chosen teams, and of Khorne and Nurgle teams as Khorngors and Pestigors.
 Construct = Construct
+Construct_description = Constructs are artificial bodies animated by dark magic, such as the Flesh Golems of Necromantic Horror teams.
 Dryad = Dryad
+Dryad_description = Dryads are tree spirits of the deep forests. Only the Star Player Willow Rosebark has this keyword.
 Dwarf = Dwarf
+Dwarf_description = Dwarfs are short, stubborn and heavily armoured players found in Dwarf, Chaos Dwarf and Old World Alliance teams. Their ancient grudges have earned them more than one Hatred.
 Dwarfs = Dwarfs
 Elf = Elf
+Elf_description = Elves are agile, graceful players who rely on speed and passing, fielded by Dark Elf, High Elf, Wood Elf and Elven Union teams.
 Ghoul = Ghoul
+Ghoul_description = Ghouls are swift, feral corpse-eaters that act as runners for Shambling Undead and Necromantic Horror teams.
 Gnoblar = Gnoblar
+Gnoblar_description = Gnoblars are tiny, cowardly greenskins that fill the line of Ogre teams and try to stay out of the way of the big lads.
 Gnome = Gnome
+Gnome_description = Gnomes are small, cunning tricksters and illusionists who form the core of Gnome teams.
 Goblin = Goblin
+Goblin_description = Goblins are small, sneaky greenskins. They field their own teams and play alongside Orcs, Chaos Dwarfs and Chaos Renegades, often armed with secret weapons.
 Halfling = Halfling
+Halfling_description = Halflings are small folk far fonder of food than of football. They form Halfling teams and may join Human teams as catchers.
 Halflings = Halflings
 Human = Human
+Human_description = Humans are the most widespread players in the game, making up Human, Amazon, Bretonnian, Imperial Nobility, Norse and Old World Alliance teams among many others.
 Humans = Humans
 Lizardman = Lizardman
+Lizardman_description = Lizardmen are the cold-blooded servants of the Old Ones: nimble Skinks, tough Saurus and mighty Kroxigors.
 Minotaur = Minotaur
+Minotaur_description = Minotaurs are huge, bull-headed Big Guys driven by Unchannelled Fury, hired by Chaos Chosen, Chaos Dwarf and Chaos Renegade teams.
 Ogre = Ogre
+Ogre_description = Ogres are massive, hungry Big Guys who field their own teams and hire out to Chaos, Human and Old World Alliance teams.
 Orc = Orc
+Orc_description = Orcs are tough, aggressive greenskins who play a brutal running game for Orc, Black Orc and Chaos Renegade teams.
 Skaven = Skaven
+Skaven_description = Skaven are fast, treacherous ratmen. Gutter Runners, Clanrats and Rat Ogres play for Skaven, Underworld Denizens and Chaos Renegade teams.
 Skeleton = Skeleton
+Skeleton_description = Skeletons are the reanimated bones of the long dead, slow but hard to keep down, fielded by Shambling Undead and Tomb Kings teams.
 Snakeman = Snakeman
+Snakeman_description = Snakemen are serpentine creatures. Only the Star Player Boa Kon'ssstriktr has this keyword.
 Snotling = Snotling
+Snotling_description = Snotlings are the smallest greenskins of all, swarming the pitch for Snotling and Underworld Denizens teams along with their Fun-hoppas, Fungus Flingas and Pump Wagons.
 Spawn = Spawn
+Spawn_description = Spawn are mutated, mindless horrors gifted by the Dark Gods, such as the Bloodspawn of Khorne and the Rotspawn of Nurgle.
 Spite = Spite
+Spite_description = Spites are tiny forest spirits. Only the Star Player Swiftvine Glimmershard has this keyword.
 Squirrel = Squirrel
+Squirrel_description = This keyword belongs to a single furry Star Player: Akhorne the Squirrel.
 Thrall = Thrall
+Thrall_description = Thralls are the human servants of Vampire teams, and the ones Vampires feed on when Blood Lust takes hold.
 Treeman = Treeman
+Treeman_description = Treemen are ancient, slow-moving tree spirits that act as Big Guys for Wood Elf, Halfling, Gnome and Old World Alliance teams.
 Troll = Troll
+Troll_description = Trolls are dim-witted, regenerating Big Guys who play for greenskin, Chaos and Underworld teams. Some players hold a Hatred of Trolls.
 Undead = Undead
+Undead_description = Undead players have been raised from the grave by dark magic and play for Shambling Undead, Necromantic Horror, Tomb Kings and Vampire teams. Some players hold a Hatred of the Undead.
 Vampire = Vampire
+Vampire_description = Vampires are undying nobles, fast and strong but bound by Blood Lust to feed on their Thralls.
 Werewolf = Werewolf
+Werewolf_description = Werewolves are ferocious shapeshifters fielded by Necromantic Horror teams.
 Wraith = Wraith
+Wraith_description = Wraiths are spectral undead that glide across the pitch for Necromantic Horror teams.
 Yhetee = Yhetee
+Yhetee_description = Yhetees are savage Big Guys from the frozen north who play for Norse teams.
 Zoat = Zoat
+Zoat_description = Zoats are rare, reclusive forest creatures. Only the Star Player Zolcath the Zoat has this keyword.
 Zombie = Zombie
+Zombie_description = Zombies are slow, shambling corpses that make up the cheap linemen of Shambling Undead and Necromantic Horror teams.
 
 Lineman = Lineman
+Lineman_description = Linemen are the backbone of a team. They can be hired in large numbers and have access to the team's basic skill categories.
 Thrower = Thrower
+Thrower_description = Throwers are the team's playmakers, specialised in passing the ball.
 Catcher = Catcher
+Catcher_description = Catchers specialise in receiving passes and running the ball into the End Zone.
 Blitzer = Blitzer
+Blitzer_description = Blitzers combine speed and strength to break through the opposing line and hunt the ball carrier.
 Blocker = Blocker
+Blocker_description = Blockers are strong, tough players that hold the Line of Scrimmage and knock opponents down.
 Runner = Runner
+Runner_description = Runners are fast, agile players that move the ball up the pitch.
 BigGuy = Big Guy
+BigGuy_description = Big Guys are huge, powerful and often unreliable players. A team can only include a limited number of them.
 Special = Special
+Special_description = Special players fill a unique role in their team that does not fit any other position keyword.
 
 # Amazon
 
@@ -581,7 +730,7 @@ DislocatedShoulder = Dislocated Shoulder
 
 PassingCompletion = Passing Completion
 ThrowingCompletion = Throwing Completion
-LandingOnfeet = Landing on his Feet
+LandingOnFeet = Landing on his Feet
 Deflection = Deflection
 Interception = Interception
 Casualty = Casualty
@@ -618,6 +767,7 @@ ArmourValue = Armour Value
 General = General
 Mutation = Mutation
 Pass = Pass
+Pass_description = This player may re-roll a failed Passing Ability test when performing a Pass action.
 Devious = Devious
 Trait = Trait
 
@@ -628,203 +778,482 @@ Trait = Trait
 # General
 
 Block = Block
+Block_description = When a Both Down result is applied during a Block action, this player may choose to ignore it and not be Knocked Down.
 Dauntless = Dauntless
+Dauntless_description = When this player performs a Block action, if the nominated target has a higher Strength characteristic, roll a D6 and add this player's Strength. If the total exceeds the target's Strength, this player's Strength is increased to match it for the duration of the Block.
 Fend = Fend
+Fend_description = If this player is pushed back as the result of any block dice result, they may prevent the player that pushed them back from following-up. Fend cannot be used against a player with the Juggernaut skill during a Blitz action.
 Frenzy = Frenzy
+Frenzy_description = Every time this player performs a Block action, they must follow-up if the target is pushed back. If the target is still Standing after being pushed back and this player is able to follow-up, they must perform a second block against the same target.
 Kick = Kick
+Kick_description = If this player is nominated to be the kicking player during a kick-off, you may choose to halve the result of the D6 used to determine the number of squares the ball deviates, rounding any fractions down.
 Pro = Pro
+Pro_description = Once per team turn, this player may attempt to re-roll one dice that was rolled for them. Roll a D6: on a 3+ the dice may be re-rolled, on a 1-2 the original result stands. A team re-roll cannot then be used for that dice.
 SteadyFooting = Steady Footing
+SteadyFooting_description = When this player would be Knocked Down or Placed Prone, roll a D6. On a 6, they keep their footing and remain Standing.
 StripBall = Strip Ball
+StripBall_description = When this player targets an opposing player that is in possession of the ball with a Block action, choosing a Push Back result will cause that player to drop the ball in the square they are pushed back into.
 SureHands = Sure Hands
+SureHands_description = This player may re-roll any failed attempt to pick up the ball. In addition, the Strip Ball skill cannot be used against a player with this skill.
 Tackle = Tackle
+Tackle_description = When an active opposing player attempts to Dodge from a square in which they were Marked by one or more players with this skill, that player cannot use the Dodge skill.
 Taunt = Taunt
+Taunt_description = When an opposing player performs a Block action against this player, if this player is pushed back, you may choose for the attacker to follow-up, and they must do so.
 Wrestle = Wrestle
+Wrestle_description = This player may use this skill when a Both Down result is applied, either when they perform a Block action or when they are the target of a Block action. Instead of applying the result normally, both players are Placed Prone and no Armour roll is made.
 
 # Agility
 
 Catch = Catch
+Catch_description = This player may re-roll a failed Agility test when attempting to catch the ball.
 Defensive = Defensive
+Defensive_description = During your opponent's team turn, any opposition players being Marked by this player cannot use the Guard skill.
 DivingCatch = Diving Catch
+DivingCatch_description = This player may attempt to catch the ball if a pass, throw-in or kick-off causes it to land in a square within their Tackle Zone after scattering or deviating. Additionally, this player may apply a +1 modifier to any attempt to catch an accurate pass if they occupy the target square.
 DivingTackle = Diving Tackle
+DivingTackle_description = Should an active opposing player that is attempting to Dodge, Jump or Leap in order to vacate a square in which they are being Marked by this player pass their Agility test, you may declare this player will use this skill. Your opponent must subtract 2 from the result, then this player is Placed Prone in the vacated square.
 Dodge = Dodge
+Dodge_description = Once per team turn, during their activation, this player may re-roll a failed Agility test when attempting to Dodge. Additionally, this player may choose to use this skill when they are the target of a Block action and a Stumble result is applied.
 HitAndRun = Hit and Run
+HitAndRun_description = After this player performs a Block action, they may immediately move one free square, ignoring Tackle Zones, so long as they are still Standing. They must ensure that after this free move, they are not Marked by or Marking any opposition players.
 JumpUp = Jump Up
+JumpUp_description = If this player is Prone they may stand up for free. Additionally, if this player is Prone when activated, they may attempt to Jump Up and perform a Block action by making an Agility test with a +1 modifier.
 Leap = Leap
+Leap_description = During their movement, instead of jumping over a single square that is occupied by a Prone or Stunned player, a player with this skill may choose to Leap over any single adjacent square, including unoccupied squares and squares occupied by Standing players. Additionally, this player may reduce any negative modifier applied to the Agility test when they attempt to Jump or Leap by 1.
 SideStep = Side Step
+SideStep_description = If this player is pushed back for any reason, they are not moved into a square chosen by the opposing coach. Instead the coach of this player may choose any unoccupied square adjacent to this player.
 SafePairOfHands = Safe Pair of Hands
+SafePairOfHands_description = If this player is Knocked Down or Placed Prone whilst in possession of the ball, the ball does not bounce. Instead, you may place the ball in an unoccupied square adjacent to the one this player occupies when they become Prone.
 Sprint = Sprint
+Sprint_description = When this player performs any action that includes movement, they may attempt to Rush three times, rather than the usual two.
 SureFeet = SureFeet
+SureFeet_description = Once per team turn, during their activation, this player may re-roll the D6 when attempting to Rush.
 
 # Devious
 
 DirtyPlayer = Dirty Player
+DirtyPlayer_description = When this player commits a Foul action, you may apply a +1 modifier to either the Armour roll or Injury roll made against the victim.
 EyeGouge = Eye Gouge
+EyeGouge_description = When this player performs a Block action and the target is pushed back, the target loses their Tackle Zone until they are next activated.
 Fumblerooski = Fumblerooski
+Fumblerooski_description = When this player performs a Move or Blitz action whilst in possession of the ball, they may choose to drop the ball in any square they vacate during their movement. No Agility test is required.
 LethalFlight = Lethal Flight
+LethalFlight_description = When this player is thrown by a team-mate and lands in a square occupied by an opposing player, you may apply a +1 modifier to the Armour roll or Injury roll made against that player.
 LoneFouler = Lone Fouler
+LoneFouler_description = If this player commits a Foul action with no offensive assists, they may re-roll the Armour roll.
 PileDriver = Pile Driver
+PileDriver_description = When an opposing player is Knocked Down by this player as the result of a Block action, this player may immediately commit a free Foul action against the Knocked Down player. To use this skill, this player must be Standing after the block and must not have moved. Using this skill ends this player's activation.
 PutTheBootIn = Put the Boot in
+PutTheBootIn_description = When this player provides an offensive assist to a Foul action, they add +2 to the Armour roll instead of +1.
 QuickFoul = Quick Foul
+QuickFoul_description = When this player commits a Foul action, their activation does not have to end once the Foul has been committed. They may continue to move if they have movement remaining.
 Saboteur = Saboteur
+Saboteur_description = When this player is Knocked Down, roll a D6. On a 4+, every adjacent opposing player is Knocked Down as well.
 Shadowing = Shadowing
+Shadowing_description = When an opposing player in this player's Tackle Zone voluntarily moves out of it, this player may attempt to follow them. Roll a D6 and add this player's MA minus the opponent's MA: on a 6 or more, this player moves into the square vacated.
 SneakyGit = Sneaky Git
+SneakyGit_description = When this player performs a Foul action, they are not Sent-off for committing a Foul should they roll a natural double on the Armour roll. Additionally, the activation of this player does not have to end once the Foul has been committed.
 ViolentInnovator = Violent Innovator
+ViolentInnovator_description = When this player causes a Casualty with a Special action granted by a skill or trait, they earn 2 SPP as if it had been caused by a Block action.
 
 # Strength
 
 ArmBar = Arm Bar
+ArmBar_description = If an opposing player Falls Over as the result of failing their Agility test when attempting to Dodge, Jump or Leap out of a square in which they were being Marked by this player, you may apply a +1 modifier to either the Armour roll or Injury roll.
 Brawler = Brawler
+Brawler_description = When this player performs a Block action on its own (but not as part of a Blitz action), this player may re-roll a single Both Down result.
 BreakTackle = Break Tackle
+BreakTackle_description = Once during their activation, after making an Agility test in order to Dodge, this player may modify the dice roll by +1 if their Strength characteristic is 4 or less, or by +2 if their Strength characteristic is 5 or more.
 BullsEye = Bulls Eye
+BullsEye_description = When this player performs a Throw Team-mate action and the throw is superb, the thrown player lands in the target square without scattering.
 Grab = Grab
+Grab_description = When this player performs a Block action on its own, using this skill prevents the target from using the Side Step skill. Additionally, when a Push Back result is applied, this player may choose any unoccupied square adjacent to the target to push them into.
 Guard = Guard
+Guard_description = A player with this skill is able to offer both offensive and defensive assists regardless of how many opposing players are Marking them.
 Juggernaut = Juggernaut
+Juggernaut_description = When this player performs a Blitz action, the target of the Block action cannot use the Fend, Stand Firm or Wrestle skills, and this player may choose to treat a Both Down result as a Push Back result.
 MightyBlow = Mighty Blow
+MightyBlow_description = When an opposing player is Knocked Down as the result of a Block action performed by this player, you may apply a +1 modifier to either the Armour roll or Injury roll. This skill cannot be used with the Stab or Chainsaw traits.
 MultipleBlock = Multiple Block
+MultipleBlock_description = When this player performs a Block action on its own, they may choose to perform two Block actions, each targeting a different player they are Marking, at the cost of a -2 Strength modifier on both blocks. Neither target may receive offensive assists.
 StandFirm = StandFirm
+StandFirm_description = This player may choose not to be pushed back, either as the result of a Block action made against them or by a chain-push.
 StrongArm = Strong Arm
+StrongArm_description = This player may apply a +1 modifier when they make a Passing Ability test to perform a Throw Team-mate action. A player that has the Throw Team-mate trait cannot have this skill without it.
 ThickSkull = Thick Skull
+ThickSkull_description = When an Injury roll is made against this player, they can only be KO'd on a roll of 9, and will treat a roll of 8 as a Stunned result. If this player also has the Stunty trait, they can only be KO'd on a roll of 8, and will treat a roll of 7 as a Stunned result.
 
 # Passing
 
 Accurate = Accurate
+Accurate_description = When this player performs a Quick Pass action or a Short Pass action, you may apply an additional +1 modifier to the Passing Ability test.
 Cannoneer = Cannoneer
+Cannoneer_description = When this player performs a Long Pass action or a Long Bomb Pass action, you may apply an additional +1 modifier to the Passing Ability test.
 CloudBurster = Cloud Burster
+CloudBurster_description = When this player performs a Long Pass action or a Long Bomb Pass action, you may choose to make the opposing coach re-roll a successful attempt to interfere with the pass.
 DumpOff = Dump-off
+DumpOff_description = If this player is nominated as the target of a Block action and is in possession of the ball, they may immediately perform a Quick Pass action, interrupting the activation of the opposing player. This Quick Pass cannot cause a turnover.
 GiveAndGo = Give and Go
+GiveAndGo_description = If this player performs a Hand-off action, their activation does not have to end once the hand-off is resolved. They may continue to move if they have movement remaining.
 HailMaryPass = Hail Mary Pass
+HailMaryPass_description = When this player performs a Pass action, the target square can be anywhere on the pitch. A Hail Mary Pass is never accurate, always scatters three times and cannot be interfered with. It is always a Fumble on a natural 1.
 Leader = Leader
+Leader_description = A team with one or more players with this skill gains a single extra team re-roll, called a Leader re-roll, which can only be used if at least one player with this skill is on the pitch.
 NervesOfSteel = Nerves of Steel
+NervesOfSteel_description = This player may ignore any modifier for being Marked when they attempt to perform a Pass action, attempt to catch the ball or attempt to interfere with a pass.
 OnTheBall = On the Ball
+OnTheBall_description = This player may move up to three squares when the opposing coach declares a Pass action, or one square when the ball is kicked during a kick-off. This move is made regardless of Tackle Zones and without Dodging.
 Punt = Punt
+Punt_description = Instead of performing a Pass action, this player may kick the ball in any direction. The ball deviates from this player's square, using a D6 to determine the number of squares it travels, and cannot be interfered with.
 SafePass = Safe Pass
+SafePass_description = Should this player fumble a Pass action, the ball is not dropped, it does not bounce from the square this player occupies and no turnover is caused.
 
 # Mutation
 
 BigHand = Big Hand
+BigHand_description = This player may ignore any modifier for being Marked or for Pouring Rain weather conditions when they attempt to pick up the ball.
 Claws = Claws
+Claws_description = When you make an Armour roll against an opposing player that was Knocked Down as the result of a Block action performed by this player, a roll of 8+ before applying any modifiers will break their armour, regardless of their Armour Value.
 DisturbingPresence = Disturbing Presence
+DisturbingPresence_description = When an opposing player performs a Pass action, attempts to interfere with a pass or attempts to catch the ball, they must apply a -1 modifier for each player on your team with this skill that is within three squares of them.
 ExtraArms = Extra Arms
+ExtraArms_description = This player may apply a +1 modifier when they attempt to pick up or catch the ball, or when they attempt to interfere with a pass.
 FoulAppearance = Foul Appearance
+FoulAppearance_description = When an opposing player declares a Block action targeting this player, or any Special action that targets this player, their coach must first roll a D6. On a roll of 1, the player cannot perform the declared action and the action is wasted.
 Horns = Horns
+Horns_description = When this player performs a Block action as part of a Blitz action, you may apply a +1 modifier to this player's Strength characteristic.
 IronHardSkin = Iron Hard Skin
+IronHardSkin_description = The Claws skill cannot be used when making an Armour roll against this player. Additionally, opposing coaches cannot apply any modifiers to Armour rolls made against this player.
 MonstrousMouth = Monstrous Mouth
+MonstrousMouth_description = This player may re-roll any failed attempt to catch the ball. In addition, the Strip Ball skill cannot be used against a player with this skill.
 PrehensileTail = Prehensile Tail
+PrehensileTail_description = When an active opposing player attempts to Dodge, Jump or Leap in order to vacate a square in which they are being Marked by this player, there is an additional -1 modifier applied to the active player's Agility test.
 Tentacles = Tentacles
+Tentacles_description = This player can use this skill when an opposing player they are Marking voluntarily moves out of a square within their Tackle Zone. Roll a D6, adding this player's Strength and subtracting the opponent's: on a 6 or higher, the opposing player is held firmly in place and their movement comes to an end.
 TwoHeads = Two Heads
+TwoHeads_description = This player may apply a +1 modifier to the Agility test when they attempt to Dodge.
 VeryLongLegs = Very Long Legs
+VeryLongLegs_description = This player may reduce any negative modifier applied to the Agility test when they attempt to Jump over a Prone or Stunned player, or to Leap, by 1. Additionally, this player may apply a +2 modifier to any attempts to interfere with a pass. This player ignores the Cloud Burster skill.
 
 # Trait
 
 AlwaysHungry = Always Hungry
+AlwaysHungry_description = If this player wishes to perform a Throw Team-mate action, roll a D6 after they have finished moving but before they throw their team-mate. On a 2+, continue with the throw as normal. On a 1, this player may attempt to eat their team-mate.
 Animosity = Animosity ({ $position })
+Animosity_description = This player is jealous of and dislikes certain other players on their team, as shown in brackets. When this player wishes to hand-off or pass the ball to such a team-mate, roll a D6. On a 1, this player refuses and their activation ends.
 AnimalSavagery = Animal Savagery
+AnimalSavagery_description = When this player is activated, roll a D6 after declaring their action, applying a +2 modifier if they declared a Block or Blitz action. On a 1-3, this player lashes out at an adjacent Standing team-mate, who is Knocked Down; if there is none, their activation ends immediately.
 BallChain = Ball Chain
+BallChain_description = When this player is activated, the only action they may perform is a Ball & Chain Move Special action. This player moves randomly using the Throw-in template and automatically performs a Block action against any player in the square they move into.
 BloodLust = Blood Lust ({ $value })
+BloodLust_description = When this player is activated, after declaring their action, roll a D6. If the result is equal to or higher than the number shown in brackets, they may act as normal. Otherwise, they must feed on a Thrall team-mate or leave the pitch at the end of their activation.
 Bombardier = Bombardier
+Bombardier_description = When activated and if they are Standing, this player can perform a Throw Bomb Special action. This Special action is performed exactly like a Pass action, except that the ball is replaced by a bomb that explodes where it lands.
 BoneHead = Bone Head
+BoneHead_description = When this player is activated, roll a D6 after declaring their action. On a 1, this player forgets what they are doing and their activation ends immediately. Additionally, this player loses their Tackle Zone until they are next activated.
 BreatheFire = Breathe Fire
+BreatheFire_description = Once per activation, instead of performing a Block action, this player may perform a Breathe Fire Special action against an opposing player they are Marking. Roll a D6: on a 6 the target is Knocked Down, on a 4-5 they are Placed Prone, on a 2-3 nothing happens and on a 1 this player is Placed Prone.
 Chainsaw = Chainsaw
+Chainsaw_description = Instead of performing a Block action, this player may perform a Chainsaw Attack Special action. Roll a D6: on a 2+ the nominated target is hit by the Chainsaw and an Armour roll with a +3 modifier is made against them. On a 1 the Chainsaw violently kicks back and hits this player instead.
 Decay = Decay
+Decay_description = If this player suffers a Casualty result on the Injury table, there is a +1 modifier applied to all rolls made against this player on the Casualty table.
 Drunkard = Drunkard
+Drunkard_description = This player suffers a -1 penalty to the dice roll when attempting to Rush.
 Hatred = Hatred ({ $keyword })
+Hatred_description = When this player performs a Block action against an opposing player with the keyword shown in brackets, they may re-roll a single block dice.
 HypnoticGaze = Hypnotic Gaze
+HypnoticGaze_description = During their activation, this player may perform a Hypnotic Gaze Special action against an opposing player they are Marking. Make an Agility test with a -1 modifier for each player Marking this player other than the target. If passed, the target loses their Tackle Zone and cannot catch, intercept or pass the ball, assist, or move voluntarily until their next activation.
 Insignificant = Insignificant
+Insignificant_description = When building a team, no more than half of its players may have this trait. This player earns no SPP from being thrown or kicked by a team-mate.
 KickTeamMate = Kick Team Mate
+KickTeamMate_description = Once per team turn, in addition to another player performing either a Pass or a Throw Team-mate action, a single player with this trait on the team may perform a Kick Team-mate Special action and kick a Standing team-mate with the Right Stuff trait that is adjacent to them.
 Loner = Loner ({ $value }+)
+Loner_description = If this player wishes to use a team re-roll, roll a D6. If the roll is equal to or higher than the target number shown in brackets, this player may use the team re-roll as normal. Otherwise, the original result stands and the re-roll is lost.
 MyBall = My Ball
+MyBall_description = A player with this trait may not willingly give up the ball when in possession of it, and so may not make Pass actions, Hand-off actions, or use any other skill or trait that would allow them to relinquish possession of the ball.
 NoBall = No Ball
+NoBall_description = This player cannot attempt to pick up, catch or intercept the ball, and they cannot perform Pass or Hand-off actions.
 PickMeUp = Pick-Me-Up
+PickMeUp_description = At the end of the opposition's team turn, roll a D6 for each Prone, non-Stunned team-mate within three squares of a Standing player with this trait. On a 5+, the Prone player may immediately stand up.
 PlagueRidden = Plague Ridden
+PlagueRidden_description = Once per game, if an opposition player with a Strength characteristic of 4 or less that does not have the Decay, Regeneration or Stunty traits suffers a Casualty result of 15-16, DEAD as the result of a Block action performed or a Foul action committed by this player, that player may be raised as a new Rotter Lineman on your team.
 PogoStick = Pogo Stick
+PogoStick_description = During their movement, instead of jumping over a single square that is occupied by a Prone or Stunned player, this player may choose to Leap over any single adjacent square. Additionally, this player may ignore any negative modifiers when making the Agility test to Leap.
 ProjectileVomit = Projectile Vomit
+ProjectileVomit_description = Instead of performing a Block action, this player may perform a Projectile Vomit Special action. Roll a D6: on a 2+ the target is hit and an Armour roll is made against them. On a 1, this player vomits over themselves and an Armour roll is made against them.
 ReallyStupid = Really Stupid
+ReallyStupid_description = When this player is activated, roll a D6, applying a +2 modifier if this player is adjacent to one or more Standing team-mates that do not have this trait. On a 1-3, this player forgets what they are doing and their activation ends immediately, losing their Tackle Zone.
 Regeneration = Regeneration
+Regeneration_description = After a Casualty roll has been made against this player, roll a D6. On a 4+, the Casualty roll is discarded without effect and the player is placed in the Reserves box rather than the Casualty box.
 RightStuff = Right Stuff
+RightStuff_description = If this player also has a Strength characteristic of 3 or less, they can be thrown by a team-mate with the Throw Team-mate skill.
 SecretWeapon = Secret Weapon
+SecretWeapon_description = When a drive in which this player took part ends, even if this player was not on the pitch at the end of the drive, this player will be Sent-off for committing a Foul.
 Stab = Stab
+Stab_description = Instead of performing a Block action, this player may perform a Stab Special action against an opposing player they are Marking. Make an unmodified Armour roll against the victim: if it is broken, make an Injury roll; otherwise, nothing happens.
 Stunty = Stunty
+Stunty_description = When this player makes an Agility test in order to Dodge, they ignore any -1 modifiers for being Marked in the square they have moved into. However, when an opposing player attempts to interfere with a pass performed by this player, that player may apply a +1 modifier. Additionally, Injury rolls made against this player use the Stunty Injury table.
 Swoop = Swoop
+Swoop_description = If this player is thrown by a team-mate, they do not scatter before landing as they normally would. Instead, the Throw-in template is used to determine their direction and they move up to D6 squares in that direction.
 TakeRoots = Take Roots
+TakeRoots_description = When this player is activated, roll a D6 after declaring their action. On a 1, this player becomes Rooted: they cannot move from the square they occupy for any reason until the end of the drive or until they are Knocked Down or Placed Prone.
 Timmmber = Timmm-ber
+Timmmber_description = If this player has a Movement Allowance of 2 or less, apply a +1 modifier to the dice roll when they attempt to stand up for each Standing team-mate adjacent to them that is not Marked. A natural 1 is always a failure.
 Trickster = Trickster
+Trickster_description = When this player is about to be hit by a Block action or a Special action that replaces a Block action, before determining how many dice are rolled, they may be removed from the pitch and placed in any other unoccupied square adjacent to the player performing the Block action.
 ThrowTeamMate = Throw Team-Mate
+ThrowTeamMate_description = If this player has a Strength characteristic of 5 or more, they may perform a Throw Team-mate action, allowing them to throw a Standing team-mate with the Right Stuff trait that is adjacent to them.
 Titchy = Titchy
+Titchy_description = This player may apply a +1 modifier to any Agility tests they make in order to Dodge. However, if an opposition player dodges into a square within the Tackle Zone of this player, this player does not count as Marking the moving player.
 UnchannelledFury = Unchannelled Fury
+UnchannelledFury_description = When this player is activated, roll a D6 after declaring their action, applying a +2 modifier if they declared a Block or Blitz action. On a 1-3, this player rages incoherently and their activation ends immediately.
 Unsteady = Unsteady
+Unsteady_description = This player cannot use team re-rolls when attempting to Dodge or Rush.
 
 # Special Skills
 BlindRage = Blind Rage
+BlindRage_description = Akhorne may re-roll the D6 when using the Dauntless skill.
 SavageBlow = Savage Blow
+SavageBlow_description = Once per game, when Barik performs a Block action, he may re-roll any number of the block dice.
 BlastIt = Blast It!
+BlastIt_description = Once per game, Barik may re-roll a failed Passing Ability test when performing a Throw Bomb Special action.
 PutridRegurgitation = Putrid Regurgitation
+PutridRegurgitation_description = Once per game, Bilerot may perform a Projectile Vomit Special action in addition to another action, and may re-roll the D6 to see if it hits.
 LookIntoMyEyes = Look into my Eyes
+LookIntoMyEyes_description = Once per game, at the start of any of his activations, Boa may choose an opposing player in base contact and roll a D6. On a 1 or 2 nothing happens; on a 3+ that player drops the ball if holding it, and Boa takes possession.
 GhostlyFlames = Ghostly Flames
+GhostlyFlames_description = When Bryce performs a Block action and the target is Knocked Down, you may apply an additional modifier to the Armour roll or Injury roll to represent the ghostly fire of his blows.
 TastyMorsel = Tasty Morsel
+TastyMorsel_description = When Bryce is pushed back or Knocked Down, opponents relish the chance to eat him: roll a D6, and on a 1 he is removed from play and placed in the Casualty box.
 StarOfTheShow = Star of the Show
+StarOfTheShow_description = Once per game, this player may use a team re-roll even if Loner would normally prevent it, without rolling for Loner.
 ASneakyPair = A Sneaky Pair
 MesmerizingGaze = Mesmerizing Gaze
+MesmerizingGaze_description = Once per game, Eldril may re-roll a failed Agility test when performing a Hypnotic Gaze Special action.
 BalefulHex = Baleful Hex
+BalefulHex_description = Once per game, at the start of any of her activations, Estelle may nominate an opposing player within five squares and roll a D6. On a 2+, that player becomes distracted and loses their Tackle Zone until the end of the next team turn.
 BrutalBlock = Brutal Block
+BrutalBlock_description = Once per game, when an opposing player is Knocked Down by a Block action performed by this player, you may apply an additional +1 modifier to the Casualty roll.
 WhirlingDervish = Whirling Dervish
+WhirlingDervish_description = When this player performs a Blitz action, they may perform two Block actions against different targets, moving between them as normal.
 FrenziedRush = Frenzied Rush
+FrenziedRush_description = Once per game, when this player performs a Blitz action, they may gain the Frenzy skill and an extra point of Movement for the duration of that action.
 ShotToNothing = Shot to Nothing
+ShotToNothing_description = Once per game, this player may perform a Pass action with a Long Bomb range without the usual penalty, and the pass cannot be interfered with.
 PrimalSavagery = Primal Savagery
+PrimalSavagery_description = Once per game, when this player rolls for Animal Savagery, they may choose to lash out at an adjacent opposing player instead of a team-mate.
 TwoForOne = Two for One
 GoredByTheBull = Gored by the Bull
+GoredByTheBull_description = Once per game, when this player performs a Blitz action, you may add an additional +1 modifier to the Strength characteristic granted by the Horns skill.
 Incorporeal = Incorporeal
+Incorporeal_description = This player may apply a +1 modifier to any Agility test made to Dodge and is never Marked by the opposing player they dodge away from.
 Slayer = Slayer
+Slayer_description = When this player performs a Block action against an opposing player with a Strength characteristic of 5 or more, they may apply a +1 modifier to their own Strength.
 WisdomOfTheWhiteDwarf = Wisdom of the White Dwarf
+WisdomOfTheWhiteDwarf_description = Once per team turn, Grim may grant one adjacent team-mate one of the following skills until the end of their activation: Block, Break Tackle, Dauntless or Stand Firm.
 QuickBite = Quick Bite
+QuickBite_description = After this player performs a Block action, if the target was Knocked Down, they may immediately make an additional Armour roll against a different adjacent opposing player.
 OldPro = Old Pro
+OldPro_description = Once per game, Helmut may use his Pro skill to re-roll a single dice rolled as part of an Armour roll made against him.
 UnstoppableMomentum = Unstoppable Momentum
+UnstoppableMomentum_description = When this player performs a Blitz action, you may re-roll a single block dice.
 DwarvenScourge = Dwarven Scourge
+DwarvenScourge_description = Once per game, when an opposing player with the Dwarf keyword is Knocked Down by this player, you may apply an additional +1 modifier to the Injury roll.
 RaidingParty = Raiding Party
+RaidingParty_description = Once per game, this player may move an adjacent team-mate that is not Marked one square in any direction, as long as it does not leave them Marked.
 TheFlashingBlade = The Flashing Blade
+TheFlashingBlade_description = When this player performs a Stab Special action, you may apply an additional +1 modifier to the Armour roll.
 SwiftAsTheBreeze = Swift as the Breeze
+SwiftAsTheBreeze_description = Once per game, this player may re-roll a single failed Agility test made to Dodge, Jump or Leap, regardless of any other skill used.
 DwarfenGrit = Dwarfen Grit
+DwarfenGrit_description = Once per game, when a player on this team is Knocked Down, you may re-roll the Armour roll made against them.
 Indomitable = Indomitable
+Indomitable_description = Once per game, when this player performs a Block action, they may treat a Push Back result as a Knocked Down result against the target.
 BlackInk = Black Ink
+BlackInk_description = Once per game, this player may perform a Hypnotic Gaze Special action against an opposing player within three squares, rather than one they are Marking.
 LordOfChaos = Lord of Chaos
+LordOfChaos_description = A team that includes this player gains an extra team re-roll for the first half. If it is not used during the first half, it may be used during the second half.
 ViciousVines = Vicious Vines
+ViciousVines_description = Once per game, instead of performing a Block action, this player may perform a Block action against any Standing opposing player within two squares, without moving.
 MaximumCarnage = Maximum Carnage
+MaximumCarnage_description = Once per game, when this player performs a Blitz action, they may perform the Block action against every adjacent opposing player in turn.
 CrushingBlow = Crushing Blow
+CrushingBlow_description = Once per game, when an opposing player is Knocked Down as the result of a Block action performed by this player, you may apply an additional +1 modifier to the Armour roll.
 KickThemWhileTheyAreDown = Kick 'em While They're Down!
+KickThemWhileTheyAreDown_description = Once per game, this player may perform a Foul action without it counting as the team's Foul action for the turn, and gains an additional +1 modifier to the Armour roll.
 HalflingLuck = Halfling Luck
+HalflingLuck_description = Once per game, this player may re-roll any single dice roll they have made other than an Armour, Injury or Casualty roll, even if they are a Loner.
 ToxinConnoisseur = Toxin Connoisseur
+ToxinConnoisseur_description = Once per game, this player may perform a Stab Special action with an additional +1 modifier to the Injury roll.
 ThinkingManTroll = Thinking Man's Troll
+ThinkingManTroll_description = Once per team turn, this player may re-roll the D6 when rolling for Really Stupid or Bone Head.
 CatchOfTheDay = Catch of the Day
+CatchOfTheDay_description = If this player ends their activation in the opposing half with an unoccupied adjacent square, the ball may be placed there when it bounces nearby, as if they had caught it.
 BoundingLeap = Bounding Leap
+BoundingLeap_description = Once per game, this player may apply a +1 modifier to the Agility test when attempting to Leap, and may Leap over two squares instead of one.
 SlashingNails = Slashing Nails
+SlashingNails_description = When this player performs a Block action and the target is Knocked Down, you may apply the Claws skill to the Armour roll.
 Ram = Ram
+Ram_description = When this player performs a Blitz action, if the target is Knocked Down, you may apply a +1 modifier to the Armour roll.
 Yoink = Yoink!
+Yoink_description = Once per game, this player may attempt to take the ball from an adjacent opposing player in possession of it, instead of performing a Block action.
 FuryOfTheBloodGod = Fury of the Blood God
+FuryOfTheBloodGod_description = Once per game, when this player performs a Block action, they may treat any block dice result as a Pow! result.
 MasterAssassin = Master Assassin
+MasterAssassin_description = When this player performs a Stab Special action, you may re-roll the Armour roll.
 PumpUpTheCrowd = Pump Up the Crowd
+PumpUpTheCrowd_description = Once per game, when this player scores a touchdown, your team immediately gains a team re-roll.
 StrongPassingGame = Strong Passing Game
+StrongPassingGame_description = Once per game, this player may re-roll a failed Passing Ability test when performing a Throw Team-mate action.
 FuriousOutburst = Furious Outburst
+FuriousOutburst_description = Once per game, instead of performing a Block action, this player may teleport to any unoccupied square within three squares and perform a Block action against an adjacent opposing player.
 SneakiestOfTheLot = Sneakiest of the Lot
+SneakiestOfTheLot_description = A team that includes this player may commit two Foul actions per team turn, provided this player commits one of them.
 WorkingInTandem = Working in Tandem
 BeerBarrelBash = Beer Barrel Bash!
+BeerBarrelBash_description = Once per game, this player may throw a barrel at an opposing player up to a Short Pass away. If it hits, the target is Knocked Down.
 KrumpAndSmash = Krump and Smash
+KrumpAndSmash_description = Once per game, when a Big Guy on this team performs a Block action, you may re-roll a single block dice.
 SavageMauling = Savage Mauling
+SavageMauling_description = Once per game, after this player makes an Injury roll against an opposing player, you may re-roll it.
 WoodlandFury = Woodland Fury
+WoodlandFury_description = Once per game, when a player on this team performs a Blitz action, they may gain the Frenzy skill until the end of their activation.
 WatchOut = Watch Out!
+WatchOut_description = Once per game, when an opposing player performs a Block action against a team-mate adjacent to this player, this player may make them re-roll the block dice.
 ExcuseMeAreYouAZoat = Excuse Me, Are You a Zoat?
+ExcuseMeAreYouAZoat_description = Once per game, this player may perform a Hypnotic Gaze Special action and may re-roll the Agility test if it fails.
 BlastingSolvesEverything = Blastin' Solves Everything
+BlastingSolvesEverything_description = This team may hire Bombardiers and other Secret Weapons without counting them against the usual limit, and may purchase Bribes at a reduced cost.
 Kaboom = Kaboom!
+Kaboom_description = Once per game, when this player throws a bomb, the explosion affects every square adjacent to the landing square, and all players there are Knocked Down on a 4+.
 AllYouCanEat = All You Can Eat
+AllYouCanEat_description = Once per game, this player may re-roll the D6 when rolling for Always Hungry.
 Reliable = Reliable
+Reliable_description = If this player fumbles a Throw Team-mate action, the thrown player scatters from the thrower's square and the throw does not cause a turnover unless the thrown player is injured.
 ConsummateProfessional = Consummate Professional
+ConsummateProfessional_description = Once per game, this player may re-roll a single dice that was rolled as part of a Passing Ability test, Agility test, Armour roll or Injury roll.
 Treacherous = Treacherous
+Treacherous_description = Once per game, if this player is adjacent to a team-mate in possession of the ball, they may take the ball from them without a roll.
 IllBeBack = I'll Be Back!
+IllBeBack_description = Once per game, if this player is Sent-off for committing a Foul, they are placed in the Reserves box instead.
 TheBallista = The Ballista
+TheBallista_description = Once per game, this player may re-roll a failed Passing Ability test when performing a Pass action.
 
 # V5
 
 BurstOfSpeed = Burst of Speed
+BurstOfSpeed_description = Once per game, this player may attempt to Rush three additional times during their activation.
 DirtyPlayerNumber = Dirty Player (+{ $value })
+DirtyPlayerNumber_description = When this player commits a Foul action, either the Armour roll or the Injury roll made against the victim may be modified by the amount shown in brackets.
 MightyBlowNumber = Mighty Blow (+{ $value })
+MightyBlowNumber_description = When an opposing player is Knocked Down as the result of a Block action performed by this player, you may modify either the Armour roll or Injury roll by the amount shown in brackets. This skill cannot be used with the Stab or Chainsaw traits.
 NoHands = No Hands
+NoHands_description = This player is unable to take possession of the ball. They may not attempt to pick it up, to catch it, or attempt to interfere with a pass.
 RunningPass = Running Pass
-ThenIStartedBlastin = Then I Started Blastin'!
\ No newline at end of file
+RunningPass_description = If this player performs a Quick Pass action, their activation does not have to end once the pass is resolved. They may continue to move after the pass if they have movement remaining.
+ThenIStartedBlastin = Then I Started Blastin'!
+ThenIStartedBlastin_description = Once per game, this player may make a ranged attack against an opposing player within three squares: roll a D6 and, on a 4+, the target is Knocked Down.
+
+###############################################################
+# Brackets
+###############################################################
+
+SingleElimination = Single elimination
+DoubleElimination = Double elimination
+Standings = Standings
+NafElo = NAF Elo
+Winners = Winners bracket
+Losers = Losers bracket
+GrandFinal = Grand final
+GrandFinalReset = Grand final reset
+BracketRound = { $side }, round { $round }
+
+###############################################################
+# Ratings
+###############################################################
+
+Glicko2 = Glicko-2
+TrueSkill = TrueSkill
+
+###############################################################
+# Injury causes
+###############################################################
+
+Foul = Foul
+Crowd = Crowd
+FailedDodge = Failed dodge
+FailedRush = Failed rush
+Other = Other
+
+###############################################################
+# Reports
+###############################################################
+
+MatchReport = Match report
+Team = Team
+Coach = Coach
+CurrentTeamValue = Current team value
+Weather = Weather
+Fans = Fans
+Date = Date
+PreGame = Pre-game sequence
+Inducements = Inducements
+FirstHalf = First half
+SecondHalf = Second half
+ExtraTime = Extra time
+FinalScore = Final score
+Penalties = Penalties
+StarPlayerPoints = Star player points
+Player = Player
+Injuries = Injuries
+Cause = Cause
+InflictedBy = Inflicted by
+Winnings = Winnings
+DedicatedFans = Dedicated fans
+ExpensiveMistakes = Expensive mistakes
+MostValuablePlayers = Most valuable players
+SentOff = Sent off
+PushedIntoCrowd = Pushed into the crowd
+NothingToReport = Nothing to report
+Roster = Roster
+Players = Players
+Number = #
+Name = Name
+Position = Position
+ShortMovementAllowance = MA
+ShortStrength = ST
+ShortAgility = AG
+ShortPassingAbility = PA
+ShortArmourValue = AV
+Skills = Skills
+HiringValue = Hiring value
+CurrentValue = Current value
+Staff = Staff
+Quantity = Quantity
+Treasury = Treasury
+TeamValue = Team value
+SpecialRules = Special rules
+ModifiedCharacteristic = Highlighted characteristics differ from the position profile.
+
+###############################################################
+# Pitch formats
+###############################################################
+
+Standard = Standard
+Sevens = Sevens
+
+###############################################################
+# Formatting
+###############################################################
+
+GoldPieces = { $value }k
+PlayersCount =
+    { $count ->
+        [one] { $count } player
+       *[other] { $count } players
+    }
+StarPlayerPointsCount =
+    { $count ->
+        [one] { $count } star player point
+       *[other] { $count } star player points
+    }
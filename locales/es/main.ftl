@@ -0,0 +1,1259 @@
+###############################################################
+# Errors
+###############################################################
+
+UnsupportedVersion = Versión no compatible
+NotEnoughPlayers = No hay suficientes jugadores (mínimo 11)
+TooMuchPlayers = Demasiados jugadores (máximo 16)
+TreasuryExceeded = Tesorería superada
+RosterNotExist = Esta plantilla no existe
+NotEnoughFans = No hay suficientes hinchas fieles
+TooMuchFans = Demasiados hinchas fieles
+PositionNotInRoster = Posición no disponible para esta plantilla
+PositionNotDefined = Posición no definida
+PositionMaxExceeded = Máximo de la posición superado
+TooMuchBigMen = Demasiados grandullones
+StaffNotInRoster = Este personal no existe para esta plantilla
+StaffExceededMaximum = Máximo de personal superado
+IncorrectTreasury = Tesorería incorrecta
+PlayersMustMatchTeamVersion = Los jugadores deben coincidir con la versión del equipo
+TeamsMustMatchGameVersion = Los equipos deben coincidir con la versión del partido
+CanNotCreateGameBeforeAnotherAlreadyPlayed = No se puede crear un partido antes de otro ya jugado
+SameCoachForBothTeams = Es el mismo entrenador para ambos equipos
+GameShouldHaveTwoTeams = Un partido debe tener dos equipos
+TeamAlreadyPlayingGame = Un equipo ya está jugando un partido
+StartGameBeforeAddingEvents = El partido debe empezar antes de añadir eventos
+GameNeedsAWinner = Este partido necesita un equipo ganador
+GamePenaltiesShouldHaveAWinner = Los penaltis del partido deben tener un ganador
+GameAlreadyClosed = El partido ya está cerrado
+GameNotClosed = El partido debe estar terminado y cerrado
+JourneymanPositionNotFound = Posición de jugador eventual no encontrada
+NotAPlayingTeam = No es un equipo que esté jugando
+JourneymenShouldBeOkBeforeBuyingInducements = Los jugadores eventuales deben resolverse antes de comprar incentivos
+SkillNotAvailableForPlayer = Habilidad no disponible para el jugador
+CaptainMissing = El equipo no tiene capitán, nombra uno
+TooMuchCaptains = El equipo tiene demasiados capitanes
+PlayerCanNotBeCaptain = Este jugador no puede ser el capitán del equipo
+NotEnoughTeamsForBracket = No hay suficientes equipos para crear un cuadro
+GameNotInBracket = Este partido no forma parte del cuadro
+BracketMatchAlreadyFollowedUp = Los partidos siguientes de este cruce del cuadro ya se han jugado
+PlayerNotInTeam = Este jugador no está en el equipo
+PlayerNumberAlreadyUsed = Otro jugador del equipo ya lleva este número
+TierNotAllowedInTournament = Este nivel de plantilla no está permitido en el torneo
+TournamentBudgetExceeded = Presupuesto del torneo superado
+TournamentSkillsExceeded = Demasiadas habilidades para el torneo
+TournamentSameSkillMaximumExceeded = La misma habilidad se ha elegido demasiadas veces para el torneo
+TournamentEliteSkillNotAllowed = Las habilidades de élite no están permitidas en el torneo
+TournamentSkillNotAllowed = Esta habilidad no está permitida en el torneo
+InvalidTeamFile = Archivo de equipo no válido
+InvalidTeamFileLine = Línea no válida en el archivo de equipo
+UnknownRoster = Plantilla desconocida
+UnknownPosition = Posición desconocida
+UnknownSkill = Habilidad desconocida
+UnknownAdvancement = Mejora desconocida
+UnknownInjury = Lesión desconocida
+UnknownStaff = Personal desconocido
+InvalidSerializedData = Datos serializados no válidos
+UnsupportedFormat = Formato de serialización no compatible
+DatabaseError = Error de base de datos
+DatabaseUnavailable = Base de datos no disponible
+DatabaseConstraintViolation = Restricción de la base de datos infringida
+DatabaseMigrationFailed = La migración de la base de datos ha fallado
+InvalidDatabaseData = Datos no válidos en la base de datos
+UnsupportedDatabase = Base de datos no compatible
+SquareOutOfPitch = La casilla está fuera del campo
+SquareNotInOwnHalf = La casilla no está en la mitad del equipo
+SquareAlreadyOccupied = La casilla ya está ocupada
+PlayerAlreadyOnPitch = El jugador ya está en el campo
+PlayerNotAvailable = El jugador no está disponible
+TooMuchPlayersOnPitch = Demasiados jugadores en el campo
+NotEnoughPlayersOnPitch = No hay suficientes jugadores en el campo
+NotEnoughPlayersOnLineOfScrimmage = No hay suficientes jugadores en la línea de melé
+TooMuchPlayersInWideZone = Demasiados jugadores en una banda
+GameNotInProgress = El partido no está en curso
+NotTeamTurn = No es el turno de este equipo
+PlayerCanNotAct = Este jugador no puede actuar ahora
+InvalidPath = Recorrido de movimiento no válido
+InvalidTarget = Objetivo no válido
+ActionAlreadyUsed = Esta acción ya se ha usado en este turno
+UnsupportedLanguage = Este idioma no es compatible
+InvalidTranslations = Estas traducciones no son Fluent válido
+TranslationsFileNotReadable = No se puede leer el archivo de traducciones
+TranslationsNotEnabled = No se pueden cargar traducciones sin la funcionalidad i18n
+
+###############################################################
+# Game status
+###############################################################
+
+Scheduled = Programado
+PreGameSequence = Secuencia previa al partido en curso
+GameInProgress = Partido en curso
+PostGameSequence = Secuencia posterior al partido en curso
+WaitingForValidation = Esperando validación
+Closed = Cerrado
+
+###############################################################
+# Versions
+###############################################################
+
+V1 = V1 - 1987
+V2 = V2 - 1991
+V3 = V3 - 1994
+V4 = V4 LRB - 2000
+V5 = V5 Temporada 2 - 2020
+V5S3 = V5 Temporada 3 - 2025
+
+###############################################################
+# Weather
+###############################################################
+
+SwelteringHeat = Calor Sofocante
+SwelteringHeat_description = ¡Algunos jugadores se desmayan por el calor insoportable! D3 jugadores elegidos al azar de cada equipo que estén en el campo al terminar una entrada se colocan en el banquillo de reservas. Se pierden la siguiente entrada.
+VerySunny = Muy Soleado
+VerySunny_description = ¡Un día glorioso, pero el cielo despejado y el sol radiante dificultan el juego de pase! Aplica un modificador de -1 cada vez que un jugador haga un chequeo de Capacidad de Pase.
+PerfectConditions = Condiciones Perfectas
+PerfectConditions_description = Ni demasiado frío ni demasiado calor. Un día cálido, seco y ligeramente nublado ofrece condiciones perfectas para el Blood Bowl.
+PouringRain = Lluvia Torrencial
+PouringRain_description = ¡Un aguacero torrencial empapa a los jugadores y vuelve el balón muy resbaladizo! Aplica un modificador de -1 cada vez que un jugador haga un chequeo de Agilidad para atrapar o recoger el balón, o para intentar interferir en un pase.
+Blizzard = Ventisca
+Blizzard_description = El frío helador y las fuertes nevadas vuelven el terreno traicionero. Aplica un modificador de -1 cada vez que un jugador intente Forzar la marcha una casilla adicional. Además, por la escasa visibilidad solo pueden intentarse pases rápidos y cortos.
+
+###############################################################
+# Inducements
+###############################################################
+
+PrayersToNuffle = Plegarias a Nuffle
+PrayersToNuffle_description = Cada Plegaria a Nuffle permite a tu equipo tirar una vez en la tabla de Plegarias a Nuffle antes del partido.
+PartTimeAssistantCoaches = Ayudantes de Entrenador a Tiempo Parcial
+PartTimeAssistantCoaches_description = Cada Ayudante de Entrenador a Tiempo Parcial suma 1 a las tiradas de Entrenamiento Brillante de tu equipo durante el partido.
+TempAgencyCheerleaders = Animadoras Temporales
+TempAgencyCheerleaders_description = Cada Animadora Temporal suma 1 a la Afición Animando de tu equipo durante el partido.
+TeamMascot = Mascota del Equipo
+TeamMascot_description = Una vez por parte, la Mascota te permite tirar un D6 cuando necesitas una segunda oportunidad de equipo: con un 5+ obtienes una segunda oportunidad gratuita.
+WeatherMage = Mago del Clima
+WeatherMage_description = Una vez por partido, al comienzo de uno de tus turnos o de una entrada, el Mago del Clima puede cambiar el clima tirando en la tabla de Clima, y puedes repetir el resultado si lo deseas.
+BlitzersBestKegs = Barriles de Blitzer's Best
+BlitzersBestKegs_description = Cada Barril de Blitzer's Best suma 1 a la tirada para ver si los jugadores inconscientes se recuperan al final de cada entrada.
+Bribes = Sobornos
+Bribes_description = Cuando un jugador de tu equipo es expulsado por el árbitro, puedes usar un Soborno. Tira un D6: con un 2+ el jugador no es expulsado y no se produce cambio de turno. Con un 1, el Soborno se pierde.
+ExtraTeamTraining = Entrenamiento Adicional
+ExtraTeamTraining_description = Cada Entrenamiento Adicional concede a tu equipo una segunda oportunidad de equipo adicional que puede usarse en cualquiera de las dos partes.
+MortuaryAssistant = Ayudante de Funeraria
+MortuaryAssistant_description = Una vez por partido, cuando un jugador de tu equipo con el rasgo Regeneración falla su tirada de Regeneración, el Ayudante de Funeraria puede repetirla.
+PlagueDoctor = Médico de la Peste
+PlagueDoctor_description = Una vez por partido, el Médico de la Peste puede repetir una tirada de Regeneración fallida o curar a un jugador inconsciente, colocándolo en el banquillo de reservas.
+RiotousRookies = Novatos Alborotadores
+RiotousRookies_description = Antes del partido, tu equipo gana 2D3+1 jugadores eventuales durante el partido.
+WanderingApothecaries = Médicos Ambulantes
+WanderingApothecaries_description = Cada Médico Ambulante puede usarse una vez por partido exactamente igual que el médico del equipo.
+HalflingMasterChef = Chef Mediano
+HalflingMasterChef_description = Al comienzo de cada parte, tira tres D6. Por cada 4+, tu equipo gana una segunda oportunidad de equipo y el equipo rival pierde una.
+BiasedRefereeDodgyLeagueRep = Árbitro Parcial: Representante Dudoso de la Liga
+BiasedRefereeDodgyLeagueRep_description = Cada vez que un jugador rival cometa una Falta y el árbitro no lo vea, tira un D6. Con un 5+, el jugador es expulsado de todos modos.
+WizardSportsWizard = Hechicero: Hechicero Deportivo
+WizardSportsWizard_description = Una vez por partido, el Hechicero Deportivo puede lanzar un hechizo, como una Bola de Fuego o ¡Zap!, contra jugadores rivales.
+StarPlayer = Jugador Estrella
+StarPlayer_description = Los Jugadores Estrella son jugadores famosos que pueden contratarse como incentivo para un único partido. Juegan en tu equipo y cuentan como Solitarios.
+MegaStarPlayer = Mega Estrella
+MegaStarPlayer_description = Las Mega Estrellas son los Jugadores Estrella más famosos de todos. Pueden contratarse como incentivo para un único partido, siguiendo las mismas reglas que los demás Jugadores Estrella.
+FamousCoachingStaff = Cuerpo Técnico Famoso
+FamousCoachingStaff_description = Entrenadores, médicos y especialistas de renombre que pueden contratarse como incentivo para un único partido, cada uno con una regla especial única.
+
+# Old
+
+BloodweiserKegs = Barriles de Bloodweiser
+BloodweiserKegs_description = Cada Barril de Bloodweiser suma 1 a la tirada para ver si los jugadores inconscientes se recuperan al final de cada entrada.
+SpecialPlays = Jugadas Especiales
+SpecialPlays_description = Cada carta de Jugada Especial permite a tu equipo robar y usar una Jugada Especial durante el partido.
+
+###############################################################
+# Prayers to Nuffle
+###############################################################
+
+TreacherousTrapdoor = Trampilla Traicionera
+TreacherousTrapdoor_description = Hasta el final de esta parte, cada vez que un jugador entre en una casilla de trampilla, tira un D6. Con un 1, la trampilla se abre, el jugador cae por ella y se coloca en el banquillo de reservas. Si llevaba el balón, este rebota desde la casilla de la trampilla.
+FriendsWithTheRef = Amigo del Árbitro
+FriendsWithTheRef_description = Hasta el final de esta entrada, al discutir con el árbitro, trata un resultado de 5-6 como Bueno, visto así y un resultado de 2-4 como Fuera de aquí.
+Stiletto = Estilete
+Stiletto_description = Elige al azar un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final de esta entrada, ese jugador gana el rasgo Apuñalar.
+IronMan = Hombre de Hierro
+IronMan_description = Elige un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final del partido, su Armadura mejora en 1, hasta un máximo de 11+.
+KnuckleDusters = Puños Americanos
+KnuckleDusters_description = Elige un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final de esta entrada, ese jugador gana la habilidad Golpe Mortífero (+1).
+BadHabits = Malos Hábitos
+BadHabits_description = Elige al azar D3 jugadores rivales disponibles para esta entrada y sin el rasgo Solitario. Hasta el final de esta entrada, esos jugadores ganan el rasgo Solitario (2+).
+GreasyCleats = Tacos Grasientos
+GreasyCleats_description = Elige al azar un jugador rival disponible para esta entrada. Alguien ha manipulado sus botas: hasta el final de esta entrada, su Movimiento se reduce en 1.
+BlessingsOfNuffle = Bendiciones de Nuffle
+BlessingsOfNuffle_description = Elige un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final del partido, ese jugador gana la habilidad Profesional.
+MolesUnderThePitch = Topos bajo el Campo
+MolesUnderThePitch_description = Hasta el final de esta parte, aplica un modificador de -1 cada vez que un jugador intente Forzar la marcha, o de -2 si ambos entrenadores obtuvieron este resultado.
+PerfectPassing = Pase Perfecto
+PerfectPassing_description = Hasta el final del partido, cualquier jugador de tu equipo que complete un pase gana 2 PE en lugar del 1 PE habitual.
+DazzlingCatching = Recepción Deslumbrante
+DazzlingCatching_description = Hasta el final del partido, cualquier jugador de tu equipo que atrape un pase gana 1 PE.
+FanInteraction = Interacción con la Afición
+FanInteraction_description = Hasta el final de esta entrada, si un jugador rival sufre una Lesión al ser empujado hacia el público, tu equipo gana 2 PE como si lo hubiera lesionado con un Placaje.
+FoulingFrenzy = Frenesí de Faltas
+FoulingFrenzy_description = Hasta el final del partido, cualquier jugador de tu equipo que cause una Lesión con una Falta gana 2 PE, como si la hubiera causado con un Placaje.
+ThrowARock = Lanzar una Piedra
+ThrowARock_description = Hasta el final de esta entrada, si un jugador rival pierde tiempo, al final de su turno puedes tirar un D6. Con un 5+, un aficionado furioso le lanza una piedra y es Derribado inmediatamente.
+UnderScrutiny = Bajo Vigilancia
+UnderScrutiny_description = Hasta el final de esta parte, cualquier jugador rival que cometa una Falta es visto automáticamente por el árbitro, aunque no saque dobles.
+IntensiveTraining = Entrenamiento Intensivo
+IntensiveTraining_description = Elige al azar un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final del partido, ese jugador gana una habilidad Primaria a tu elección.
+
+# Old
+
+BlessedStatueOfNuffle = Estatua Bendita de Nuffle
+BlessedStatueOfNuffle_description = Elige un jugador de tu equipo disponible para esta entrada y sin el rasgo Solitario. Hasta el final del partido, ese jugador gana la habilidad Profesional.
+NecessaryViolence = Violencia Necesaria
+NecessaryViolence_description = Hasta el final del partido, cualquier jugador de tu equipo que cause una Lesión gana 3 PE en lugar de los 2 PE habituales.
+
+###############################################################
+# Roster names
+###############################################################
+
+AmazonRoster = Amazonas
+BlackOrcRoster = Orcos Negros
+BretonnianRoster = Bretonianos
+ChaosChosenRoster = Elegidos del Caos
+ChaosDwarfRoster = Enanos del Caos
+ChaosRenegadeRoster = Renegados del Caos
+DarkElfRoster = Elfos Oscuros
+DwarfRoster = Enanos
+ElvenUnionRoster = Unión Élfica
+GnomeRoster = Gnomos
+GoblinRoster = Goblins
+HalflingRoster = Medianos
+HighElfRoster = Altos Elfos
+HumanRoster = Humanos
+ImperialNobilityRoster = Nobleza Imperial
+KhorneRoster = Khorne
+LizardmenRoster = Hombres Lagarto
+NecromanticHorrorRoster = Horror Nigromántico
+NorseRoster = Nórdicos
+NurgleRoster = Nurgle
+OgreRoster = Ogros
+OldWorldAllianceRoster = Alianza del Viejo Mundo
+OrcRoster = Orcos
+ShamblingUndeadRoster = No Muertos
+SkavenRoster = Skaven
+SnotlingRoster = Snotlings
+TombKingsRoster = Reyes Funerarios
+UnderworldDenizensRoster = Habitantes del Inframundo
+VampireRoster = Vampiros
+WoodElfRoster = Elfos Silvanos
+
+###############################################################
+# Special leagues and rules
+###############################################################
+
+# Special leagues
+
+BadlandsBrawl = Reyerta de las Tierras Yermas
+BadlandsBrawl_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos de la Reyerta de las Tierras Yermas.
+ChaosClash = Choque del Caos
+ElvenKingdomsLeague = Liga de los Reinos Élficos
+ElvenKingdomsLeague_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos de la Liga de los Reinos Élficos.
+HalflingThimbleCup = Copa Dedal Mediana
+HalflingThimbleCup_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos de la Copa Dedal Mediana.
+LustrianSuperleague = Superliga de Lustria
+LustrianSuperleague_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos de la Superliga de Lustria.
+OldWorldClassic = Clásico del Viejo Mundo
+OldWorldClassic_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos del Clásico del Viejo Mundo.
+SylvanianSpotlight = Foco de Sylvania
+SylvanianSpotlight_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos del Foco de Sylvania.
+UnderworldChallenge = Desafío del Inframundo
+UnderworldChallenge_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos del Desafío del Inframundo.
+WoodlandLeague = Liga de los Bosques
+WorldsEdgeSuperleague = Superliga del Fin del Mundo
+WorldsEdgeSuperleague_description = Este equipo puede contratar Jugadores Estrella e incentivos disponibles para los equipos de la Superliga del Fin del Mundo.
+
+# Special rules
+
+BrawlingBrutes = Brutos Pendencieros
+BrawlingBrutes_description = Este equipo puede contratar jugadores con la palabra clave Grandullón sin que cuenten para el límite habitual, y puede repetir un único resultado Ambos Derribados una vez por partido.
+BriberyAndCorruption = Soborno y Corrupción
+BriberyAndCorruption_description = Una vez por partido, si este equipo saca un 1 natural al usar un Soborno, puede repetir la tirada. Además, los Sobornos le cuestan menos como incentivos.
+FavouredOf = Favorecido de...
+FavouredOf_description = Al crear este equipo, el entrenador debe elegir qué dios del Caos lo favorece. Esta elección determina qué Jugadores Estrella e incentivos puede contratar.
+FavouredOfHashut = Favorecido de Hashut
+FavouredOfHashut_description = Este equipo está favorecido por Hashut y puede contratar Jugadores Estrella e incentivos disponibles para los equipos con esta regla especial.
+FavouredOfKhorne = Favorecido de Khorne
+FavouredOfKhorne_description = Este equipo está favorecido por Khorne y puede contratar Jugadores Estrella e incentivos disponibles para los equipos con esta regla especial.
+FavouredOfNurgle = Favorecido de Nurgle
+FavouredOfNurgle_description = Este equipo está favorecido por Nurgle y puede contratar Jugadores Estrella e incentivos disponibles para los equipos con esta regla especial.
+LowCostLinemen = Líneas Baratos
+LowCostLinemen_description = Los líneas de este equipo no cuentan en su Valor Actual al calcular los incentivos y el dinero de bolsillo disponible.
+MastersOfUndeath = Maestros de la No Muerte
+MastersOfUndeath_description = Una vez por partido, el entrenador principal de este equipo puede alzar a un muerto: cuando un jugador rival sufre un resultado MUERTO, puede unirse al equipo como nuevo Zombi de línea durante el resto del partido.
+Swarming = Enjambre
+Swarming_description = Durante cada secuencia de Inicio de Entrada, tras el paso 2 y antes del paso 3, puedes sacar D3 jugadores con este rasgo del banquillo de reservas y desplegarlos en el campo, siempre que no estén en una banda ni en la línea de melé.
+TeamCaptain = Capitán del Equipo
+TeamCaptain_description = Al crear este equipo, un jugador debe ser nombrado Capitán. Mientras el capitán esté en el campo, puedes tirar un D6 cada vez que uses una segunda oportunidad de equipo: con un 6, no se pierde.
+VampireLord = Señor Vampiro
+VampireLord_description = Este equipo puede contratar un Médico de la Peste o un Ayudante de Funeraria como incentivo, y sus Vampiros pueden alimentarse de Siervos para saciar su Sed de Sangre.
+
+###############################################################
+# Staff
+###############################################################
+
+Cheerleader = Animadoras
+AssistantCoach = Ayudantes de entrenador
+Apothecary = Médico
+ReRoll = Segundas oportunidades
+
+###############################################################
+# Famous Coaching Staff
+###############################################################
+
+AyleenAndar = Ayleen Andar
+FinkDaFixer = Fink da Fixer
+GalandrilSilverwater = Galandril Silverwater
+JosefBugman = Josef Bugman
+KariColdsteel = Kari Coldsteel
+KrotShockwhisker = Krot Shockwhisker
+MungoSpinecracker = Mungo Spinecracker
+PapaSkullbones = Papa Skullbones
+ProfessorFronkelheim = Professor Fronkelheim
+SchielundScharlitan = Schielund Scharlitan
+
+###############################################################
+# Position names
+###############################################################
+
+# Keywords & Common
+
+All = Todos
+
+Animal = Animal
+Animal_description = Los animales son bestias que saltan al campo junto a sus cuidadores, como el zorro del bosque de los Gnomos o el jabalí cervecero nórdico.
+Beastman = Hombre Bestia
+Beastman_description = Los hombres bestia son seguidores astados de los Dioses Oscuros que forman el grueso de los Elegidos del Caos, y juegan como Khorngors y Pestigors para Khorne y Nurgle.
+Construct = Constructo
+Construct_description = Los constructos son cuerpos artificiales animados por magia oscura, como los gólems de carne de los Horrores Nigrománticos.
+Dryad = Dríade
+Dryad_description = Las dríades son espíritus arbóreos de los bosques profundos. Solo el jugador estrella Willow Rosebark tiene esta palabra clave.
+Dwarf = Enano
+Dwarf_description = Los enanos son jugadores bajos, tercos y muy acorazados de los equipos Enanos, Enanos del Caos y de la Alianza del Viejo Mundo. Sus viejos rencores les han valido más de un Odio.
+Dwarfs = Enanos
+Elf = Elfo
+Elf_description = Los elfos son jugadores ágiles y elegantes que confían en la velocidad y el pase, alineados por Elfos Oscuros, Altos Elfos, Elfos Silvanos y la Unión Élfica.
+Ghoul = Necrófago
+Ghoul_description = Los necrófagos son carroñeros rápidos y salvajes que hacen de corredores para No Muertos y Horrores Nigrománticos.
+Gnoblar = Gnoblar
+Gnoblar_description = Los gnoblars son pielesverdes diminutos y cobardes que llenan la línea de los equipos Ogros y procuran no estorbar a los grandes.
+Gnome = Gnomo
+Gnome_description = Los gnomos son pequeños bromistas e ilusionistas astutos que forman el núcleo de los equipos Gnomos.
+Goblin = Goblin
+Goblin_description = Los goblins son pielesverdes pequeños y taimados. Tienen sus propios equipos y juegan junto a Orcos, Enanos del Caos y Renegados del Caos, a menudo con armas secretas.
+Halfling = Mediano
+Halfling_description = Los medianos son gente menuda mucho más aficionada a la comida que al fútbol. Forman equipos Medianos y pueden unirse a equipos Humanos como receptores.
+Halflings = Medianos
+Human = Humano
+Human_description = Los humanos son los jugadores más extendidos y forman, entre otros, equipos Humanos, Amazonas, Bretonianos, de Nobleza Imperial, Nórdicos y de la Alianza del Viejo Mundo.
+Humans = Humanos
+Lizardman = Hombre Lagarto
+Lizardman_description = Los hombres lagarto son los siervos de sangre fría de los Ancestrales: eslizones ágiles, saurios duros y poderosos kroxigors.
+Minotaur = Minotauro
+Minotaur_description = Los minotauros son enormes grandullones con cabeza de toro llenos de Furia Desatada, contratados por Elegidos del Caos, Enanos del Caos y Renegados del Caos.
+Ogre = Ogro
+Ogre_description = Los ogros son grandullones enormes y hambrientos que tienen sus propios equipos y se alquilan a equipos del Caos, Humanos y de la Alianza del Viejo Mundo.
+Orc = Orco
+Orc_description = Los orcos son pielesverdes duros y agresivos que practican un juego de carrera brutal en los equipos Orcos, Orcos Negros y Renegados del Caos.
+Skaven = Skaven
+Skaven_description = Los skaven son hombres rata rápidos y traicioneros. Corredores de alcantarilla, guerreros de clan y ratas ogro juegan para Skaven, Moradores del Inframundo y Renegados del Caos.
+Skeleton = Esqueleto
+Skeleton_description = Los esqueletos son los huesos reanimados de los muertos, lentos pero difíciles de derribar, alineados por No Muertos y Reyes Funerarios.
+Snakeman = Hombre Serpiente
+Snakeman_description = Los hombres serpiente son criaturas serpentinas. Solo el jugador estrella Boa Kon'ssstriktr tiene esta palabra clave.
+Snotling = Snotling
+Snotling_description = Los snotlings son los pielesverdes más pequeños e inundan el campo para los equipos Snotlings y del Inframundo, junto a sus saltarines, lanzahongos y carros de bombeo.
+Spawn = Engendro
+Spawn_description = Los engendros son horrores mutantes y sin mente, regalos de los Dioses Oscuros, como el engendro de sangre de Khorne o el engendro pútrido de Nurgle.
+Spite = Spite
+Spite_description = Los spites son diminutos espíritus del bosque. Solo el jugador estrella Swiftvine Glimmershard tiene esta palabra clave.
+Squirrel = Ardilla
+Squirrel_description = Esta palabra clave pertenece a un único jugador estrella peludo: Akhorne la Ardilla.
+Thrall = Siervo
+Thrall_description = Los siervos son los sirvientes humanos de los equipos Vampiros, de quienes se alimentan los Vampiros cuando les domina la Sed de Sangre.
+Treeman = Hombre Árbol
+Treeman_description = Los hombres árbol son antiguos y lentos espíritus arbóreos que hacen de grandullones para Elfos Silvanos, Medianos, Gnomos y la Alianza del Viejo Mundo.
+Troll = Trol
+Troll_description = Los troles son grandullones torpes que se regeneran y juegan para equipos pielesverdes, del Caos y del Inframundo. Algunos jugadores sienten Odio hacia los troles.
+Undead = No Muerto
+Undead_description = Los no muertos han sido sacados de la tumba por la magia oscura y juegan para No Muertos, Horrores Nigrománticos, Reyes Funerarios y Vampiros. Algunos jugadores sienten Odio hacia los no muertos.
+Vampire = Vampiro
+Vampire_description = Los vampiros son nobles inmortales, rápidos y fuertes pero atados a la Sed de Sangre que los empuja a alimentarse de sus siervos.
+Werewolf = Hombre Lobo
+Werewolf_description = Los hombres lobo son feroces cambiaformas alineados por los Horrores Nigrománticos.
+Wraith = Espectro
+Wraith_description = Los espectros son no muertos etéreos que se deslizan por el campo para los Horrores Nigrománticos.
+Yhetee = Yhetee
+Yhetee_description = Los yhetees son grandullones salvajes del norte helado que juegan para los equipos Nórdicos.
+Zoat = Zoat
+Zoat_description = Los zoats son criaturas del bosque raras y esquivas. Solo el jugador estrella Zolcath el Zoat tiene esta palabra clave.
+Zombie = Zombi
+Zombie_description = Los zombis son cadáveres lentos y tambaleantes que forman los jugadores de línea baratos de No Muertos y Horrores Nigrománticos.
+
+Lineman = Línea
+Lineman_description = Los líneas son la columna vertebral de un equipo. Pueden contratarse en gran número y tienen acceso a las categorías de habilidades básicas del equipo.
+Thrower = Lanzador
+Thrower_description = Los lanzadores son los creadores de juego del equipo, especializados en pasar el balón.
+Catcher = Receptor
+Catcher_description = Los receptores se especializan en atrapar pases y llevar el balón a la zona de anotación.
+Blitzer = Blitzer
+Blitzer_description = Los blitzers combinan velocidad y fuerza para atravesar la línea rival y cazar al portador del balón.
+Blocker = Bloqueador
+Blocker_description = Los bloqueadores son jugadores fuertes y duros que sostienen la línea de melé y derriban a los rivales.
+Runner = Corredor
+Runner_description = Los corredores son jugadores rápidos y ágiles que hacen avanzar el balón por el campo.
+BigGuy = Grandullón
+BigGuy_description = Los Grandullones son jugadores enormes, poderosos y a menudo poco fiables. Un equipo solo puede incluir un número limitado de ellos.
+Special = Especial
+Special_description = Los jugadores especiales cumplen un papel único en su equipo que no encaja con ninguna otra palabra clave de posición.
+
+# Amazon
+
+EagleWarriorLinewoman = Guerrera Águila de línea
+PiranhaWarriorBlitzer = Guerrera Piraña blitzer
+PythonWarriorThrower = Guerrera Pitón lanzadora
+JaguarWarriorBlocker = Guerrera Jaguar bloqueadora
+
+# Black Orc
+
+GoblinBruiserLineman = Goblin Matón de línea
+BlackOrc = Orco Negro
+TrainedTroll = Trol Adiestrado
+
+# Bretonnian
+
+Squires = Escudero
+KnightCatcher = Caballero receptor
+KnightThrower = Caballero lanzador
+GrailKnight = Caballero del Grial
+
+# Chaos Chosen
+
+BeastmanRunnerLineman = Hombre Bestia corredor de línea
+ChosenBlocker = Elegido bloqueador
+ChaosTroll = Trol del Caos
+ChaosOgre = Ogro del Caos
+
+# Chaos Dwarf
+
+HobgoblinLineman = Hobgoblin de línea
+HobgoblinSneakyStabba = Hobgoblin Apuñalador Furtivo
+ChaosDwarfBlocker = Enano del Caos bloqueador
+ChaosDwarfFlamesmith = Enano del Caos Forjallamas
+BullCentaurBlitzer = Centauro Toro blitzer
+
+# Chaos Renegade
+
+RenegadeHumanLineman = Humano Renegado de línea
+RenegadeHumanThrower = Humano Renegado lanzador
+RenegadeGoblin = Goblin Renegado
+RenegadeOrc = Orco Renegado
+RenegadeSkaven = Skaven Renegado
+RenegadeDarkElf = Elfo Oscuro Renegado
+RenegadeTroll = Trol Renegado
+RenegadeOgre = Ogro Renegado
+RenegadeMinotaur = Minotauro Renegado
+RenegadeRatOgre = Rata Ogro Renegada
+
+# Dark Elf
+
+DarkElfLineman = Elfo Oscuro de línea
+Assassin = Asesino
+WitchElf = Bruja Elfa
+
+# Dwarf
+
+DwarfBlockerLineman = Enano de línea
+TrollSlayer = Matatrols
+Deathroller = Apisonadora
+
+# Gnome
+
+GnomeLineman = Gnomo de línea
+GnomeBeastmaster = Gnomo Señor de las Bestias
+GnomeIllusionist = Gnomo Ilusionista
+WoodlandFox = Zorro del Bosque
+AlternForestTreeman = Hombre Árbol del Bosque de Altern
+
+# Goblin
+
+GoblinLineman = Goblin de línea
+Bomma = Bombardero
+Looney = Chiflado
+Fanatic = Fanático
+Pogoer = Saltarín
+Ooligan = 'Ooligan
+DoomDiver = Buceador Suicida
+
+# Halfling
+
+HalflingHopefulLineman = Mediano Esperanzado de línea
+HalflingHefty = Mediano Robusto
+HalflingCatcher = Mediano receptor
+
+# High Elf
+
+HighElfLineman = Alto Elfo de línea
+PhoenixWarrior = Guerrero Fénix
+DragonPrince = Príncipe Dragón
+WhiteLion = León Blanco
+
+# Human
+
+HumanLineman = Humano de línea
+HalflingHopeful = Mediano Esperanzado
+
+# Imperial Nobility
+
+ImperialRetainerLineman = Criado Imperial de línea
+ImperialThrower = Lanzador Imperial
+NobleBlitzer = Noble blitzer
+Bodyguard = Guardaespaldas
+
+# Khorne
+
+BloodbornMarauderLineman = Merodeador Nacido de la Sangre de línea
+Khorngor = Khorngor
+Bloodseeker = Buscasangre
+Bloodspawn = Engendro de Sangre
+
+# Lizardmen
+
+SkinkRunnerLineman = Eslizón corredor de línea
+ChameleonSkink = Eslizón Camaleón
+SaurusBlocker = Saurio bloqueador
+Kroxigor = Kroxigor
+
+# Necromantic Horror
+
+ZombieLineman = Zombi de línea
+GhoulRunner = Necrófago corredor
+FleshGolem = Gólem de Carne
+
+# Norse
+
+NorseRaiderLineman = Saqueador Nórdico de línea
+BeerBoar = Jabalí Cervecero
+NorseBerzerker = Berserker Nórdico
+Valkyrie = Valquiria
+Ulfwerener = Ulfwerener
+
+# Nurgle
+
+RotterLineman = Podrido de línea
+Pestigor = Pestigor
+Bloater = Hinchado
+Rotspawn = Engendro Putrefacto
+
+# Ogre
+
+GnoblarLineman = Gnoblar de línea
+OgreRuntPunter = Ogro Enclenque Pateador
+OgreBlocker = Ogro bloqueador
+
+# Old World Alliance
+
+OldWorldHumanLineman = Humano del Viejo Mundo de línea
+OldWorldHumanThrower = Humano del Viejo Mundo lanzador
+OldWorldHumanCatcher = Humano del Viejo Mundo receptor
+OldWorldDwarfLineman = Enano del Viejo Mundo de línea
+OldWorldDwarfBlitzer = Enano del Viejo Mundo blitzer
+OldWorldDwarfBlocker = Enano del Viejo Mundo bloqueador
+OldWorldDwarfRunner = Enano del Viejo Mundo corredor
+OldWorldHumanBlitzer = Humano del Viejo Mundo blitzer
+OldWorldDwarfTrollSlayer = Matatrols Enano del Viejo Mundo
+OldWorldHalflingHopefuls = Medianos Esperanzados del Viejo Mundo
+
+# Orc
+
+OrcLineman = Orco de línea
+BigUn = Orco Grandote
+UntrainedTroll = Trol sin Adiestrar
+
+# Shambling Undead
+
+SkeletonLineman = Esqueleto de línea
+WightBlitzer = Tumulario blitzer
+Mummy = Momia
+
+# Skaven
+
+SkavenClanratLineman = Skaven Guerrero de Clan de línea
+GutterRunner = Corredor de Alcantarilla
+RatOgre = Rata Ogro
+
+# Snotling
+
+SnotlingLineman = Snotling de línea
+FungusFlinga = Lanzahongos
+FunHoppa = Saltarín Divertido
+StiltyRunna = Corredor con Zancos
+PumpWagon = Carro de Bombeo
+
+# Tomb Kings
+
+AnointedThrower = Ungido lanzador
+AnointedBlitzer = Ungido blitzer
+TombGuardian = Guardián de la Tumba
+
+# Underworld Denizens
+
+UnderworldGoblinLineman = Goblin del Inframundo de línea
+UnderworldSnotling = Snotling del Inframundo
+SkavenClanrat = Skaven Guerrero de Clan
+SkavenThrower = Skaven lanzador
+SkavenBlitzer = Skaven blitzer
+UnderworldTroll = Trol del Inframundo
+MutantRatOgre = Rata Ogro Mutante
+
+# Vampire
+
+ThrallLineman = Siervo de línea
+VampireRunner = Vampiro corredor
+VampireBlitzer = Vampiro blitzer
+VampireThrower = Vampiro lanzador
+Vargheist = Vargheist
+
+# Wood Elf
+
+WoodElfLineman = Elfo Silvano de línea
+Wardancer = Danzante Guerrero
+LorenForestTreeman = Hombre Árbol del Bosque de Loren
+
+# Others
+
+Journeyman = Jugador eventual
+
+# Stars
+
+AkhorneTheSquirrel = Akhorne The Squirrel
+AnqiPanqi = Anqi Panqi
+BarikFarblast = Barik Farblast
+BilerotVomitflesh = Bilerot Vomitflesh
+BoaKonSsstriktr = Boa Kon'ssstriktr
+BomberDribblesnot = Bomber Dribblesnot
+BryceTheSliceCambuel = Bryce 'The Slice' Cambuel
+CaptainKarinaVonRiesz = Captain Karina Von Riesz
+CindyPiewhistle = Cindy Piewhistle
+CountLuthorVonDrakenborg = Count Luthor von Drakenborg
+DeeprootStrongbranch = Deeproot Strongbranch
+DriblAndDrull = Dribl and Drull
+Dribl = Dribl
+Drull = Drull
+EldrilSidewinder = Eldril Sidewinder
+EstelleLaVeneaux = Estelle la Veneaux
+FrankNStein = Frank 'n' Stein
+FungusTheLoon = Fungus the Loon
+GlartSmashrip = Glart Smashrip
+GlorielSummerbloom = Gloriel Summerbloom
+GlotlStop = Glotl Stop
+GrakAndCrumbleberry = Grak and Crumbleberry
+Grak = Grak
+Crumbleberry = Crumbleberry
+GrashnakBlackhoof = Grashnak Blackhoof
+GretchenWachterTheBloodBowlWidow = Gretchen Wächter 'The Blood Bowl Widow'
+GriffOberwald = Griff Oberwald
+GrimIronjaw = Grim Ironjaw
+GrombrindalTheWhiteDwarf = Grombrindal, The White Dwarf
+GufflePussmaw = Guffle Pussmaw
+HakflemSkuttlespike = Hakflem Skuttlespike
+HelmutWulf = Helmut Wulf
+HTharkTheUnstoppable = H'thark the Unstoppable
+IvanTAnimalDeathshroud = Ivan 'the Animal' Deathshroud
+IvarEriksson = Ivar Eriksson
+JeremiahKool = Jeremiah Kool
+JordellFreshbreeze = Jordell Freshbreeze
+KarlaVonKill = Karla Von Kill
+KirothKrakeneye = Kiroth Krakeneye
+KreekTheVerminatorRustgouger = Kreek 'the Verminator' Rustgouger
+LordBorakTheDespoiler = Lord Borak the Despoiler
+MapleHighgrove = Maple Highgrove
+MaxSpleenripper = Max Spleenripper
+MightyZug = Mighty Zug
+MorgNThorg = Morg 'n' Thorg
+NobblaBlackwart = Nobbla Blackwart
+PuggyBaconbreath = Puggy Baconbreath
+RashnakBackstabber = Rashnak Backstabber
+RipperBolgrot = Ripper Bolgrot
+RodneyRoachbait = Rodney Roachbait
+RowanaForestfoot = Rowana Forestfoot
+RoxannaDarknail = Roxanna Darknail
+RumbelowSheepskin = Rumbelow Sheepskin
+ScrappaSorehead = Scrappa Sorehead
+ScylaAnfingrimm = Scyla Anfingrimm
+SkitterStabStab = Skitter Stab-stab
+SkrorgSnowpelt = Skrorg Snowpelt
+SkrullHalfheight = Skrull Halfheight
+SwiftvineGlimmershard = Swiftvine Glimmershard
+TheBlackGobbo = The Black Gobbo
+TheSwiftTwins = The Swift Twins
+LucienSwift = Lucien Swift
+ValenSwift = Valen Swift
+ThorssonStoutmead = Thorsson Stoutmead
+VaragGhoulChewer = Varag Ghoul-Chewer
+WilhelmChaney = Wilhelm Chaney
+WillowRosebark = Willow Rosebark
+WithergraspDoubledrool = Withergrasp Doubledrool
+ZolcathTheZoat = Zolcath the Zoat
+ZzhargMadeye = Zzharg Madeye
+
+###############################################################
+# Injuries
+###############################################################
+
+Stunned = Aturdido
+KO = Inconsciente
+BadlyHurt = Malherido
+SeriouslyHurt = Gravemente Herido
+SeriousInjury = Lesión Grave
+HeadInjury = Lesión en la Cabeza
+SmashedKnee = Rodilla Destrozada
+BrokenArm = Brazo Roto
+DislocatedHip = Cadera Dislocada
+NeckInjury = Lesión de Cuello
+Dead = Muerto
+
+# Old
+
+DislocatedShoulder = Hombro Dislocado
+
+###############################################################
+# Successes
+###############################################################
+
+PassingCompletion = Pase Completado
+ThrowingCompletion = Lanzamiento Completado
+LandingOnFeet = Aterrizaje de Pie
+Deflection = Desvío
+Interception = Intercepción
+Casualty = Lesión
+Touchdown = Touchdown
+MostValuablePlayer = Jugador Más Valioso
+StarPlayerPoint = Punto de Estrellato
+
+###############################################################
+# Advancement choices
+###############################################################
+
+RandomPrimarySkill = Habilidad Primaria Aleatoria ({ $skill_category })
+RandomSecondarySkill = Habilidad Secundaria Aleatoria ({ $skill_category })
+ChosenPrimarySkill = Habilidad Primaria Elegida
+ChosenSecondarySkill = Habilidad Secundaria Elegida
+RandomCharacteristic = Característica Aleatoria
+
+###############################################################
+# Advancements
+###############################################################
+
+ChosenSkill = Habilidad Elegida ({ $skill })
+RandomSkill = Habilidad Aleatoria ({ $skill })
+MovementAllowance = Movimiento
+Strength = Fuerza
+Agility = Agilidad
+PassingAbility = Capacidad de Pase
+ArmourValue = Armadura
+
+###############################################################
+# Skill Categories
+###############################################################
+
+General = General
+Mutation = Mutación
+Pass = Pase
+Pass_description = Este jugador puede repetir un chequeo de Capacidad de Pase fallido al realizar una acción de Pase.
+Devious = Taimada
+Trait = Rasgo
+
+###############################################################
+# Skills & Traits
+###############################################################
+
+# General
+
+Block = Placar
+Block_description = Cuando se aplica un resultado Ambos Derribados durante un Placaje, este jugador puede elegir ignorarlo y no ser Derribado.
+Dauntless = Intrépido
+Dauntless_description = Cuando este jugador realiza un Placaje, si el objetivo elegido tiene más Fuerza, tira un D6 y suma la Fuerza de este jugador. Si el total supera la Fuerza del objetivo, la Fuerza de este jugador aumenta hasta igualarla durante el Placaje.
+Fend = Zafarse
+Fend_description = Si este jugador es empujado por cualquier resultado de los dados de placaje, puede impedir que el jugador que lo empujó le siga. Zafarse no puede usarse contra un jugador con la habilidad Imparable durante un Blitz.
+Frenzy = Furia
+Frenzy_description = Cada vez que este jugador realiza un Placaje, debe seguir al objetivo si este es empujado. Si el objetivo sigue En Pie tras ser empujado y este jugador puede seguirle, debe realizar un segundo placaje contra el mismo objetivo.
+Kick = Patada
+Kick_description = Si este jugador es designado para patear durante una patada inicial, puedes dividir a la mitad el resultado del D6 que determina cuántas casillas se desvía el balón, redondeando hacia abajo.
+Pro = Profesional
+Pro_description = Una vez por turno de equipo, este jugador puede intentar repetir un dado que se haya tirado para él. Tira un D6: con un 3+ el dado puede repetirse; con un 1-2 se mantiene el resultado original. Después no puede usarse una segunda oportunidad de equipo para ese dado.
+SteadyFooting = Pie Firme
+SteadyFooting_description = Cuando este jugador vaya a ser Derribado o Tumbado, tira un D6. Con un 6 mantiene el equilibrio y sigue En Pie.
+StripBall = Robar Balón
+StripBall_description = Cuando este jugador realiza un Placaje contra un rival que tiene el balón, elegir un resultado Empujón hace que ese jugador suelte el balón en la casilla a la que es empujado.
+SureHands = Manos Seguras
+SureHands_description = Este jugador puede repetir cualquier intento fallido de recoger el balón. Además, la habilidad Robar Balón no puede usarse contra un jugador con esta habilidad.
+Tackle = Entrada
+Tackle_description = Cuando un rival activo intenta Esquivar desde una casilla en la que estaba Marcado por uno o más jugadores con esta habilidad, ese jugador no puede usar la habilidad Esquivar.
+Taunt = Provocar
+Taunt_description = Cuando un rival realiza un Placaje contra este jugador, si este jugador es empujado, puedes elegir que el atacante le siga, y debe hacerlo.
+Wrestle = Lucha
+Wrestle_description = Este jugador puede usar esta habilidad cuando se aplica un resultado Ambos Derribados, tanto si realiza un Placaje como si es su objetivo. En lugar de aplicar el resultado normalmente, ambos jugadores quedan Tumbados y no se hace tirada de Armadura.
+
+# Agility
+
+Catch = Atrapar
+Catch_description = Este jugador puede repetir un chequeo de Agilidad fallido al intentar atrapar el balón.
+Defensive = Defensivo
+Defensive_description = Durante el turno del rival, los jugadores rivales Marcados por este jugador no pueden usar la habilidad Defensa.
+DivingCatch = Recepción en Plancha
+DivingCatch_description = Este jugador puede intentar atrapar el balón si un pase, saque de banda o patada inicial hace que caiga en una casilla dentro de su zona de defensa tras dispersarse o desviarse. Además, puede aplicar un modificador de +1 a cualquier intento de atrapar un pase preciso si ocupa la casilla objetivo.
+DivingTackle = Placaje en Plancha
+DivingTackle_description = Si un rival activo que intenta Esquivar, Saltar o dar un Brinco para abandonar una casilla en la que lo Marca este jugador supera su chequeo de Agilidad, puedes declarar que este jugador usa esta habilidad. Tu rival debe restar 2 al resultado y este jugador queda Tumbado en la casilla abandonada.
+Dodge = Esquivar
+Dodge_description = Una vez por turno de equipo, durante su activación, este jugador puede repetir un chequeo de Agilidad fallido al intentar Esquivar. Además, puede usar esta habilidad cuando es objetivo de un Placaje y se aplica un resultado Tropezón.
+HitAndRun = Golpear y Correr
+HitAndRun_description = Tras realizar un Placaje, este jugador puede moverse inmediatamente una casilla gratis, ignorando las zonas de defensa, siempre que siga En Pie. Tras este movimiento gratuito no debe estar Marcado por ni Marcando a ningún rival.
+JumpUp = Levantarse de un Salto
+JumpUp_description = Si este jugador está Tumbado puede levantarse gratis. Además, si está Tumbado al activarse, puede intentar levantarse de un salto y realizar un Placaje superando un chequeo de Agilidad con un modificador de +1.
+Leap = Brinco
+Leap_description = Durante su movimiento, en lugar de saltar sobre una única casilla ocupada por un jugador Tumbado o Aturdido, un jugador con esta habilidad puede dar un Brinco sobre cualquier casilla adyacente, incluidas casillas vacías y ocupadas por jugadores En Pie. Además, puede reducir en 1 cualquier modificador negativo al chequeo de Agilidad al Saltar o dar un Brinco.
+SideStep = Paso Lateral
+SideStep_description = Si este jugador es empujado por cualquier motivo, no se mueve a una casilla elegida por el entrenador rival. En su lugar, su entrenador puede elegir cualquier casilla vacía adyacente a este jugador.
+SafePairOfHands = Manos Firmes
+SafePairOfHands_description = Si este jugador es Derribado o Tumbado mientras tiene el balón, el balón no rebota. En su lugar, puedes colocarlo en una casilla vacía adyacente a la que ocupa este jugador al quedar Tumbado.
+Sprint = Esprintar
+Sprint_description = Cuando este jugador realiza cualquier acción que incluya movimiento, puede intentar Forzar la marcha tres veces en lugar de las dos habituales.
+SureFeet = Pies Firmes
+SureFeet_description = Una vez por turno de equipo, durante su activación, este jugador puede repetir el D6 al intentar Forzar la marcha.
+
+# Devious
+
+DirtyPlayer = Jugador Sucio
+DirtyPlayer_description = Cuando este jugador comete una Falta, puedes aplicar un modificador de +1 a la tirada de Armadura o a la tirada de Heridas contra la víctima.
+EyeGouge = Sacar los Ojos
+EyeGouge_description = Cuando este jugador realiza un Placaje y el objetivo es empujado, este pierde su zona de defensa hasta su siguiente activación.
+Fumblerooski = Fumblerooski
+Fumblerooski_description = Cuando este jugador realiza un Movimiento o un Blitz mientras tiene el balón, puede soltarlo en cualquier casilla que abandone durante su movimiento. No necesita chequeo de Agilidad.
+LethalFlight = Vuelo Letal
+LethalFlight_description = Cuando un compañero lanza a este jugador y aterriza en una casilla ocupada por un rival, puedes aplicar un modificador de +1 a la tirada de Armadura o de Heridas contra ese jugador.
+LoneFouler = Faltero Solitario
+LoneFouler_description = Si este jugador comete una Falta sin ayudas ofensivas, puede repetir la tirada de Armadura.
+PileDriver = Machacar
+PileDriver_description = Cuando este jugador Derriba a un rival con un Placaje, puede cometer inmediatamente una Falta gratuita contra el jugador Derribado. Para ello debe seguir En Pie tras el placaje y no haberse movido. Usar esta habilidad termina su activación.
+PutTheBootIn = Meter la Bota
+PutTheBootIn_description = Cuando este jugador da una ayuda ofensiva a una Falta, suma +2 a la tirada de Armadura en lugar de +1.
+QuickFoul = Falta Rápida
+QuickFoul_description = Cuando este jugador comete una Falta, su activación no tiene por qué terminar tras la Falta. Puede seguir moviéndose si le queda movimiento.
+Saboteur = Saboteador
+Saboteur_description = Cuando este jugador es Derribado, tira un D6. Con un 4+, todos los rivales adyacentes son Derribados también.
+Shadowing = Sombra
+Shadowing_description = Cuando un rival en la zona de defensa de este jugador sale de ella voluntariamente, este jugador puede intentar seguirle. Tira un D6 y suma el MO de este jugador menos el MO del rival: con un 6 o más, este jugador se mueve a la casilla abandonada.
+SneakyGit = Rastrero
+SneakyGit_description = Cuando este jugador comete una Falta, no es Expulsado si saca un doble natural en la tirada de Armadura. Además, su activación no tiene por qué terminar tras la Falta.
+ViolentInnovator = Innovador Violento
+ViolentInnovator_description = Cuando este jugador causa una Lesión con una acción Especial otorgada por una habilidad o rasgo, gana 2 PE como si la hubiera causado con un Placaje.
+
+# Strength
+
+ArmBar = Llave de Brazo
+ArmBar_description = Si un rival Cae al fallar su chequeo de Agilidad al intentar Esquivar, Saltar o dar un Brinco desde una casilla en la que lo Marcaba este jugador, puedes aplicar un modificador de +1 a la tirada de Armadura o de Heridas.
+Brawler = Camorrista
+Brawler_description = Cuando este jugador realiza un Placaje por sí solo (no como parte de un Blitz), puede repetir un único resultado Ambos Derribados.
+BreakTackle = Romper Placaje
+BreakTackle_description = Una vez durante su activación, tras hacer un chequeo de Agilidad para Esquivar, este jugador puede modificar la tirada en +1 si su Fuerza es 4 o menos, o en +2 si es 5 o más.
+BullsEye = Diana
+BullsEye_description = Cuando este jugador realiza un Lanzar Compañero y el lanzamiento es soberbio, el jugador lanzado aterriza en la casilla objetivo sin dispersarse.
+Grab = Agarrar
+Grab_description = Cuando este jugador realiza un Placaje por sí solo, esta habilidad impide que el objetivo use Paso Lateral. Además, al aplicar un resultado Empujón, este jugador puede elegir cualquier casilla vacía adyacente al objetivo hacia la que empujarlo.
+Guard = Defensa
+Guard_description = Un jugador con esta habilidad puede dar ayudas ofensivas y defensivas sin importar cuántos rivales lo Marquen.
+Juggernaut = Imparable
+Juggernaut_description = Cuando este jugador realiza un Blitz, el objetivo del Placaje no puede usar las habilidades Zafarse, Mantenerse Firme ni Lucha, y este jugador puede tratar un resultado Ambos Derribados como un Empujón.
+MightyBlow = Golpe Mortífero
+MightyBlow_description = Cuando un rival es Derribado por un Placaje de este jugador, puedes aplicar un modificador de +1 a la tirada de Armadura o de Heridas. Esta habilidad no puede usarse con los rasgos Apuñalar o Motosierra.
+MultipleBlock = Placaje Múltiple
+MultipleBlock_description = Cuando este jugador realiza un Placaje por sí solo, puede elegir realizar dos Placajes contra dos jugadores distintos a los que Marque, con un modificador de -2 a la Fuerza en ambos. Ningún objetivo puede recibir ayudas ofensivas.
+StandFirm = Mantenerse Firme
+StandFirm_description = Este jugador puede elegir no ser empujado, ya sea por un Placaje contra él o por un empujón en cadena.
+StrongArm = Brazo Fuerte
+StrongArm_description = Este jugador puede aplicar un modificador de +1 al chequeo de Capacidad de Pase para Lanzar Compañero. Un jugador con el rasgo Lanzar Compañero no puede tener esta habilidad sin él.
+ThickSkull = Cabeza Dura
+ThickSkull_description = Cuando se hace una tirada de Heridas contra este jugador, solo queda Inconsciente con un 9 y trata un 8 como Aturdido. Si también tiene el rasgo Escurridizo, solo queda Inconsciente con un 8 y trata un 7 como Aturdido.
+
+# Passing
+
+Accurate = Preciso
+Accurate_description = Cuando este jugador realiza un Pase Rápido o un Pase Corto, puedes aplicar un modificador adicional de +1 al chequeo de Capacidad de Pase.
+Cannoneer = Cañonero
+Cannoneer_description = Cuando este jugador realiza un Pase Largo o una Bomba Larga, puedes aplicar un modificador adicional de +1 al chequeo de Capacidad de Pase.
+CloudBurster = Revientanubes
+CloudBurster_description = Cuando este jugador realiza un Pase Largo o una Bomba Larga, puedes obligar al entrenador rival a repetir un intento exitoso de interferir el pase.
+DumpOff = Pase Precipitado
+DumpOff_description = Si este jugador es objetivo de un Placaje y tiene el balón, puede realizar inmediatamente un Pase Rápido, interrumpiendo la activación del rival. Este Pase Rápido no puede provocar un cambio de turno.
+GiveAndGo = Dar y Correr
+GiveAndGo_description = Si este jugador realiza una Entrega, su activación no tiene por qué terminar tras la entrega. Puede seguir moviéndose si le queda movimiento.
+HailMaryPass = Pase a la Desesperada
+HailMaryPass_description = Cuando este jugador realiza un Pase, la casilla objetivo puede estar en cualquier parte del campo. Un Pase a la Desesperada nunca es preciso, siempre se dispersa tres veces y no puede interferirse. Un 1 natural siempre es un Balón Perdido.
+Leader = Líder
+Leader_description = Un equipo con uno o más jugadores con esta habilidad gana una segunda oportunidad de equipo adicional, llamada segunda oportunidad de Líder, que solo puede usarse si al menos un jugador con esta habilidad está en el campo.
+NervesOfSteel = Nervios de Acero
+NervesOfSteel_description = Este jugador puede ignorar cualquier modificador por estar Marcado al intentar Pasar, atrapar el balón o interferir un pase.
+OnTheBall = Atento al Balón
+OnTheBall_description = Este jugador puede moverse hasta tres casillas cuando el entrenador rival declara un Pase, o una casilla cuando se patea el balón en la patada inicial. Este movimiento ignora las zonas de defensa y no requiere Esquivar.
+Punt = Despeje
+Punt_description = En lugar de realizar un Pase, este jugador puede patear el balón en cualquier dirección. El balón se desvía desde su casilla, usando un D6 para determinar cuántas casillas recorre, y no puede interferirse.
+SafePass = Pase Seguro
+SafePass_description = Si este jugador pierde el balón al realizar un Pase, el balón no cae, no rebota desde su casilla y no se produce cambio de turno.
+
+# Mutation
+
+BigHand = Mano Grande
+BigHand_description = Este jugador puede ignorar cualquier modificador por estar Marcado o por Lluvia Torrencial al intentar recoger el balón.
+Claws = Garras
+Claws_description = Al hacer una tirada de Armadura contra un rival Derribado por un Placaje de este jugador, un 8+ antes de aplicar modificadores rompe su armadura, sin importar su valor de Armadura.
+DisturbingPresence = Presencia Perturbadora
+DisturbingPresence_description = Cuando un rival realiza un Pase, intenta interferir un pase o intenta atrapar el balón, debe aplicar un modificador de -1 por cada jugador de tu equipo con esta habilidad a tres casillas o menos.
+ExtraArms = Brazos Adicionales
+ExtraArms_description = Este jugador puede aplicar un modificador de +1 al intentar recoger o atrapar el balón, o al intentar interferir un pase.
+FoulAppearance = Apariencia Asquerosa
+FoulAppearance_description = Cuando un rival declara un Placaje o cualquier acción Especial contra este jugador, su entrenador debe tirar primero un D6. Con un 1, el jugador no puede realizar la acción declarada y esta se pierde.
+Horns = Cuernos
+Horns_description = Cuando este jugador realiza un Placaje como parte de un Blitz, puedes aplicar un modificador de +1 a su Fuerza.
+IronHardSkin = Piel de Hierro
+IronHardSkin_description = La habilidad Garras no puede usarse en tiradas de Armadura contra este jugador. Además, los entrenadores rivales no pueden aplicar modificadores a las tiradas de Armadura contra él.
+MonstrousMouth = Boca Monstruosa
+MonstrousMouth_description = Este jugador puede repetir cualquier intento fallido de atrapar el balón. Además, la habilidad Robar Balón no puede usarse contra un jugador con esta habilidad.
+PrehensileTail = Cola Prensil
+PrehensileTail_description = Cuando un rival activo intenta Esquivar, Saltar o dar un Brinco para abandonar una casilla en la que lo Marca este jugador, se aplica un modificador adicional de -1 a su chequeo de Agilidad.
+Tentacles = Tentáculos
+Tentacles_description = Este jugador puede usar esta habilidad cuando un rival al que Marca sale voluntariamente de una casilla de su zona de defensa. Tira un D6, suma la Fuerza de este jugador y resta la del rival: con un 6 o más, el rival queda sujeto y su movimiento termina.
+TwoHeads = Dos Cabezas
+TwoHeads_description = Este jugador puede aplicar un modificador de +1 al chequeo de Agilidad al intentar Esquivar.
+VeryLongLegs = Piernas Muy Largas
+VeryLongLegs_description = Este jugador puede reducir en 1 cualquier modificador negativo al chequeo de Agilidad al Saltar sobre un jugador Tumbado o Aturdido, o al dar un Brinco. Además, puede aplicar un modificador de +2 a cualquier intento de interferir un pase. Este jugador ignora la habilidad Revientanubes.
+
+# Trait
+
+AlwaysHungry = Siempre Hambriento
+AlwaysHungry_description = Si este jugador quiere lanzar a un compañero, tira un D6 tras su movimiento pero antes del lanzamiento. Con un 2+ lanza con normalidad. Con un 1, este jugador puede intentar comerse a su compañero.
+Animosity = Animosidad ({ $position })
+Animosity_description = Este jugador tiene celos de ciertos compañeros, indicados entre paréntesis. Si quiere entregar o pasar el balón a uno de ellos, tira un D6. Con un 1 se niega y su activación termina.
+AnimalSavagery = Salvajismo Animal
+AnimalSavagery_description = Al activar a este jugador, tras declarar su acción, tira un D6, sumando 2 si es un Placaje o un Blitz. Con un 1-3 arremete contra un compañero En Pie adyacente, que es Derribado; si no hay ninguno, su activación termina inmediatamente.
+BallChain = Bola y Cadena
+BallChain_description = Al activarse, este jugador solo puede realizar un Movimiento de Bola y Cadena. Se mueve al azar usando la plantilla de saque de banda y placa automáticamente a cualquier jugador en la casilla a la que entre.
+BloodLust = Sed de Sangre ({ $value })
+BloodLust_description = Al activar a este jugador, tras declarar su acción, tira un D6. Si el resultado es igual o superior al valor entre paréntesis, actúa con normalidad. Si no, debe alimentarse de un compañero Siervo o abandonar el campo al final de su activación.
+Bombardier = Bombardero
+Bombardier_description = Si este jugador está En Pie al activarse, puede lanzar una bomba. Esta acción Especial se resuelve como un Pase, salvo que se lanza una bomba en lugar del balón, que explota donde cae.
+BoneHead = Cabeza Hueca
+BoneHead_description = Al activar a este jugador, tras declarar su acción, tira un D6. Con un 1 olvida lo que iba a hacer y su activación termina inmediatamente. Además, pierde su zona de defensa hasta su siguiente activación.
+BreatheFire = Aliento de Fuego
+BreatheFire_description = Una vez por activación, en lugar de un Placaje, este jugador puede escupir fuego a un rival al que Marque. Tira un D6: con un 6 el objetivo es Derribado, con un 4-5 queda Tumbado, con un 2-3 no pasa nada y con un 1 este jugador queda Tumbado.
+Chainsaw = Motosierra
+Chainsaw_description = En lugar de un Placaje, este jugador puede realizar un ataque con motosierra. Tira un D6: con un 2+ el objetivo es alcanzado y se hace contra él una tirada de Armadura con +3. Con un 1 la motosierra rebota y alcanza a este jugador.
+Decay = Putrefacción
+Decay_description = Si este jugador sufre una Lesión en la tabla de Heridas, se aplica un modificador de +1 a todas las tiradas en la tabla de Lesiones contra él.
+Drunkard = Borracho
+Drunkard_description = Este jugador sufre un -1 a la tirada al Forzar la marcha.
+Hatred = Odio ({ $keyword })
+Hatred_description = Cuando este jugador placa a un rival con la palabra clave indicada entre paréntesis, puede repetir un único dado de placaje.
+HypnoticGaze = Mirada Hipnótica
+HypnoticGaze_description = Durante su activación, este jugador puede hipnotizar a un rival al que Marque. Haz un chequeo de Agilidad con -1 por cada otro jugador que lo Marque. Si lo supera, el objetivo pierde su zona de defensa y no puede atrapar, interceptar, pasar, ayudar ni moverse voluntariamente hasta su siguiente activación.
+Insignificant = Insignificante
+Insignificant_description = Al crear un equipo, como máximo la mitad de sus jugadores pueden tener este rasgo. Este jugador no gana PE cuando un compañero lo lanza o lo patea.
+KickTeamMate = Patear Compañero
+KickTeamMate_description = Una vez por turno, además de un Pase o un Lanzar Compañero, un jugador con este rasgo puede patear a un compañero En Pie adyacente con el rasgo Cosa Correcta.
+Loner = Solitario ({ $value }+)
+Loner_description = Si este jugador quiere usar una segunda oportunidad de equipo, tira un D6. Si el resultado es igual o superior al valor entre paréntesis, la usa con normalidad. Si no, se mantiene el resultado original y la segunda oportunidad se pierde.
+MyBall = Mi Balón
+MyBall_description = Un jugador con este rasgo no suelta el balón voluntariamente: no puede pasarlo, entregarlo ni usar ninguna habilidad que le haga renunciar a él.
+NoBall = Sin Balón
+NoBall_description = Este jugador no puede recoger, atrapar ni interceptar el balón, ni pasarlo ni entregarlo.
+PickMeUp = Levántame
+PickMeUp_description = Al final del turno rival, tira un D6 por cada compañero Tumbado y no Aturdido a tres casillas o menos de un jugador En Pie con este rasgo. Con un 5+, ese compañero puede levantarse inmediatamente.
+PlagueRidden = Apestado
+PlagueRidden_description = Una vez por partido, un rival con Fuerza 4 o menos sin Putrefacción, Regeneración ni Escurridizo que muera por un Placaje o Falta de este jugador puede unirse a tu equipo como nuevo Podrido de línea.
+PogoStick = Pogo Saltarín
+PogoStick_description = En lugar de saltar sobre un jugador Tumbado o Aturdido, este jugador puede saltar sobre cualquier casilla adyacente. Además, ignora los modificadores negativos al chequeo de Agilidad al Saltar.
+ProjectileVomit = Vómito Proyectil
+ProjectileVomit_description = En lugar de un Placaje, este jugador puede vomitar. Tira un D6: con un 2+ el objetivo es alcanzado y se hace contra él una tirada de Armadura. Con un 1 se vomita encima y se hace contra él una tirada de Armadura.
+ReallyStupid = Realmente Estúpido
+ReallyStupid_description = Al activar a este jugador, tira un D6, sumando 2 si está junto a uno o más compañeros En Pie sin este rasgo. Con un 1-3 olvida lo que iba a hacer, su activación termina inmediatamente y pierde su zona de defensa.
+Regeneration = Regeneración
+Regeneration_description = Tras una tirada en la tabla de Lesiones contra este jugador, tira un D6. Con un 4+ la tirada se descarta sin efecto y el jugador va al banquillo de reservas en lugar del de lesionados.
+RightStuff = Cosa Correcta
+RightStuff_description = Si este jugador tiene además Fuerza 3 o menos, puede ser lanzado por un compañero con Lanzar Compañero.
+SecretWeapon = Arma Secreta
+SecretWeapon_description = Cuando termina una entrada en la que ha jugado este jugador, es Expulsado por cometer una Falta, aunque ya no esté en el campo al final de la entrada.
+Stab = Apuñalar
+Stab_description = En lugar de un Placaje, este jugador puede apuñalar a un rival al que Marque. Haz una tirada de Armadura sin modificar contra la víctima: si la armadura se rompe, se hace una tirada de Heridas; si no, no pasa nada.
+Stunty = Escurridizo
+Stunty_description = Al hacer un chequeo de Agilidad para Esquivar, este jugador ignora los modificadores de -1 por estar Marcado en la casilla de destino. Sin embargo, si un rival interfiere su pase, obtiene +1. Además, las tiradas de Heridas contra él usan la tabla de Heridas de Escurridizos.
+Swoop = Planear
+Swoop_description = Cuando un compañero lanza a este jugador, no se dispersa antes de aterrizar como es habitual. En su lugar, la plantilla de saque de banda determina su dirección y se mueve hasta D6 casillas en esa dirección.
+TakeRoots = Echar Raíces
+TakeRoots_description = Al activar a este jugador, tras declarar su acción, tira un D6. Con un 1 echa raíces: no puede abandonar su casilla hasta el final de la entrada, salvo que sea Derribado o Tumbado.
+Timmmber = ¡Árbol va!
+Timmmber_description = Si este jugador tiene un Movimiento de 2 o menos, obtiene +1 al levantarse por cada compañero En Pie y no Marcado adyacente. Un 1 natural siempre es un fallo.
+Trickster = Embaucador
+Trickster_description = Cuando este jugador vaya a ser objetivo de un Placaje o de una acción Especial que lo sustituya, antes de determinar el número de dados puede retirarse y colocarse en otra casilla vacía adyacente al jugador que placa.
+ThrowTeamMate = Lanzar Compañero
+ThrowTeamMate_description = Si este jugador tiene Fuerza 5 o más, puede lanzar a un compañero En Pie adyacente con el rasgo Cosa Correcta.
+Titchy = Canijo
+Titchy_description = Este jugador obtiene +1 a los chequeos de Agilidad para Esquivar. Sin embargo, si un rival esquiva hacia su zona de defensa, este jugador no cuenta como que lo Marca.
+UnchannelledFury = Furia Descontrolada
+UnchannelledFury_description = Al activar a este jugador, tras declarar su acción, tira un D6, sumando 2 si es un Placaje o un Blitz. Con un 1-3 se enfurece sin control y su activación termina inmediatamente.
+Unsteady = Inestable
+Unsteady_description = Este jugador no puede usar segundas oportunidades de equipo al Esquivar o Forzar la marcha.
+
+# Special Skills
+BlindRage = Furia Ciega
+BlindRage_description = Akhorne puede repetir el D6 al usar la habilidad Intrépido.
+SavageBlow = Golpe Salvaje
+SavageBlow_description = Una vez por partido, cuando Barik realiza un Placaje, puede repetir cualquier número de dados de placaje.
+BlastIt = ¡Revienta!
+BlastIt_description = Una vez por partido, Barik puede repetir un chequeo de Capacidad de Pase fallido al lanzar una bomba.
+PutridRegurgitation = Regurgitación Pútrida
+PutridRegurgitation_description = Una vez por partido, Bilerot puede usar Vómito Proyectil además de otra acción, y puede repetir el D6 para ver si acierta.
+LookIntoMyEyes = Mírame a los Ojos
+LookIntoMyEyes_description = Una vez por partido, al inicio de cualquiera de sus activaciones, Boa puede elegir a un rival adyacente y tirar un D6. Con un 1 o 2 no pasa nada; con un 3+ ese jugador suelta el balón si lo tiene y Boa se hace con él.
+GhostlyFlames = Llamas Fantasmales
+GhostlyFlames_description = Cuando Bryce realiza un Placaje y el objetivo es Derribado, puedes aplicar un modificador adicional a la tirada de Armadura o de Heridas que representa el fuego fantasmal de sus golpes.
+TastyMorsel = Bocado Sabroso
+TastyMorsel_description = Cuando Bryce es empujado o Derribado, los rivales ansían comérselo: tira un D6 y, con un 1, se retira del juego y se coloca en el banquillo de lesionados.
+StarOfTheShow = Estrella del Espectáculo
+StarOfTheShow_description = Una vez por partido, este jugador puede usar una segunda oportunidad de equipo aunque Solitario lo impidiera, sin tirar por Solitario.
+ASneakyPair = Una Pareja Astuta
+MesmerizingGaze = Mirada Cautivadora
+MesmerizingGaze_description = Una vez por partido, Eldril puede repetir un chequeo de Agilidad fallido al usar Mirada Hipnótica.
+BalefulHex = Maleficio Funesto
+BalefulHex_description = Una vez por partido, al inicio de cualquiera de sus activaciones, Estelle puede elegir a un rival a cinco casillas o menos y tirar un D6. Con un 2+, ese jugador se distrae y pierde su zona de defensa hasta el final del siguiente turno de equipo.
+BrutalBlock = Placaje Brutal
+BrutalBlock_description = Una vez por partido, cuando un rival es Derribado por un Placaje de este jugador, puedes aplicar un modificador adicional de +1 a la tirada de Lesión.
+WhirlingDervish = Derviche Giratorio
+WhirlingDervish_description = Cuando este jugador realiza un Blitz, puede realizar dos Placajes contra objetivos distintos, moviéndose entre ellos con normalidad.
+FrenziedRush = Carga Frenética
+FrenziedRush_description = Una vez por partido, cuando este jugador realiza un Blitz, puede obtener la habilidad Furia y un punto de Movimiento adicional mientras dure esa acción.
+ShotToNothing = Nada que Perder
+ShotToNothing_description = Una vez por partido, este jugador puede realizar un Pase a distancia de Bomba Larga sin la penalización habitual, y el pase no puede interferirse.
+PrimalSavagery = Salvajismo Primigenio
+PrimalSavagery_description = Una vez por partido, al tirar por Salvajismo Animal, este jugador puede arremeter contra un rival adyacente en lugar de un compañero.
+TwoForOne = Dos por Uno
+GoredByTheBull = Corneado por el Toro
+GoredByTheBull_description = Una vez por partido, cuando este jugador realiza un Blitz, puedes añadir un modificador adicional de +1 a la Fuerza que otorga la habilidad Cuernos.
+Incorporeal = Incorpóreo
+Incorporeal_description = Este jugador puede aplicar un modificador de +1 a cualquier chequeo de Agilidad para Esquivar y nunca está Marcado por el rival del que se aleja.
+Slayer = Matador
+Slayer_description = Cuando este jugador placa a un rival con Fuerza 5 o más, puede aplicar un modificador de +1 a su propia Fuerza.
+WisdomOfTheWhiteDwarf = Sabiduría del Enano Blanco
+WisdomOfTheWhiteDwarf_description = Una vez por turno de equipo, Grim puede otorgar a un compañero adyacente una de las siguientes habilidades hasta el final de su activación: Placar, Romper Placaje, Intrépido o Mantenerse Firme.
+QuickBite = Mordisco Rápido
+QuickBite_description = Tras realizar un Placaje en el que el objetivo es Derribado, este jugador puede hacer inmediatamente una tirada de Armadura adicional contra otro rival adyacente.
+OldPro = Viejo Profesional
+OldPro_description = Una vez por partido, Helmut puede usar su habilidad Profesional para repetir un único dado de una tirada de Armadura contra él.
+UnstoppableMomentum = Impulso Imparable
+UnstoppableMomentum_description = Cuando este jugador realiza un Blitz, puedes repetir un único dado de placaje.
+DwarvenScourge = Azote de Enanos
+DwarvenScourge_description = Una vez por partido, cuando este jugador Derriba a un rival con la palabra clave Enano, puedes aplicar un modificador adicional de +1 a la tirada de Heridas.
+RaidingParty = Partida de Saqueo
+RaidingParty_description = Una vez por partido, este jugador puede mover a un compañero adyacente no Marcado una casilla en cualquier dirección, siempre que no quede Marcado.
+TheFlashingBlade = La Hoja Centelleante
+TheFlashingBlade_description = Cuando este jugador usa Apuñalar, puedes aplicar un modificador adicional de +1 a la tirada de Armadura.
+SwiftAsTheBreeze = Veloz como la Brisa
+SwiftAsTheBreeze_description = Una vez por partido, este jugador puede repetir un único chequeo de Agilidad fallido para Esquivar, Saltar o dar un Brinco, sin importar otras habilidades usadas.
+DwarfenGrit = Tenacidad Enana
+DwarfenGrit_description = Una vez por partido, cuando un jugador de este equipo es Derribado, puedes repetir la tirada de Armadura contra él.
+Indomitable = Indomable
+Indomitable_description = Una vez por partido, cuando este jugador realiza un Placaje, puede tratar un resultado Empujón como Derribado contra el objetivo.
+BlackInk = Tinta Negra
+BlackInk_description = Una vez por partido, este jugador puede usar Mirada Hipnótica contra un rival a tres casillas o menos en lugar de uno al que Marque.
+LordOfChaos = Señor del Caos
+LordOfChaos_description = Un equipo con este jugador gana una segunda oportunidad de equipo adicional para la primera parte. Si no se usa en ella, puede usarse en la segunda parte.
+ViciousVines = Enredaderas Despiadadas
+ViciousVines_description = Una vez por partido, sin moverse, este jugador puede realizar un Placaje contra cualquier rival En Pie a dos casillas o menos.
+MaximumCarnage = Masacre Total
+MaximumCarnage_description = Una vez por partido, cuando este jugador realiza un Blitz, puede realizar un Placaje contra cada rival adyacente por turnos.
+CrushingBlow = Golpe Aplastante
+CrushingBlow_description = Una vez por partido, cuando un rival es Derribado por un Placaje de este jugador, puedes aplicar un modificador adicional de +1 a la tirada de Armadura.
+KickThemWhileTheyAreDown = ¡Patéalos en el Suelo!
+KickThemWhileTheyAreDown_description = Una vez por partido, este jugador puede cometer una Falta sin que cuente como la Falta del equipo en ese turno, y obtiene un modificador adicional de +1 a la tirada de Armadura.
+HalflingLuck = Suerte de Mediano
+HalflingLuck_description = Una vez por partido, este jugador puede repetir cualquier tirada propia que no sea de Armadura, Heridas o Lesión, aunque sea Solitario.
+ToxinConnoisseur = Experto en Toxinas
+ToxinConnoisseur_description = Una vez por partido, este jugador puede usar Apuñalar con un modificador adicional de +1 a la tirada de Heridas.
+ThinkingManTroll = Trol Pensador
+ThinkingManTroll_description = Una vez por turno de equipo, este jugador puede repetir el D6 al tirar por Realmente Estúpido o Cabeza Hueca.
+CatchOfTheDay = Captura del Día
+CatchOfTheDay_description = Si este jugador termina su activación en el campo rival con una casilla adyacente vacía, el balón puede colocarse allí cuando rebote cerca, como si lo hubiera atrapado.
+BoundingLeap = Brinco Saltarín
+BoundingLeap_description = Una vez por partido, este jugador puede aplicar un modificador de +1 al chequeo de Agilidad al dar un Brinco, y puede saltar dos casillas en lugar de una.
+SlashingNails = Uñas Cortantes
+SlashingNails_description = Cuando este jugador realiza un Placaje y el objetivo es Derribado, puedes aplicar la habilidad Garras a la tirada de Armadura.
+Ram = Ariete
+Ram_description = Cuando este jugador realiza un Blitz, si el objetivo es Derribado, puedes aplicar un modificador de +1 a la tirada de Armadura.
+Yoink = ¡Mío!
+Yoink_description = Una vez por partido, en lugar de un Placaje, este jugador puede intentar quitarle el balón a un rival adyacente que lo tenga.
+FuryOfTheBloodGod = Furia del Dios de la Sangre
+FuryOfTheBloodGod_description = Una vez por partido, cuando este jugador realiza un Placaje, puede tratar cualquier resultado de los dados de placaje como ¡Pow!.
+MasterAssassin = Maestro Asesino
+MasterAssassin_description = Cuando este jugador usa Apuñalar, puedes repetir la tirada de Armadura.
+PumpUpTheCrowd = Enardecer al Público
+PumpUpTheCrowd_description = Una vez por partido, cuando este jugador anota un touchdown, tu equipo gana inmediatamente una segunda oportunidad de equipo.
+StrongPassingGame = Juego de Pase Fuerte
+StrongPassingGame_description = Una vez por partido, este jugador puede repetir un chequeo de Capacidad de Pase fallido al Lanzar Compañero.
+FuriousOutburst = Arrebato Furioso
+FuriousOutburst_description = Una vez por partido, en lugar de un Placaje, este jugador puede teletransportarse a cualquier casilla vacía a tres casillas o menos y placar a un rival adyacente.
+SneakiestOfTheLot = El Más Astuto de Todos
+SneakiestOfTheLot_description = Un equipo con este jugador puede cometer dos Faltas por turno de equipo, siempre que este jugador cometa una de ellas.
+WorkingInTandem = Trabajo en Tándem
+BeerBarrelBash = ¡Barrilazo!
+BeerBarrelBash_description = Una vez por partido, este jugador puede lanzar un barril a un rival a distancia de Pase Corto o menos. Si acierta, el objetivo es Derribado.
+KrumpAndSmash = Machacar y Aplastar
+KrumpAndSmash_description = Una vez por partido, cuando un Grandullón de este equipo realiza un Placaje, puedes repetir un único dado de placaje.
+SavageMauling = Zarpazo Salvaje
+SavageMauling_description = Una vez por partido, tras hacer una tirada de Heridas contra un rival, puedes repetirla.
+WoodlandFury = Furia del Bosque
+WoodlandFury_description = Una vez por partido, cuando un jugador de este equipo realiza un Blitz, puede obtener la habilidad Furia hasta el final de su activación.
+WatchOut = ¡Cuidado!
+WatchOut_description = Una vez por partido, cuando un rival placa a un compañero adyacente a este jugador, este jugador puede obligarle a repetir los dados de placaje.
+ExcuseMeAreYouAZoat = Disculpe, ¿es usted un Zoat?
+ExcuseMeAreYouAZoat_description = Una vez por partido, este jugador puede usar Mirada Hipnótica y repetir el chequeo de Agilidad si falla.
+BlastingSolvesEverything = Disparar lo Soluciona Todo
+BlastingSolvesEverything_description = Este equipo puede contratar Bombarderos y otras Armas Secretas sin que cuenten para el límite habitual, y puede comprar Sobornos a un coste reducido.
+Kaboom = ¡Kabum!
+Kaboom_description = Una vez por partido, cuando este jugador lanza una bomba, la explosión afecta a todas las casillas adyacentes a la casilla de caída, y los jugadores que haya en ellas son Derribados con un 4+.
+AllYouCanEat = Todo lo que Puedas Comer
+AllYouCanEat_description = Una vez por partido, este jugador puede repetir el D6 al tirar por Siempre Hambriento.
+Reliable = Fiable
+Reliable_description = Si este jugador pierde el balón al Lanzar Compañero, el jugador lanzado se dispersa desde la casilla del lanzador y no se produce cambio de turno salvo que el lanzado resulte herido.
+ConsummateProfessional = Profesional Consumado
+ConsummateProfessional_description = Una vez por partido, este jugador puede repetir un único dado de un chequeo de Capacidad de Pase, chequeo de Agilidad, tirada de Armadura o tirada de Heridas.
+Treacherous = Traicionero
+Treacherous_description = Una vez por partido, si este jugador está adyacente a un compañero que tiene el balón, puede quitárselo sin tirar.
+IllBeBack = ¡Volveré!
+IllBeBack_description = Una vez por partido, si este jugador es Expulsado por cometer una Falta, se coloca en el banquillo de reservas.
+TheBallista = La Balista
+TheBallista_description = Una vez por partido, este jugador puede repetir un chequeo de Capacidad de Pase fallido al realizar un Pase.
+
+# V5
+
+BurstOfSpeed = Arranque de Velocidad
+BurstOfSpeed_description = Una vez por partido, este jugador puede intentar Forzar la marcha tres veces adicionales durante su activación.
+DirtyPlayerNumber = Jugador Sucio (+{ $value })
+DirtyPlayerNumber_description = Cuando este jugador comete una Falta, la tirada de Armadura o de Heridas contra la víctima puede modificarse en el valor indicado entre paréntesis.
+MightyBlowNumber = Golpe Mortífero (+{ $value })
+MightyBlowNumber_description = Cuando un rival es Derribado por un Placaje de este jugador, puedes modificar la tirada de Armadura o de Heridas en el valor indicado entre paréntesis. Esta habilidad no puede usarse con los rasgos Apuñalar o Motosierra.
+NoHands = Sin Manos
+NoHands_description = Este jugador no puede tener el balón. No puede intentar recogerlo, atraparlo ni interferir un pase.
+RunningPass = Pase en Carrera
+RunningPass_description = Si este jugador realiza un Pase Rápido, su activación no tiene por qué terminar tras el pase. Puede seguir moviéndose después si le queda movimiento.
+ThenIStartedBlastin = ¡Y Entonces Empecé a Disparar!
+ThenIStartedBlastin_description = Una vez por partido, este jugador puede realizar un ataque a distancia contra un rival a tres casillas o menos: tira un D6 y, con un 4+, el objetivo es Derribado.
+
+###############################################################
+# Brackets
+###############################################################
+
+SingleElimination = Eliminación directa
+DoubleElimination = Doble eliminación
+Standings = Clasificación
+NafElo = Elo NAF
+Winners = Cuadro de ganadores
+Losers = Cuadro de perdedores
+GrandFinal = Gran final
+GrandFinalReset = Desempate de la gran final
+BracketRound = { $side }, ronda { $round }
+
+###############################################################
+# Ratings
+###############################################################
+
+Glicko2 = Glicko-2
+TrueSkill = TrueSkill
+
+###############################################################
+# Injury causes
+###############################################################
+
+Foul = Falta
+Crowd = Público
+FailedDodge = Esquiva fallida
+FailedRush = Forzar la marcha fallido
+Other = Otro
+
+###############################################################
+# Reports
+###############################################################
+
+MatchReport = Informe del partido
+Team = Equipo
+Coach = Entrenador
+CurrentTeamValue = Valor actual del equipo
+Weather = Clima
+Fans = Aficionados
+Date = Fecha
+PreGame = Secuencia previa al partido
+Inducements = Incentivos
+FirstHalf = Primera parte
+SecondHalf = Segunda parte
+ExtraTime = Prórroga
+FinalScore = Resultado final
+Penalties = Penaltis
+StarPlayerPoints = Puntos de estrellato
+Player = Jugador
+Injuries = Lesiones
+Cause = Causa
+InflictedBy = Causada por
+Winnings = Ganancias
+DedicatedFans = Aficionados fieles
+ExpensiveMistakes = Errores costosos
+MostValuablePlayers = Jugadores más valiosos
+SentOff = Expulsado
+PushedIntoCrowd = Empujado al público
+NothingToReport = Nada que informar
+Roster = Plantilla
+Players = Jugadores
+Number = #
+Name = Nombre
+Position = Posición
+ShortMovementAllowance = MO
+ShortStrength = FU
+ShortAgility = AG
+ShortPassingAbility = PS
+ShortArmourValue = AR
+Skills = Habilidades
+HiringValue = Coste de contratación
+CurrentValue = Valor actual
+Staff = Personal
+Quantity = Cantidad
+Treasury = Tesorería
+TeamValue = Valor del equipo
+SpecialRules = Reglas especiales
+ModifiedCharacteristic = Las características resaltadas difieren del perfil de la posición.
+
+###############################################################
+# Pitch formats
+###############################################################
+
+Standard = Estándar
+Sevens = Sevens
+
+###############################################################
+# Formatting
+###############################################################
+
+GoldPieces = { $value }k mo
+PlayersCount =
+    { $count ->
+        [one] { $count } jugador
+       *[other] { $count } jugadores
+    }
+StarPlayerPointsCount =
+    { $count ->
+        [one] { $count } punto de estrellato
+       *[other] { $count } puntos de estrellato
+    }
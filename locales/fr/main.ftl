@@ -26,12 +26,60 @@ StartGameBeforeAddingEvents = Un match doit avoir démarré avant d'avoir des é
 GameNeedsAWinner = Ce match nécessite un vainqueur
 GamePenaltiesShouldHaveAWinner = Les tirs au but lors d'un match doivent avoir un vainqueur
 GameAlreadyClosed = Le match est déjà clôturé
+GameNotClosed = Le match doit être terminé et clôturé
 JourneymanPositionNotFound = Le poste de journalier n'a pas été trouvé
 NotAPlayingTeam = L'équipe n'est pas l'une du match
 JourneymenShouldBeOkBeforeBuyingInducements = Les journaliers doivent être ajoutés avant d'acheter des coups de pouce
 SkillNotAvailableForPlayer = Joueur ne peut pas obtenir cette compétence
 CaptainMissing = Il n'y a pas de capitaine dans l'équipe, veuillez en nommer un
 TooMuchCaptains = Il y a trop de capitaines dans l'équipe
+PlayerCanNotBeCaptain = Ce joueur ne peut pas être le capitaine de l'équipe
+NotEnoughTeamsForBracket = Pas assez d'équipes pour créer un tableau
+GameNotInBracket = Ce match ne fait pas partie du tableau
+BracketMatchAlreadyFollowedUp = Les matchs suivants de ce match du tableau ont déjà été joués
+PlayerNotInTeam = Ce joueur ne fait pas partie de l'équipe
+PlayerNumberAlreadyUsed = Un autre joueur de l'équipe porte déjà ce numéro
+TierNotAllowedInTournament = Ce tier de roster n'est pas autorisé dans le tournoi
+TournamentBudgetExceeded = Budget du tournoi dépassé
+TournamentSkillsExceeded = Trop de compétences pour le tournoi
+TournamentSameSkillMaximumExceeded = La même compétence est prise trop de fois pour le tournoi
+TournamentEliteSkillNotAllowed = Les compétences élites ne sont pas autorisées dans le tournoi
+TournamentSkillNotAllowed = Cette compétence n'est pas autorisée dans le tournoi
+InvalidTeamFile = Fichier d'équipe invalide
+InvalidTeamFileLine = Ligne invalide dans le fichier d'équipe
+UnknownRoster = Roster inconnu
+UnknownPosition = Poste inconnu
+UnknownSkill = Compétence inconnue
+UnknownAdvancement = Progression inconnue
+UnknownInjury = Blessure inconnue
+UnknownStaff = Encadrement inconnu
+InvalidSerializedData = Données sérialisées invalides
+UnsupportedFormat = Format de sérialisation non supporté
+DatabaseError = Erreur de base de données
+DatabaseUnavailable = Base de données indisponible
+DatabaseConstraintViolation = Contrainte de la base de données non respectée
+DatabaseMigrationFailed = Échec de la migration de la base de données
+InvalidDatabaseData = Données invalides dans la base de données
+UnsupportedDatabase = Base de données non supportée
+SquareOutOfPitch = Case en dehors du terrain
+SquareNotInOwnHalf = Case en dehors de la moitié de terrain de l'équipe
+SquareAlreadyOccupied = Case déjà occupée
+PlayerAlreadyOnPitch = Joueur déjà sur le terrain
+PlayerNotAvailable = Joueur indisponible
+TooMuchPlayersOnPitch = Trop de joueurs sur le terrain
+NotEnoughPlayersOnPitch = Pas assez de joueurs sur le terrain
+NotEnoughPlayersOnLineOfScrimmage = Pas assez de joueurs sur la ligne d'engagement
+TooMuchPlayersInWideZone = Trop de joueurs dans une zone latérale
+GameNotInProgress = Le match n'est pas en cours
+NotTeamTurn = Ce n'est pas le tour de cette équipe
+PlayerCanNotAct = Ce joueur ne peut pas agir maintenant
+InvalidPath = Chemin de déplacement invalide
+InvalidTarget = Cible invalide
+ActionAlreadyUsed = Cette action a déjà été utilisée ce tour-ci
+UnsupportedLanguage = Cette langue n’est pas prise en charge
+InvalidTranslations = Ces traductions ne sont pas au format Fluent valide
+TranslationsFileNotReadable = Le fichier de traductions ne peut pas être lu
+TranslationsNotEnabled = Les traductions ne peuvent pas être chargées sans la fonctionnalité i18n
 
 ###############################################################
 # Game status
@@ -75,51 +123,92 @@ Blizzard_description = Le froid et les chuttes de neige importantes rendent le s
 ###############################################################
 
 PrayersToNuffle = Prières à Nuffle
+PrayersToNuffle_description = Chaque Prière à Nuffle permet à votre équipe d'effectuer un jet sur le tableau des Prières à Nuffle avant le match.
 PartTimeAssistantCoaches = Coachs Assistants à Temps Partiel
+PartTimeAssistantCoaches_description = Chaque Coach Assistant à Temps Partiel ajoute 1 aux jets de Coaching Brillant de votre équipe pour la durée du match.
 TempAgencyCheerleaders = Cheerleaders Intérimaires
+TempAgencyCheerleaders_description = Chaque Cheerleader Intérimaire ajoute 1 aux Fans Enthousiastes de votre équipe pour la durée du match.
 TeamMascot = Mascotte d'Équipe
+TeamMascot_description = Une fois par mi-temps, la Mascotte vous permet de lancer un D6 quand une relance d'équipe est nécessaire : sur 5+, vous obtenez une relance gratuite.
 WeatherMage = Mage Météo
+WeatherMage_description = Une fois par match, au début d'un de vos tours ou d'un drive, le Mage Météo peut changer la météo en effectuant un jet sur le tableau Météo, que vous pouvez relancer.
 BlitzersBestKegs = Fûts de Blitz Premium
+BlitzersBestKegs_description = Chaque Fût de Blitz Premium ajoute 1 au jet de récupération des joueurs KO à la fin de chaque drive.
 Bribes = Pots de vin
+Bribes_description = Quand un joueur de votre équipe est expulsé par l'arbitre, vous pouvez utiliser un Pot de vin. Lancez un D6 : sur 2+, le joueur n'est pas expulsé et aucun turnover n'a lieu. Sur 1, le Pot de vin est perdu.
 ExtraTeamTraining = Entraînement Supplémentaire
+ExtraTeamTraining_description = Chaque Entraînement Supplémentaire accorde à votre équipe une relance d'équipe supplémentaire utilisable pendant l'une ou l'autre mi-temps.
 MortuaryAssistant = Assistant Funéraire
+MortuaryAssistant_description = Une fois par match, quand un joueur de votre équipe avec le trait Régénération rate son jet de Régénération, l'Assistant Funéraire peut le relancer.
 PlagueDoctor = Médecin de la Peste
+PlagueDoctor_description = Une fois par match, le Médecin de la Peste peut relancer un jet de Régénération raté, ou soigner un joueur KO en le plaçant en Réserves.
 RiotousRookies = Débutants Déchaînés
+RiotousRookies_description = Avant le match, votre équipe gagne 2D3+1 joueurs temporaires pour la durée du match.
 WanderingApothecaries = Apothicaire Ambulant
+WanderingApothecaries_description = Chaque Apothicaire Ambulant peut être utilisé une fois par match exactement comme l'apothicaire de l'équipe.
 HalflingMasterChef = Chef Cuistot Halfling
+HalflingMasterChef_description = Au début de chaque mi-temps, lancez trois D6. Pour chaque 4+, votre équipe gagne une relance d'équipe et l'équipe adverse en perd une.
 BiasedRefereeDodgyLeagueRep = Arbitre Partial: Représentant de ligue louche
+BiasedRefereeDodgyLeagueRep_description = Chaque fois qu'un adversaire commet une Agression sans être vu par l'arbitre, lancez un D6. Sur 5+, le joueur est expulsé malgré tout.
 WizardSportsWizard = Sorcier: Sorcier-Sportif
+WizardSportsWizard_description = Une fois par match, le Sorcier-Sportif peut lancer un sort, comme une Boule de Feu ou Zap!, contre des joueurs adverses.
+StarPlayer = Star Player
+StarPlayer_description = Les Star Players sont des joueurs célèbres qui peuvent être recrutés en coup de pouce pour un seul match. Ils jouent dans votre équipe et sont considérés comme Solitaires.
+MegaStarPlayer = Mega Star
+MegaStarPlayer_description = Les Mega Stars sont les plus célèbres des Star Players. Ils peuvent être recrutés en coup de pouce pour un seul match, selon les mêmes règles que les autres Star Players.
+FamousCoachingStaff = Coachs Célèbres
+FamousCoachingStaff_description = Les Coachs Célèbres sont des entraîneurs, apothicaires et spécialistes renommés qui peuvent être recrutés en coup de pouce pour un seul match, chacun apportant une règle spéciale unique.
 
 # Old
 
 BloodweiserKegs = Fûts de Bloodweiser
+BloodweiserKegs_description = Chaque Fût de Bloodweiser ajoute 1 au jet de récupération des joueurs KO à la fin de chaque drive.
 SpecialPlays = Préparatifs spéciaux
+SpecialPlays_description = Chaque carte de Préparatif Spécial permet à votre équipe de piocher et d'utiliser un Préparatif Spécial pendant le match.
 
 ###############################################################
 # Prayers to Nuffle
 ###############################################################
 
 TreacherousTrapdoor = Trappe Traîtresse
+TreacherousTrapdoor_description = Jusqu'à la fin de la mi-temps, chaque fois qu'un joueur entre dans une case de trappe, lancez un D6. Sur 1, la trappe s'ouvre et le joueur tombe, il est placé en Réserves. S'il avait le ballon, celui-ci rebondit depuis la case de la trappe.
 FriendsWithTheRef = Pote avec l’Arbitre
+FriendsWithTheRef_description = Jusqu'à la fin du drive, lors d'une contestation de l'arbitre, un résultat de 5-6 est traité comme Bon, si vous le dites comme ça et un résultat de 2-4 comme Dégagez d'ici.
 Stiletto = Stylet
+Stiletto_description = Choisissez au hasard un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du drive, ce joueur gagne le trait Poignard.
 IronMan = Homme de Fer
+IronMan_description = Choisissez un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du match, sa valeur d'Armure est améliorée de 1, jusqu'à un maximum de 11+.
 KnuckleDusters = Gants Cloutés
+KnuckleDusters_description = Choisissez un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du drive, ce joueur gagne la compétence Chataîgne (+1).
 BadHabits = Mauvaises Habitudes
+BadHabits_description = Choisissez au hasard D3 joueurs adverses disponibles pour ce drive et sans le trait Solitaire. Jusqu'à la fin du drive, ces joueurs gagnent le trait Solitaire (2+).
 GreasyCleats = Crampons Graisseux
+GreasyCleats_description = Choisissez au hasard un joueur adverse disponible pour ce drive. Ses chaussures ont été sabotées : jusqu'à la fin du drive, son Mouvement est réduit de 1.
 BlessingsOfNuffle = Bénédiction de Nuffle
+BlessingsOfNuffle_description = Choisissez un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du match, ce joueur gagne la compétence Pro.
 MolesUnderThePitch = Des Taupes sous le Terrain
+MolesUnderThePitch_description = Jusqu'à la fin de la mi-temps, appliquez un modificateur de -1 chaque fois qu'un joueur tente de Foncer, ou de -2 si les deux coachs ont obtenu ce résultat.
 PerfectPassing = Passe Parfaite
+PerfectPassing_description = Jusqu'à la fin du match, tout joueur de votre équipe qui réussit une passe gagne 2 PSP au lieu de 1.
 DazzlingCatching = Réception Étourdissante
+DazzlingCatching_description = Jusqu'à la fin du match, tout joueur de votre équipe qui réceptionne une passe gagne 1 PSP.
 FanInteraction = Interaction avec les Fans
+FanInteraction_description = Jusqu'à la fin du drive, si un adversaire subit un Sorti après avoir été poussé dans la foule, le joueur qui l'a repoussé gagne 2 PSP comme s'il l'avait blessé par un Blocage.
 FoulingFrenzy = Frénésie d’Agression
+FoulingFrenzy_description = Jusqu'à la fin du match, tout joueur de votre équipe qui cause un Sorti par une Agression gagne 2 PSP, comme s'il l'avait causé par un Blocage.
 ThrowARock = Lancer de Pierre
+ThrowARock_description = Jusqu'à la fin du drive, si un adversaire temporise, vous pouvez lancer un D6 à la fin de son tour. Sur 5+, un fan en colère lui jette une pierre et il est immédiatement plaqué.
 UnderScrutiny = Sous Surveillance
+UnderScrutiny_description = Jusqu'à la fin de la mi-temps, tout adversaire qui commet une Agression est automatiquement vu par l'arbitre, même sans obtenir de double.
 IntensiveTraining = Entraînement Intensif
+IntensiveTraining_description = Choisissez au hasard un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du match, ce joueur gagne une compétence Principale de votre choix.
 
 # Old
 
 BlessedStatueOfNuffle = Statue Bénie de Nuffle
+BlessedStatueOfNuffle_description = Choisissez un joueur de votre équipe disponible pour ce drive et sans le trait Solitaire. Jusqu'à la fin du match, ce joueur gagne la compétence Pro.
 NecessaryViolence = Violence Nécessaire
+NecessaryViolence_description = Jusqu'à la fin du match, tout joueur de votre équipe qui cause un Sorti gagne 3 PSP au lieu de 2.
 
 ###############################################################
 # Roster names
@@ -163,29 +252,48 @@ WoodElfRoster = Elfes Sylvains
 # Special leagues
 
 BadlandsBrawl = Bagarre des Terres Arides
+BadlandsBrawl_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes du Badlands Brawl.
 ChaosClash = Clash du Chaos
 ElvenKingdomsLeague = Ligue des Royaumes Elfiques
+ElvenKingdomsLeague_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de l'Elven Kingdoms League.
 HalflingThimbleCup = Coupe du Dé à Coudre Halfling
+HalflingThimbleCup_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de la Halfling Thimble Cup.
 LustrianSuperleague = Super-ligue de Lustrie
+LustrianSuperleague_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de la Lustrian Superleague.
 OldWorldClassic = Classique du Vieux Monde
+OldWorldClassic_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de l'Old World Classic.
 SylvanianSpotlight = Spot de Sylvanie
+SylvanianSpotlight_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes du Sylvanian Spotlight.
 UnderworldChallenge = Défi des Bas-Fonds
+UnderworldChallenge_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de l'Underworld Challenge.
 WoodlandLeague = Ligue Sylvestre
 WorldsEdgeSuperleague = Super-ligue des Bords du Monde
+WorldsEdgeSuperleague_description = Cette équipe peut recruter les Star Players et coups de pouce accessibles aux équipes de la Worlds Edge Superleague.
 
 # Special rules
 
 BrawlingBrutes = Bagarreurs Brutaux
+BrawlingBrutes_description = Cette équipe peut recruter des Gros Bras sans qu'ils comptent dans la limite habituelle, et peut relancer un unique résultat Les Deux Plaqués une fois par match.
 BriberyAndCorruption = Chantage et Corruption
+BriberyAndCorruption_description = Une fois par match, si cette équipe obtient un 1 naturel en utilisant un Pot de vin, le jet peut être relancé. De plus, les Pots de vin lui coûtent moins cher en coup de pouce.
 FavouredOf = Favoris de...
+FavouredOf_description = À la création de cette équipe, le coach doit choisir le dieu du Chaos qui la favorise. Ce choix détermine les Star Players et coups de pouce accessibles.
 FavouredOfHashut = Favoris de Hashut
+FavouredOfHashut_description = Cette équipe est favorisée par Hashut et peut recruter les Star Players et coups de pouce accessibles aux équipes possédant cette règle spéciale.
 FavouredOfKhorne = Favoris de Khorne
+FavouredOfKhorne_description = Cette équipe est favorisée par Khorne et peut recruter les Star Players et coups de pouce accessibles aux équipes possédant cette règle spéciale.
 FavouredOfNurgle = Favoris de Nurgle
+FavouredOfNurgle_description = Cette équipe est favorisée par Nurgle et peut recruter les Star Players et coups de pouce accessibles aux équipes possédant cette règle spéciale.
 LowCostLinemen = Linemen à Vil Prix
+LowCostLinemen_description = Les trois-quarts de cette équipe ne comptent pas dans sa Valeur Actuelle lors du calcul des coups de pouce et de la petite monnaie disponible.
 MastersOfUndeath = Maîtres de la Non–vie
+MastersOfUndeath_description = Une fois par match, le coach principal de cette équipe peut relever un mort : quand un adversaire est tué, il peut rejoindre l'équipe en tant que nouveau Zombie pour le reste du match.
 Swarming = Déferlement
+Swarming_description = Pendant chaque séquence de début de drive, après l'étape 2 et avant l'étape 3, vous pouvez retirer D3 joueurs avec ce trait des Réserves et les placer sur le terrain, en dehors des zones latérales et de la ligne de mêlée.
 TeamCaptain = Capitaine
+TeamCaptain_description = À la création de l'équipe, un joueur doit être désigné Capitaine. Tant qu'il est sur le terrain, lancez un D6 à chaque utilisation d'une relance d'équipe : sur 6, la relance n'est pas perdue.
 VampireLord = Seigneur Vampire
+VampireLord_description = Cette équipe peut recruter un Médecin de la Peste ou un Assistant Funéraire en coup de pouce, et ses Vampires peuvent se nourrir sur les Sbires pour assouvir leur Soif de Sang.
 
 ###############################################################
 # Staff
@@ -220,50 +328,91 @@ SchielundScharlitan = Schielund Scharlitan
 All = Tous
 
 Animal = Animal
+Animal_description = Les animaux sont des bêtes qui entrent sur le terrain avec leurs maîtres, comme le renard des bois des équipes de Gnomes ou le sanglier à bière nordique.
 Beastman = Homme-bête
+Beastman_description = Les hommes-bêtes sont des adorateurs cornus des Dieux Sombres qui forment le gros des équipes d'Élus du Chaos, ainsi que les Khorngors et Pestigors des équipes de Khorne et de Nurgle.
 Construct = Amalgame
+Construct_description = Les amalgames sont des corps animés par la magie noire, comme les golems de chair des équipes d'Horreurs Nécromantiques.
 Dryad = Driade
+Dryad_description = Les driades sont des esprits des arbres des forêts profondes. Seul le joueur vedette Willow Rosebark possède ce mot-clé.
 Dwarf = Nain
+Dwarf_description = Les nains sont des joueurs petits, têtus et lourdement protégés, présents dans les équipes de Nains, de Nains du Chaos et de l'Alliance du Vieux Monde. Leurs vieilles rancunes leur valent plus d'une Haine.
 Dwarfs = Nains
 Elf = Elfe
+Elf_description = Les elfes sont des joueurs agiles et gracieux qui misent sur la vitesse et la passe, alignés par les équipes d'Elfes Noirs, de Hauts Elfes, d'Elfes Sylvains et de l'Union Elfique.
 Ghoul = Goule
+Ghoul_description = Les goules sont des charognards rapides et sauvages qui servent de coureurs aux équipes de Morts-Vivants et d'Horreurs Nécromantiques.
 Gnoblar = Gnoblar
+Gnoblar_description = Les gnoblars sont de minuscules peaux-vertes peureux qui garnissent la ligne des équipes d'Ogres en évitant de gêner les gros.
 Gnome = Gnome
+Gnome_description = Les gnomes sont de petits farceurs et illusionnistes rusés qui forment le cœur des équipes de Gnomes.
 Goblin = Gobelin
+Goblin_description = Les gobelins sont de petits peaux-vertes sournois. Ils ont leurs propres équipes et jouent aux côtés des Orques, des Nains du Chaos et des Renégats du Chaos, souvent munis d'armes secrètes.
 Halfling = Halfling
+Halfling_description = Les halflings sont de petites gens bien plus portées sur la nourriture que sur le football. Ils forment les équipes de Halflings et peuvent rejoindre les équipes d'Humains comme receveurs.
 Halflings = Halflings
 Human = Humain
+Human_description = Les humains sont les joueurs les plus répandus, formant entre autres les équipes d'Humains, d'Amazones, de Bretonniens, de Noblesse Impériale, de Nordiques et de l'Alliance du Vieux Monde.
 Humans = Humains
 Lizardman = Homme-lézard
+Lizardman_description = Les hommes-lézards sont les serviteurs à sang froid des Anciens : skinks agiles, saurus robustes et puissants kroxigors.
 Minotaur = Minotaure
+Minotaur_description = Les minotaures sont d'énormes gros bras à tête de taureau animés d'une Fureur Débridée, engagés par les Élus du Chaos, les Nains du Chaos et les Renégats du Chaos.
 Ogre = Ogre
+Ogre_description = Les ogres sont des gros bras massifs et affamés qui ont leurs propres équipes et se louent aux équipes du Chaos, d'Humains et de l'Alliance du Vieux Monde.
 Orc = Orque
+Orc_description = Les orques sont des peaux-vertes robustes et agressifs qui pratiquent un jeu de course brutal pour les équipes d'Orques, d'Orques Noirs et de Renégats du Chaos.
 Skaven = Skaven
+Skaven_description = Les skavens sont des hommes-rats rapides et perfides. Coureurs d'égout, guerriers des clans et rat-ogres jouent pour les équipes de Skavens, des Bas-Fonds et de Renégats du Chaos.
 Skeleton = Squelette
+Skeleton_description = Les squelettes sont les ossements ranimés de défunts, lents mais difficiles à abattre, alignés par les équipes de Morts-Vivants et des Rois des Tombes.
 Snakeman = Homme-serpent
+Snakeman_description = Les hommes-serpents sont des créatures ophidiennes. Seul le joueur vedette Boa Kon'ssstriktr possède ce mot-clé.
 Snotling = Snotling
+Snotling_description = Les snotlings sont les plus petits des peaux-vertes et envahissent le terrain pour les équipes de Snotlings et des Bas-Fonds, avec leurs bondisseurs, lance-champignons et chariots à pompe.
 Spawn = Rejeton
+Spawn_description = Les rejetons sont des horreurs mutantes et sans esprit offertes par les Dieux Sombres, comme le rejeton de sang de Khorne ou le rejeton putride de Nurgle.
 Spite = Spite
+Spite_description = Les spites sont de minuscules esprits des forêts. Seul le joueur vedette Swiftvine Glimmershard possède ce mot-clé.
 Squirrel = Ecureuil
+Squirrel_description = Ce mot-clé n'appartient qu'à un seul joueur vedette à fourrure : Akhorne l'Ecureuil.
 Thrall = Sbire
+Thrall_description = Les sbires sont les serviteurs humains des équipes de Vampires, et ceux dont les Vampires se nourrissent quand la Soif de Sang les prend.
 Treeman = Homme-arbre
+Treeman_description = Les hommes-arbres sont d'anciens et lents esprits des arbres qui servent de gros bras aux équipes d'Elfes Sylvains, de Halflings, de Gnomes et de l'Alliance du Vieux Monde.
 Troll = Troll
+Troll_description = Les trolls sont des gros bras stupides qui se régénèrent, jouant pour les équipes de peaux-vertes, du Chaos et des Bas-Fonds. Certains joueurs ont la Haine des trolls.
 Undead = Morts vivants
+Undead_description = Les morts-vivants ont été tirés de la tombe par la magie noire et jouent pour les équipes de Morts-Vivants, d'Horreurs Nécromantiques, des Rois des Tombes et de Vampires. Certains joueurs ont la Haine des morts-vivants.
 Vampire = Vampire
+Vampire_description = Les vampires sont des nobles immortels, rapides et forts mais liés par la Soif de Sang qui les pousse à se nourrir de leurs sbires.
 Werewolf = Loup-garou
+Werewolf_description = Les loups-garous sont de féroces métamorphes alignés par les équipes d'Horreurs Nécromantiques.
 Wraith = Spectre
+Wraith_description = Les spectres sont des morts-vivants éthérés qui glissent sur le terrain pour les équipes d'Horreurs Nécromantiques.
 Yhetee = Yéti
+Yhetee_description = Les yétis sont de sauvages gros bras venus du grand nord qui jouent pour les équipes de Nordiques.
 Zoat = Zoat
+Zoat_description = Les zoats sont de rares et discrètes créatures des forêts. Seul le joueur vedette Zolcath le Zoat possède ce mot-clé.
 Zombie = Zombie
+Zombie_description = Les zombies sont des cadavres lents et titubants qui forment les trois-quarts bon marché des équipes de Morts-Vivants et d'Horreurs Nécromantiques.
 
 Lineman = Trois-quart
+Lineman_description = Les trois-quarts sont la colonne vertébrale d'une équipe. Ils peuvent être recrutés en grand nombre et ont accès aux catégories de compétences de base de l'équipe.
 Thrower = Lanceur
+Thrower_description = Les lanceurs sont les meneurs de jeu de l'équipe, spécialisés dans la passe.
 Catcher = Receveur
+Catcher_description = Les receveurs sont spécialisés dans la réception des passes et la course jusqu'à la zone d'en-but.
 Blitzer = Blitzer
+Blitzer_description = Les blitzers allient vitesse et force pour percer la ligne adverse et traquer le porteur du ballon.
 Blocker = Bloqueur
+Blocker_description = Les bloqueurs sont des joueurs forts et résistants qui tiennent la ligne de mêlée et plaquent les adversaires.
 Runner = Coureur
+Runner_description = Les coureurs sont des joueurs rapides et agiles qui font progresser le ballon sur le terrain.
 BigGuy = Gros Bras
+BigGuy_description = Les Gros Bras sont des joueurs énormes, puissants et souvent peu fiables. Une équipe ne peut en compter qu'un nombre limité.
 Special = Spécial
+Special_description = Les joueurs spéciaux occupent un rôle unique dans leur équipe qui ne correspond à aucun autre mot-clé de poste.
 
 # Amazon
 
@@ -581,7 +730,7 @@ DislocatedShoulder = Épaule Disloquée
 
 PassingCompletion = Reussite de Passe
 ThrowingCompletion = Réussite de Lancer
-LandingOnfeet = Retomber sur ses Pieds
+LandingOnFeet = Retomber sur ses Pieds
 Deflection = Détournement
 Interception = Interception
 Casualty = Élimination
@@ -618,6 +767,7 @@ ArmourValue = Armure
 General = Général
 Mutation = Mutation
 Pass = Passe
+Pass_description = Ce joueur peut relancer un test de Capacité de Passe raté lors d'une action de Passe.
 Devious = Scélérate
 Trait = Trait
 
@@ -628,203 +778,482 @@ Trait = Trait
 # General
 
 Block = Blocage
+Block_description = Lorsqu'un résultat Les Deux Plaqués est appliqué pendant une action de Blocage, ce joueur peut choisir de l'ignorer et de ne pas être Plaqué.
 Dauntless = Intrépide
+Dauntless_description = Quand ce joueur effectue une action de Blocage contre une cible dont la Force est supérieure, lancez un D6 et ajoutez la Force de ce joueur. Si le total dépasse la Force de la cible, la Force de ce joueur devient égale à celle de la cible pour ce Blocage.
 Fend = Parade
+Fend_description = Si ce joueur est repoussé par un résultat de dé de blocage, il peut empêcher le joueur qui l'a repoussé de le poursuivre. Parade ne peut pas être utilisé contre un joueur avec Juggernaut lors d'une action de Blitz.
 Frenzy = Frénésie
+Frenzy_description = Chaque fois que ce joueur effectue un Blocage, il doit poursuivre si la cible est repoussée. Si la cible est toujours debout après avoir été repoussée et que ce joueur a pu poursuivre, il doit effectuer un second blocage contre la même cible.
 Kick = Frappe Précise
+Kick_description = Si ce joueur est désigné pour botter lors du coup d'envoi, vous pouvez diviser par deux le résultat du D6 déterminant la distance de déviation du ballon, en arrondissant à l'inférieur.
 Pro = Pro
+Pro_description = Une fois par tour d'équipe, ce joueur peut tenter de relancer un dé lancé pour lui. Lancez un D6 : sur 3+ le dé peut être relancé, sur 1-2 le résultat initial est conservé. Aucune relance d'équipe ne peut ensuite être utilisée pour ce dé.
 SteadyFooting = Appuis Sûrs
+SteadyFooting_description = Quand ce joueur devrait être plaqué ou mis à terre, lancez un D6. Sur 6, il garde l'équilibre et reste debout.
 StripBall = Arracher le Ballon
+StripBall_description = Quand ce joueur bloque un adversaire en possession du ballon, un résultat Repoussé force ce joueur à lâcher le ballon dans la case où il est repoussé.
 SureHands = Prise Sûre
+SureHands_description = Ce joueur peut relancer toute tentative ratée de ramasser le ballon. De plus, la compétence Arracher le Ballon ne peut pas être utilisée contre lui.
 Tackle = Tacle
+Tackle_description = Quand un adversaire actif tente d'Esquiver hors d'une case où il était marqué par un ou plusieurs joueurs possédant cette compétence, il ne peut pas utiliser la compétence Esquive.
 Taunt = Provocation
+Taunt_description = Quand un adversaire bloque ce joueur et que celui-ci est repoussé, vous pouvez obliger l'attaquant à le poursuivre.
 Wrestle = Lutte
+Wrestle_description = Ce joueur peut utiliser cette compétence quand un résultat Les Deux Plaqués est appliqué, qu'il bloque ou qu'il soit bloqué. Au lieu du résultat normal, les deux joueurs sont mis à terre et aucun jet d'Armure n'est effectué.
 
 # Agility
 
 Catch = Réception
+Catch_description = Ce joueur peut relancer un test d'Agilité raté lorsqu'il tente de réceptionner le ballon.
 Defensive = Défenseur
+Defensive_description = Pendant le tour de l'adversaire, les joueurs adverses marqués par ce joueur ne peuvent pas utiliser la compétence Garde.
 DivingCatch = Réception Plongeante
+DivingCatch_description = Ce joueur peut tenter de réceptionner le ballon s'il atterrit dans une case de sa Zone de Tacle après une dispersion ou une déviation. De plus, il bénéficie d'un modificateur de +1 pour réceptionner une passe précise s'il occupe la case ciblée.
 DivingTackle = Tacle Plongeant
+DivingTackle_description = Si un adversaire actif réussit son test d'Agilité pour Esquiver, Sauter ou Bondir hors d'une case marquée par ce joueur, vous pouvez déclarer l'utilisation de cette compétence. L'adversaire soustrait 2 au résultat, puis ce joueur est mis à terre dans la case libérée.
 Dodge = Esquive
+Dodge_description = Une fois par tour d'équipe, ce joueur peut relancer un test d'Agilité raté pour Esquiver. De plus, quand il est la cible d'un Blocage et qu'un résultat Bousculé est appliqué, il peut utiliser cette compétence.
 HitAndRun = Frappe et Cours
+HitAndRun_description = Après un Blocage, ce joueur peut immédiatement se déplacer d'une case gratuite, en ignorant les Zones de Tacle, s'il est toujours debout. Après ce déplacement, il ne doit ni marquer ni être marqué par un adversaire.
 JumpUp = Rétablissement
+JumpUp_description = Si ce joueur est à terre, il peut se relever gratuitement. De plus, s'il est à terre lors de son activation, il peut tenter de se relever et d'effectuer un Blocage en réussissant un test d'Agilité avec un modificateur de +1.
 Leap = Saut
+Leap_description = Pendant son mouvement, au lieu de sauter par-dessus une case occupée par un joueur à terre ou sonné, ce joueur peut Bondir par-dessus n'importe quelle case adjacente, même vide ou occupée par un joueur debout. De plus, il réduit de 1 tout modificateur négatif appliqué au test d'Agilité pour Sauter ou Bondir.
 SideStep = Glissade Contrôlée
+SideStep_description = Si ce joueur est repoussé, ce n'est pas le coach adverse qui choisit la case. Son propre coach choisit n'importe quelle case libre adjacente à ce joueur.
 SafePairOfHands = Libération Contrôlée
+SafePairOfHands_description = Si ce joueur est plaqué ou mis à terre alors qu'il a le ballon, celui-ci ne rebondit pas. Vous pouvez à la place le placer dans une case libre adjacente à ce joueur.
 Sprint = Sprint
+Sprint_description = Quand ce joueur effectue une action comprenant un mouvement, il peut tenter de Foncer trois fois au lieu de deux.
 SureFeet = Équilibre
+SureFeet_description = Une fois par tour d'équipe, pendant son activation, ce joueur peut relancer le D6 lorsqu'il tente de Foncer.
 
 # Devious
 
 DirtyPlayer = Joueur Déloyal
+DirtyPlayer_description = Quand ce joueur effectue une Agression, vous pouvez appliquer un modificateur de +1 au jet d'Armure ou au jet de Blessure contre la victime.
 EyeGouge = Fourchette
+EyeGouge_description = Quand ce joueur bloque et que la cible est repoussée, celle-ci perd sa Zone de Tacle jusqu'à sa prochaine activation.
 Fumblerooski = Fumblerooskie
+Fumblerooski_description = Quand ce joueur effectue une action de Mouvement ou de Blitz avec le ballon, il peut le lâcher dans n'importe quelle case qu'il quitte pendant son mouvement. Aucun test d'Agilité n'est requis.
 LethalFlight = Vol Fatal
+LethalFlight_description = Quand ce joueur est lancé par un coéquipier et atterrit sur une case occupée par un adversaire, vous pouvez appliquer un modificateur de +1 au jet d'Armure ou de Blessure contre celui-ci.
 LoneFouler = Agresseur Solitaire
+LoneFouler_description = Si ce joueur effectue une Agression sans soutien offensif, il peut relancer le jet d'Armure.
 PileDriver = Marteau Pilon
+PileDriver_description = Quand un adversaire est plaqué par un Blocage de ce joueur, celui-ci peut immédiatement effectuer une Agression gratuite contre lui. Ce joueur doit être debout après le blocage et ne pas s'être déplacé. Utiliser cette compétence met fin à son activation.
 PutTheBootIn = Coup de Crampons
+PutTheBootIn_description = Quand ce joueur soutient une Agression, il ajoute +2 au jet d'Armure au lieu de +1.
 QuickFoul = Agression Éclair
+QuickFoul_description = Quand ce joueur effectue une Agression, son activation ne se termine pas forcément après celle-ci. Il peut continuer à se déplacer s'il lui reste du mouvement.
 Saboteur = Saboteur
+Saboteur_description = Quand ce joueur est plaqué, lancez un D6. Sur 4+, tous les adversaires adjacents sont également plaqués.
 Shadowing = Poursuite
+Shadowing_description = Quand un adversaire dans la Zone de Tacle de ce joueur en sort volontairement, ce joueur peut tenter de le suivre. Lancez un D6 et ajoutez le M de ce joueur moins celui de l'adversaire : sur 6 ou plus, ce joueur se déplace dans la case libérée.
 SneakyGit = Sournois
+SneakyGit_description = Quand ce joueur effectue une Agression, il n'est pas expulsé s'il obtient un double naturel sur le jet d'Armure. De plus, son activation ne se termine pas forcément après l'Agression.
 ViolentInnovator = Innovateur Violent
+ViolentInnovator_description = Quand ce joueur cause un Sorti avec une action spéciale conférée par une compétence ou un trait, il gagne 2 PSP comme s'il l'avait causé par un Blocage.
 
 # Strength
 
 ArmBar = Clé de Bras
+ArmBar_description = Si un adversaire chute après avoir raté son test d'Agilité pour Esquiver, Sauter ou Bondir hors d'une case marquée par ce joueur, vous pouvez appliquer un modificateur de +1 au jet d'Armure ou au jet de Blessure.
 Brawler = Bagarreur
+Brawler_description = Quand ce joueur effectue une action de Blocage seule (pas lors d'un Blitz), il peut relancer un unique résultat Les Deux Plaqués.
 BreakTackle = Esquive en Force
+BreakTackle_description = Une fois pendant son activation, après un test d'Agilité pour Esquiver, ce joueur peut modifier le jet de +1 si sa Force est de 4 ou moins, ou de +2 si sa Force est de 5 ou plus.
 BullsEye = Dans le Mille
+BullsEye_description = Quand ce joueur effectue un Lancer de Coéquipier parfait, le joueur lancé atterrit dans la case ciblée sans se disperser.
 Grab = Projection
+Grab_description = Quand ce joueur effectue une action de Blocage seule, la cible ne peut pas utiliser Glissade Contrôlée. De plus, sur un résultat Repoussé, ce joueur peut choisir n'importe quelle case libre adjacente à la cible pour l'y repousser.
 Guard = Garde
+Guard_description = Un joueur avec cette compétence peut apporter des soutiens offensifs et défensifs quel que soit le nombre d'adversaires qui le marquent.
 Juggernaut = Juggernaut
+Juggernaut_description = Quand ce joueur effectue un Blitz, la cible du Blocage ne peut pas utiliser Parade, Stabilité ni Lutte, et ce joueur peut traiter un résultat Les Deux Plaqués comme un résultat Repoussé.
 MightyBlow = Chataîgne
+MightyBlow_description = Quand un adversaire est plaqué suite à un Blocage de ce joueur, vous pouvez appliquer un modificateur de +1 au jet d'Armure ou au jet de Blessure. Cette compétence ne peut pas être utilisée avec Poignard ou Tronçonneuse.
 MultipleBlock = Blocage Multiple
+MultipleBlock_description = Quand ce joueur effectue une action de Blocage seule, il peut bloquer deux adversaires différents qu'il marque, avec un modificateur de -2 en Force pour les deux blocages. Aucune cible ne reçoit de soutien offensif.
 StandFirm = Stabilité
+StandFirm_description = Ce joueur peut choisir de ne pas être repoussé, que ce soit suite à un Blocage contre lui ou à une poussée en chaîne.
 StrongArm = Bras Musclé
+StrongArm_description = Ce joueur bénéficie d'un modificateur de +1 à son test de Capacité de Passe pour une action de Lancer de Coéquipier.
 ThickSkull = Crâne Épais
+ThickSkull_description = Quand un jet de Blessure est effectué contre ce joueur, il n'est KO que sur un 9 et traite un 8 comme un résultat Sonné. S'il possède aussi Minus, il n'est KO que sur un 8 et traite un 7 comme Sonné.
 
 # Passing
 
 Accurate = Précision
+Accurate_description = Quand ce joueur effectue une Passe Rapide ou une Passe Courte, vous pouvez appliquer un modificateur supplémentaire de +1 au test de Capacité de Passe.
 Cannoneer = Cannonier
+Cannoneer_description = Quand ce joueur effectue une Passe Longue ou une Longue Bombe, vous pouvez appliquer un modificateur supplémentaire de +1 au test de Capacité de Passe.
 CloudBurster = Perce-Nuages
+CloudBurster_description = Quand ce joueur effectue une Passe Longue ou une Longue Bombe, vous pouvez obliger le coach adverse à relancer une tentative réussie d'interférence avec la passe.
 DumpOff = Délestage
+DumpOff_description = Si ce joueur est la cible d'un Blocage alors qu'il a le ballon, il peut immédiatement effectuer une Passe Rapide, interrompant l'activation de l'adversaire. Cette passe ne peut pas provoquer de turnover.
 GiveAndGo = Transmission dans la Course
+GiveAndGo_description = Si ce joueur effectue une Transmission, son activation ne se termine pas forcément après celle-ci. Il peut continuer à se déplacer s'il lui reste du mouvement.
 HailMaryPass = Passe Désespérée
+HailMaryPass_description = Quand ce joueur effectue une Passe, la case ciblée peut se trouver n'importe où sur le terrain. Une Passe Désespérée n'est jamais précise, se disperse toujours trois fois et ne peut pas être interceptée. Elle est toujours maladroite sur un 1 naturel.
 Leader = Chef
+Leader_description = Une équipe comptant un ou plusieurs joueurs avec cette compétence gagne une relance d'équipe supplémentaire, dite relance de Chef, utilisable uniquement si au moins un de ces joueurs est sur le terrain.
 NervesOfSteel = Nerfs d’Acier
+NervesOfSteel_description = Ce joueur peut ignorer les modificateurs dus au marquage lorsqu'il tente une Passe, une réception ou une interférence avec une passe.
 OnTheBall = Sur le Ballon
+OnTheBall_description = Ce joueur peut se déplacer de trois cases quand l'adversaire déclare une Passe, ou d'une case quand le ballon est botté lors du coup d'envoi. Ce déplacement ignore les Zones de Tacle et ne nécessite pas d'Esquive.
 Punt = Dégagement
+Punt_description = Au lieu d'une Passe, ce joueur peut botter le ballon dans n'importe quelle direction. Le ballon dévie depuis sa case d'un nombre de cases déterminé par un D6 et ne peut pas être intercepté.
 SafePass = Passe Assurée
+SafePass_description = Si ce joueur rate sa passe de façon maladroite, le ballon n'est pas lâché, il ne rebondit pas et aucun turnover n'est provoqué.
 
 # Mutation
 
 BigHand = Main Démesurée
+BigHand_description = Ce joueur peut ignorer les modificateurs dus au marquage ou à une Pluie Battante lorsqu'il tente de ramasser le ballon.
 Claws = Griffes
+Claws_description = Lors d'un jet d'Armure contre un adversaire plaqué par un Blocage de ce joueur, un résultat de 8+ avant modificateurs brise son armure, quelle que soit sa valeur d'Armure.
 DisturbingPresence = Présence Perturbante
+DisturbingPresence_description = Quand un adversaire effectue une Passe, tente d'interférer avec une passe ou de réceptionner le ballon, il subit un modificateur de -1 pour chaque joueur de votre équipe avec cette compétence situé à trois cases ou moins.
 ExtraArms = Bras Supplémentaires
+ExtraArms_description = Ce joueur bénéficie d'un modificateur de +1 lorsqu'il tente de ramasser ou de réceptionner le ballon, ou d'interférer avec une passe.
 FoulAppearance = Répulsion
+FoulAppearance_description = Quand un adversaire déclare un Blocage ou une action spéciale ciblant ce joueur, son coach doit d'abord lancer un D6. Sur un 1, le joueur ne peut pas effectuer l'action déclarée et celle-ci est perdue.
 Horns = Cornes
+Horns_description = Quand ce joueur effectue un Blocage lors d'un Blitz, vous pouvez appliquer un modificateur de +1 à sa Force.
 IronHardSkin = Peau de Fer
+IronHardSkin_description = La compétence Griffes ne peut pas être utilisée lors d'un jet d'Armure contre ce joueur. De plus, le coach adverse ne peut appliquer aucun modificateur aux jets d'Armure contre lui.
 MonstrousMouth = Grande Gueule
+MonstrousMouth_description = Ce joueur peut relancer toute tentative ratée de réceptionner le ballon. De plus, la compétence Arracher le Ballon ne peut pas être utilisée contre lui.
 PrehensileTail = Queue Préhensile
+PrehensileTail_description = Quand un adversaire actif tente d'Esquiver, Sauter ou Bondir hors d'une case marquée par ce joueur, il subit un modificateur supplémentaire de -1 à son test d'Agilité.
 Tentacles = Tentacules
+Tentacles_description = Ce joueur peut utiliser cette compétence quand un adversaire qu'il marque quitte volontairement sa Zone de Tacle. Lancez un D6, ajoutez la Force de ce joueur et soustrayez celle de l'adversaire : sur 6 ou plus, l'adversaire est retenu et son mouvement prend fin.
 TwoHeads = Deux Têtes
+TwoHeads_description = Ce joueur bénéficie d'un modificateur de +1 à son test d'Agilité lorsqu'il tente d'Esquiver.
 VeryLongLegs = Très Longues Jambes
+VeryLongLegs_description = Ce joueur réduit de 1 tout modificateur négatif à son test d'Agilité pour Sauter par-dessus un joueur à terre ou sonné, ou pour Bondir. De plus, il bénéficie d'un modificateur de +2 pour interférer avec une passe et ignore la compétence Perce-Nuages.
 
 # Trait
 
 AlwaysHungry = Toujours Affamé
+AlwaysHungry_description = Si ce joueur veut effectuer un Lancer de Coéquipier, lancez un D6 après son mouvement mais avant le lancer. Sur 2+, le lancer se déroule normalement. Sur 1, ce joueur peut tenter de manger son coéquipier.
 Animosity = Animosité ({ $position })
+Animosity_description = Ce joueur jalouse certains coéquipiers, indiqués entre parenthèses. Quand il veut transmettre ou passer le ballon à l'un d'eux, lancez un D6. Sur 1, il refuse et son activation se termine.
 AnimalSavagery = Sauvagerie Animale
+AnimalSavagery_description = Quand ce joueur est activé, lancez un D6 après avoir déclaré son action, avec un modificateur de +2 pour un Blocage ou un Blitz. Sur 1-3, il s'en prend à un coéquipier debout adjacent, qui est plaqué ; s'il n'y en a aucun, son activation se termine immédiatement.
 BallChain = Chaîne & Boulet
+BallChain_description = Quand ce joueur est activé, la seule action possible est le Mouvement Chaîne & Boulet. Il se déplace aléatoirement à l'aide du gabarit de remise en jeu et bloque automatiquement tout joueur se trouvant dans la case où il entre.
 BloodLust = Soif de Sang ({ $value })
+BloodLust_description = Quand ce joueur est activé, après avoir déclaré son action, lancez un D6. Si le résultat est supérieur ou égal à la valeur entre parenthèses, il agit normalement. Sinon, il doit se nourrir sur un coéquipier Sbire ou quitter le terrain à la fin de son activation.
 Bombardier = Bombardier
+Bombardier_description = Quand il est activé et debout, ce joueur peut effectuer l'action spéciale Lancer de Bombe. Elle se résout comme une Passe, mais avec une bombe qui explose là où elle atterrit.
 BoneHead = Cerveau Lent
+BoneHead_description = Quand ce joueur est activé, lancez un D6 après avoir déclaré son action. Sur 1, il oublie ce qu'il fait et son activation se termine immédiatement. De plus, il perd sa Zone de Tacle jusqu'à sa prochaine activation.
 BreatheFire = Souffle Ardent
+BreatheFire_description = Une fois par activation, au lieu d'un Blocage, ce joueur peut cracher du feu sur un adversaire qu'il marque. Lancez un D6 : sur 6 la cible est plaquée, sur 4-5 elle est mise à terre, sur 2-3 rien ne se passe et sur 1 c'est ce joueur qui est mis à terre.
 Chainsaw = Tronçonneuse
+Chainsaw_description = Au lieu d'un Blocage, ce joueur peut effectuer l'action spéciale Attaque à la Tronçonneuse. Lancez un D6 : sur 2+, la cible est touchée et subit un jet d'Armure avec un modificateur de +3. Sur 1, la tronçonneuse rebondit et touche ce joueur à la place.
 Decay = Décomposition
+Decay_description = Si ce joueur subit un résultat Sorti sur le tableau de Blessure, tous les jets effectués pour lui sur le tableau des Sortis bénéficient d'un modificateur de +1.
 Drunkard = Ivrogne
+Drunkard_description = Ce joueur subit un malus de -1 au jet de dé lorsqu'il tente de Foncer.
 Hatred = Haine ({ $keyword })
+Hatred_description = Quand ce joueur bloque un adversaire possédant le mot-clé indiqué entre parenthèses, il peut relancer un unique dé de blocage.
 HypnoticGaze = Regard Hypnotique
+HypnoticGaze_description = Pendant son activation, ce joueur peut effectuer l'action spéciale Regard Hypnotique contre un adversaire qu'il marque. Faites un test d'Agilité avec un -1 par joueur le marquant autre que la cible. En cas de réussite, la cible perd sa Zone de Tacle et ne peut plus réceptionner, intercepter, passer, soutenir ni se déplacer volontairement jusqu'à sa prochaine activation.
 Insignificant = Insignifiant
+Insignificant_description = À la création d'une équipe, au plus la moitié des joueurs peut posséder ce trait. Ce joueur ne gagne pas de PSP lorsqu'il est lancé ou botté par un coéquipier.
 KickTeamMate = Botter un Coéquipier
+KickTeamMate_description = Une fois par tour d'équipe, en plus d'une Passe ou d'un Lancer de Coéquipier, un joueur avec ce trait peut effectuer l'action spéciale Botter un Coéquipier sur un coéquipier debout et adjacent possédant Poids Plume.
 Loner = Solitaire ({ $value }+)
+Loner_description = Si ce joueur veut utiliser une relance d'équipe, lancez un D6. Si le résultat est supérieur ou égal à la valeur entre parenthèses, la relance est utilisée normalement. Sinon, le résultat initial est conservé et la relance est perdue.
 MyBall = Mon Ballon
+MyBall_description = Un joueur avec ce trait ne peut pas se séparer volontairement du ballon : il ne peut ni passer, ni transmettre, ni utiliser une compétence ou un trait lui permettant d'abandonner le ballon.
 NoBall = Sans Ballon
+NoBall_description = Ce joueur ne peut pas tenter de ramasser, réceptionner ou intercepter le ballon, et ne peut effectuer ni Passe ni Transmission.
 PickMeUp = Choppe-moi
+PickMeUp_description = À la fin du tour adverse, lancez un D6 pour chaque coéquipier à terre et non sonné situé à trois cases ou moins d'un joueur debout avec ce trait. Sur 5+, ce coéquipier peut se relever immédiatement.
 PlagueRidden = Contagieux
+PlagueRidden_description = Une fois par match, si un adversaire de Force 4 ou moins sans Décomposition, Régénération ni Minus est tué suite à un Blocage ou une Agression de ce joueur, il peut rejoindre votre équipe en tant que nouveau Pourri.
 PogoStick = Monté sur Ressort
+PogoStick_description = Pendant son mouvement, au lieu de sauter par-dessus un joueur à terre ou sonné, ce joueur peut Bondir par-dessus n'importe quelle case adjacente. De plus, il ignore les modificateurs négatifs au test d'Agilité pour Bondir.
 ProjectileVomit = Gerbe de Vomi
+ProjectileVomit_description = Au lieu d'un Blocage, ce joueur peut effectuer l'action spéciale Gerbe de Vomi. Lancez un D6 : sur 2+, la cible est touchée et subit un jet d'Armure. Sur 1, ce joueur se vomit dessus et subit un jet d'Armure.
 ReallyStupid = Gros Débile
+ReallyStupid_description = Quand ce joueur est activé, lancez un D6, avec un modificateur de +2 s'il est adjacent à un ou plusieurs coéquipiers debout ne possédant pas ce trait. Sur 1-3, il oublie ce qu'il fait, son activation se termine immédiatement et il perd sa Zone de Tacle.
 Regeneration = Régénération
+Regeneration_description = Après un jet de Sorti contre ce joueur, lancez un D6. Sur 4+, le jet de Sorti est ignoré et le joueur est placé en Réserves au lieu des Sortis.
 RightStuff = Poids Plume
+RightStuff_description = Si ce joueur a aussi une Force de 3 ou moins, il peut être lancé par un coéquipier possédant Lancer de Coéquipier.
 SecretWeapon = Arme Secrète
+SecretWeapon_description = À la fin d'un drive auquel ce joueur a participé, même s'il n'était plus sur le terrain, il est expulsé comme s'il avait commis une Agression.
 Stab = Poignard
+Stab_description = Au lieu d'un Blocage, ce joueur peut poignarder un adversaire qu'il marque. Effectuez un jet d'Armure non modifié contre la victime : si l'armure est brisée, effectuez un jet de Blessure ; sinon, rien ne se passe.
 Stunty = Minus
+Stunty_description = Quand ce joueur fait un test d'Agilité pour Esquiver, il ignore les modificateurs de -1 dus au marquage dans la case d'arrivée. En revanche, un adversaire qui tente d'interférer avec sa passe bénéficie d'un +1. De plus, les jets de Blessure contre lui utilisent le tableau de Blessure des Minus.
 Swoop = Piqué
+Swoop_description = Si ce joueur est lancé par un coéquipier, il ne se disperse pas normalement avant d'atterrir. Le gabarit de remise en jeu détermine sa direction et il se déplace de jusqu'à D6 cases dans cette direction.
 TakeRoots = Prendre Racine
+TakeRoots_description = Quand ce joueur est activé, lancez un D6 après avoir déclaré son action. Sur 1, il prend racine : il ne peut plus quitter sa case jusqu'à la fin du drive ou jusqu'à ce qu'il soit plaqué ou mis à terre.
 Timmmber = Timmm-ber!
+Timmmber_description = Si ce joueur a un Mouvement de 2 ou moins, il bénéficie d'un modificateur de +1 pour se relever pour chaque coéquipier debout adjacent non marqué. Un 1 naturel est toujours un échec.
 Trickster = Farceur
+Trickster_description = Quand ce joueur est sur le point d'être la cible d'un Blocage ou d'une action spéciale remplaçant un Blocage, avant de déterminer le nombre de dés, il peut être retiré du terrain et replacé dans une autre case libre adjacente au joueur qui bloque.
 ThrowTeamMate = Lancer de Coéquipier
+ThrowTeamMate_description = Si ce joueur a une Force de 5 ou plus, il peut effectuer un Lancer de Coéquipier, lançant un coéquipier debout et adjacent possédant Poids Plume.
 Titchy = Microbe
+Titchy_description = Ce joueur bénéficie d'un +1 à ses tests d'Agilité pour Esquiver. En revanche, quand un adversaire esquive dans sa Zone de Tacle, ce joueur n'est pas considéré comme le marquant.
 UnchannelledFury = Fureur Débridée
+UnchannelledFury_description = Quand ce joueur est activé, lancez un D6 après avoir déclaré son action, avec un modificateur de +2 s'il a déclaré un Blocage ou un Blitz. Sur 1-3, il enrage de façon incohérente et son activation se termine immédiatement.
 Unsteady = Instable
+Unsteady_description = Ce joueur ne peut pas utiliser de relance d'équipe lorsqu'il tente d'Esquiver ou de Foncer.
 
 # Special Skills
 BlindRage = Rage Aveugle
+BlindRage_description = Akhorne peut relancer le D6 lorsqu'il utilise la compétence Intrépide.
 SavageBlow = Coup Sauvage
+SavageBlow_description = Une fois par match, quand Barik effectue un Blocage, il peut relancer n'importe quel nombre de dés de blocage.
 BlastIt = Et Boom!
+BlastIt_description = Une fois par match, Barik peut relancer un test de Capacité de Passe raté lors d'une action spéciale Lancer de Bombe.
 PutridRegurgitation = Régurgitation Putride
+PutridRegurgitation_description = Une fois par match, Bilerot peut effectuer l'action spéciale Gerbe de Vomi en plus d'une autre action, et peut relancer le D6 pour toucher.
 LookIntoMyEyes = Regarde dans mes Yeux
+LookIntoMyEyes_description = Une fois par match, au début d'une de ses activations, Boa peut choisir un adversaire au contact et lancer un D6. Sur 1 ou 2 rien ne se passe ; sur 3+ ce joueur lâche le ballon s'il l'a et Boa s'en empare.
 GhostlyFlames = Flames Fantomatiques
+GhostlyFlames_description = Quand Bryce bloque et que la cible est plaquée, vous pouvez appliquer un modificateur supplémentaire au jet d'Armure ou de Blessure pour représenter le feu spectral de ses coups.
 TastyMorsel = Morceau Appétissant
+TastyMorsel_description = Quand Bryce est repoussé ou plaqué, les adversaires rêvent de le dévorer : lancez un D6 et, sur 1, il est retiré du jeu et placé dans la case des Sortis.
 StarOfTheShow = Star du Show
+StarOfTheShow_description = Une fois par match, ce joueur peut utiliser une relance d'équipe sans effectuer de jet de Solitaire.
 ASneakyPair = Une Paire Sournoise
 MesmerizingGaze = Danse Hypnotique
+MesmerizingGaze_description = Une fois par match, Eldril peut relancer un test d'Agilité raté lors de l'action spéciale Regard Hypnotique.
 BalefulHex = Maléfice Funeste
+BalefulHex_description = Une fois par match, au début d'une de ses activations, Estelle peut désigner un adversaire à cinq cases ou moins et lancer un D6. Sur 2+, ce joueur est distrait et perd sa Zone de Tacle jusqu'à la fin du tour d'équipe suivant.
 BrutalBlock = Blocage Brutal
+BrutalBlock_description = Une fois par match, quand un adversaire est plaqué par un Blocage de ce joueur, vous pouvez appliquer un modificateur supplémentaire de +1 au jet de Sorti.
 WhirlingDervish = Dervish Tourneur
+WhirlingDervish_description = Quand ce joueur effectue un Blitz, il peut effectuer deux Blocages contre des cibles différentes, en se déplaçant normalement entre les deux.
 FrenziedRush = Élan Frénétique
+FrenziedRush_description = Une fois par match, quand ce joueur effectue un Blitz, il peut gagner la compétence Frénésie et un point de Mouvement supplémentaire pour la durée de cette action.
 ShotToNothing = Tout ou Rien
+ShotToNothing_description = Une fois par match, ce joueur peut effectuer une Longue Bombe sans le malus habituel, et la passe ne peut pas être interceptée.
 PrimalSavagery = Sauvagerie Primaire
+PrimalSavagery_description = Une fois par match, quand ce joueur effectue un jet de Sauvagerie Animale, il peut s'en prendre à un adversaire adjacent plutôt qu'à un coéquipier.
 TwoForOne = Deux pour Un
 GoredByTheBull = Encorné par le Taureau
+GoredByTheBull_description = Une fois par match, quand ce joueur effectue un Blitz, vous pouvez ajouter un modificateur supplémentaire de +1 à la Force conférée par la compétence Cornes.
 Incorporeal = Éthérée
+Incorporeal_description = Ce joueur bénéficie d'un +1 à tout test d'Agilité pour Esquiver et n'est jamais considéré comme marqué par l'adversaire qu'il esquive.
 Slayer = Tueur
+Slayer_description = Quand ce joueur bloque un adversaire de Force 5 ou plus, il bénéficie d'un modificateur de +1 à sa propre Force.
 WisdomOfTheWhiteDwarf = Sagesse du Nain Blanc
+WisdomOfTheWhiteDwarf_description = Une fois par tour d'équipe, Grim peut conférer à un coéquipier adjacent l'une des compétences suivantes jusqu'à la fin de son activation : Blocage, Esquive en Force, Intrépide ou Stabilité.
 QuickBite = Morsure Rapide
+QuickBite_description = Après un Blocage ayant plaqué la cible, ce joueur peut immédiatement effectuer un jet d'Armure supplémentaire contre un autre adversaire adjacent.
 OldPro = Vieux Pro
+OldPro_description = Une fois par match, Helmut peut utiliser sa compétence Pro pour relancer un dé d'un jet d'Armure effectué contre lui.
 UnstoppableMomentum = Élan Imparable
+UnstoppableMomentum_description = Quand ce joueur effectue un Blitz, vous pouvez relancer un unique dé de blocage.
 DwarvenScourge = Fléau des Nains
+DwarvenScourge_description = Une fois par match, quand un adversaire Nain est plaqué par ce joueur, vous pouvez appliquer un modificateur supplémentaire de +1 au jet de Blessure.
 RaidingParty = Maraudage
+RaidingParty_description = Une fois par match, ce joueur peut déplacer d'une case un coéquipier adjacent non marqué, à condition que celui-ci ne se retrouve pas marqué.
 TheFlashingBlade = La Lame Eclair
+TheFlashingBlade_description = Quand ce joueur effectue l'action spéciale Poignard, vous pouvez appliquer un modificateur supplémentaire de +1 au jet d'Armure.
 SwiftAsTheBreeze = Vif comme la Bise
+SwiftAsTheBreeze_description = Une fois par match, ce joueur peut relancer un test d'Agilité raté pour Esquiver, Sauter ou Bondir, quelles que soient les autres compétences utilisées.
 DwarfenGrit = Détermination Naine
+DwarfenGrit_description = Une fois par match, quand un joueur de cette équipe est plaqué, vous pouvez relancer le jet d'Armure effectué contre lui.
 Indomitable = Indomptable
+Indomitable_description = Une fois par match, quand ce joueur effectue un Blocage, il peut traiter un résultat Repoussé comme un résultat Plaqué contre la cible.
 BlackInk = Encre Noire
+BlackInk_description = Une fois par match, ce joueur peut effectuer l'action spéciale Regard Hypnotique contre un adversaire situé à trois cases ou moins, plutôt que contre un adversaire qu'il marque.
 LordOfChaos = Seigneur du Chaos
+LordOfChaos_description = Une équipe comptant ce joueur gagne une relance d'équipe supplémentaire pour la première mi-temps. Si elle n'est pas utilisée, elle peut l'être en seconde mi-temps.
 ViciousVines = Vignes Vicieuses
+ViciousVines_description = Une fois par match, ce joueur peut bloquer n'importe quel adversaire debout situé à deux cases ou moins, sans se déplacer.
 MaximumCarnage = Carnage Maximal
+MaximumCarnage_description = Une fois par match, quand ce joueur effectue un Blitz, il peut bloquer successivement chaque adversaire adjacent.
 CrushingBlow = Coup Destructeur
+CrushingBlow_description = Une fois par match, quand un adversaire est plaqué par un Blocage de ce joueur, vous pouvez appliquer un modificateur supplémentaire de +1 au jet d'Armure.
 KickThemWhileTheyAreDown = Frappe les quand ils sont vulnérables!
+KickThemWhileTheyAreDown_description = Une fois par match, ce joueur peut effectuer une Agression qui ne compte pas comme l'Agression de l'équipe pour ce tour, et bénéficie d'un +1 supplémentaire au jet d'Armure.
 HalflingLuck = Chance Halfling
+HalflingLuck_description = Une fois par match, ce joueur peut relancer n'importe quel jet de dé autre qu'un jet d'Armure, de Blessure ou de Sorti, même s'il est Solitaire.
 ToxinConnoisseur = Expert en Toxines
+ToxinConnoisseur_description = Une fois par match, ce joueur peut effectuer l'action spéciale Poignard avec un modificateur supplémentaire de +1 au jet de Blessure.
 ThinkingManTroll = Le Troll Penseur
+ThinkingManTroll_description = Une fois par tour d'équipe, ce joueur peut relancer le D6 pour Gros Débile ou Cerveau Lent.
 CatchOfTheDay = Prise du Jour
+CatchOfTheDay_description = Si ce joueur termine son activation dans la moitié adverse, le ballon rebondissant à proximité peut être placé dans une case libre adjacente, comme s'il l'avait réceptionné.
 BoundingLeap = Bond en Avant
+BoundingLeap_description = Une fois par match, ce joueur bénéficie d'un +1 au test d'Agilité pour Bondir, et peut bondir par-dessus deux cases au lieu d'une.
 SlashingNails = Ongles Acérés
+SlashingNails_description = Quand ce joueur bloque et que la cible est plaquée, vous pouvez appliquer la compétence Griffes au jet d'Armure.
 Ram = Bélier
+Ram_description = Quand ce joueur effectue un Blitz et que la cible est plaquée, vous pouvez appliquer un modificateur de +1 au jet d'Armure.
 Yoink = Schboing!
+Yoink_description = Une fois par match, au lieu d'un Blocage, ce joueur peut tenter de prendre le ballon à un adversaire adjacent qui le possède.
 FuryOfTheBloodGod = Fureur du Dieu du Sang
+FuryOfTheBloodGod_description = Une fois par match, quand ce joueur bloque, il peut traiter n'importe quel résultat de dé de blocage comme un résultat Plaqué.
 MasterAssassin = Maitre Assassin
+MasterAssassin_description = Quand ce joueur effectue l'action spéciale Poignard, vous pouvez relancer le jet d'Armure.
 PumpUpTheCrowd = Faire Vibrer le Public
+PumpUpTheCrowd_description = Une fois par match, quand ce joueur marque un touchdown, votre équipe gagne immédiatement une relance d'équipe.
 StrongPassingGame = Fort en Jeu de Passe
+StrongPassingGame_description = Une fois par match, ce joueur peut relancer un test de Capacité de Passe raté lors d'un Lancer de Coéquipier.
 FuriousOutburst = Explosion de Fureur
+FuriousOutburst_description = Une fois par match, au lieu d'un Blocage, ce joueur peut se téléporter dans une case libre à trois cases ou moins et bloquer un adversaire adjacent.
 SneakiestOfTheLot = Le plus Sournois de Tous
+SneakiestOfTheLot_description = Une équipe comptant ce joueur peut effectuer deux Agressions par tour d'équipe, à condition que ce joueur en effectue une.
 WorkingInTandem = Travail en Tandem
 BeerBarrelBash = Pillonage de Tonneaux de Bière
+BeerBarrelBash_description = Une fois par match, ce joueur peut lancer un tonneau sur un adversaire situé à portée de Passe Courte. S'il touche, la cible est plaquée.
 KrumpAndSmash = 'Krazer et 'Klater
+KrumpAndSmash_description = Une fois par match, quand un Gros Bras de cette équipe bloque, vous pouvez relancer un unique dé de blocage.
 SavageMauling = Mutilation Sauvage
+SavageMauling_description = Une fois par match, après un jet de Blessure de ce joueur contre un adversaire, vous pouvez le relancer.
 WoodlandFury = Fureur Sylvestre
+WoodlandFury_description = Une fois par match, quand un joueur de cette équipe effectue un Blitz, il peut gagner la compétence Frénésie jusqu'à la fin de son activation.
 WatchOut = Attention!
+WatchOut_description = Une fois par match, quand un adversaire bloque un coéquipier adjacent à ce joueur, celui-ci peut l'obliger à relancer les dés de blocage.
 ExcuseMeAreYouAZoat = Etes-vous un Zoat?
+ExcuseMeAreYouAZoat_description = Une fois par match, ce joueur peut effectuer l'action spéciale Regard Hypnotique et peut relancer le test d'Agilité en cas d'échec.
 BlastingSolvesEverything = La Poudre Résout tous les Problèmes
+BlastingSolvesEverything_description = Cette équipe peut recruter des Bombardiers et autres Armes Secrètes sans qu'ils comptent dans la limite habituelle, et peut acheter des Pots de vin à coût réduit.
 Kaboom = Kaboom!
+Kaboom_description = Une fois par match, quand ce joueur lance une bombe, l'explosion touche toutes les cases adjacentes au point d'impact et les joueurs qui s'y trouvent sont plaqués sur 4+.
 AllYouCanEat = Buffet à Volonté
+AllYouCanEat_description = Une fois par match, ce joueur peut relancer le D6 pour Toujours Affamé.
 Reliable = Fiable
+Reliable_description = Si ce joueur rate son Lancer de Coéquipier de façon maladroite, le joueur lancé se disperse depuis la case du lanceur et aucun turnover n'a lieu sauf si le joueur lancé est blessé.
 ConsummateProfessional = Grand Professionnel
+ConsummateProfessional_description = Une fois par match, ce joueur peut relancer un dé d'un test de Capacité de Passe, d'un test d'Agilité, d'un jet d'Armure ou d'un jet de Blessure.
 Treacherous = Traître
+Treacherous_description = Une fois par match, si ce joueur est adjacent à un coéquipier qui a le ballon, il peut le lui prendre sans jet de dé.
 IllBeBack = Je Reviendrai!
+IllBeBack_description = Une fois par match, si ce joueur est expulsé pour une Agression, il est placé en Réserves à la place.
 TheBallista = La Balliste
+TheBallista_description = Une fois par match, ce joueur peut relancer un test de Capacité de Passe raté lors d'une Passe.
 
 # Old
 
 BurstOfSpeed = Pointe de Vitesse
+BurstOfSpeed_description = Une fois par match, ce joueur peut tenter de Foncer trois fois supplémentaires pendant son activation.
 DirtyPlayerNumber = Joueur Déloyal (+{ $value })
+DirtyPlayerNumber_description = Quand ce joueur effectue une action d'Agression, le jet d'Armure ou le jet de Blessure contre la victime peut être modifié de la valeur indiquée entre parenthèses.
 MightyBlowNumber = Chataîgne (+{ $value })
+MightyBlowNumber_description = Quand un adversaire est plaqué suite à un Blocage de ce joueur, vous pouvez modifier le jet d'Armure ou le jet de Blessure de la valeur indiquée entre parenthèses. Cette compétence ne peut pas être utilisée avec Poignard ou Tronçonneuse.
 NoHands = Sans les Mains
+NoHands_description = Ce joueur ne peut pas prendre possession du ballon. Il ne peut ni le ramasser, ni le réceptionner, ni tenter d'interférer avec une passe.
 RunningPass = Passe dans la Course
-ThenIStartedBlastin = Puis, j'ai commencé à tirer!
\ No newline at end of file
+RunningPass_description = Si ce joueur effectue une Passe Rapide, son activation ne se termine pas forcément après la passe. Il peut continuer à se déplacer s'il lui reste du mouvement.
+ThenIStartedBlastin = Puis, j'ai commencé à tirer!
+ThenIStartedBlastin_description = Une fois par match, ce joueur peut tirer sur un adversaire situé à trois cases ou moins : lancez un D6 et, sur 4+, la cible est plaquée.
+
+###############################################################
+# Brackets
+###############################################################
+
+SingleElimination = Élimination directe
+DoubleElimination = Double élimination
+Standings = Classement
+NafElo = Elo NAF
+Winners = Tableau des vainqueurs
+Losers = Tableau des perdants
+GrandFinal = Grande finale
+GrandFinalReset = Finale décisive
+BracketRound = { $side }, tour { $round }
+
+###############################################################
+# Ratings
+###############################################################
+
+Glicko2 = Glicko-2
+TrueSkill = TrueSkill
+
+###############################################################
+# Injury causes
+###############################################################
+
+Foul = Agression
+Crowd = Foule
+FailedDodge = Esquive ratée
+FailedRush = Foncer raté
+Other = Autre
+
+###############################################################
+# Reports
+###############################################################
+
+MatchReport = Compte rendu de match
+Team = Équipe
+Coach = Coach
+CurrentTeamValue = Valeur d'équipe actuelle
+Weather = Météo
+Fans = Supporters
+Date = Date
+PreGame = Séquence d'avant-match
+Inducements = Coups de pouce
+FirstHalf = Première mi-temps
+SecondHalf = Seconde mi-temps
+ExtraTime = Prolongations
+FinalScore = Score final
+Penalties = Tirs au but
+StarPlayerPoints = Points de star
+Player = Joueur
+Injuries = Blessures
+Cause = Cause
+InflictedBy = Infligée par
+Winnings = Gains
+DedicatedFans = Fans dévoués
+ExpensiveMistakes = Erreurs coûteuses
+MostValuablePlayers = Joueurs les plus utiles
+SentOff = Expulsé
+PushedIntoCrowd = Poussé dans la foule
+NothingToReport = Rien à signaler
+Roster = Roster
+Players = Joueurs
+Number = N°
+Name = Nom
+Position = Poste
+ShortMovementAllowance = M
+ShortStrength = F
+ShortAgility = AG
+ShortPassingAbility = CP
+ShortArmourValue = AR
+Skills = Compétences
+HiringValue = Coût d'embauche
+CurrentValue = Valeur actuelle
+Staff = Encadrement
+Quantity = Quantité
+Treasury = Trésorerie
+TeamValue = Valeur d'équipe
+SpecialRules = Règles spéciales
+ModifiedCharacteristic = Les caractéristiques mises en évidence diffèrent du profil du poste.
+
+###############################################################
+# Pitch formats
+###############################################################
+
+Standard = Standard
+Sevens = Sevens
+
+###############################################################
+# Formatting
+###############################################################
+
+GoldPieces = { $value } k po
+PlayersCount =
+    { $count ->
+        [one] { $count } joueur
+       *[other] { $count } joueurs
+    }
+StarPlayerPointsCount =
+    { $count ->
+        [one] { $count } point de star
+       *[other] { $count } points de star
+    }
@@ -0,0 +1,1259 @@
+###############################################################
+# Errors
+###############################################################
+
+UnsupportedVersion = Versione non supportata
+NotEnoughPlayers = Giocatori insufficienti (minimo 11)
+TooMuchPlayers = Troppi giocatori (massimo 16)
+TreasuryExceeded = Tesoreria superata
+RosterNotExist = Questa rosa non esiste
+NotEnoughFans = Tifosi fedeli insufficienti
+TooMuchFans = Troppi tifosi fedeli
+PositionNotInRoster = Ruolo non disponibile per questa rosa
+PositionNotDefined = Ruolo non definito
+PositionMaxExceeded = Massimo del ruolo superato
+TooMuchBigMen = Troppi grossi
+StaffNotInRoster = Questo staff non esiste per questa rosa
+StaffExceededMaximum = Massimo dello staff superato
+IncorrectTreasury = Tesoreria non corretta
+PlayersMustMatchTeamVersion = I giocatori devono corrispondere alla versione della squadra
+TeamsMustMatchGameVersion = Le squadre devono corrispondere alla versione della partita
+CanNotCreateGameBeforeAnotherAlreadyPlayed = Non si può creare una partita prima di un'altra già giocata
+SameCoachForBothTeams = Lo stesso allenatore guida entrambe le squadre
+GameShouldHaveTwoTeams = Una partita deve avere due squadre
+TeamAlreadyPlayingGame = Una squadra sta già giocando una partita
+StartGameBeforeAddingEvents = La partita deve iniziare prima di aggiungere eventi
+GameNeedsAWinner = Questa partita ha bisogno di una squadra vincitrice
+GamePenaltiesShouldHaveAWinner = I rigori della partita devono avere un vincitore
+GameAlreadyClosed = La partita è già chiusa
+GameNotClosed = La partita deve essere terminata e chiusa
+JourneymanPositionNotFound = Ruolo del giocatore occasionale non trovato
+NotAPlayingTeam = Non è una squadra in gioco
+JourneymenShouldBeOkBeforeBuyingInducements = I giocatori occasionali devono essere risolti prima di acquistare incentivi
+SkillNotAvailableForPlayer = Abilità non disponibile per il giocatore
+CaptainMissing = La squadra non ha un capitano, nominane uno
+TooMuchCaptains = La squadra ha troppi capitani
+PlayerCanNotBeCaptain = Questo giocatore non può essere il capitano della squadra
+NotEnoughTeamsForBracket = Squadre insufficienti per creare un tabellone
+GameNotInBracket = Questa partita non fa parte del tabellone
+BracketMatchAlreadyFollowedUp = Le partite successive di questo incontro del tabellone sono già state giocate
+PlayerNotInTeam = Questo giocatore non è nella squadra
+PlayerNumberAlreadyUsed = Un altro giocatore della squadra ha già questo numero
+TierNotAllowedInTournament = Questo livello di rosa non è ammesso nel torneo
+TournamentBudgetExceeded = Budget del torneo superato
+TournamentSkillsExceeded = Troppe abilità per il torneo
+TournamentSameSkillMaximumExceeded = La stessa abilità è scelta troppe volte per il torneo
+TournamentEliteSkillNotAllowed = Le abilità d'élite non sono ammesse nel torneo
+TournamentSkillNotAllowed = Questa abilità non è ammessa nel torneo
+InvalidTeamFile = File della squadra non valido
+InvalidTeamFileLine = Riga non valida nel file della squadra
+UnknownRoster = Rosa sconosciuta
+UnknownPosition = Ruolo sconosciuto
+UnknownSkill = Abilità sconosciuta
+UnknownAdvancement = Avanzamento sconosciuto
+UnknownInjury = Infortunio sconosciuto
+UnknownStaff = Staff sconosciuto
+InvalidSerializedData = Dati serializzati non validi
+UnsupportedFormat = Formato di serializzazione non supportato
+DatabaseError = Errore del database
+DatabaseUnavailable = Database non disponibile
+DatabaseConstraintViolation = Vincolo del database violato
+DatabaseMigrationFailed = Migrazione del database non riuscita
+InvalidDatabaseData = Dati non validi nel database
+UnsupportedDatabase = Database non supportato
+SquareOutOfPitch = La casella è fuori dal campo
+SquareNotInOwnHalf = La casella non è nella metà campo della squadra
+SquareAlreadyOccupied = La casella è già occupata
+PlayerAlreadyOnPitch = Il giocatore è già in campo
+PlayerNotAvailable = Il giocatore non è disponibile
+TooMuchPlayersOnPitch = Troppi giocatori in campo
+NotEnoughPlayersOnPitch = Non ci sono abbastanza giocatori in campo
+NotEnoughPlayersOnLineOfScrimmage = Giocatori insufficienti sulla linea di mischia
+TooMuchPlayersInWideZone = Troppi giocatori in una fascia laterale
+GameNotInProgress = La partita non è in corso
+NotTeamTurn = Non è il turno di questa squadra
+PlayerCanNotAct = Questo giocatore non può agire ora
+InvalidPath = Percorso di movimento non valido
+InvalidTarget = Bersaglio non valido
+ActionAlreadyUsed = Questa azione è già stata usata in questo turno
+UnsupportedLanguage = Questa lingua non è supportata
+InvalidTranslations = Queste traduzioni non sono Fluent valido
+TranslationsFileNotReadable = Il file delle traduzioni non può essere letto
+TranslationsNotEnabled = Le traduzioni non possono essere caricate senza la funzionalità i18n
+
+###############################################################
+# Game status
+###############################################################
+
+Scheduled = Programmata
+PreGameSequence = Sequenza pre-partita in corso
+GameInProgress = Partita in corso
+PostGameSequence = Sequenza post-partita in corso
+WaitingForValidation = In attesa di convalida
+Closed = Chiusa
+
+###############################################################
+# Versions
+###############################################################
+
+V1 = V1 - 1987
+V2 = V2 - 1991
+V3 = V3 - 1994
+V4 = V4 LRB - 2000
+V5 = V5 Stagione 2 - 2020
+V5S3 = V5 Stagione 3 - 2025
+
+###############################################################
+# Weather
+###############################################################
+
+SwelteringHeat = Caldo Torrido
+SwelteringHeat_description = Alcuni giocatori svengono per il caldo insopportabile! D3 giocatori scelti a caso di ogni squadra che sono in campo alla fine di un drive vengono messi nelle Riserve. Devono saltare il drive successivo.
+VerySunny = Molto Soleggiato
+VerySunny_description = Una giornata splendida, ma il cielo limpido e la luce abbagliante ostacolano il gioco di passaggio! Applica un modificatore di -1 ogni volta che un giocatore effettua un test di Abilità di Passaggio.
+PerfectConditions = Condizioni Perfette
+PerfectConditions_description = Né troppo freddo né troppo caldo. Una giornata tiepida, asciutta e leggermente nuvolosa offre condizioni perfette per il Blood Bowl.
+PouringRain = Pioggia Battente
+PouringRain_description = Un acquazzone torrenziale inzuppa i giocatori e rende la palla molto scivolosa! Applica un modificatore di -1 ogni volta che un giocatore effettua un test di Agilità per ricevere o raccogliere la palla, o per tentare di interferire con un passaggio.
+Blizzard = Bufera di Neve
+Blizzard_description = Il gelo e le forti nevicate rendono il terreno insidioso. Applica un modificatore di -1 ogni volta che un giocatore tenta di Scattare per una casella extra. Inoltre, la scarsa visibilità consente di tentare solo passaggi Rapidi e Corti.
+
+###############################################################
+# Inducements
+###############################################################
+
+PrayersToNuffle = Preghiere a Nuffle
+PrayersToNuffle_description = Ogni Preghiera a Nuffle permette alla tua squadra di tirare una volta sulla tabella delle Preghiere a Nuffle prima della partita.
+PartTimeAssistantCoaches = Assistenti Allenatori Part-time
+PartTimeAssistantCoaches_description = Ogni Assistente Allenatore Part-time aggiunge 1 ai tiri di Allenamento Brillante della tua squadra per tutta la partita.
+TempAgencyCheerleaders = Cheerleader Interinali
+TempAgencyCheerleaders_description = Ogni Cheerleader Interinale aggiunge 1 ai Tifosi Esultanti della tua squadra per tutta la partita.
+TeamMascot = Mascotte della Squadra
+TeamMascot_description = Una volta per tempo, la Mascotte ti permette di tirare un D6 quando serve un ritiro di squadra: con 5+ ottieni un ritiro gratuito.
+WeatherMage = Mago del Tempo
+WeatherMage_description = Una volta per partita, all'inizio di uno dei tuoi turni o di un drive, il Mago del Tempo può cambiare il meteo tirando sulla tabella del Meteo, e puoi ritirare il risultato se lo desideri.
+BlitzersBestKegs = Barili di Blitzer's Best
+BlitzersBestKegs_description = Ogni Barile di Blitzer's Best aggiunge 1 al tiro per vedere se i giocatori svenuti si riprendono alla fine di ogni drive.
+Bribes = Mazzette
+Bribes_description = Quando un giocatore della tua squadra viene espulso dall'arbitro, puoi usare una Mazzetta. Tira un D6: con 2+ il giocatore non viene espulso e non si verifica un turnover. Con 1, la Mazzetta è sprecata.
+ExtraTeamTraining = Allenamento Extra
+ExtraTeamTraining_description = Ogni Allenamento Extra concede alla tua squadra un ritiro di squadra aggiuntivo utilizzabile in uno qualsiasi dei due tempi.
+MortuaryAssistant = Assistente di Obitorio
+MortuaryAssistant_description = Una volta per partita, quando un giocatore della tua squadra con il tratto Rigenerazione fallisce il tiro di Rigenerazione, l'Assistente di Obitorio può ritirarlo.
+PlagueDoctor = Medico della Peste
+PlagueDoctor_description = Una volta per partita, il Medico della Peste può ritirare un tiro di Rigenerazione fallito oppure curare un giocatore svenuto, mettendolo nelle Riserve.
+RiotousRookies = Reclute Turbolente
+RiotousRookies_description = Prima della partita, la tua squadra ottiene 2D3+1 giocatori occasionali per tutta la partita.
+WanderingApothecaries = Medici Itineranti
+WanderingApothecaries_description = Ogni Medico Itinerante può essere usato una volta per partita esattamente come il medico della squadra.
+HalflingMasterChef = Maestro Cuoco Halfling
+HalflingMasterChef_description = All'inizio di ogni tempo, tira tre D6. Per ogni 4+, la tua squadra ottiene un ritiro di squadra e la squadra avversaria ne perde uno.
+BiasedRefereeDodgyLeagueRep = Arbitro Parziale: Rappresentante di Lega Losco
+BiasedRefereeDodgyLeagueRep_description = Ogni volta che un giocatore avversario commette un Fallo senza essere visto dall'arbitro, tira un D6. Con 5+, il giocatore viene espulso comunque.
+WizardSportsWizard = Mago: Mago Sportivo
+WizardSportsWizard_description = Una volta per partita, il Mago Sportivo può lanciare un incantesimo, come una Palla di Fuoco o Zap!, contro i giocatori avversari.
+StarPlayer = Giocatore Stella
+StarPlayer_description = I Giocatori Stella sono giocatori famosi che possono essere ingaggiati come incentivo per una sola partita. Giocano nella tua squadra e contano come Solitari.
+MegaStarPlayer = Mega Stella
+MegaStarPlayer_description = Le Mega Stelle sono i Giocatori Stella più famosi di tutti. Possono essere ingaggiate come incentivo per una sola partita, seguendo le stesse regole degli altri Giocatori Stella.
+FamousCoachingStaff = Staff Tecnico Famoso
+FamousCoachingStaff_description = Allenatori, medici e specialisti rinomati che possono essere ingaggiati come incentivo per una sola partita, ciascuno con una regola speciale unica.
+
+# Old
+
+BloodweiserKegs = Barili di Bloodweiser
+BloodweiserKegs_description = Ogni Barile di Bloodweiser aggiunge 1 al tiro per vedere se i giocatori svenuti si riprendono alla fine di ogni drive.
+SpecialPlays = Giocate Speciali
+SpecialPlays_description = Ogni carta Giocata Speciale permette alla tua squadra di pescare e usare una Giocata Speciale durante la partita.
+
+###############################################################
+# Prayers to Nuffle
+###############################################################
+
+TreacherousTrapdoor = Botola Insidiosa
+TreacherousTrapdoor_description = Fino alla fine di questo tempo, ogni volta che un giocatore entra in una casella botola, tira un D6. Con 1, la botola si apre, il giocatore ci cade dentro e viene messo nelle Riserve. Se aveva la palla, questa rimbalza dalla casella della botola.
+FriendsWithTheRef = Amico dell'Arbitro
+FriendsWithTheRef_description = Fino alla fine di questo drive, quando protesti con l'arbitro, considera un risultato di 5-6 come Beh, detto così e un risultato di 2-4 come Fuori di qui.
+Stiletto = Stiletto
+Stiletto_description = Scegli a caso un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine di questo drive, quel giocatore ottiene il tratto Pugnalare.
+IronMan = Uomo di Ferro
+IronMan_description = Scegli un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine della partita, la sua Armatura migliora di 1, fino a un massimo di 11+.
+KnuckleDusters = Tirapugni
+KnuckleDusters_description = Scegli un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine di questo drive, quel giocatore ottiene l'abilità Colpo Possente (+1).
+BadHabits = Cattive Abitudini
+BadHabits_description = Scegli a caso D3 giocatori avversari disponibili per questo drive e senza il tratto Solitario. Fino alla fine di questo drive, quei giocatori ottengono il tratto Solitario (2+).
+GreasyCleats = Tacchetti Unti
+GreasyCleats_description = Scegli a caso un giocatore avversario disponibile per questo drive. Qualcuno ha manomesso le sue scarpe: fino alla fine di questo drive, il suo Movimento è ridotto di 1.
+BlessingsOfNuffle = Benedizioni di Nuffle
+BlessingsOfNuffle_description = Scegli un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine della partita, quel giocatore ottiene l'abilità Professionista.
+MolesUnderThePitch = Talpe sotto il Campo
+MolesUnderThePitch_description = Fino alla fine di questo tempo, applica un modificatore di -1 ogni volta che un giocatore tenta di Scattare, o di -2 se entrambi gli allenatori hanno ottenuto questo risultato.
+PerfectPassing = Passaggio Perfetto
+PerfectPassing_description = Fino alla fine della partita, ogni giocatore della tua squadra che completa un passaggio ottiene 2 PSP invece del solito 1 PSP.
+DazzlingCatching = Ricezione Abbagliante
+DazzlingCatching_description = Fino alla fine della partita, ogni giocatore della tua squadra che riceve un passaggio ottiene 1 PSP.
+FanInteraction = Interazione con i Tifosi
+FanInteraction_description = Fino alla fine di questo drive, se un giocatore avversario subisce un Infortunio dopo essere stato spinto tra il pubblico, la tua squadra ottiene 2 PSP come se fosse stato ferito da un Blocco.
+FoulingFrenzy = Frenesia di Falli
+FoulingFrenzy_description = Fino alla fine della partita, ogni giocatore della tua squadra che causa un Infortunio con un Fallo ottiene 2 PSP, come se lo avesse causato con un Blocco.
+ThrowARock = Lancia un Sasso
+ThrowARock_description = Fino alla fine di questo drive, se un giocatore avversario perde tempo, alla fine del suo turno puoi tirare un D6. Con 5+, un tifoso furioso gli lancia un sasso ed è immediatamente Atterrato.
+UnderScrutiny = Sotto Osservazione
+UnderScrutiny_description = Fino alla fine di questo tempo, ogni giocatore avversario che commette un Fallo viene visto automaticamente dall'arbitro, anche senza ottenere un doppio.
+IntensiveTraining = Allenamento Intensivo
+IntensiveTraining_description = Scegli a caso un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine della partita, quel giocatore ottiene un'abilità Primaria a tua scelta.
+
+# Old
+
+BlessedStatueOfNuffle = Statua Benedetta di Nuffle
+BlessedStatueOfNuffle_description = Scegli un giocatore della tua squadra disponibile per questo drive e senza il tratto Solitario. Fino alla fine della partita, quel giocatore ottiene l'abilità Professionista.
+NecessaryViolence = Violenza Necessaria
+NecessaryViolence_description = Fino alla fine della partita, ogni giocatore della tua squadra che causa un Infortunio ottiene 3 PSP invece dei soliti 2 PSP.
+
+###############################################################
+# Roster names
+###############################################################
+
+AmazonRoster = Amazzoni
+BlackOrcRoster = Orchi Neri
+BretonnianRoster = Bretonniani
+ChaosChosenRoster = Prescelti del Caos
+ChaosDwarfRoster = Nani del Caos
+ChaosRenegadeRoster = Rinnegati del Caos
+DarkElfRoster = Elfi Oscuri
+DwarfRoster = Nani
+ElvenUnionRoster = Unione Elfica
+GnomeRoster = Gnomi
+GoblinRoster = Goblin
+HalflingRoster = Halfling
+HighElfRoster = Alti Elfi
+HumanRoster = Umani
+ImperialNobilityRoster = Nobiltà Imperiale
+KhorneRoster = Khorne
+LizardmenRoster = Uomini Lucertola
+NecromanticHorrorRoster = Orrore Negromantico
+NorseRoster = Norsmanni
+NurgleRoster = Nurgle
+OgreRoster = Ogri
+OldWorldAllianceRoster = Alleanza del Vecchio Mondo
+OrcRoster = Orchi
+ShamblingUndeadRoster = Non Morti Barcollanti
+SkavenRoster = Skaven
+SnotlingRoster = Snotling
+TombKingsRoster = Re dei Sepolcri
+UnderworldDenizensRoster = Abitanti del Sottomondo
+VampireRoster = Vampiri
+WoodElfRoster = Elfi Silvani
+
+###############################################################
+# Special leagues and rules
+###############################################################
+
+# Special leagues
+
+BadlandsBrawl = Rissa delle Terre Desolate
+BadlandsBrawl_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Rissa delle Terre Desolate.
+ChaosClash = Scontro del Caos
+ElvenKingdomsLeague = Lega dei Regni Elfici
+ElvenKingdomsLeague_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Lega dei Regni Elfici.
+HalflingThimbleCup = Coppa Ditale Halfling
+HalflingThimbleCup_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Coppa Ditale Halfling.
+LustrianSuperleague = Superlega di Lustria
+LustrianSuperleague_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Superlega di Lustria.
+OldWorldClassic = Classica del Vecchio Mondo
+OldWorldClassic_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Classica del Vecchio Mondo.
+SylvanianSpotlight = Riflettori di Sylvania
+SylvanianSpotlight_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre dei Riflettori di Sylvania.
+UnderworldChallenge = Sfida del Sottomondo
+UnderworldChallenge_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Sfida del Sottomondo.
+WoodlandLeague = Lega dei Boschi
+WorldsEdgeSuperleague = Superlega del Confine del Mondo
+WorldsEdgeSuperleague_description = Questa squadra può ingaggiare Giocatori Stella e incentivi disponibili per le squadre della Superlega del Confine del Mondo.
+
+# Special rules
+
+BrawlingBrutes = Bruti Attaccabrighe
+BrawlingBrutes_description = Questa squadra può ingaggiare giocatori con la parola chiave Grosso senza che contino nel limite abituale, e può ritirare un singolo risultato Entrambi Atterrati una volta per partita.
+BriberyAndCorruption = Corruzione e Mazzette
+BriberyAndCorruption_description = Una volta per partita, se questa squadra ottiene un 1 naturale usando una Mazzetta, può ritirare il dado. Inoltre, le Mazzette le costano meno come incentivi.
+FavouredOf = Favorito di...
+FavouredOf_description = Alla creazione di questa squadra, l'allenatore deve scegliere quale dio del Caos la favorisce. Questa scelta determina quali Giocatori Stella e incentivi la squadra può ingaggiare.
+FavouredOfHashut = Favorito di Hashut
+FavouredOfHashut_description = Questa squadra è favorita da Hashut e può ingaggiare Giocatori Stella e incentivi disponibili per le squadre con questa regola speciale.
+FavouredOfKhorne = Favorito di Khorne
+FavouredOfKhorne_description = Questa squadra è favorita da Khorne e può ingaggiare Giocatori Stella e incentivi disponibili per le squadre con questa regola speciale.
+FavouredOfNurgle = Favorito di Nurgle
+FavouredOfNurgle_description = Questa squadra è favorita da Nurgle e può ingaggiare Giocatori Stella e incentivi disponibili per le squadre con questa regola speciale.
+LowCostLinemen = Linemen a Basso Costo
+LowCostLinemen_description = I linemen di questa squadra non contano nel suo Valore Attuale quando si calcolano gli incentivi e la piccola cassa disponibile.
+MastersOfUndeath = Maestri della Non Morte
+MastersOfUndeath_description = Una volta per partita, l'allenatore capo di questa squadra può rianimare un morto: quando un giocatore avversario subisce un risultato MORTO, può unirsi alla squadra come nuovo Zombi lineman per il resto della partita.
+Swarming = Sciame
+Swarming_description = Durante ogni sequenza di Inizio Drive, dopo il passo 2 e prima del passo 3, puoi prendere D3 giocatori con questo tratto dalle Riserve e schierarli in campo, purché non siano in una fascia laterale né sulla linea di mischia.
+TeamCaptain = Capitano della Squadra
+TeamCaptain_description = Alla creazione di questa squadra, un giocatore deve essere nominato Capitano. Finché il capitano è in campo, puoi tirare un D6 ogni volta che usi un ritiro di squadra: con 6, il ritiro non viene perso.
+VampireLord = Signore Vampiro
+VampireLord_description = Questa squadra può ingaggiare un Medico della Peste o un Assistente di Obitorio come incentivo, e i suoi Vampiri possono nutrirsi dei Servi per placare la loro Sete di Sangue.
+
+###############################################################
+# Staff
+###############################################################
+
+Cheerleader = Cheerleader
+AssistantCoach = Assistenti allenatori
+Apothecary = Medico
+ReRoll = Ritiri
+
+###############################################################
+# Famous Coaching Staff
+###############################################################
+
+AyleenAndar = Ayleen Andar
+FinkDaFixer = Fink da Fixer
+GalandrilSilverwater = Galandril Silverwater
+JosefBugman = Josef Bugman
+KariColdsteel = Kari Coldsteel
+KrotShockwhisker = Krot Shockwhisker
+MungoSpinecracker = Mungo Spinecracker
+PapaSkullbones = Papa Skullbones
+ProfessorFronkelheim = Professor Fronkelheim
+SchielundScharlitan = Schielund Scharlitan
+
+###############################################################
+# Position names
+###############################################################
+
+# Keywords & Common
+
+All = Tutti
+
+Animal = Animale
+Animal_description = Gli animali sono bestie che scendono in campo con i loro addestratori, come la volpe dei boschi degli Gnomi o il cinghiale da birra norreno.
+Beastman = Uomo Bestia
+Beastman_description = Gli uomini bestia sono seguaci cornuti degli Dei Oscuri che formano il grosso dei Prescelti del Caos, e giocano come Khorngor e Pestigor per Khorne e Nurgle.
+Construct = Costrutto
+Construct_description = I costrutti sono corpi artificiali animati dalla magia oscura, come i golem di carne degli Orrori Negromantici.
+Dryad = Driade
+Dryad_description = Le driadi sono spiriti arborei delle foreste profonde. Solo il giocatore stella Willow Rosebark ha questa parola chiave.
+Dwarf = Nano
+Dwarf_description = I nani sono giocatori bassi, testardi e pesantemente corazzati delle squadre di Nani, Nani del Caos e dell'Alleanza del Vecchio Mondo. I loro antichi rancori gli sono valsi più di un Odio.
+Dwarfs = Nani
+Elf = Elfo
+Elf_description = Gli elfi sono giocatori agili ed eleganti che puntano su velocità e passaggi, schierati da Elfi Oscuri, Alti Elfi, Elfi Silvani e Unione Elfica.
+Ghoul = Ghoul
+Ghoul_description = I ghoul sono divoratori di cadaveri rapidi e selvaggi che fanno da corridori per Non Morti e Orrori Negromantici.
+Gnoblar = Gnoblar
+Gnoblar_description = I gnoblar sono minuscoli pelleverde codardi che riempiono la linea delle squadre di Ogre cercando di non intralciare i grossi.
+Gnome = Gnomo
+Gnome_description = Gli gnomi sono piccoli burloni e illusionisti astuti che formano il cuore delle squadre di Gnomi.
+Goblin = Goblin
+Goblin_description = I goblin sono piccoli pelleverde subdoli. Hanno squadre proprie e giocano accanto a Orchi, Nani del Caos e Rinnegati del Caos, spesso con armi segrete.
+Halfling = Halfling
+Halfling_description = Gli halfling sono gente minuta molto più appassionata di cibo che di football. Formano squadre di Halfling e possono unirsi alle squadre di Umani come ricevitori.
+Halflings = Halfling
+Human = Umano
+Human_description = Gli umani sono i giocatori più diffusi e formano, tra le altre, squadre di Umani, Amazzoni, Bretonniani, Nobiltà Imperiale, Norreni e dell'Alleanza del Vecchio Mondo.
+Humans = Umani
+Lizardman = Uomo Lucertola
+Lizardman_description = Gli uomini lucertola sono i servitori a sangue freddo degli Antichi: skink agili, saurus robusti e potenti kroxigor.
+Minotaur = Minotauro
+Minotaur_description = I minotauri sono enormi bestioni dalla testa di toro in preda alla Furia Incontrollata, ingaggiati da Prescelti del Caos, Nani del Caos e Rinnegati del Caos.
+Ogre = Ogre
+Ogre_description = Gli ogre sono bestioni massicci e affamati che hanno squadre proprie e si vendono a squadre del Caos, di Umani e dell'Alleanza del Vecchio Mondo.
+Orc = Orco
+Orc_description = Gli orchi sono pelleverde duri e aggressivi che praticano un gioco di corsa brutale per Orchi, Orchi Neri e Rinnegati del Caos.
+Skaven = Skaven
+Skaven_description = Gli skaven sono uomini ratto veloci e infidi. Corridori delle fogne, ratti dei clan e ratti ogre giocano per Skaven, Abitanti del Sottosuolo e Rinnegati del Caos.
+Skeleton = Scheletro
+Skeleton_description = Gli scheletri sono le ossa rianimate di antichi defunti, lenti ma difficili da abbattere, schierati da Non Morti e Re dei Sepolcri.
+Snakeman = Uomo Serpente
+Snakeman_description = Gli uomini serpente sono creature serpentine. Solo il giocatore stella Boa Kon'ssstriktr ha questa parola chiave.
+Snotling = Snotling
+Snotling_description = Gli snotling sono i più piccoli tra i pelleverde e invadono il campo per le squadre di Snotling e del Sottosuolo, con i loro saltatori, lanciafunghi e carri a pompa.
+Spawn = Progenie
+Spawn_description = Le progenie sono orrori mutanti e senza mente donati dagli Dei Oscuri, come la progenie di sangue di Khorne o la progenie putrida di Nurgle.
+Spite = Spite
+Spite_description = Gli spite sono minuscoli spiriti della foresta. Solo il giocatore stella Swiftvine Glimmershard ha questa parola chiave.
+Squirrel = Scoiattolo
+Squirrel_description = Questa parola chiave appartiene a un solo giocatore stella peloso: Akhorne lo Scoiattolo.
+Thrall = Servo
+Thrall_description = I servi sono i servitori umani delle squadre di Vampiri, di cui i Vampiri si nutrono quando li coglie la Sete di Sangue.
+Treeman = Uomo Albero
+Treeman_description = Gli uomini albero sono antichi e lenti spiriti arborei che fanno da bestioni per Elfi Silvani, Halfling, Gnomi e l'Alleanza del Vecchio Mondo.
+Troll = Troll
+Troll_description = I troll sono bestioni ottusi che si rigenerano e giocano per squadre di pelleverde, del Caos e del Sottosuolo. Alcuni giocatori nutrono Odio verso i troll.
+Undead = Non Morto
+Undead_description = I non morti sono stati strappati alla tomba dalla magia oscura e giocano per Non Morti, Orrori Negromantici, Re dei Sepolcri e Vampiri. Alcuni giocatori nutrono Odio verso i non morti.
+Vampire = Vampiro
+Vampire_description = I vampiri sono nobili immortali, veloci e forti ma legati alla Sete di Sangue che li spinge a nutrirsi dei loro servi.
+Werewolf = Lupo Mannaro
+Werewolf_description = I lupi mannari sono feroci mutaforma schierati dagli Orrori Negromantici.
+Wraith = Spettro
+Wraith_description = Gli spettri sono non morti eterei che scivolano sul campo per gli Orrori Negromantici.
+Yhetee = Yhetee
+Yhetee_description = Gli yhetee sono bestioni selvaggi del nord gelato che giocano per le squadre di Norreni.
+Zoat = Zoat
+Zoat_description = Gli zoat sono rare e schive creature della foresta. Solo il giocatore stella Zolcath lo Zoat ha questa parola chiave.
+Zombie = Zombi
+Zombie_description = Gli zombi sono cadaveri lenti e barcollanti che formano i giocatori di linea economici di Non Morti e Orrori Negromantici.
+
+Lineman = Lineman
+Lineman_description = I linemen sono la spina dorsale di una squadra. Possono essere ingaggiati in gran numero e hanno accesso alle categorie di abilità di base della squadra.
+Thrower = Lanciatore
+Thrower_description = I lanciatori sono i registi della squadra, specializzati nel passaggio della palla.
+Catcher = Ricevitore
+Catcher_description = I ricevitori sono specializzati nel ricevere passaggi e portare la palla in meta.
+Blitzer = Blitzer
+Blitzer_description = I blitzer uniscono velocità e forza per sfondare la linea avversaria e dare la caccia al portatore di palla.
+Blocker = Bloccatore
+Blocker_description = I bloccatori sono giocatori forti e resistenti che tengono la linea di mischia e atterrano gli avversari.
+Runner = Corridore
+Runner_description = I corridori sono giocatori veloci e agili che fanno avanzare la palla sul campo.
+BigGuy = Grosso
+BigGuy_description = I Grossi sono giocatori enormi, potenti e spesso inaffidabili. Una squadra può includerne solo un numero limitato.
+Special = Speciale
+Special_description = I giocatori speciali ricoprono un ruolo unico nella loro squadra che non corrisponde a nessun'altra parola chiave di ruolo.
+
+# Amazon
+
+EagleWarriorLinewoman = Guerriera Aquila lineman
+PiranhaWarriorBlitzer = Guerriera Piranha blitzer
+PythonWarriorThrower = Guerriera Pitone lanciatrice
+JaguarWarriorBlocker = Guerriera Giaguaro bloccatrice
+
+# Black Orc
+
+GoblinBruiserLineman = Goblin Picchiatore lineman
+BlackOrc = Orco Nero
+TrainedTroll = Troll Addestrato
+
+# Bretonnian
+
+Squires = Scudiero
+KnightCatcher = Cavaliere ricevitore
+KnightThrower = Cavaliere lanciatore
+GrailKnight = Cavaliere del Graal
+
+# Chaos Chosen
+
+BeastmanRunnerLineman = Uomo Bestia corridore lineman
+ChosenBlocker = Prescelto bloccatore
+ChaosTroll = Troll del Caos
+ChaosOgre = Ogre del Caos
+
+# Chaos Dwarf
+
+HobgoblinLineman = Hobgoblin lineman
+HobgoblinSneakyStabba = Hobgoblin Pugnalatore Subdolo
+ChaosDwarfBlocker = Nano del Caos bloccatore
+ChaosDwarfFlamesmith = Nano del Caos Forgiafiamme
+BullCentaurBlitzer = Centauro Toro blitzer
+
+# Chaos Renegade
+
+RenegadeHumanLineman = Umano Rinnegato lineman
+RenegadeHumanThrower = Umano Rinnegato lanciatore
+RenegadeGoblin = Goblin Rinnegato
+RenegadeOrc = Orco Rinnegato
+RenegadeSkaven = Skaven Rinnegato
+RenegadeDarkElf = Elfo Oscuro Rinnegato
+RenegadeTroll = Troll Rinnegato
+RenegadeOgre = Ogre Rinnegato
+RenegadeMinotaur = Minotauro Rinnegato
+RenegadeRatOgre = Ratto Ogre Rinnegato
+
+# Dark Elf
+
+DarkElfLineman = Elfo Oscuro lineman
+Assassin = Assassino
+WitchElf = Elfa Strega
+
+# Dwarf
+
+DwarfBlockerLineman = Nano lineman
+TrollSlayer = Sventratroll
+Deathroller = Rullo della Morte
+
+# Gnome
+
+GnomeLineman = Gnomo lineman
+GnomeBeastmaster = Gnomo Domatore
+GnomeIllusionist = Gnomo Illusionista
+WoodlandFox = Volpe dei Boschi
+AlternForestTreeman = Uomo Albero della Foresta di Altern
+
+# Goblin
+
+GoblinLineman = Goblin lineman
+Bomma = Bombarolo
+Looney = Svitato
+Fanatic = Fanatico
+Pogoer = Saltellatore
+Ooligan = 'Ooligan
+DoomDiver = Tuffatore della Morte
+
+# Halfling
+
+HalflingHopefulLineman = Halfling Speranzoso lineman
+HalflingHefty = Halfling Robusto
+HalflingCatcher = Halfling ricevitore
+
+# High Elf
+
+HighElfLineman = Alto Elfo lineman
+PhoenixWarrior = Guerriero Fenice
+DragonPrince = Principe Drago
+WhiteLion = Leone Bianco
+
+# Human
+
+HumanLineman = Umano lineman
+HalflingHopeful = Halfling Speranzoso
+
+# Imperial Nobility
+
+ImperialRetainerLineman = Servitore Imperiale lineman
+ImperialThrower = Lanciatore Imperiale
+NobleBlitzer = Nobile blitzer
+Bodyguard = Guardia del Corpo
+
+# Khorne
+
+BloodbornMarauderLineman = Predone Nato dal Sangue lineman
+Khorngor = Khorngor
+Bloodseeker = Cercasangue
+Bloodspawn = Progenie del Sangue
+
+# Lizardmen
+
+SkinkRunnerLineman = Skink corridore lineman
+ChameleonSkink = Skink Camaleonte
+SaurusBlocker = Saurus bloccatore
+Kroxigor = Kroxigor
+
+# Necromantic Horror
+
+ZombieLineman = Zombi lineman
+GhoulRunner = Ghoul corridore
+FleshGolem = Golem di Carne
+
+# Norse
+
+NorseRaiderLineman = Predatore Norsmanno lineman
+BeerBoar = Cinghiale della Birra
+NorseBerzerker = Berserker Norsmanno
+Valkyrie = Valchiria
+Ulfwerener = Ulfwerener
+
+# Nurgle
+
+RotterLineman = Putrido lineman
+Pestigor = Pestigor
+Bloater = Gonfio
+Rotspawn = Progenie Putrida
+
+# Ogre
+
+GnoblarLineman = Gnoblar lineman
+OgreRuntPunter = Ogre Nanerottolo Calciatore
+OgreBlocker = Ogre bloccatore
+
+# Old World Alliance
+
+OldWorldHumanLineman = Umano del Vecchio Mondo lineman
+OldWorldHumanThrower = Umano del Vecchio Mondo lanciatore
+OldWorldHumanCatcher = Umano del Vecchio Mondo ricevitore
+OldWorldDwarfLineman = Nano del Vecchio Mondo lineman
+OldWorldDwarfBlitzer = Nano del Vecchio Mondo blitzer
+OldWorldDwarfBlocker = Nano del Vecchio Mondo bloccatore
+OldWorldDwarfRunner = Nano del Vecchio Mondo corridore
+OldWorldHumanBlitzer = Umano del Vecchio Mondo blitzer
+OldWorldDwarfTrollSlayer = Sventratroll Nano del Vecchio Mondo
+OldWorldHalflingHopefuls = Halfling Speranzosi del Vecchio Mondo
+
+# Orc
+
+OrcLineman = Orco lineman
+BigUn = Orco Grosso
+UntrainedTroll = Troll non Addestrato
+
+# Shambling Undead
+
+SkeletonLineman = Scheletro lineman
+WightBlitzer = Spettro dei Tumuli blitzer
+Mummy = Mummia
+
+# Skaven
+
+SkavenClanratLineman = Skaven Ratto del Clan lineman
+GutterRunner = Corridore delle Fogne
+RatOgre = Ratto Ogre
+
+# Snotling
+
+SnotlingLineman = Snotling lineman
+FungusFlinga = Lanciafunghi
+FunHoppa = Saltellatore Allegro
+StiltyRunna = Corridore sui Trampoli
+PumpWagon = Carro a Pompa
+
+# Tomb Kings
+
+AnointedThrower = Unto lanciatore
+AnointedBlitzer = Unto blitzer
+TombGuardian = Guardiano della Tomba
+
+# Underworld Denizens
+
+UnderworldGoblinLineman = Goblin del Sottomondo lineman
+UnderworldSnotling = Snotling del Sottomondo
+SkavenClanrat = Skaven Ratto del Clan
+SkavenThrower = Skaven lanciatore
+SkavenBlitzer = Skaven blitzer
+UnderworldTroll = Troll del Sottomondo
+MutantRatOgre = Ratto Ogre Mutante
+
+# Vampire
+
+ThrallLineman = Servo lineman
+VampireRunner = Vampiro corridore
+VampireBlitzer = Vampiro blitzer
+VampireThrower = Vampiro lanciatore
+Vargheist = Vargheist
+
+# Wood Elf
+
+WoodElfLineman = Elfo Silvano lineman
+Wardancer = Danzatore di Guerra
+LorenForestTreeman = Uomo Albero della Foresta di Loren
+
+# Others
+
+Journeyman = Giocatore occasionale
+
+# Stars
+
+AkhorneTheSquirrel = Akhorne The Squirrel
+AnqiPanqi = Anqi Panqi
+BarikFarblast = Barik Farblast
+BilerotVomitflesh = Bilerot Vomitflesh
+BoaKonSsstriktr = Boa Kon'ssstriktr
+BomberDribblesnot = Bomber Dribblesnot
+BryceTheSliceCambuel = Bryce 'The Slice' Cambuel
+CaptainKarinaVonRiesz = Captain Karina Von Riesz
+CindyPiewhistle = Cindy Piewhistle
+CountLuthorVonDrakenborg = Count Luthor von Drakenborg
+DeeprootStrongbranch = Deeproot Strongbranch
+DriblAndDrull = Dribl and Drull
+Dribl = Dribl
+Drull = Drull
+EldrilSidewinder = Eldril Sidewinder
+EstelleLaVeneaux = Estelle la Veneaux
+FrankNStein = Frank 'n' Stein
+FungusTheLoon = Fungus the Loon
+GlartSmashrip = Glart Smashrip
+GlorielSummerbloom = Gloriel Summerbloom
+GlotlStop = Glotl Stop
+GrakAndCrumbleberry = Grak and Crumbleberry
+Grak = Grak
+Crumbleberry = Crumbleberry
+GrashnakBlackhoof = Grashnak Blackhoof
+GretchenWachterTheBloodBowlWidow = Gretchen Wächter 'The Blood Bowl Widow'
+GriffOberwald = Griff Oberwald
+GrimIronjaw = Grim Ironjaw
+GrombrindalTheWhiteDwarf = Grombrindal, The White Dwarf
+GufflePussmaw = Guffle Pussmaw
+HakflemSkuttlespike = Hakflem Skuttlespike
+HelmutWulf = Helmut Wulf
+HTharkTheUnstoppable = H'thark the Unstoppable
+IvanTAnimalDeathshroud = Ivan 'the Animal' Deathshroud
+IvarEriksson = Ivar Eriksson
+JeremiahKool = Jeremiah Kool
+JordellFreshbreeze = Jordell Freshbreeze
+KarlaVonKill = Karla Von Kill
+KirothKrakeneye = Kiroth Krakeneye
+KreekTheVerminatorRustgouger = Kreek 'the Verminator' Rustgouger
+LordBorakTheDespoiler = Lord Borak the Despoiler
+MapleHighgrove = Maple Highgrove
+MaxSpleenripper = Max Spleenripper
+MightyZug = Mighty Zug
+MorgNThorg = Morg 'n' Thorg
+NobblaBlackwart = Nobbla Blackwart
+PuggyBaconbreath = Puggy Baconbreath
+RashnakBackstabber = Rashnak Backstabber
+RipperBolgrot = Ripper Bolgrot
+RodneyRoachbait = Rodney Roachbait
+RowanaForestfoot = Rowana Forestfoot
+RoxannaDarknail = Roxanna Darknail
+RumbelowSheepskin = Rumbelow Sheepskin
+ScrappaSorehead = Scrappa Sorehead
+ScylaAnfingrimm = Scyla Anfingrimm
+SkitterStabStab = Skitter Stab-stab
+SkrorgSnowpelt = Skrorg Snowpelt
+SkrullHalfheight = Skrull Halfheight
+SwiftvineGlimmershard = Swiftvine Glimmershard
+TheBlackGobbo = The Black Gobbo
+TheSwiftTwins = The Swift Twins
+LucienSwift = Lucien Swift
+ValenSwift = Valen Swift
+ThorssonStoutmead = Thorsson Stoutmead
+VaragGhoulChewer = Varag Ghoul-Chewer
+WilhelmChaney = Wilhelm Chaney
+WillowRosebark = Willow Rosebark
+WithergraspDoubledrool = Withergrasp Doubledrool
+ZolcathTheZoat = Zolcath the Zoat
+ZzhargMadeye = Zzharg Madeye
+
+###############################################################
+# Injuries
+###############################################################
+
+Stunned = Stordito
+KO = KO
+BadlyHurt = Ferito Gravemente
+SeriouslyHurt = Ferito Seriamente
+SeriousInjury = Infortunio Serio
+HeadInjury = Trauma Cranico
+SmashedKnee = Ginocchio Fracassato
+BrokenArm = Braccio Rotto
+DislocatedHip = Anca Lussata
+NeckInjury = Lesione al Collo
+Dead = Morto
+
+# Old
+
+DislocatedShoulder = Spalla Lussata
+
+###############################################################
+# Successes
+###############################################################
+
+PassingCompletion = Passaggio Completato
+ThrowingCompletion = Lancio Completato
+LandingOnFeet = Atterraggio in Piedi
+Deflection = Deviazione
+Interception = Intercetto
+Casualty = Infortunio
+Touchdown = Touchdown
+MostValuablePlayer = Giocatore Più Prezioso
+StarPlayerPoint = Punto Stella
+
+###############################################################
+# Advancement choices
+###############################################################
+
+RandomPrimarySkill = Abilità Primaria Casuale ({ $skill_category })
+RandomSecondarySkill = Abilità Secondaria Casuale ({ $skill_category })
+ChosenPrimarySkill = Abilità Primaria Scelta
+ChosenSecondarySkill = Abilità Secondaria Scelta
+RandomCharacteristic = Caratteristica Casuale
+
+###############################################################
+# Advancements
+###############################################################
+
+ChosenSkill = Abilità Scelta ({ $skill })
+RandomSkill = Abilità Casuale ({ $skill })
+MovementAllowance = Movimento
+Strength = Forza
+Agility = Agilità
+PassingAbility = Abilità di Passaggio
+ArmourValue = Armatura
+
+###############################################################
+# Skill Categories
+###############################################################
+
+General = Generale
+Mutation = Mutazione
+Pass = Passaggio
+Pass_description = Questo giocatore può ritirare un test di Abilità di Passaggio fallito quando effettua un'azione di Passaggio.
+Devious = Subdola
+Trait = Tratto
+
+###############################################################
+# Skills & Traits
+###############################################################
+
+# General
+
+Block = Bloccare
+Block_description = Quando durante un Blocco si applica un risultato Entrambi Atterrati, questo giocatore può scegliere di ignorarlo e non essere Atterrato.
+Dauntless = Intrepido
+Dauntless_description = Quando questo giocatore effettua un Blocco, se il bersaglio designato ha una Forza più alta, tira un D6 e aggiungi la Forza di questo giocatore. Se il totale supera la Forza del bersaglio, la Forza di questo giocatore aumenta fino a eguagliarla per la durata del Blocco.
+Fend = Respingere
+Fend_description = Se questo giocatore viene spinto indietro da qualsiasi risultato dei dadi blocco, può impedire al giocatore che lo ha spinto di seguirlo. Respingere non può essere usata contro un giocatore con l'abilità Inarrestabile durante un Blitz.
+Frenzy = Frenesia
+Frenzy_description = Ogni volta che questo giocatore effettua un Blocco, deve seguire il bersaglio se viene spinto indietro. Se il bersaglio è ancora In Piedi dopo la spinta e questo giocatore può seguirlo, deve effettuare un secondo blocco contro lo stesso bersaglio.
+Kick = Calcio
+Kick_description = Se questo giocatore è designato come calciatore durante un calcio d'inizio, puoi dimezzare il risultato del D6 che determina di quante caselle devia la palla, arrotondando per difetto.
+Pro = Professionista
+Pro_description = Una volta per turno di squadra, questo giocatore può tentare di ritirare un dado tirato per lui. Tira un D6: con 3+ il dado può essere ritirato, con 1-2 resta il risultato originale. Per quel dado non si può poi usare un ritiro di squadra.
+SteadyFooting = Passo Saldo
+SteadyFooting_description = Quando questo giocatore sta per essere Atterrato o messo Prono, tira un D6. Con 6 mantiene l'equilibrio e resta In Piedi.
+StripBall = Strappare Palla
+StripBall_description = Quando questo giocatore effettua un Blocco contro un avversario in possesso della palla, scegliere un risultato Spinto Indietro fa cadere la palla a quel giocatore nella casella in cui viene spinto.
+SureHands = Mani Sicure
+SureHands_description = Questo giocatore può ritirare qualsiasi tentativo fallito di raccogliere la palla. Inoltre, l'abilità Strappare Palla non può essere usata contro un giocatore con questa abilità.
+Tackle = Placcaggio
+Tackle_description = Quando un avversario attivo tenta di Schivare da una casella in cui era Marcato da uno o più giocatori con questa abilità, non può usare l'abilità Schivare.
+Taunt = Provocare
+Taunt_description = Quando un avversario effettua un Blocco contro questo giocatore, se questo giocatore viene spinto indietro, puoi scegliere che l'attaccante lo segua, e deve farlo.
+Wrestle = Lotta
+Wrestle_description = Questo giocatore può usare questa abilità quando si applica un risultato Entrambi Atterrati, sia quando effettua un Blocco sia quando ne è il bersaglio. Invece di applicare il risultato normalmente, entrambi i giocatori vengono messi Proni e non si effettua alcun tiro Armatura.
+
+# Agility
+
+Catch = Ricevere
+Catch_description = Questo giocatore può ritirare un test di Agilità fallito quando tenta di ricevere la palla.
+Defensive = Difensivo
+Defensive_description = Durante il turno avversario, i giocatori avversari Marcati da questo giocatore non possono usare l'abilità Guardia.
+DivingCatch = Presa in Tuffo
+DivingCatch_description = Questo giocatore può tentare di ricevere la palla se un passaggio, una rimessa o un calcio d'inizio la fa atterrare in una casella nella sua zona di placcaggio dopo la dispersione o la deviazione. Inoltre, può applicare un modificatore di +1 a qualsiasi tentativo di ricevere un passaggio preciso se occupa la casella bersaglio.
+DivingTackle = Placcaggio in Tuffo
+DivingTackle_description = Se un avversario attivo che tenta di Schivare, Saltare o fare un Balzo per lasciare una casella in cui è Marcato da questo giocatore supera il test di Agilità, puoi dichiarare che questo giocatore usa questa abilità. L'avversario deve sottrarre 2 dal risultato e questo giocatore viene messo Prono nella casella lasciata libera.
+Dodge = Schivare
+Dodge_description = Una volta per turno di squadra, durante la sua attivazione, questo giocatore può ritirare un test di Agilità fallito quando tenta di Schivare. Inoltre, può usare questa abilità quando è bersaglio di un Blocco e si applica un risultato Inciampa.
+HitAndRun = Colpisci e Fuggi
+HitAndRun_description = Dopo aver effettuato un Blocco, questo giocatore può muovere subito di una casella gratuita, ignorando le zone di placcaggio, purché sia ancora In Piedi. Dopo questo movimento gratuito non deve essere Marcato da né Marcare alcun avversario.
+JumpUp = Balzare in Piedi
+JumpUp_description = Se questo giocatore è Prono può rialzarsi gratuitamente. Inoltre, se è Prono quando viene attivato, può tentare di balzare in piedi ed effettuare un Blocco superando un test di Agilità con un modificatore di +1.
+Leap = Balzo
+Leap_description = Durante il movimento, invece di saltare sopra una singola casella occupata da un giocatore Prono o Stordito, un giocatore con questa abilità può fare un Balzo sopra qualsiasi casella adiacente, comprese caselle libere e occupate da giocatori In Piedi. Inoltre, può ridurre di 1 qualsiasi modificatore negativo al test di Agilità quando tenta di Saltare o fare un Balzo.
+SideStep = Passo Laterale
+SideStep_description = Se questo giocatore viene spinto indietro per qualsiasi motivo, non viene mosso in una casella scelta dall'allenatore avversario. È invece il suo allenatore a scegliere una qualsiasi casella libera adiacente a questo giocatore.
+SafePairOfHands = Mani Affidabili
+SafePairOfHands_description = Se questo giocatore viene Atterrato o messo Prono mentre è in possesso della palla, la palla non rimbalza. Puoi invece posizionarla in una casella libera adiacente a quella occupata da questo giocatore quando diventa Prono.
+Sprint = Sprint
+Sprint_description = Quando questo giocatore effettua un'azione che include movimento, può tentare di Scattare tre volte invece delle solite due.
+SureFeet = Piedi Sicuri
+SureFeet_description = Una volta per turno di squadra, durante la sua attivazione, questo giocatore può ritirare il D6 quando tenta di Scattare.
+
+# Devious
+
+DirtyPlayer = Giocatore Scorretto
+DirtyPlayer_description = Quando questo giocatore commette un Fallo, puoi applicare un modificatore di +1 al tiro Armatura o al tiro Ferita contro la vittima.
+EyeGouge = Cavare gli Occhi
+EyeGouge_description = Quando questo giocatore effettua un Blocco e il bersaglio viene spinto indietro, il bersaglio perde la zona di placcaggio fino alla sua prossima attivazione.
+Fumblerooski = Fumblerooski
+Fumblerooski_description = Quando questo giocatore effettua un Movimento o un Blitz in possesso della palla, può lasciarla cadere in qualsiasi casella che abbandona durante il movimento. Non serve alcun test di Agilità.
+LethalFlight = Volo Letale
+LethalFlight_description = Quando questo giocatore viene lanciato da un compagno e atterra in una casella occupata da un avversario, puoi applicare un modificatore di +1 al tiro Armatura o Ferita contro quel giocatore.
+LoneFouler = Fallo Solitario
+LoneFouler_description = Se questo giocatore commette un Fallo senza assist offensivi, può ritirare il tiro Armatura.
+PileDriver = Schiacciare
+PileDriver_description = Quando questo giocatore Atterra un avversario con un Blocco, può commettere subito un Fallo gratuito contro il giocatore Atterrato. Per farlo deve essere In Piedi dopo il blocco e non deve essersi mosso. Usare questa abilità termina la sua attivazione.
+PutTheBootIn = Dare il Calcio
+PutTheBootIn_description = Quando questo giocatore fornisce un assist offensivo a un Fallo, aggiunge +2 al tiro Armatura invece di +1.
+QuickFoul = Fallo Rapido
+QuickFoul_description = Quando questo giocatore commette un Fallo, la sua attivazione non deve per forza terminare dopo il Fallo. Può continuare a muovere se ha ancora movimento.
+Saboteur = Sabotatore
+Saboteur_description = Quando questo giocatore viene Atterrato, tira un D6. Con 4+, anche tutti gli avversari adiacenti vengono Atterrati.
+Shadowing = Pedinare
+Shadowing_description = Quando un avversario nella zona di placcaggio di questo giocatore ne esce volontariamente, questo giocatore può tentare di seguirlo. Tira un D6 e aggiungi il MO di questo giocatore meno il MO dell'avversario: con 6 o più, questo giocatore si muove nella casella lasciata libera.
+SneakyGit = Furbastro
+SneakyGit_description = Quando questo giocatore commette un Fallo, non viene Espulso se ottiene un doppio naturale al tiro Armatura. Inoltre, la sua attivazione non deve per forza terminare dopo il Fallo.
+ViolentInnovator = Innovatore Violento
+ViolentInnovator_description = Quando questo giocatore causa un Infortunio con un'azione Speciale concessa da un'abilità o un tratto, ottiene 2 PSP come se lo avesse causato con un Blocco.
+
+# Strength
+
+ArmBar = Leva al Braccio
+ArmBar_description = Se un avversario Cade dopo aver fallito il test di Agilità nel tentativo di Schivare, Saltare o fare un Balzo da una casella in cui era Marcato da questo giocatore, puoi applicare un modificatore di +1 al tiro Armatura o Ferita.
+Brawler = Attaccabrighe
+Brawler_description = Quando questo giocatore effettua un Blocco da solo (ma non come parte di un Blitz), può ritirare un singolo risultato Entrambi Atterrati.
+BreakTackle = Rompere il Placcaggio
+BreakTackle_description = Una volta durante la sua attivazione, dopo un test di Agilità per Schivare, questo giocatore può modificare il tiro di +1 se la sua Forza è 4 o meno, o di +2 se è 5 o più.
+BullsEye = Centro Perfetto
+BullsEye_description = Quando questo giocatore effettua un Lanciare Compagno e il lancio è superbo, il giocatore lanciato atterra nella casella bersaglio senza disperdersi.
+Grab = Afferrare
+Grab_description = Quando questo giocatore effettua un Blocco da solo, questa abilità impedisce al bersaglio di usare Passo Laterale. Inoltre, quando si applica un risultato Spinto Indietro, questo giocatore può scegliere qualsiasi casella libera adiacente al bersaglio in cui spingerlo.
+Guard = Guardia
+Guard_description = Un giocatore con questa abilità può fornire assist offensivi e difensivi indipendentemente da quanti avversari lo Marcano.
+Juggernaut = Inarrestabile
+Juggernaut_description = Quando questo giocatore effettua un Blitz, il bersaglio del Blocco non può usare le abilità Respingere, Piantarsi o Lotta, e questo giocatore può trattare un risultato Entrambi Atterrati come Spinto Indietro.
+MightyBlow = Colpo Possente
+MightyBlow_description = Quando un avversario viene Atterrato da un Blocco di questo giocatore, puoi applicare un modificatore di +1 al tiro Armatura o Ferita. Questa abilità non può essere usata con i tratti Pugnalare o Motosega.
+MultipleBlock = Blocco Multiplo
+MultipleBlock_description = Quando questo giocatore effettua un Blocco da solo, può scegliere di effettuare due Blocchi, ciascuno contro un diverso giocatore che Marca, con un modificatore di -2 alla Forza in entrambi. Nessuno dei bersagli può ricevere assist offensivi.
+StandFirm = Piantarsi
+StandFirm_description = Questo giocatore può scegliere di non essere spinto indietro, sia da un Blocco contro di lui sia da una spinta a catena.
+StrongArm = Braccio Forte
+StrongArm_description = Questo giocatore può applicare un modificatore di +1 al test di Abilità di Passaggio per un Lanciare Compagno. Un giocatore con il tratto Lanciare Compagno non può avere questa abilità senza di esso.
+ThickSkull = Testa Dura
+ThickSkull_description = Quando si effettua un tiro Ferita contro questo giocatore, va KO solo con 9 e tratta un 8 come Stordito. Se ha anche il tratto Piccoletto, va KO solo con 8 e tratta un 7 come Stordito.
+
+# Passing
+
+Accurate = Preciso
+Accurate_description = Quando questo giocatore effettua un Passaggio Rapido o Corto, puoi applicare un modificatore aggiuntivo di +1 al test di Abilità di Passaggio.
+Cannoneer = Cannoniere
+Cannoneer_description = Quando questo giocatore effettua un Passaggio Lungo o una Bomba Lunga, puoi applicare un modificatore aggiuntivo di +1 al test di Abilità di Passaggio.
+CloudBurster = Squarcianuvole
+CloudBurster_description = Quando questo giocatore effettua un Passaggio Lungo o una Bomba Lunga, puoi obbligare l'allenatore avversario a ritirare un tentativo riuscito di interferire con il passaggio.
+DumpOff = Scarico
+DumpOff_description = Se questo giocatore è designato come bersaglio di un Blocco ed è in possesso della palla, può effettuare subito un Passaggio Rapido, interrompendo l'attivazione dell'avversario. Questo Passaggio Rapido non può causare un turnover.
+GiveAndGo = Dai e Vai
+GiveAndGo_description = Se questo giocatore effettua una Consegna, la sua attivazione non deve per forza terminare dopo la consegna. Può continuare a muovere se ha ancora movimento.
+HailMaryPass = Passaggio della Disperazione
+HailMaryPass_description = Quando questo giocatore effettua un Passaggio, la casella bersaglio può essere ovunque sul campo. Un Passaggio della Disperazione non è mai preciso, si disperde sempre tre volte e non può essere intercettato. Un 1 naturale è sempre un Fumble.
+Leader = Leader
+Leader_description = Una squadra con uno o più giocatori con questa abilità ottiene un ritiro di squadra aggiuntivo, detto ritiro del Leader, utilizzabile solo se almeno un giocatore con questa abilità è in campo.
+NervesOfSteel = Nervi d'Acciaio
+NervesOfSteel_description = Questo giocatore può ignorare qualsiasi modificatore per essere Marcato quando tenta di passare, ricevere la palla o interferire con un passaggio.
+OnTheBall = Sulla Palla
+OnTheBall_description = Questo giocatore può muovere fino a tre caselle quando l'allenatore avversario dichiara un Passaggio, o di una casella quando la palla viene calciata al calcio d'inizio. Questo movimento ignora le zone di placcaggio e non richiede di Schivare.
+Punt = Rinvio
+Punt_description = Invece di effettuare un Passaggio, questo giocatore può calciare la palla in qualsiasi direzione. La palla devia dalla sua casella, usando un D6 per determinare quante caselle percorre, e non può essere intercettata.
+SafePass = Passaggio Sicuro
+SafePass_description = Se questo giocatore commette un fumble durante un Passaggio, la palla non cade, non rimbalza dalla sua casella e non si verifica alcun turnover.
+
+# Mutation
+
+BigHand = Mano Grande
+BigHand_description = Questo giocatore può ignorare qualsiasi modificatore per essere Marcato o per Pioggia Battente quando tenta di raccogliere la palla.
+Claws = Artigli
+Claws_description = Quando effettui un tiro Armatura contro un avversario Atterrato da un Blocco di questo giocatore, un 8+ prima dei modificatori sfonda l'armatura, indipendentemente dal suo valore di Armatura.
+DisturbingPresence = Presenza Inquietante
+DisturbingPresence_description = Quando un avversario effettua un Passaggio, tenta di interferire con un passaggio o di ricevere la palla, deve applicare un modificatore di -1 per ogni giocatore della tua squadra con questa abilità entro tre caselle.
+ExtraArms = Braccia Extra
+ExtraArms_description = Questo giocatore può applicare un modificatore di +1 quando tenta di raccogliere o ricevere la palla, o di interferire con un passaggio.
+FoulAppearance = Aspetto Ripugnante
+FoulAppearance_description = Quando un avversario dichiara un Blocco o qualsiasi azione Speciale contro questo giocatore, il suo allenatore deve prima tirare un D6. Con 1, il giocatore non può effettuare l'azione dichiarata e l'azione è sprecata.
+Horns = Corna
+Horns_description = Quando questo giocatore effettua un Blocco come parte di un Blitz, puoi applicare un modificatore di +1 alla sua Forza.
+IronHardSkin = Pelle di Ferro
+IronHardSkin_description = L'abilità Artigli non può essere usata nei tiri Armatura contro questo giocatore. Inoltre, gli allenatori avversari non possono applicare modificatori ai tiri Armatura contro di lui.
+MonstrousMouth = Bocca Mostruosa
+MonstrousMouth_description = Questo giocatore può ritirare qualsiasi tentativo fallito di ricevere la palla. Inoltre, l'abilità Strappare Palla non può essere usata contro un giocatore con questa abilità.
+PrehensileTail = Coda Prensile
+PrehensileTail_description = Quando un avversario attivo tenta di Schivare, Saltare o fare un Balzo per lasciare una casella in cui è Marcato da questo giocatore, si applica un modificatore aggiuntivo di -1 al suo test di Agilità.
+Tentacles = Tentacoli
+Tentacles_description = Questo giocatore può usare questa abilità quando un avversario che Marca esce volontariamente da una casella nella sua zona di placcaggio. Tira un D6, aggiungi la Forza di questo giocatore e sottrai quella dell'avversario: con 6 o più, l'avversario viene trattenuto e il suo movimento termina.
+TwoHeads = Due Teste
+TwoHeads_description = Questo giocatore può applicare un modificatore di +1 al test di Agilità quando tenta di Schivare.
+VeryLongLegs = Gambe Lunghissime
+VeryLongLegs_description = Questo giocatore può ridurre di 1 qualsiasi modificatore negativo al test di Agilità quando tenta di Saltare un giocatore Prono o Stordito, o di fare un Balzo. Inoltre, può applicare un modificatore di +2 a qualsiasi tentativo di interferire con un passaggio. Questo giocatore ignora l'abilità Squarcianuvole.
+
+# Trait
+
+AlwaysHungry = Sempre Affamato
+AlwaysHungry_description = Se questo giocatore vuole lanciare un compagno, tira un D6 dopo il suo movimento ma prima del lancio. Con 2+ lancia normalmente. Con 1, questo giocatore può tentare di mangiare il compagno.
+Animosity = Animosità ({ $position })
+Animosity_description = Questo giocatore è invidioso di certi compagni, indicati tra parentesi. Se vuole consegnare o passare la palla a uno di loro, tira un D6. Con 1 si rifiuta e la sua attivazione termina.
+AnimalSavagery = Ferocia Animale
+AnimalSavagery_description = All'attivazione di questo giocatore, dopo aver dichiarato l'azione, tira un D6, aggiungendo 2 se è un Blocco o un Blitz. Con 1-3 si scaglia contro un compagno In Piedi adiacente, che viene Atterrato; se non ce ne sono, la sua attivazione termina subito.
+BallChain = Palla e Catena
+BallChain_description = Quando viene attivato, questo giocatore può effettuare solo un Movimento Palla e Catena. Si muove a caso usando la sagoma della rimessa e blocca automaticamente qualsiasi giocatore nella casella in cui entra.
+BloodLust = Sete di Sangue ({ $value })
+BloodLust_description = All'attivazione di questo giocatore, dopo aver dichiarato l'azione, tira un D6. Se il risultato è pari o superiore al valore tra parentesi, agisce normalmente. Altrimenti deve nutrirsi di un compagno Servo o lasciare il campo alla fine della sua attivazione.
+Bombardier = Bombardiere
+Bombardier_description = Se questo giocatore è In Piedi quando viene attivato, può lanciare una bomba. Questa azione Speciale si risolve come un Passaggio, ma si lancia una bomba invece della palla, che esplode dove atterra.
+BoneHead = Testa Vuota
+BoneHead_description = All'attivazione di questo giocatore, dopo aver dichiarato l'azione, tira un D6. Con 1 dimentica cosa stava facendo e la sua attivazione termina subito. Inoltre perde la zona di placcaggio fino alla sua prossima attivazione.
+BreatheFire = Soffio di Fuoco
+BreatheFire_description = Una volta per attivazione, invece di un Blocco, questo giocatore può sputare fuoco su un avversario che Marca. Tira un D6: con 6 il bersaglio è Atterrato, con 4-5 è messo Prono, con 2-3 non succede nulla e con 1 questo giocatore è messo Prono.
+Chainsaw = Motosega
+Chainsaw_description = Invece di un Blocco, questo giocatore può effettuare un attacco con la motosega. Tira un D6: con 2+ il bersaglio è colpito e si effettua contro di lui un tiro Armatura con +3. Con 1 la motosega rimbalza e colpisce invece questo giocatore.
+Decay = Decomposizione
+Decay_description = Se questo giocatore subisce un Infortunio sulla tabella Ferite, si applica un modificatore di +1 a tutti i tiri sulla tabella Infortuni contro di lui.
+Drunkard = Ubriacone
+Drunkard_description = Questo giocatore subisce -1 al tiro quando tenta di Scattare.
+Hatred = Odio ({ $keyword })
+Hatred_description = Quando questo giocatore blocca un avversario con la parola chiave indicata tra parentesi, può ritirare un singolo dado blocco.
+HypnoticGaze = Sguardo Ipnotico
+HypnoticGaze_description = Durante la sua attivazione, questo giocatore può ipnotizzare un avversario che Marca. Effettua un test di Agilità con -1 per ogni altro giocatore che lo Marca. Se lo supera, il bersaglio perde la zona di placcaggio e non può ricevere, intercettare, passare, fornire assist né muoversi volontariamente fino alla sua prossima attivazione.
+Insignificant = Insignificante
+Insignificant_description = Nel creare una squadra, al massimo metà dei giocatori può avere questo tratto. Questo giocatore non ottiene PSP quando viene lanciato o calciato da un compagno.
+KickTeamMate = Calciare Compagno
+KickTeamMate_description = Una volta per turno, oltre a un Passaggio o a un Lanciare Compagno, un giocatore con questo tratto può calciare un compagno In Piedi adiacente con il tratto Roba Giusta.
+Loner = Solitario ({ $value }+)
+Loner_description = Se questo giocatore vuole usare un ritiro di squadra, tira un D6. Se il risultato è pari o superiore al valore tra parentesi, il ritiro si usa normalmente. Altrimenti resta il risultato originale e il ritiro va perso.
+MyBall = Palla Mia
+MyBall_description = Un giocatore con questo tratto non cede volontariamente la palla: non può passarla, consegnarla né usare abilità che gli facciano rinunciare al possesso.
+NoBall = Niente Palla
+NoBall_description = Questo giocatore non può raccogliere, ricevere né intercettare la palla, né passarla o consegnarla.
+PickMeUp = Tirami Su
+PickMeUp_description = Alla fine del turno avversario, tira un D6 per ogni compagno Prono e non Stordito entro tre caselle da un giocatore In Piedi con questo tratto. Con 5+, quel compagno può rialzarsi subito.
+PlagueRidden = Appestato
+PlagueRidden_description = Una volta per partita, un avversario con Forza 4 o meno senza Decomposizione, Rigenerazione o Piccoletto ucciso da un Blocco o un Fallo di questo giocatore può unirsi alla tua squadra come nuovo Putrido lineman.
+PogoStick = Bastone a Molla
+PogoStick_description = Invece di saltare sopra un giocatore Prono o Stordito, questo giocatore può saltare sopra qualsiasi casella adiacente. Inoltre ignora i modificatori negativi al test di Agilità quando Salta.
+ProjectileVomit = Vomito Proiettile
+ProjectileVomit_description = Invece di un Blocco, questo giocatore può vomitare. Tira un D6: con 2+ il bersaglio è colpito e si effettua contro di lui un tiro Armatura. Con 1 si vomita addosso e si effettua contro di lui un tiro Armatura.
+ReallyStupid = Davvero Stupido
+ReallyStupid_description = All'attivazione di questo giocatore, tira un D6, aggiungendo 2 se è accanto a uno o più compagni In Piedi senza questo tratto. Con 1-3 dimentica cosa stava facendo, la sua attivazione termina subito e perde la zona di placcaggio.
+Regeneration = Rigenerazione
+Regeneration_description = Dopo un tiro sulla tabella Infortuni contro questo giocatore, tira un D6. Con 4+ il tiro viene annullato senza effetto e il giocatore va nella panchina delle riserve invece che in quella degli infortunati.
+RightStuff = Roba Giusta
+RightStuff_description = Se questo giocatore ha anche Forza 3 o meno, può essere lanciato da un compagno con Lanciare Compagno.
+SecretWeapon = Arma Segreta
+SecretWeapon_description = Quando termina un drive a cui ha partecipato questo giocatore, viene Espulso per aver commesso un Fallo, anche se alla fine del drive non era più in campo.
+Stab = Pugnalare
+Stab_description = Invece di un Blocco, questo giocatore può pugnalare un avversario che Marca. Effettua un tiro Armatura non modificato contro la vittima: se l'armatura è sfondata, segue un tiro Ferita; altrimenti non succede nulla.
+Stunty = Piccoletto
+Stunty_description = Nel test di Agilità per Schivare, questo giocatore ignora i modificatori di -1 per essere Marcato nella casella di arrivo. Tuttavia, se un avversario interferisce con il suo passaggio, ottiene +1. Inoltre, i tiri Ferita contro di lui usano la tabella Ferite dei Piccoletti.
+Swoop = Planata
+Swoop_description = Quando questo giocatore viene lanciato da un compagno, non si disperde prima di atterrare come di consueto. La sagoma della rimessa determina invece la sua direzione e si muove fino a D6 caselle in quella direzione.
+TakeRoots = Mettere Radici
+TakeRoots_description = All'attivazione di questo giocatore, dopo aver dichiarato l'azione, tira un D6. Con 1 mette radici: non può lasciare la sua casella fino alla fine del drive, a meno che non venga Atterrato o messo Prono.
+Timmmber = Legname!
+Timmmber_description = Se questo giocatore ha un Movimento di 2 o meno, ottiene +1 quando si rialza per ogni compagno In Piedi e non Marcato adiacente. Un 1 naturale è sempre un fallimento.
+Trickster = Imbroglione
+Trickster_description = Quando questo giocatore sta per essere bersaglio di un Blocco o di un'azione Speciale che lo sostituisce, prima di determinare il numero di dadi può essere rimosso e posizionato in un'altra casella libera adiacente al giocatore che blocca.
+ThrowTeamMate = Lanciare Compagno
+ThrowTeamMate_description = Se questo giocatore ha Forza 5 o più, può lanciare un compagno In Piedi adiacente con il tratto Roba Giusta.
+Titchy = Minuscolo
+Titchy_description = Questo giocatore ottiene +1 ai test di Agilità per Schivare. Tuttavia, se un avversario schiva nella sua zona di placcaggio, questo giocatore non conta come Marcarlo.
+UnchannelledFury = Furia Incontrollata
+UnchannelledFury_description = All'attivazione di questo giocatore, dopo aver dichiarato l'azione, tira un D6, aggiungendo 2 se è un Blocco o un Blitz. Con 1-3 si infuria senza controllo e la sua attivazione termina subito.
+Unsteady = Instabile
+Unsteady_description = Questo giocatore non può usare ritiri di squadra quando Schiva o Scatta.
+
+# Special Skills
+BlindRage = Rabbia Cieca
+BlindRage_description = Akhorne può ritirare il D6 quando usa l'abilità Intrepido.
+SavageBlow = Colpo Selvaggio
+SavageBlow_description = Una volta per partita, quando Barik effettua un Blocco, può ritirare un qualsiasi numero di dadi blocco.
+BlastIt = Fallo Saltare!
+BlastIt_description = Una volta per partita, Barik può ritirare un test di Abilità di Passaggio fallito quando lancia una bomba.
+PutridRegurgitation = Rigurgito Putrido
+PutridRegurgitation_description = Una volta per partita, Bilerot può usare Vomito Proiettile in aggiunta a un'altra azione, e può ritirare il D6 per vedere se colpisce.
+LookIntoMyEyes = Guardami negli Occhi
+LookIntoMyEyes_description = Una volta per partita, all'inizio di una qualsiasi sua attivazione, Boa può scegliere un avversario adiacente e tirare un D6. Con 1 o 2 non succede nulla; con 3+ quel giocatore lascia cadere la palla se la possiede e Boa se ne impossessa.
+GhostlyFlames = Fiamme Spettrali
+GhostlyFlames_description = Quando Bryce effettua un Blocco e il bersaglio viene Atterrato, puoi applicare un modificatore aggiuntivo al tiro Armatura o Ferita che rappresenta il fuoco spettrale dei suoi colpi.
+TastyMorsel = Bocconcino Prelibato
+TastyMorsel_description = Quando Bryce viene spinto indietro o Atterrato, gli avversari non vedono l'ora di mangiarlo: tira un D6 e, con 1, viene rimosso dal gioco e messo nella panchina degli infortunati.
+StarOfTheShow = Stella dello Spettacolo
+StarOfTheShow_description = Una volta per partita, questo giocatore può usare un ritiro di squadra anche se Solitario normalmente lo impedirebbe, senza tirare per Solitario.
+ASneakyPair = Una Coppia Subdola
+MesmerizingGaze = Sguardo Ammaliante
+MesmerizingGaze_description = Una volta per partita, Eldril può ritirare un test di Agilità fallito quando usa Sguardo Ipnotico.
+BalefulHex = Maleficio Funesto
+BalefulHex_description = Una volta per partita, all'inizio di una qualsiasi sua attivazione, Estelle può scegliere un avversario entro cinque caselle e tirare un D6. Con 2+, quel giocatore si distrae e perde la zona di placcaggio fino alla fine del turno di squadra successivo.
+BrutalBlock = Blocco Brutale
+BrutalBlock_description = Una volta per partita, quando un avversario viene Atterrato da un Blocco di questo giocatore, puoi applicare un modificatore aggiuntivo di +1 al tiro Infortunio.
+WhirlingDervish = Derviscio Roteante
+WhirlingDervish_description = Quando questo giocatore effettua un Blitz, può effettuare due Blocchi contro bersagli diversi, muovendosi tra di essi normalmente.
+FrenziedRush = Carica Frenetica
+FrenziedRush_description = Una volta per partita, quando questo giocatore effettua un Blitz, può ottenere l'abilità Frenesia e un punto di Movimento extra per la durata dell'azione.
+ShotToNothing = Niente da Perdere
+ShotToNothing_description = Una volta per partita, questo giocatore può effettuare un Passaggio alla distanza di Bomba Lunga senza la consueta penalità, e il passaggio non può essere intercettato.
+PrimalSavagery = Ferocia Primordiale
+PrimalSavagery_description = Una volta per partita, quando tira per Ferocia Animale, questo giocatore può scagliarsi contro un avversario adiacente invece che contro un compagno.
+TwoForOne = Due per Uno
+GoredByTheBull = Incornato dal Toro
+GoredByTheBull_description = Una volta per partita, quando questo giocatore effettua un Blitz, puoi aggiungere un modificatore aggiuntivo di +1 alla Forza concessa dall'abilità Corna.
+Incorporeal = Incorporeo
+Incorporeal_description = Questo giocatore può applicare un modificatore di +1 a qualsiasi test di Agilità per Schivare e non è mai Marcato dall'avversario da cui si allontana.
+Slayer = Sterminatore
+Slayer_description = Quando questo giocatore blocca un avversario con Forza 5 o più, può applicare un modificatore di +1 alla propria Forza.
+WisdomOfTheWhiteDwarf = Saggezza del Nano Bianco
+WisdomOfTheWhiteDwarf_description = Una volta per turno di squadra, Grim può concedere a un compagno adiacente una delle seguenti abilità fino alla fine della sua attivazione: Bloccare, Rompere il Placcaggio, Intrepido o Piantarsi.
+QuickBite = Morso Rapido
+QuickBite_description = Dopo un Blocco in cui il bersaglio viene Atterrato, questo giocatore può effettuare subito un tiro Armatura aggiuntivo contro un altro avversario adiacente.
+OldPro = Vecchio Professionista
+OldPro_description = Una volta per partita, Helmut può usare la sua abilità Professionista per ritirare un singolo dado di un tiro Armatura contro di lui.
+UnstoppableMomentum = Slancio Inarrestabile
+UnstoppableMomentum_description = Quando questo giocatore effettua un Blitz, puoi ritirare un singolo dado blocco.
+DwarvenScourge = Flagello dei Nani
+DwarvenScourge_description = Una volta per partita, quando questo giocatore Atterra un avversario con la parola chiave Nano, puoi applicare un modificatore aggiuntivo di +1 al tiro Ferita.
+RaidingParty = Razzia
+RaidingParty_description = Una volta per partita, questo giocatore può muovere un compagno adiacente non Marcato di una casella in qualsiasi direzione, purché non finisca Marcato.
+TheFlashingBlade = La Lama Lampeggiante
+TheFlashingBlade_description = Quando questo giocatore usa Pugnalare, puoi applicare un modificatore aggiuntivo di +1 al tiro Armatura.
+SwiftAsTheBreeze = Rapido come la Brezza
+SwiftAsTheBreeze_description = Una volta per partita, questo giocatore può ritirare un singolo test di Agilità fallito per Schivare, Saltare o fare un Balzo, indipendentemente da altre abilità usate.
+DwarfenGrit = Tenacia Nanica
+DwarfenGrit_description = Una volta per partita, quando un giocatore di questa squadra viene Atterrato, puoi ritirare il tiro Armatura contro di lui.
+Indomitable = Indomabile
+Indomitable_description = Una volta per partita, quando questo giocatore effettua un Blocco, può trattare un risultato Spinto Indietro come Atterrato contro il bersaglio.
+BlackInk = Inchiostro Nero
+BlackInk_description = Una volta per partita, questo giocatore può usare Sguardo Ipnotico contro un avversario entro tre caselle invece che contro uno che Marca.
+LordOfChaos = Signore del Caos
+LordOfChaos_description = Una squadra con questo giocatore ottiene un ritiro di squadra aggiuntivo per il primo tempo. Se non viene usato nel primo tempo, può essere usato nel secondo.
+ViciousVines = Viticci Feroci
+ViciousVines_description = Una volta per partita, senza muoversi, questo giocatore può effettuare un Blocco contro qualsiasi avversario In Piedi entro due caselle.
+MaximumCarnage = Carneficina Totale
+MaximumCarnage_description = Una volta per partita, quando questo giocatore effettua un Blitz, può effettuare a turno un Blocco contro ogni avversario adiacente.
+CrushingBlow = Colpo Schiacciante
+CrushingBlow_description = Una volta per partita, quando un avversario viene Atterrato da un Blocco di questo giocatore, puoi applicare un modificatore aggiuntivo di +1 al tiro Armatura.
+KickThemWhileTheyAreDown = Calciali Quando Sono a Terra!
+KickThemWhileTheyAreDown_description = Una volta per partita, questo giocatore può commettere un Fallo senza che conti come il Fallo della squadra per quel turno, e ottiene un modificatore aggiuntivo di +1 al tiro Armatura.
+HalflingLuck = Fortuna Halfling
+HalflingLuck_description = Una volta per partita, questo giocatore può ritirare un qualsiasi proprio tiro di dado che non sia un tiro Armatura, Ferita o Infortunio, anche se è Solitario.
+ToxinConnoisseur = Intenditore di Tossine
+ToxinConnoisseur_description = Una volta per partita, questo giocatore può usare Pugnalare con un modificatore aggiuntivo di +1 al tiro Ferita.
+ThinkingManTroll = Troll Pensatore
+ThinkingManTroll_description = Una volta per turno di squadra, questo giocatore può ritirare il D6 quando tira per Davvero Stupido o Testa Vuota.
+CatchOfTheDay = Pesca del Giorno
+CatchOfTheDay_description = Se questo giocatore termina la sua attivazione nella metà campo avversaria con una casella adiacente libera, la palla può essere posizionata lì quando rimbalza nelle vicinanze, come se l'avesse ricevuta.
+BoundingLeap = Balzo Possente
+BoundingLeap_description = Una volta per partita, questo giocatore può applicare un modificatore di +1 al test di Agilità quando fa un Balzo, e può balzare sopra due caselle invece di una.
+SlashingNails = Unghie Taglienti
+SlashingNails_description = Quando questo giocatore effettua un Blocco e il bersaglio viene Atterrato, puoi applicare l'abilità Artigli al tiro Armatura.
+Ram = Ariete
+Ram_description = Quando questo giocatore effettua un Blitz, se il bersaglio viene Atterrato, puoi applicare un modificatore di +1 al tiro Armatura.
+Yoink = Mia!
+Yoink_description = Una volta per partita, invece di un Blocco, questo giocatore può tentare di prendere la palla a un avversario adiacente che la possiede.
+FuryOfTheBloodGod = Furia del Dio del Sangue
+FuryOfTheBloodGod_description = Una volta per partita, quando questo giocatore effettua un Blocco, può trattare qualsiasi risultato dei dadi blocco come Pow!.
+MasterAssassin = Maestro Assassino
+MasterAssassin_description = Quando questo giocatore usa Pugnalare, puoi ritirare il tiro Armatura.
+PumpUpTheCrowd = Scalda la Folla
+PumpUpTheCrowd_description = Una volta per partita, quando questo giocatore segna un touchdown, la tua squadra ottiene subito un ritiro di squadra.
+StrongPassingGame = Gioco di Passaggi Solido
+StrongPassingGame_description = Una volta per partita, questo giocatore può ritirare un test di Abilità di Passaggio fallito quando effettua un Lanciare Compagno.
+FuriousOutburst = Sfogo Furioso
+FuriousOutburst_description = Una volta per partita, invece di un Blocco, questo giocatore può teletrasportarsi in qualsiasi casella libera entro tre caselle ed effettuare un Blocco contro un avversario adiacente.
+SneakiestOfTheLot = Il Più Subdolo di Tutti
+SneakiestOfTheLot_description = Una squadra con questo giocatore può commettere due Falli per turno di squadra, purché questo giocatore ne commetta uno.
+WorkingInTandem = Lavoro in Tandem
+BeerBarrelBash = Botta di Botte!
+BeerBarrelBash_description = Una volta per partita, questo giocatore può lanciare una botte contro un avversario entro la distanza di un Passaggio Corto. Se colpisce, il bersaglio è Atterrato.
+KrumpAndSmash = Pesta e Spacca
+KrumpAndSmash_description = Una volta per partita, quando un Grosso di questa squadra effettua un Blocco, puoi ritirare un singolo dado blocco.
+SavageMauling = Sbranamento Selvaggio
+SavageMauling_description = Una volta per partita, dopo che questo giocatore ha effettuato un tiro Ferita contro un avversario, puoi ritirarlo.
+WoodlandFury = Furia dei Boschi
+WoodlandFury_description = Una volta per partita, quando un giocatore di questa squadra effettua un Blitz, può ottenere l'abilità Frenesia fino alla fine della sua attivazione.
+WatchOut = Attento!
+WatchOut_description = Una volta per partita, quando un avversario blocca un compagno adiacente a questo giocatore, questo giocatore può fargli ritirare i dadi blocco.
+ExcuseMeAreYouAZoat = Scusi, Lei è uno Zoat?
+ExcuseMeAreYouAZoat_description = Una volta per partita, questo giocatore può usare Sguardo Ipnotico e ritirare il test di Agilità se fallisce.
+BlastingSolvesEverything = Sparare Risolve Tutto
+BlastingSolvesEverything_description = Questa squadra può ingaggiare Bombardieri e altre Armi Segrete senza che contino nel limite abituale, e può acquistare Corruzioni a un costo ridotto.
+Kaboom = Kabum!
+Kaboom_description = Una volta per partita, quando questo giocatore lancia una bomba, l'esplosione colpisce ogni casella adiacente a quella di atterraggio, e tutti i giocatori lì presenti vengono Atterrati con 4+.
+AllYouCanEat = Mangia Quanto Vuoi
+AllYouCanEat_description = Una volta per partita, questo giocatore può ritirare il D6 quando tira per Sempre Affamato.
+Reliable = Affidabile
+Reliable_description = Se questo giocatore commette un fumble durante un Lanciare Compagno, il giocatore lanciato si disperde dalla casella del lanciatore e il lancio non causa un turnover a meno che il lanciato non venga ferito.
+ConsummateProfessional = Professionista Consumato
+ConsummateProfessional_description = Una volta per partita, questo giocatore può ritirare un singolo dado di un test di Abilità di Passaggio, test di Agilità, tiro Armatura o tiro Ferita.
+Treacherous = Traditore
+Treacherous_description = Una volta per partita, se questo giocatore è adiacente a un compagno in possesso della palla, può prendergliela senza tirare.
+IllBeBack = Tornerò!
+IllBeBack_description = Una volta per partita, se questo giocatore viene Espulso per aver commesso un Fallo, viene invece messo nella panchina delle riserve.
+TheBallista = La Balista
+TheBallista_description = Una volta per partita, questo giocatore può ritirare un test di Abilità di Passaggio fallito quando effettua un Passaggio.
+
+# V5
+
+BurstOfSpeed = Scatto Bruciante
+BurstOfSpeed_description = Una volta per partita, questo giocatore può tentare di Scattare tre volte aggiuntive durante la sua attivazione.
+DirtyPlayerNumber = Giocatore Scorretto (+{ $value })
+DirtyPlayerNumber_description = Quando questo giocatore commette un Fallo, il tiro Armatura o Ferita contro la vittima può essere modificato del valore indicato tra parentesi.
+MightyBlowNumber = Colpo Possente (+{ $value })
+MightyBlowNumber_description = Quando un avversario viene Atterrato da un Blocco di questo giocatore, puoi modificare il tiro Armatura o Ferita del valore indicato tra parentesi. Questa abilità non può essere usata con i tratti Pugnalare o Motosega.
+NoHands = Senza Mani
+NoHands_description = Questo giocatore non può entrare in possesso della palla. Non può tentare di raccoglierla, riceverla né interferire con un passaggio.
+RunningPass = Passaggio in Corsa
+RunningPass_description = Se questo giocatore effettua un Passaggio Rapido, la sua attivazione non deve per forza terminare dopo il passaggio. Può continuare a muovere se ha ancora movimento.
+ThenIStartedBlastin = E Poi Ho Cominciato a Sparare!
+ThenIStartedBlastin_description = Una volta per partita, questo giocatore può effettuare un attacco a distanza contro un avversario entro tre caselle: tira un D6 e, con 4+, il bersaglio è Atterrato.
+
+###############################################################
+# Brackets
+###############################################################
+
+SingleElimination = Eliminazione diretta
+DoubleElimination = Doppia eliminazione
+Standings = Classifica
+NafElo = Elo NAF
+Winners = Tabellone vincenti
+Losers = Tabellone perdenti
+GrandFinal = Finalissima
+GrandFinalReset = Spareggio della finalissima
+BracketRound = { $side }, turno { $round }
+
+###############################################################
+# Ratings
+###############################################################
+
+Glicko2 = Glicko-2
+TrueSkill = TrueSkill
+
+###############################################################
+# Injury causes
+###############################################################
+
+Foul = Fallo
+Crowd = Pubblico
+FailedDodge = Schivata fallita
+FailedRush = Scatto fallito
+Other = Altro
+
+###############################################################
+# Reports
+###############################################################
+
+MatchReport = Referto della partita
+Team = Squadra
+Coach = Allenatore
+CurrentTeamValue = Valore attuale della squadra
+Weather = Meteo
+Fans = Tifosi
+Date = Data
+PreGame = Sequenza pre-partita
+Inducements = Incentivi
+FirstHalf = Primo tempo
+SecondHalf = Secondo tempo
+ExtraTime = Tempi supplementari
+FinalScore = Risultato finale
+Penalties = Rigori
+StarPlayerPoints = Punti Star Player
+Player = Giocatore
+Injuries = Infortuni
+Cause = Causa
+InflictedBy = Inflitto da
+Winnings = Incassi
+DedicatedFans = Tifosi fedeli
+ExpensiveMistakes = Errori costosi
+MostValuablePlayers = Giocatori più preziosi
+SentOff = Espulso
+PushedIntoCrowd = Spinto tra il pubblico
+NothingToReport = Niente da segnalare
+Roster = Rosa
+Players = Giocatori
+Number = #
+Name = Nome
+Position = Posizione
+ShortMovementAllowance = MO
+ShortStrength = FO
+ShortAgility = AG
+ShortPassingAbility = PA
+ShortArmourValue = AR
+Skills = Abilità
+HiringValue = Costo di ingaggio
+CurrentValue = Valore attuale
+Staff = Staff
+Quantity = Quantità
+Treasury = Tesoreria
+TeamValue = Valore della squadra
+SpecialRules = Regole speciali
+ModifiedCharacteristic = Le caratteristiche evidenziate differiscono dal profilo della posizione.
+
+###############################################################
+# Pitch formats
+###############################################################
+
+Standard = Standard
+Sevens = Sevens
+
+###############################################################
+# Formatting
+###############################################################
+
+GoldPieces = { $value }k mo
+PlayersCount =
+    { $count ->
+        [one] { $count } giocatore
+       *[other] { $count } giocatori
+    }
+StarPlayerPointsCount =
+    { $count ->
+        [one] { $count } punto Star Player
+       *[other] { $count } punti Star Player
+    }
@@ -0,0 +1,95 @@
+CREATE TABLE coaches (
+    id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    name TEXT NOT NULL,
+    elo DOUBLE PRECISION,
+    ratings TEXT NOT NULL
+);
+
+CREATE TABLE teams (
+    id INTEGER PRIMARY KEY,
+    version TEXT NOT NULL,
+    roster TEXT NOT NULL,
+    name TEXT NOT NULL,
+    coach_id INTEGER NOT NULL REFERENCES coaches (id),
+    treasury INTEGER NOT NULL,
+    external_logo_url TEXT,
+    dedicated_fans INTEGER NOT NULL,
+    under_creation INTEGER NOT NULL,
+    in_offseason INTEGER NOT NULL
+);
+
+CREATE INDEX teams_coach_id ON teams (coach_id);
+
+CREATE TABLE team_staff (
+    team_id INTEGER NOT NULL REFERENCES teams (id) ON DELETE CASCADE,
+    staff TEXT NOT NULL,
+    quantity INTEGER NOT NULL,
+    PRIMARY KEY (team_id, staff)
+);
+
+CREATE TABLE players (
+    team_id INTEGER NOT NULL REFERENCES teams (id) ON DELETE CASCADE,
+    id INTEGER NOT NULL,
+    number INTEGER NOT NULL,
+    version TEXT NOT NULL,
+    position TEXT NOT NULL,
+    roster TEXT NOT NULL,
+    name TEXT NOT NULL,
+    star_player_points INTEGER NOT NULL,
+    player_type TEXT NOT NULL,
+    miss_next_game INTEGER NOT NULL,
+    is_captain INTEGER NOT NULL,
+    PRIMARY KEY (team_id, id)
+);
+
+CREATE TABLE player_advancements (
+    team_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    sequence INTEGER NOT NULL,
+    advancement TEXT NOT NULL,
+    PRIMARY KEY (team_id, player_id, sequence),
+    FOREIGN KEY (team_id, player_id) REFERENCES players (team_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE player_injuries (
+    team_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    sequence INTEGER NOT NULL,
+    injury TEXT NOT NULL,
+    PRIMARY KEY (team_id, player_id, sequence),
+    FOREIGN KEY (team_id, player_id) REFERENCES players (team_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE player_hatred (
+    team_id INTEGER NOT NULL,
+    player_id INTEGER NOT NULL,
+    sequence INTEGER NOT NULL,
+    keyword TEXT NOT NULL,
+    PRIMARY KEY (team_id, player_id, sequence),
+    FOREIGN KEY (team_id, player_id) REFERENCES players (team_id, id) ON DELETE CASCADE
+);
+
+CREATE TABLE games (
+    id INTEGER PRIMARY KEY,
+    title TEXT,
+    version TEXT NOT NULL,
+    created_by TEXT,
+    game_at TEXT NOT NULL,
+    started INTEGER NOT NULL,
+    closed INTEGER NOT NULL,
+    first_team_id INTEGER NOT NULL,
+    second_team_id INTEGER NOT NULL,
+    first_team TEXT NOT NULL,
+    second_team TEXT NOT NULL,
+    needs_winner INTEGER NOT NULL
+);
+
+CREATE INDEX games_first_team_id ON games (first_team_id);
+CREATE INDEX games_second_team_id ON games (second_team_id);
+
+CREATE TABLE game_events (
+    game_id INTEGER NOT NULL REFERENCES games (id) ON DELETE CASCADE,
+    sequence INTEGER NOT NULL,
+    event TEXT NOT NULL,
+    PRIMARY KEY (game_id, sequence)
+);
@@ -5,7 +5,7 @@ pub(crate) fn list_for_success() -> Vec<Success> {
     vec![
         Success::PassingCompletion,
         Success::ThrowingCompletion,
-        Success::LandingOnfeet,
+        Success::LandingOnFeet,
         Success::Interception,
         Success::Casualty,
         Success::Touchdown,
@@ -21,7 +21,7 @@ pub(crate) fn star_player_points_for_success(
     match success {
         Success::PassingCompletion => 1,
         Success::ThrowingCompletion => 1,
-        Success::LandingOnfeet => 1,
+        Success::LandingOnFeet => 1,
         Success::Deflection => 0,
         Success::Interception => 2,
         Success::Casualty => {
@@ -1,17 +1,18 @@
 use crate::errors::Error;
 use crate::players::Player;
 use crate::skills::{Skill, SkillCategory};
-use crate::translation::{LOCALES, TranslatedName, TypeName, language_from};
+use crate::translation::{TranslatedName, TypeName, lookup, lookup_with_args};
 use crate::versions::Version;
-use fluent_templates::Loader;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
-use std::collections::HashMap;
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum AdvancementChoice {
     RandomPrimarySkill(SkillCategory),
     RandomSecondarySkill(SkillCategory),
@@ -25,23 +26,17 @@ impl TypeName for AdvancementChoice {}
 impl TranslatedName for AdvancementChoice {
     fn name(&self, lang_id: &str) -> String {
         match self {
-            AdvancementChoice::RandomPrimarySkill(skill_category) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            AdvancementChoice::RandomPrimarySkill(skill_category) => lookup_with_args(
+                lang_id,
                 "RandomPrimarySkill",
-                &HashMap::from([(
-                    Cow::from("skill_category"),
-                    skill_category.name(lang_id).into(),
-                )]),
+                vec![("skill_category", skill_category.name(lang_id).into())],
             ),
-            AdvancementChoice::RandomSecondarySkill(skill_category) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            AdvancementChoice::RandomSecondarySkill(skill_category) => lookup_with_args(
+                lang_id,
                 "RandomSecondarySkill",
-                &HashMap::from([(
-                    Cow::from("skill_category"),
-                    skill_category.name(lang_id).into(),
-                )]),
+                vec![("skill_category", skill_category.name(lang_id).into())],
             ),
-            _ => LOCALES.lookup(&language_from(lang_id), &*self.type_name()),
+            _ => lookup(lang_id, &self.type_name()),
         }
     }
 }
@@ -93,7 +88,8 @@ impl AdvancementChoice {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Advancement {
     ChosenSkill(Skill),
     RandomSkill(Skill),
@@ -109,17 +105,17 @@ impl TypeName for Advancement {}
 impl TranslatedName for Advancement {
     fn name(&self, lang_id: &str) -> String {
         match self {
-            Advancement::ChosenSkill(skill) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Advancement::ChosenSkill(skill) => lookup_with_args(
+                lang_id,
                 "ChosenSkill",
-                &HashMap::from([(Cow::from("skill"), skill.name(lang_id).into())]),
+                vec![("skill", skill.name(lang_id).into())],
             ),
-            Advancement::RandomSkill(skill) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Advancement::RandomSkill(skill) => lookup_with_args(
+                lang_id,
                 "RandomSkill",
-                &HashMap::from([(Cow::from("skill"), skill.name(lang_id).into())]),
+                vec![("skill", skill.name(lang_id).into())],
             ),
-            _ => LOCALES.lookup(&language_from(lang_id), &*self.type_name()),
+            _ => lookup(lang_id, &self.type_name()),
         }
     }
 }
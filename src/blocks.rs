@@ -0,0 +1,569 @@
+use crate::dices::BlockDie;
+use crate::errors::Error;
+use crate::injuries::Injury;
+use crate::players::Player;
+use crate::skills::{Skill, SkillCondition, SkillTrigger};
+use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum BlockOutcome {
+    AttackerDown,
+    BothDown,
+    BothPlacedProne,
+    NoEffect,
+    DefenderPushed,
+    DefenderDown,
+}
+
+impl BlockOutcome {
+    pub fn is_turnover(&self) -> bool {
+        matches!(self, BlockOutcome::AttackerDown | BlockOutcome::BothDown)
+    }
+
+    pub fn is_defender_knocked_down(&self) -> bool {
+        matches!(self, BlockOutcome::BothDown | BlockOutcome::DefenderDown)
+    }
+
+    pub(crate) fn attacker_preference(&self) -> u8 {
+        match self {
+            BlockOutcome::AttackerDown => 0,
+            BlockOutcome::BothDown => 1,
+            BlockOutcome::BothPlacedProne => 2,
+            BlockOutcome::NoEffect => 3,
+            BlockOutcome::DefenderPushed => 4,
+            BlockOutcome::DefenderDown => 5,
+        }
+    }
+
+    pub(crate) fn defender_preference(&self) -> u8 {
+        match self {
+            BlockOutcome::DefenderDown => 0,
+            BlockOutcome::BothDown => 1,
+            BlockOutcome::BothPlacedProne => 2,
+            BlockOutcome::DefenderPushed => 3,
+            BlockOutcome::NoEffect => 4,
+            BlockOutcome::AttackerDown => 5,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum BlockDiceChooser {
+    Attacker,
+    Defender,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct KnockDownOdds {
+    pub armour_broken: f64,
+    pub stunned: f64,
+    pub knocked_out: f64,
+    pub casualty: f64,
+    pub injuries: Vec<(Injury, f64)>,
+}
+
+impl KnockDownOdds {
+    fn add_injury(&mut self, injury: Injury, probability: f64) {
+        match injury {
+            Injury::Stunned => self.stunned += probability,
+            Injury::KO => self.knocked_out += probability,
+            injury => {
+                self.casualty += probability;
+                match self
+                    .injuries
+                    .iter_mut()
+                    .find(|(existing_injury, _)| existing_injury.eq(&injury))
+                {
+                    Some((_, existing_probability)) => *existing_probability += probability,
+                    None => self.injuries.push((injury, probability)),
+                }
+            }
+        }
+    }
+
+    fn scaled(&self, factor: f64) -> Self {
+        Self {
+            armour_broken: self.armour_broken * factor,
+            stunned: self.stunned * factor,
+            knocked_out: self.knocked_out * factor,
+            casualty: self.casualty * factor,
+            injuries: self
+                .injuries
+                .iter()
+                .map(|(injury, probability)| (injury.clone(), probability * factor))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct BlockOdds {
+    pub outcomes: Vec<(BlockOutcome, f64)>,
+    pub turnover: f64,
+    pub defender_knocked_down: f64,
+    pub knock_down: KnockDownOdds,
+    pub overall: KnockDownOdds,
+}
+
+impl BlockOdds {
+    pub fn outcome(&self, outcome: &BlockOutcome) -> f64 {
+        self.outcomes
+            .iter()
+            .filter(|(existing_outcome, _)| existing_outcome.eq(outcome))
+            .map(|(_, probability)| probability)
+            .sum()
+    }
+}
+
+fn two_dice_sums() -> Vec<(u8, f64)> {
+    (2..=12)
+        .map(|sum| {
+            let combinations = 6 - (7_i8 - sum as i8).unsigned_abs();
+            (sum, combinations as f64 / 36.0)
+        })
+        .collect()
+}
+
+pub(crate) fn mighty_blow_value(player: &Player) -> u8 {
+    player
+        .skill_effects()
+        .iter()
+        .map(|effect| effect.modifier(&SkillTrigger::Armour, &[SkillCondition::Block]))
+        .max()
+        .unwrap_or(0)
+        .max(0) as u8
+}
+
+pub(crate) fn armour_broken_on(player: &Player) -> Option<u8> {
+    player
+        .skill_effects()
+        .iter()
+        .filter_map(|effect| effect.armour_broken_on)
+        .min()
+}
+
+pub(crate) fn strength_modifier(player: &Player, blitz: bool) -> u8 {
+    let conditions: &[SkillCondition] = if blitz { &[SkillCondition::Blitz] } else { &[] };
+
+    player
+        .skill_effects()
+        .iter()
+        .map(|effect| effect.modifier(&SkillTrigger::Block, conditions))
+        .sum::<i8>()
+        .max(0) as u8
+}
+
+pub fn knock_down_odds(
+    version: &Version,
+    attacker: Option<&Player>,
+    defender: &Player,
+) -> Result<KnockDownOdds, Error> {
+    let armour_value = defender.armour_value().ok_or(Error::PositionNotDefined)?;
+    let defender_skills = defender.skills();
+    let stunty = defender_skills.contains(&Skill::Stunty);
+    let thick_skull = defender_skills.contains(&Skill::ThickSkull);
+    let mighty_blow = attacker.map(mighty_blow_value).unwrap_or(0);
+    let claws = attacker.and_then(armour_broken_on);
+
+    let mut casualties: Vec<(Injury, f64)> = Vec::new();
+    for d16 in 1..=16 {
+        for d6 in 1..=6 {
+            let injury = Injury::from_casualty_roll(version, d16, d6)?;
+            match casualties
+                .iter_mut()
+                .find(|(existing_injury, _)| existing_injury.eq(&injury))
+            {
+                Some((_, probability)) => *probability += 1.0 / 96.0,
+                None => casualties.push((injury, 1.0 / 96.0)),
+            }
+        }
+    }
+
+    let mut odds = KnockDownOdds {
+        armour_broken: 0.0,
+        stunned: 0.0,
+        knocked_out: 0.0,
+        casualty: 0.0,
+        injuries: Vec::new(),
+    };
+
+    for (armour_roll, armour_probability) in two_dice_sums() {
+        let injury_modifier =
+            if armour_roll >= armour_value || claws.is_some_and(|claws| armour_roll >= claws) {
+                mighty_blow
+            } else if armour_roll + mighty_blow >= armour_value {
+                0
+            } else {
+                continue;
+            };
+        odds.armour_broken += armour_probability;
+
+        for (injury_roll, injury_probability) in two_dice_sums() {
+            let probability = armour_probability * injury_probability;
+            match Injury::from_injury_roll(
+                version,
+                injury_roll + injury_modifier,
+                stunty,
+                thick_skull,
+            )? {
+                Some(injury) => odds.add_injury(injury, probability),
+                None => {
+                    for (injury, casualty_probability) in casualties.iter() {
+                        odds.add_injury(injury.clone(), probability * casualty_probability);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(odds)
+}
+
+#[derive(Debug, Clone)]
+pub struct Block<'a> {
+    pub attacker: &'a Player,
+    pub defender: &'a Player,
+    pub offensive_assists: u8,
+    pub defensive_assists: u8,
+    pub blitz: bool,
+}
+
+impl<'a> Block<'a> {
+    pub fn new(attacker: &'a Player, defender: &'a Player) -> Self {
+        Self {
+            attacker,
+            defender,
+            offensive_assists: 0,
+            defensive_assists: 0,
+            blitz: false,
+        }
+    }
+
+    pub fn with_assists(self, offensive_assists: u8, defensive_assists: u8) -> Self {
+        Self {
+            offensive_assists,
+            defensive_assists,
+            ..self
+        }
+    }
+
+    pub fn as_blitz(self) -> Self {
+        Self {
+            blitz: true,
+            ..self
+        }
+    }
+
+    fn attacker_has(&self, skill: &Skill) -> bool {
+        self.attacker.skills().contains(skill)
+    }
+
+    fn defender_has(&self, skill: &Skill) -> bool {
+        self.defender.skills().contains(skill)
+    }
+
+    fn juggernaut(&self) -> bool {
+        self.blitz && self.attacker_has(&Skill::Juggernaut)
+    }
+
+    pub(crate) fn defender_stands_firm(&self) -> bool {
+        self.defender_has(&Skill::StandFirm) && !self.juggernaut()
+    }
+
+    pub fn dice(attacker_strength: u8, defender_strength: u8) -> (u8, BlockDiceChooser) {
+        if attacker_strength > defender_strength * 2 {
+            (3, BlockDiceChooser::Attacker)
+        } else if attacker_strength > defender_strength {
+            (2, BlockDiceChooser::Attacker)
+        } else if defender_strength > attacker_strength * 2 {
+            (3, BlockDiceChooser::Defender)
+        } else if defender_strength > attacker_strength {
+            (2, BlockDiceChooser::Defender)
+        } else {
+            (1, BlockDiceChooser::Attacker)
+        }
+    }
+
+    pub fn outcome(&self, die: &BlockDie) -> BlockOutcome {
+        let pushed = if self.defender_stands_firm() {
+            BlockOutcome::NoEffect
+        } else {
+            BlockOutcome::DefenderPushed
+        };
+
+        match die {
+            BlockDie::AttackerDown => BlockOutcome::AttackerDown,
+            BlockDie::BothDown => {
+                let attacker_wrestle = self.attacker_has(&Skill::Wrestle);
+                let defender_wrestle = self.defender_has(&Skill::Wrestle);
+
+                let both_down = match (
+                    self.attacker_has(&Skill::Block),
+                    self.defender_has(&Skill::Block),
+                ) {
+                    (true, true) => BlockOutcome::NoEffect,
+                    (true, false) if defender_wrestle => BlockOutcome::BothPlacedProne,
+                    (true, false) => BlockOutcome::DefenderDown,
+                    (false, true) if attacker_wrestle => BlockOutcome::BothPlacedProne,
+                    (false, true) => BlockOutcome::AttackerDown,
+                    (false, false) if attacker_wrestle || defender_wrestle => {
+                        BlockOutcome::BothPlacedProne
+                    }
+                    (false, false) => BlockOutcome::BothDown,
+                };
+
+                if self.juggernaut()
+                    && pushed.attacker_preference() > both_down.attacker_preference()
+                {
+                    pushed
+                } else {
+                    both_down
+                }
+            }
+            BlockDie::Push => pushed,
+            BlockDie::DefenderStumbles
+                if self.defender_has(&Skill::Dodge) && !self.attacker_has(&Skill::Tackle) =>
+            {
+                pushed
+            }
+            BlockDie::DefenderStumbles | BlockDie::DefenderDown => BlockOutcome::DefenderDown,
+        }
+    }
+
+    fn outcomes_for_dice(&self, dice: u8, chooser: BlockDiceChooser) -> Vec<(BlockOutcome, f64)> {
+        let faces: Vec<BlockOutcome> = BlockDie::FACES
+            .iter()
+            .map(|die| self.outcome(die))
+            .collect();
+        let mut rolls: Vec<Vec<BlockOutcome>> = vec![Vec::new()];
+        for _ in 0..dice {
+            rolls = rolls
+                .iter()
+                .flat_map(|roll| {
+                    faces.iter().map(move |face| {
+                        let mut roll = roll.clone();
+                        roll.push(*face);
+                        roll
+                    })
+                })
+                .collect();
+        }
+
+        let probability = 1.0 / rolls.len() as f64;
+        let mut outcomes: Vec<(BlockOutcome, f64)> = Vec::new();
+        for roll in rolls.iter() {
+            let chosen = match chooser {
+                BlockDiceChooser::Attacker => roll
+                    .iter()
+                    .max_by_key(|outcome| outcome.attacker_preference()),
+                BlockDiceChooser::Defender => roll
+                    .iter()
+                    .max_by_key(|outcome| outcome.defender_preference()),
+            };
+            let Some(chosen) = chosen else {
+                continue;
+            };
+
+            match outcomes.iter_mut().find(|(outcome, _)| outcome.eq(chosen)) {
+                Some((_, existing_probability)) => *existing_probability += probability,
+                None => outcomes.push((*chosen, probability)),
+            }
+        }
+
+        outcomes
+    }
+
+    pub fn outcomes(&self) -> Result<Vec<(BlockOutcome, f64)>, Error> {
+        let attacker_strength = self.attacker.strength().ok_or(Error::PositionNotDefined)?
+            + strength_modifier(self.attacker, self.blitz);
+        let defender_strength = self.defender.strength().ok_or(Error::PositionNotDefined)?;
+
+        let mut strengths = vec![(attacker_strength, 1.0)];
+        if self.attacker_has(&Skill::Dauntless) && defender_strength > attacker_strength {
+            let successful_faces = (1..=6)
+                .filter(|roll| roll + attacker_strength >= defender_strength)
+                .count() as f64;
+            strengths = vec![
+                (defender_strength, successful_faces / 6.0),
+                (attacker_strength, 1.0 - successful_faces / 6.0),
+            ];
+        }
+
+        let mut outcomes: Vec<(BlockOutcome, f64)> = Vec::new();
+        for (attacker_strength, strength_probability) in strengths {
+            let (dice, chooser) = Self::dice(
+                attacker_strength + self.offensive_assists,
+                defender_strength + self.defensive_assists,
+            );
+
+            for (outcome, probability) in self.outcomes_for_dice(dice, chooser) {
+                match outcomes
+                    .iter_mut()
+                    .find(|(existing_outcome, _)| existing_outcome.eq(&outcome))
+                {
+                    Some((_, existing_probability)) => {
+                        *existing_probability += probability * strength_probability
+                    }
+                    None => outcomes.push((outcome, probability * strength_probability)),
+                }
+            }
+        }
+
+        Ok(outcomes)
+    }
+
+    pub fn odds(&self) -> Result<BlockOdds, Error> {
+        let outcomes = self.outcomes()?;
+        let turnover = outcomes
+            .iter()
+            .filter(|(outcome, _)| outcome.is_turnover())
+            .map(|(_, probability)| probability)
+            .sum();
+        let defender_knocked_down = outcomes
+            .iter()
+            .filter(|(outcome, _)| outcome.is_defender_knocked_down())
+            .map(|(_, probability)| probability)
+            .sum();
+        let knock_down =
+            knock_down_odds(&self.attacker.version, Some(self.attacker), self.defender)?;
+
+        Ok(BlockOdds {
+            overall: knock_down.scaled(defender_knocked_down),
+            outcomes,
+            turnover,
+            defender_knocked_down,
+            knock_down,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::positions::Position;
+    use crate::rosters::Roster;
+
+    fn assert_close(result: f64, expected: f64) {
+        assert!(
+            (result - expected).abs() < 1e-9,
+            "{} is not {}",
+            result,
+            expected
+        );
+    }
+
+    fn lineman() -> Player {
+        Player::new(Version::V5S3, Position::HumanLineman, Roster::Human)
+    }
+
+    fn with_skills(skills: &[Skill]) -> Player {
+        let mut player = lineman();
+        player.advancements = skills
+            .iter()
+            .map(|skill| Advancement::ChosenSkill(*skill))
+            .collect();
+        player
+    }
+
+    #[test]
+    fn block_outcomes() -> Result<(), Error> {
+        let attacker = lineman();
+        let defender = lineman();
+
+        let odds = Block::new(&attacker, &defender).odds()?;
+        assert_close(odds.outcome(&BlockOutcome::AttackerDown), 1.0 / 6.0);
+        assert_close(odds.outcome(&BlockOutcome::BothDown), 1.0 / 6.0);
+        assert_close(odds.outcome(&BlockOutcome::DefenderPushed), 2.0 / 6.0);
+        assert_close(odds.outcome(&BlockOutcome::DefenderDown), 2.0 / 6.0);
+        assert_close(odds.turnover, 2.0 / 6.0);
+        assert_close(odds.defender_knocked_down, 3.0 / 6.0);
+
+        let blocker = with_skills(&[Skill::Block]);
+        let odds = Block::new(&blocker, &defender).with_assists(1, 0).odds()?;
+        assert_close(odds.defender_knocked_down, 1.0 - 0.5 * 0.5);
+        assert_close(odds.turnover, 1.0 / 36.0);
+
+        let dodger = with_skills(&[Skill::Dodge, Skill::Block]);
+        let odds = Block::new(&blocker, &dodger).odds()?;
+        assert_close(odds.outcome(&BlockOutcome::NoEffect), 1.0 / 6.0);
+        assert_close(odds.outcome(&BlockOutcome::DefenderPushed), 3.0 / 6.0);
+        let tackler = with_skills(&[Skill::Block, Skill::Tackle]);
+        let odds = Block::new(&tackler, &dodger).odds()?;
+        assert_close(odds.outcome(&BlockOutcome::DefenderDown), 2.0 / 6.0);
+
+        let odds = Block::new(&attacker, &defender).with_assists(0, 1).odds()?;
+        assert_close(odds.outcome(&BlockOutcome::DefenderDown), 1.0 / 9.0);
+
+        let dauntless = with_skills(&[Skill::Dauntless]);
+        let ogre = Player::new(Version::V5S3, Position::Ogre, Roster::Human);
+        let odds = Block::new(&dauntless, &ogre).odds()?;
+        assert_close(
+            odds.outcome(&BlockOutcome::DefenderDown),
+            5.0 / 6.0 * 2.0 / 6.0 + 1.0 / 6.0 * 1.0 / 9.0,
+        );
+
+        let stand_firm = with_skills(&[Skill::StandFirm]);
+        let juggernaut = with_skills(&[Skill::Juggernaut]);
+        let odds = Block::new(&juggernaut, &stand_firm).as_blitz().odds()?;
+        assert_close(odds.outcome(&BlockOutcome::DefenderPushed), 3.0 / 6.0);
+        let odds = Block::new(&juggernaut, &stand_firm).odds()?;
+        assert_close(odds.outcome(&BlockOutcome::NoEffect), 2.0 / 6.0);
+        let juggernaut_blocker = with_skills(&[Skill::Block, Skill::Juggernaut]);
+        let odds = Block::new(&juggernaut_blocker, &defender)
+            .as_blitz()
+            .odds()?;
+        assert_close(odds.outcome(&BlockOutcome::DefenderDown), 3.0 / 6.0);
+        assert_close(odds.outcome(&BlockOutcome::DefenderPushed), 2.0 / 6.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn knock_down() -> Result<(), Error> {
+        let defender = lineman();
+        let odds = knock_down_odds(&Version::V5S3, None, &defender)?;
+        assert_close(odds.armour_broken, 10.0 / 36.0);
+        assert_close(odds.stunned, 10.0 / 36.0 * 21.0 / 36.0);
+        assert_close(odds.knocked_out, 10.0 / 36.0 * 9.0 / 36.0);
+        assert_close(odds.casualty, 10.0 / 36.0 * 6.0 / 36.0);
+        let dead = odds
+            .injuries
+            .iter()
+            .find(|(injury, _)| injury.eq(&Injury::Dead))
+            .map(|(_, probability)| *probability);
+        assert_close(dead.unwrap_or(0.0), odds.casualty * 2.0 / 16.0);
+
+        let thick_skull = with_skills(&[Skill::ThickSkull]);
+        let odds = knock_down_odds(&Version::V5, None, &thick_skull)?;
+        assert_close(odds.knocked_out, 10.0 / 36.0 * 4.0 / 36.0);
+
+        let mighty_blow = with_skills(&[Skill::MightyBlow]);
+        let odds = knock_down_odds(&Version::V5S3, Some(&mighty_blow), &defender)?;
+        assert_close(odds.armour_broken, 15.0 / 36.0);
+        assert_close(
+            odds.casualty,
+            10.0 / 36.0 * 10.0 / 36.0 + 5.0 / 36.0 * 6.0 / 36.0,
+        );
+
+        let claws = with_skills(&[Skill::Claws]);
+        let ogre = Player::new(Version::V5S3, Position::Ogre, Roster::Human);
+        let odds = knock_down_odds(&Version::V5S3, Some(&claws), &ogre)?;
+        assert_close(odds.armour_broken, 15.0 / 36.0);
+
+        assert!(matches!(
+            knock_down_odds(&Version::V4, None, &defender),
+            Err(Error::UnsupportedVersion)
+        ));
+
+        Ok(())
+    }
+}
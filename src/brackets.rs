@@ -0,0 +1,939 @@
+use crate::coaches::Coach;
+use crate::elo::NAF_INITIAL_ELO;
+use crate::errors::Error;
+use crate::games::Game;
+use crate::teams::Team;
+use crate::translation::{TranslatedName, TypeName, lookup_with_args};
+use crate::versions::Version;
+use chrono::NaiveDateTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BracketFormat {
+    SingleElimination,
+    DoubleElimination,
+}
+
+impl TypeName for BracketFormat {}
+impl TranslatedName for BracketFormat {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Seeding {
+    Standings,
+    NafElo,
+}
+
+impl TypeName for Seeding {}
+impl TranslatedName for Seeding {}
+
+impl Seeding {
+    /// Orders the teams from first to last seed. Standings are computed from the finished
+    /// games between these teams: 3 points for a win, 1 for a draw, then touchdown and
+    /// casualty differences. Teams still tied keep their input order.
+    pub fn seeded_teams(&self, teams: &[Team], played_games: &[Game]) -> Vec<Team> {
+        let mut seeded_teams = teams.to_vec();
+
+        match self {
+            Seeding::Standings => {
+                let standings: Vec<(i32, (u32, i32, i32))> = teams
+                    .iter()
+                    .map(|team| (team.id, Self::standing(team, played_games)))
+                    .collect();
+                let standing = |team: &Team| {
+                    standings
+                        .iter()
+                        .find(|(team_id, _)| team_id.eq(&team.id))
+                        .map(|(_, standing)| *standing)
+                        .unwrap_or_default()
+                };
+
+                seeded_teams.sort_by_key(|team| Reverse(standing(team)))
+            }
+            Seeding::NafElo => seeded_teams.sort_by(|team_a, team_b| {
+                let elo_a = team_a.coach.elo.unwrap_or(NAF_INITIAL_ELO);
+                let elo_b = team_b.coach.elo.unwrap_or(NAF_INITIAL_ELO);
+                elo_b.total_cmp(&elo_a)
+            }),
+        }
+
+        seeded_teams
+    }
+
+    fn standing(team: &Team, played_games: &[Game]) -> (u32, i32, i32) {
+        let mut points = 0;
+        let mut touchdown_difference = 0;
+        let mut casualty_difference = 0;
+
+        for game in played_games.iter().filter(|game| game.game_finished()) {
+            let (first_team_won, second_team_won) = game.winner();
+            let score = game.score();
+            let casualties = game.casualties();
+
+            let (won, lost, touchdowns, casualties) = if game.first_team.id.eq(&team.id) {
+                (first_team_won, second_team_won, score, casualties)
+            } else if game.second_team.id.eq(&team.id) {
+                (
+                    second_team_won,
+                    first_team_won,
+                    (score.1, score.0),
+                    (casualties.1, casualties.0),
+                )
+            } else {
+                continue;
+            };
+
+            points += match (won, lost) {
+                (true, _) => 3,
+                (false, true) => 0,
+                (false, false) => 1,
+            };
+            touchdown_difference += touchdowns.0 as i32 - touchdowns.1 as i32;
+            casualty_difference += casualties.0 as i32 - casualties.1 as i32;
+        }
+
+        (points, touchdown_difference, casualty_difference)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BracketSide {
+    Winners,
+    Losers,
+    GrandFinal,
+    GrandFinalReset,
+}
+
+impl TypeName for BracketSide {}
+impl TranslatedName for BracketSide {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BracketSource {
+    Seed(usize),
+    WinnerOf(usize),
+    LoserOf(usize),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BracketEntrant {
+    Pending,
+    Bye,
+    Team(Box<Team>),
+}
+
+impl BracketEntrant {
+    pub fn team(&self) -> Option<&Team> {
+        match self {
+            BracketEntrant::Team(team) => Some(team),
+            BracketEntrant::Pending | BracketEntrant::Bye => None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        matches!(self, BracketEntrant::Pending)
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BracketMatch {
+    pub index: usize,
+    pub side: BracketSide,
+    pub round: usize,
+    pub sources: (BracketSource, BracketSource),
+    pub entrants: (BracketEntrant, BracketEntrant),
+    pub game: Option<Game>,
+    pub winner: BracketEntrant,
+    pub loser: BracketEntrant,
+}
+
+impl BracketMatch {
+    fn new(
+        index: usize,
+        side: BracketSide,
+        round: usize,
+        sources: (BracketSource, BracketSource),
+    ) -> Self {
+        Self {
+            index,
+            side,
+            round,
+            sources,
+            entrants: (BracketEntrant::Pending, BracketEntrant::Pending),
+            game: None,
+            winner: BracketEntrant::Pending,
+            loser: BracketEntrant::Pending,
+        }
+    }
+
+    pub fn is_decided(&self) -> bool {
+        !self.winner.is_pending()
+    }
+
+    pub fn is_ready_to_be_played(&self) -> bool {
+        self.game.is_none()
+            && !self.is_decided()
+            && self.entrants.0.team().is_some()
+            && self.entrants.1.team().is_some()
+    }
+
+    pub fn export(&self) -> BracketMatchExport {
+        BracketMatchExport {
+            index: self.index,
+            side: self.side,
+            round: self.round,
+            first_entrant: BracketEntrantExport::from(&self.entrants.0),
+            second_entrant: BracketEntrantExport::from(&self.entrants.1),
+            game_id: self.game.as_ref().map(|game| game.id),
+            score: self.game.as_ref().map(|game| game.score()),
+            penalties_score: self.game.as_ref().and_then(|game| game.penalties_score()),
+            winner: BracketEntrantExport::from(&self.winner),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum BracketEntrantExport {
+    Pending,
+    Bye,
+    Team {
+        id: i32,
+        name: String,
+        coach_name: String,
+    },
+}
+
+impl From<&BracketEntrant> for BracketEntrantExport {
+    fn from(entrant: &BracketEntrant) -> Self {
+        match entrant {
+            BracketEntrant::Pending => BracketEntrantExport::Pending,
+            BracketEntrant::Bye => BracketEntrantExport::Bye,
+            BracketEntrant::Team(team) => BracketEntrantExport::Team {
+                id: team.id,
+                name: team.name.clone(),
+                coach_name: team.coach.name.clone(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BracketMatchExport {
+    pub index: usize,
+    pub side: BracketSide,
+    pub round: usize,
+    pub first_entrant: BracketEntrantExport,
+    pub second_entrant: BracketEntrantExport,
+    pub game_id: Option<i32>,
+    pub score: Option<(usize, usize)>,
+    pub penalties_score: Option<(usize, usize)>,
+    pub winner: BracketEntrantExport,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bracket {
+    pub version: Version,
+    pub format: BracketFormat,
+    pub seeding: Seeding,
+    pub seeds: Vec<Team>,
+    pub matches: Vec<BracketMatch>,
+}
+
+impl Bracket {
+    pub fn create(
+        version: Version,
+        format: BracketFormat,
+        seeding: Seeding,
+        teams: &[Team],
+        played_games: &[Game],
+    ) -> Result<Self, Error> {
+        if teams.len() < 2 {
+            return Err(Error::NotEnoughTeamsForBracket);
+        }
+
+        for team in teams.iter() {
+            if team.version.ne(&version) {
+                return Err(Error::TeamsMustMatchGameVersion);
+            }
+        }
+
+        let seeds = seeding.seeded_teams(teams, played_games);
+        let size = seeds.len().next_power_of_two();
+        let rounds_number = size.trailing_zeros() as usize;
+
+        let mut matches: Vec<BracketMatch> = Vec::new();
+        let mut winners_rounds: Vec<Vec<usize>> = Vec::with_capacity(rounds_number);
+
+        let seed_order = Self::seed_order(size);
+        let mut first_round = Vec::with_capacity(size / 2);
+
+        for pair in seed_order.chunks(2) {
+            first_round.push(matches.len());
+            matches.push(BracketMatch::new(
+                matches.len(),
+                BracketSide::Winners,
+                1,
+                (BracketSource::Seed(pair[0]), BracketSource::Seed(pair[1])),
+            ));
+        }
+
+        winners_rounds.push(first_round);
+
+        for round in 2..=rounds_number {
+            let previous_round = winners_rounds.last().cloned().unwrap_or_default();
+            let mut current_round = Vec::with_capacity(previous_round.len() / 2);
+
+            for pair in previous_round.chunks(2) {
+                current_round.push(matches.len());
+                matches.push(BracketMatch::new(
+                    matches.len(),
+                    BracketSide::Winners,
+                    round,
+                    (
+                        BracketSource::WinnerOf(pair[0]),
+                        BracketSource::WinnerOf(pair[1]),
+                    ),
+                ));
+            }
+
+            winners_rounds.push(current_round);
+        }
+
+        if matches!(format, BracketFormat::DoubleElimination) {
+            Self::add_losers_bracket(&mut matches, &winners_rounds);
+        }
+
+        let mut bracket = Self {
+            version,
+            format,
+            seeding,
+            seeds,
+            matches,
+        };
+
+        bracket.resolve();
+
+        Ok(bracket)
+    }
+
+    fn seed_order(size: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = vec![1];
+
+        while order.len() < size {
+            let length = order.len() * 2;
+            order = order
+                .iter()
+                .flat_map(|&seed| [seed, length + 1 - seed])
+                .collect();
+        }
+
+        order
+    }
+
+    fn add_losers_bracket(matches: &mut Vec<BracketMatch>, winners_rounds: &[Vec<usize>]) {
+        let mut round = 1;
+        let mut losers_round: Vec<BracketSource> = Vec::new();
+
+        if let Some(first_winners_round) = winners_rounds.first() {
+            if first_winners_round.len() >= 2 {
+                for pair in first_winners_round.chunks(2) {
+                    losers_round.push(BracketSource::WinnerOf(matches.len()));
+                    matches.push(BracketMatch::new(
+                        matches.len(),
+                        BracketSide::Losers,
+                        round,
+                        (
+                            BracketSource::LoserOf(pair[0]),
+                            BracketSource::LoserOf(pair[1]),
+                        ),
+                    ));
+                }
+            } else {
+                losers_round = first_winners_round
+                    .iter()
+                    .map(|&index| BracketSource::LoserOf(index))
+                    .collect();
+            }
+        }
+
+        for winners_round in winners_rounds.iter().skip(1) {
+            round += 1;
+            let mut dropping_round = Vec::with_capacity(winners_round.len());
+
+            for (position, &winners_match) in winners_round.iter().rev().enumerate() {
+                dropping_round.push(BracketSource::WinnerOf(matches.len()));
+                matches.push(BracketMatch::new(
+                    matches.len(),
+                    BracketSide::Losers,
+                    round,
+                    (
+                        losers_round[position],
+                        BracketSource::LoserOf(winners_match),
+                    ),
+                ));
+            }
+
+            losers_round = dropping_round;
+
+            if losers_round.len() >= 2 {
+                round += 1;
+                let mut consolidation_round = Vec::with_capacity(losers_round.len() / 2);
+
+                for pair in losers_round.chunks(2) {
+                    consolidation_round.push(BracketSource::WinnerOf(matches.len()));
+                    matches.push(BracketMatch::new(
+                        matches.len(),
+                        BracketSide::Losers,
+                        round,
+                        (pair[0], pair[1]),
+                    ));
+                }
+
+                losers_round = consolidation_round;
+            }
+        }
+
+        let (Some(&winners_final), Some(&losers_champion)) = (
+            winners_rounds.last().and_then(|round| round.first()),
+            losers_round.first(),
+        ) else {
+            return;
+        };
+
+        let grand_final = matches.len();
+        matches.push(BracketMatch::new(
+            grand_final,
+            BracketSide::GrandFinal,
+            1,
+            (BracketSource::WinnerOf(winners_final), losers_champion),
+        ));
+
+        matches.push(BracketMatch::new(
+            matches.len(),
+            BracketSide::GrandFinalReset,
+            2,
+            (
+                BracketSource::WinnerOf(grand_final),
+                BracketSource::LoserOf(grand_final),
+            ),
+        ));
+    }
+
+    fn entrant_from_source(&self, source: &BracketSource) -> BracketEntrant {
+        match source {
+            BracketSource::Seed(seed) => match self.seeds.get(seed - 1) {
+                Some(team) => BracketEntrant::Team(Box::new(team.clone())),
+                None => BracketEntrant::Bye,
+            },
+            BracketSource::WinnerOf(index) => self.matches[*index].winner.clone(),
+            BracketSource::LoserOf(index) => self.matches[*index].loser.clone(),
+        }
+    }
+
+    fn reset_is_needed(&self, bracket_match: &BracketMatch) -> bool {
+        let BracketSource::WinnerOf(grand_final) = bracket_match.sources.0 else {
+            return true;
+        };
+
+        let grand_final = &self.matches[grand_final];
+
+        match (grand_final.winner.team(), grand_final.entrants.0.team()) {
+            (Some(winner), Some(winners_bracket_champion)) => winner.ne(winners_bracket_champion),
+            _ => true,
+        }
+    }
+
+    fn resolve(&mut self) {
+        let mut changed = true;
+
+        while changed {
+            changed = false;
+
+            for index in 0..self.matches.len() {
+                if self.matches[index].is_decided() {
+                    continue;
+                }
+
+                let (first_source, second_source) = self.matches[index].sources;
+
+                if self.matches[index].entrants.0.is_pending() {
+                    let entrant = self.entrant_from_source(&first_source);
+
+                    if !entrant.is_pending() {
+                        self.matches[index].entrants.0 = entrant;
+                        changed = true;
+                    }
+                }
+
+                if self.matches[index].entrants.1.is_pending() {
+                    let entrant = self.entrant_from_source(&second_source);
+
+                    if !entrant.is_pending() {
+                        self.matches[index].entrants.1 = entrant;
+                        changed = true;
+                    }
+                }
+
+                let bracket_match = &self.matches[index];
+
+                let result = match (&bracket_match.entrants.0, &bracket_match.entrants.1) {
+                    (BracketEntrant::Pending, _) | (_, BracketEntrant::Pending) => None,
+                    (BracketEntrant::Bye, other) | (other, BracketEntrant::Bye) => {
+                        Some((other.clone(), BracketEntrant::Bye))
+                    }
+                    (BracketEntrant::Team(first_team), BracketEntrant::Team(_))
+                        if matches!(bracket_match.side, BracketSide::GrandFinalReset)
+                            && !self.reset_is_needed(bracket_match) =>
+                    {
+                        Some((
+                            BracketEntrant::Team(first_team.clone()),
+                            BracketEntrant::Bye,
+                        ))
+                    }
+                    (BracketEntrant::Team(_), BracketEntrant::Team(_)) => None,
+                };
+
+                if let Some((winner, loser)) = result {
+                    self.matches[index].winner = winner;
+                    self.matches[index].loser = loser;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    pub fn matches_ready_to_be_played(&self) -> Vec<&BracketMatch> {
+        self.matches
+            .iter()
+            .filter(|bracket_match| bracket_match.is_ready_to_be_played())
+            .collect()
+    }
+
+    pub fn create_next_games(
+        &mut self,
+        first_game_id: i32,
+        created_by: Option<Coach>,
+        game_at: NaiveDateTime,
+        lang_id: &str,
+    ) -> Result<Vec<Game>, Error> {
+        let mut games: Vec<Game> = Vec::new();
+        let mut game_id = first_game_id;
+
+        for bracket_match in self.matches.iter_mut() {
+            if !bracket_match.is_ready_to_be_played() {
+                continue;
+            }
+
+            if let (Some(first_team), Some(second_team)) = (
+                bracket_match.entrants.0.team(),
+                bracket_match.entrants.1.team(),
+            ) {
+                let mut game = Game::create(
+                    game_id,
+                    created_by.clone(),
+                    self.version,
+                    game_at,
+                    first_team,
+                    second_team,
+                    true,
+                )?;
+                game.title = Some(lookup_with_args(
+                    lang_id,
+                    "BracketRound",
+                    vec![
+                        ("side", bracket_match.side.name(lang_id).into()),
+                        ("round", (bracket_match.round as i64).into()),
+                    ],
+                ));
+
+                bracket_match.game = Some(game.clone());
+                games.push(game);
+                game_id += 1;
+            }
+        }
+
+        Ok(games)
+    }
+
+    fn following_matches(&self, index: usize) -> Vec<usize> {
+        let mut following_matches: Vec<usize> = Vec::new();
+        let mut to_visit = vec![index];
+
+        while let Some(visited) = to_visit.pop() {
+            for bracket_match in self.matches.iter() {
+                let (first_source, second_source) = bracket_match.sources;
+                let is_following = [first_source, second_source].iter().any(|source| {
+                    matches!(
+                        source,
+                        BracketSource::WinnerOf(source_index) | BracketSource::LoserOf(source_index)
+                            if source_index.eq(&visited)
+                    )
+                });
+
+                if is_following && !following_matches.contains(&bracket_match.index) {
+                    following_matches.push(bracket_match.index);
+                    to_visit.push(bracket_match.index);
+                }
+            }
+        }
+
+        following_matches
+    }
+
+    pub fn record_game(&mut self, game: &Game) -> Result<(), Error> {
+        if !game.game_finished() || !game.closed {
+            return Err(Error::GameNotClosed);
+        }
+
+        let index = self
+            .matches
+            .iter()
+            .position(|bracket_match| {
+                bracket_match
+                    .game
+                    .as_ref()
+                    .is_some_and(|bracket_game| bracket_game.id.eq(&game.id))
+            })
+            .ok_or(Error::GameNotInBracket)?;
+
+        let following_matches = self.following_matches(index);
+
+        if following_matches
+            .iter()
+            .any(|following_match| self.matches[*following_match].game.is_some())
+        {
+            return Err(Error::BracketMatchAlreadyFollowedUp);
+        }
+
+        let winning_team = game.winning_team().ok_or(Error::GameNeedsAWinner)?;
+        let losing_team = if winning_team.eq(&game.first_team) {
+            game.second_team.clone()
+        } else {
+            game.first_team.clone()
+        };
+
+        for following_match in following_matches {
+            let bracket_match = &mut self.matches[following_match];
+            bracket_match.entrants = (BracketEntrant::Pending, BracketEntrant::Pending);
+            bracket_match.winner = BracketEntrant::Pending;
+            bracket_match.loser = BracketEntrant::Pending;
+        }
+
+        let bracket_match = &mut self.matches[index];
+        bracket_match.game = Some(game.clone());
+        bracket_match.winner = BracketEntrant::Team(Box::new(winning_team));
+        bracket_match.loser = BracketEntrant::Team(Box::new(losing_team));
+
+        self.resolve();
+
+        Ok(())
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.champion().is_some()
+    }
+
+    pub fn champion(&self) -> Option<&Team> {
+        self.matches.last()?.winner.team()
+    }
+
+    pub fn rounds(&self, side: BracketSide) -> Vec<Vec<&BracketMatch>> {
+        let mut rounds: Vec<Vec<&BracketMatch>> = Vec::new();
+
+        for bracket_match in self.matches.iter().filter(|m| m.side.eq(&side)) {
+            while rounds.len() < bracket_match.round {
+                rounds.push(Vec::new());
+            }
+
+            rounds[bracket_match.round - 1].push(bracket_match);
+        }
+
+        rounds
+    }
+
+    pub fn export(&self) -> Vec<BracketMatchExport> {
+        self.matches
+            .iter()
+            .map(|bracket_match| bracket_match.export())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Success;
+    use crate::staffs::Staff;
+    use crate::test_utils;
+    use std::collections::HashMap;
+
+    fn team(id: i32, elo: Option<f64>) -> Team {
+        let mut team = Team {
+            coach: Coach {
+                id: Some(id),
+                name: format!("Coach {}", id),
+                elo,
+                ratings: HashMap::new(),
+            },
+            staff: HashMap::from([(Staff::ReRoll, 1)]),
+            ..test_utils::team(id, &format!("Team {}", id), Version::V5S3, 11)
+        };
+        team.players[0].1.is_captain = true;
+
+        team
+    }
+
+    fn play(game: &mut Game, first_team_wins: bool, with_penalties: bool) {
+        game.start();
+        game.push_kicking_team(game.first_team.id).unwrap();
+
+        if with_penalties {
+            if first_team_wins {
+                game.push_penalties(4, 3).unwrap();
+            } else {
+                game.push_penalties(3, 4).unwrap();
+            }
+        } else {
+            let (team_id, player_id) = if first_team_wins {
+                (game.first_team.id, game.first_team.players[0].1.id)
+            } else {
+                (game.second_team.id, game.second_team.players[0].1.id)
+            };
+
+            game.push_success(team_id, player_id, Success::Touchdown)
+                .unwrap();
+        }
+
+        game.end_game().unwrap();
+        game.close_game().unwrap();
+    }
+
+    fn play_all(bracket: &mut Bracket, next_game_id: &mut i32) {
+        let played_at = test_utils::date();
+
+        while !bracket.is_finished() {
+            let games = bracket
+                .create_next_games(*next_game_id, None, played_at, "en")
+                .unwrap();
+            assert!(!games.is_empty());
+            *next_game_id += games.len() as i32;
+
+            for mut game in games {
+                assert!(game.needs_winner);
+                let first_team_wins = game.first_team.id < game.second_team.id;
+                let with_penalties = game.id % 2 == 0;
+                play(&mut game, first_team_wins, with_penalties);
+                bracket.record_game(&game).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn seed_order() {
+        assert_eq!(Bracket::seed_order(4), vec![1, 4, 2, 3]);
+        assert_eq!(Bracket::seed_order(8), vec![1, 8, 4, 5, 2, 7, 3, 6]);
+    }
+
+    #[test]
+    fn single_elimination_with_byes() {
+        let teams = vec![team(1, None), team(2, Some(200.0)), team(3, Some(100.0))];
+
+        let mut bracket = Bracket::create(
+            Version::V5S3,
+            BracketFormat::SingleElimination,
+            Seeding::NafElo,
+            &teams,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(bracket.seeds[0].id, 2);
+        assert_eq!(bracket.matches.len(), 3);
+        assert_eq!(bracket.matches[0].winner.team().unwrap().id, 2);
+        assert_eq!(bracket.matches_ready_to_be_played().len(), 1);
+
+        let mut next_game_id = 1;
+        play_all(&mut bracket, &mut next_game_id);
+
+        assert_eq!(next_game_id, 3);
+        assert_eq!(bracket.champion().unwrap().id, 1);
+        assert_eq!(bracket.rounds(BracketSide::Winners).len(), 2);
+        assert!(matches!(
+            bracket.export()[0].second_entrant,
+            BracketEntrantExport::Bye
+        ));
+    }
+
+    #[test]
+    fn double_elimination() {
+        let teams = vec![team(1, None), team(2, None), team(3, None), team(4, None)];
+
+        let mut bracket = Bracket::create(
+            Version::V5S3,
+            BracketFormat::DoubleElimination,
+            Seeding::Standings,
+            &teams,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(bracket.rounds(BracketSide::Winners).len(), 2);
+        assert_eq!(bracket.rounds(BracketSide::Losers).len(), 2);
+
+        let mut next_game_id = 1;
+        play_all(&mut bracket, &mut next_game_id);
+
+        assert_eq!(bracket.champion().unwrap().id, 1);
+        assert_eq!(next_game_id, 7);
+        assert!(bracket.matches.last().unwrap().game.is_none());
+    }
+
+    #[test]
+    fn standings_seeding() {
+        let teams = vec![team(1, None), team(2, None), team(3, None)];
+        let played_at = test_utils::date();
+        let mut played_games = Vec::new();
+
+        for (game_id, first_team, second_team) in [(1, 2, 0), (2, 1, 0), (3, 2, 1), (4, 0, 1)] {
+            let mut game = Game::create(
+                game_id,
+                None,
+                Version::V5S3,
+                played_at,
+                &teams[first_team],
+                &teams[second_team],
+                false,
+            )
+            .unwrap();
+
+            if game_id < 4 {
+                play(&mut game, true, false);
+            }
+
+            played_games.push(game);
+        }
+
+        let seeds = Seeding::Standings.seeded_teams(&teams, &played_games);
+        let seed_ids: Vec<i32> = seeds.iter().map(|team| team.id).collect();
+        assert_eq!(seed_ids, vec![3, 2, 1]);
+
+        let seeds = Seeding::Standings.seeded_teams(&teams, &[]);
+        let seed_ids: Vec<i32> = seeds.iter().map(|team| team.id).collect();
+        assert_eq!(seed_ids, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "i18n")]
+    fn translated_game_titles() {
+        let teams = vec![team(1, None), team(2, None)];
+
+        let mut bracket = Bracket::create(
+            Version::V5S3,
+            BracketFormat::SingleElimination,
+            Seeding::NafElo,
+            &teams,
+            &[],
+        )
+        .unwrap();
+
+        let games = bracket
+            .create_next_games(1, None, test_utils::date(), "fr")
+            .unwrap();
+        assert_eq!(
+            games[0].title.as_deref(),
+            Some("Tableau des vainqueurs, tour 1")
+        );
+    }
+
+    #[test]
+    fn record_unknown_game() {
+        let teams = vec![team(1, None), team(2, None)];
+
+        let mut bracket = Bracket::create(
+            Version::V5S3,
+            BracketFormat::SingleElimination,
+            Seeding::Standings,
+            &teams,
+            &[],
+        )
+        .unwrap();
+
+        assert!(bracket.matches[0].game.is_none());
+
+        let mut other_game = Game::create(
+            42,
+            None,
+            Version::V5S3,
+            test_utils::date(),
+            &teams[0],
+            &teams[1],
+            true,
+        )
+        .unwrap();
+        play(&mut other_game, true, false);
+
+        assert!(matches!(
+            bracket.record_game(&other_game),
+            Err(Error::GameNotInBracket)
+        ));
+    }
+
+    #[test]
+    fn record_game_checks() {
+        let teams = vec![team(1, None), team(2, None), team(3, None), team(4, None)];
+        let played_at = test_utils::date();
+
+        let mut bracket = Bracket::create(
+            Version::V5S3,
+            BracketFormat::SingleElimination,
+            Seeding::Standings,
+            &teams,
+            &[],
+        )
+        .unwrap();
+
+        let mut games = bracket.create_next_games(1, None, played_at, "en").unwrap();
+        assert_eq!(games.len(), 2);
+
+        games[0].start();
+        assert!(matches!(
+            bracket.record_game(&games[0]),
+            Err(Error::GameNotClosed)
+        ));
+
+        let mut first_game = games.remove(0);
+        let mut replayed_first_game = first_game.clone();
+        play(&mut first_game, true, false);
+        bracket.record_game(&first_game).unwrap();
+
+        let winner_id = bracket.matches[0].winner.team().unwrap().id;
+        play(&mut replayed_first_game, false, false);
+        bracket.record_game(&replayed_first_game).unwrap();
+        assert_ne!(bracket.matches[0].winner.team().unwrap().id, winner_id);
+
+        let mut second_game = games.remove(0);
+        play(&mut second_game, true, false);
+        bracket.record_game(&second_game).unwrap();
+
+        let final_games = bracket.create_next_games(3, None, played_at, "en").unwrap();
+        assert_eq!(final_games.len(), 1);
+        assert_eq!(
+            final_games[0].first_team.id,
+            replayed_first_game.winning_team().unwrap().id
+        );
+
+        assert!(matches!(
+            bracket.record_game(&first_game),
+            Err(Error::BracketMatchAlreadyFollowedUp)
+        ));
+    }
+}
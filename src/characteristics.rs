@@ -1,6 +1,8 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Characteristic {
     MovementAllowance,
     Strength,
@@ -39,4 +41,17 @@ impl Characteristic {
             value as u8
         }
     }
+
+    pub fn formatted_value(&self, value: Option<u8>) -> String {
+        match (self, value) {
+            (_, None) => "-".to_string(),
+            (
+                Characteristic::Agility
+                | Characteristic::PassingAbility
+                | Characteristic::ArmourValue,
+                Some(value),
+            ) => format!("{}+", value),
+            (_, Some(value)) => value.to_string(),
+        }
+    }
 }
@@ -1,11 +1,24 @@
+use crate::games::Game;
+use crate::ratings::{Rating, RatingSystemKind};
+use crate::rosters::Roster;
+use crate::translation::TranslatedName;
+use crate::versions::Version;
+use crate::weather::Weather;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub struct Coach {
     pub id: Option<i32>,
     pub name: String,
     pub elo: Option<f64>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ratings: HashMap<RatingSystemKind, Rating>,
 }
 
 impl Coach {
@@ -14,9 +27,14 @@ impl Coach {
             id: None,
             name: name.to_string(),
             elo: None,
+            ratings: HashMap::new(),
         }
     }
 
+    pub fn rating(&self, kind: &RatingSystemKind) -> Option<&Rating> {
+        self.ratings.get(kind)
+    }
+
     pub fn has_id(&self, id: &i32) -> bool {
         match self.id {
             Some(coach_id) => coach_id.eq(id),
@@ -31,6 +49,7 @@ impl Default for Coach {
             id: None,
             name: "".to_string(),
             elo: None,
+            ratings: HashMap::new(),
         }
     }
 }
@@ -56,3 +75,349 @@ impl Hash for Coach {
         }
     }
 }
+
+#[derive(Debug, Default, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct GamesRecord {
+    pub played: usize,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+    pub touchdowns_for: usize,
+    pub touchdowns_against: usize,
+    pub casualties_for: usize,
+    pub casualties_against: usize,
+}
+
+impl GamesRecord {
+    fn add(
+        &mut self,
+        winner: (bool, bool),
+        touchdowns: (usize, usize),
+        casualties: (usize, usize),
+    ) {
+        self.played += 1;
+
+        match winner {
+            (true, false) => self.wins += 1,
+            (false, true) => self.losses += 1,
+            _ => self.draws += 1,
+        }
+
+        self.touchdowns_for += touchdowns.0;
+        self.touchdowns_against += touchdowns.1;
+        self.casualties_for += casualties.0;
+        self.casualties_against += casualties.1;
+    }
+
+    pub fn points_rate(&self) -> f64 {
+        if self.played == 0 {
+            return 0.0;
+        }
+
+        (self.wins as f64 + self.draws as f64 / 2.0) / self.played as f64
+    }
+
+    pub fn touchdowns_difference(&self) -> i32 {
+        self.touchdowns_for as i32 - self.touchdowns_against as i32
+    }
+
+    pub fn casualties_difference(&self) -> i32 {
+        self.casualties_for as i32 - self.casualties_against as i32
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HeadToHeadRecord {
+    pub opponent: Coach,
+    pub record: GamesRecord,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoachProfile {
+    pub coach: Coach,
+    pub record: GamesRecord,
+    pub records_per_version: HashMap<Version, GamesRecord>,
+    pub records_per_roster: HashMap<Roster, GamesRecord>,
+    pub records_per_opposing_roster: HashMap<Roster, GamesRecord>,
+    pub records_per_weather: HashMap<Weather, GamesRecord>,
+    pub head_to_head_records: Vec<HeadToHeadRecord>,
+}
+
+impl CoachProfile {
+    pub fn from_games(coach: &Coach, games: &[Game]) -> Self {
+        let mut profile = Self {
+            coach: coach.clone(),
+            record: GamesRecord::default(),
+            records_per_version: HashMap::new(),
+            records_per_roster: HashMap::new(),
+            records_per_opposing_roster: HashMap::new(),
+            records_per_weather: HashMap::new(),
+            head_to_head_records: vec![],
+        };
+
+        for game in games.iter() {
+            profile.add_game(game);
+        }
+
+        profile
+    }
+
+    pub fn add_game(&mut self, game: &Game) {
+        if !game.game_finished() {
+            return;
+        }
+
+        let (winner, touchdowns, casualties) = (game.winner(), game.score(), game.casualties());
+
+        let (team, opposing_team, winner, touchdowns, casualties) =
+            if game.first_team.coach.eq(&self.coach) {
+                (
+                    &game.first_team,
+                    &game.second_team,
+                    winner,
+                    touchdowns,
+                    casualties,
+                )
+            } else if game.second_team.coach.eq(&self.coach) {
+                (
+                    &game.second_team,
+                    &game.first_team,
+                    (winner.1, winner.0),
+                    (touchdowns.1, touchdowns.0),
+                    (casualties.1, casualties.0),
+                )
+            } else {
+                return;
+            };
+
+        self.record.add(winner, touchdowns, casualties);
+
+        self.records_per_version
+            .entry(game.version)
+            .or_default()
+            .add(winner, touchdowns, casualties);
+
+        self.records_per_roster
+            .entry(team.roster)
+            .or_default()
+            .add(winner, touchdowns, casualties);
+
+        self.records_per_opposing_roster
+            .entry(opposing_team.roster)
+            .or_default()
+            .add(winner, touchdowns, casualties);
+
+        if let Some(weather) = game.weather() {
+            self.records_per_weather
+                .entry(weather)
+                .or_default()
+                .add(winner, touchdowns, casualties);
+        }
+
+        match self
+            .head_to_head_records
+            .iter_mut()
+            .find(|head_to_head| head_to_head.opponent.eq(&opposing_team.coach))
+        {
+            Some(head_to_head) => head_to_head.record.add(winner, touchdowns, casualties),
+            None => {
+                let mut record = GamesRecord::default();
+                record.add(winner, touchdowns, casualties);
+
+                self.head_to_head_records.push(HeadToHeadRecord {
+                    opponent: opposing_team.coach.clone(),
+                    record,
+                });
+            }
+        }
+    }
+
+    pub fn favourite_rosters(&self) -> Vec<(Roster, usize)> {
+        let mut rosters: Vec<(Roster, usize)> = self
+            .records_per_roster
+            .iter()
+            .map(|(roster, record)| (*roster, record.played))
+            .collect();
+
+        rosters.sort_by(|(roster, played), (other_roster, other_played)| {
+            other_played
+                .cmp(played)
+                .then_with(|| roster.name("en").cmp(&other_roster.name("en")))
+        });
+
+        rosters
+    }
+
+    pub fn head_to_head(&self, opponent: &Coach) -> Option<&GamesRecord> {
+        self.head_to_head_records
+            .iter()
+            .find(|head_to_head| head_to_head.opponent.eq(opponent))
+            .map(|head_to_head| &head_to_head.record)
+    }
+
+    pub fn points_rate_against_roster(&self, roster: &Roster) -> Option<f64> {
+        self.records_per_opposing_roster
+            .get(roster)
+            .map(|record| record.points_rate())
+    }
+
+    pub fn points_rate_with_weather(&self, weather: &Weather) -> Option<f64> {
+        self.records_per_weather
+            .get(weather)
+            .map(|record| record.points_rate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Success;
+    use crate::teams::Team;
+    use crate::test_utils;
+
+    fn game(
+        first_team: &Team,
+        second_team: &Team,
+        weather: Weather,
+        touchdowns: (usize, usize),
+        casualties: (usize, usize),
+    ) -> Game {
+        let game_at = test_utils::date();
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            game_at,
+            first_team,
+            second_team,
+            false,
+        )
+        .unwrap();
+
+        game.start();
+        game.push_weather(weather).unwrap();
+        game.push_kicking_team(first_team.id).unwrap();
+
+        for (team, touchdowns, casualties) in [
+            (first_team, touchdowns.0, casualties.0),
+            (second_team, touchdowns.1, casualties.1),
+        ] {
+            for _ in 0..touchdowns {
+                game.push_success(team.id, team.players[0].1.id, Success::Touchdown)
+                    .unwrap();
+            }
+            for _ in 0..casualties {
+                game.push_success(team.id, team.players[0].1.id, Success::Casualty)
+                    .unwrap();
+            }
+        }
+
+        game.end_game().unwrap();
+
+        game
+    }
+
+    #[test]
+    fn coach_profile() {
+        let alice = Coach::from_name("Alice");
+        let bob = Coach::from_name("Bob");
+        let carol = Coach::from_name("Carol");
+
+        let alice_humans = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let alice_dwarfs = Team {
+            roster: Roster::Dwarf,
+            ..test_utils::team(2, "Alice", Version::V5S3, 1)
+        };
+        let bob_orcs = Team {
+            roster: Roster::Orc,
+            ..test_utils::team(3, "Bob", Version::V5S3, 1)
+        };
+        let carol_skavens = Team {
+            roster: Roster::Skaven,
+            ..test_utils::team(4, "Carol", Version::V5S3, 1)
+        };
+
+        let mut unfinished_game = game(
+            &alice_humans,
+            &bob_orcs,
+            Weather::PerfectConditions,
+            (1, 0),
+            (0, 0),
+        );
+        unfinished_game.events.pop();
+
+        let games = vec![
+            game(
+                &alice_humans,
+                &bob_orcs,
+                Weather::PerfectConditions,
+                (2, 1),
+                (1, 3),
+            ),
+            game(&bob_orcs, &alice_humans, Weather::Blizzard, (1, 1), (2, 0)),
+            game(
+                &carol_skavens,
+                &alice_dwarfs,
+                Weather::PerfectConditions,
+                (3, 0),
+                (0, 1),
+            ),
+            game(
+                &alice_humans,
+                &carol_skavens,
+                Weather::PerfectConditions,
+                (2, 0),
+                (0, 0),
+            ),
+            game(
+                &bob_orcs,
+                &carol_skavens,
+                Weather::PerfectConditions,
+                (1, 0),
+                (0, 0),
+            ),
+            unfinished_game,
+        ];
+
+        let profile = CoachProfile::from_games(&alice, &games);
+
+        assert_eq!(
+            profile.record,
+            GamesRecord {
+                played: 4,
+                wins: 2,
+                draws: 1,
+                losses: 1,
+                touchdowns_for: 5,
+                touchdowns_against: 5,
+                casualties_for: 2,
+                casualties_against: 5,
+            }
+        );
+        assert_eq!(profile.record.points_rate(), 0.625);
+        assert_eq!(profile.records_per_version[&Version::V5S3].played, 4);
+        assert_eq!(
+            profile.favourite_rosters(),
+            vec![(Roster::Human, 3), (Roster::Dwarf, 1)]
+        );
+
+        let against_bob = profile.head_to_head(&bob).unwrap();
+        assert_eq!((against_bob.wins, against_bob.draws), (1, 1));
+        let against_carol = profile.head_to_head(&carol).unwrap();
+        assert_eq!((against_carol.wins, against_carol.losses), (1, 1));
+
+        assert_eq!(profile.points_rate_against_roster(&Roster::Orc), Some(0.75));
+        assert_eq!(profile.points_rate_against_roster(&Roster::Lizardmen), None);
+        assert_eq!(
+            profile.points_rate_with_weather(&Weather::PerfectConditions),
+            Some(2.0 / 3.0)
+        );
+        assert_eq!(
+            profile.points_rate_with_weather(&Weather::Blizzard),
+            Some(0.5)
+        );
+    }
+}
@@ -0,0 +1,268 @@
+use crate::errors::Error;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use sqlx::AnyPool;
+use sqlx::any::AnyPoolOptions;
+use sqlx::error::ErrorKind;
+use sqlx::migrate::Migrator;
+
+pub mod coaches;
+pub mod games;
+pub mod teams;
+
+pub use coaches::CoachRepository;
+pub use games::GameRepository;
+pub use teams::TeamRepository;
+
+pub static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("./migrations/sqlite");
+pub static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+pub async fn connect(url: &str) -> Result<AnyPool, Error> {
+    connect_with(AnyPoolOptions::new(), url).await
+}
+
+pub async fn connect_with(options: AnyPoolOptions, url: &str) -> Result<AnyPool, Error> {
+    sqlx::any::install_default_drivers();
+
+    let pool = options.connect(url).await.map_err(database_error)?;
+    migrate(&pool).await?;
+
+    Ok(pool)
+}
+
+pub async fn migrate(pool: &AnyPool) -> Result<(), Error> {
+    let backend_name = pool
+        .acquire()
+        .await
+        .map_err(database_error)?
+        .backend_name()
+        .to_string();
+
+    let migrator = match backend_name.as_str() {
+        "SQLite" => &SQLITE_MIGRATOR,
+        "PostgreSQL" => &POSTGRES_MIGRATOR,
+        _ => return Err(Error::UnsupportedDatabase),
+    };
+
+    migrator.run(pool).await.map_err(database_error)
+}
+
+pub(crate) fn database_error<E: Into<sqlx::Error>>(error: E) -> Error {
+    match error.into() {
+        sqlx::Error::Database(error) => match error.kind() {
+            ErrorKind::Other => Error::DatabaseError,
+            _ => Error::DatabaseConstraintViolation,
+        },
+        sqlx::Error::Configuration(_)
+        | sqlx::Error::Io(_)
+        | sqlx::Error::Tls(_)
+        | sqlx::Error::PoolTimedOut
+        | sqlx::Error::PoolClosed => Error::DatabaseUnavailable,
+        sqlx::Error::TypeNotFound { .. }
+        | sqlx::Error::ColumnIndexOutOfBounds { .. }
+        | sqlx::Error::ColumnNotFound(_)
+        | sqlx::Error::ColumnDecode { .. }
+        | sqlx::Error::Decode(_) => Error::InvalidDatabaseData,
+        sqlx::Error::Migrate(_) => Error::DatabaseMigrationFailed,
+        _ => Error::DatabaseError,
+    }
+}
+
+pub(crate) fn to_text<T: Serialize>(value: &T) -> Result<String, Error> {
+    match serde_json::to_value(value).map_err(|_| Error::InvalidSerializedData)? {
+        Value::String(text) => Ok(text),
+        value => Ok(value.to_string()),
+    }
+}
+
+pub(crate) fn from_text<T: DeserializeOwned>(text: &str) -> Result<T, Error> {
+    serde_json::from_str(text)
+        .or_else(|_| serde_json::from_value(Value::String(text.to_string())))
+        .map_err(|_| Error::InvalidSerializedData)
+}
+
+pub(crate) fn to_flag(value: bool) -> i32 {
+    i32::from(value)
+}
+
+pub(crate) fn from_flag(value: i64) -> bool {
+    value.ne(&0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::coaches::Coach;
+    use crate::games::Game;
+    use crate::injuries::{Injury, InjuryCause};
+    use crate::positions::Keyword;
+    use crate::ratings::{Rating, RatingSystemKind};
+    use crate::skills::Skill;
+    use crate::staffs::Staff;
+    use crate::teams::Team;
+    use crate::test_utils;
+    use crate::versions::Version;
+    use sqlx::Row;
+    use std::collections::HashMap;
+
+    async fn pool() -> AnyPool {
+        connect_with(AnyPoolOptions::new().max_connections(1), "sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    async fn clear_postgres(pool: &AnyPool) -> Result<(), Error> {
+        sqlx::query(
+            "TRUNCATE coaches, teams, team_staff, players, player_advancements, \
+             player_injuries, player_hatred, games, game_events RESTART IDENTITY",
+        )
+        .execute(pool)
+        .await
+        .map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn check_repositories(pool: AnyPool) -> Result<(), Error> {
+        let coaches = CoachRepository::new(pool.clone());
+        let teams = TeamRepository::new(pool.clone());
+        let games = GameRepository::new(pool.clone());
+
+        let mut reds = Team {
+            treasury: 20000,
+            staff: HashMap::from([(Staff::ReRoll, 2), (Staff::Apothecary, 1)]),
+            dedicated_fans: 3,
+            ..test_utils::team(1, "Reds", Version::V5S3, 11)
+        };
+        reds.players[0].1.name = "Player 1".to_string();
+        reds.players[0].1.advancements = vec![
+            Advancement::ChosenSkill(Skill::Block),
+            Advancement::Strength,
+        ];
+        reds.players[0].1.injuries = vec![Injury::SmashedKnee];
+        reds.players[0].1.hatred = vec![Keyword::Elf];
+        reds.players[0].1.star_player_points = 8;
+        teams.save(&mut reds).await?;
+        let mut blues = test_utils::team(2, "Blues", Version::V5S3, 11);
+        teams.save(&mut blues).await?;
+
+        let loaded = teams.find(1).await?.unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&reds).unwrap()
+        );
+        assert!(teams.find(3).await?.is_none());
+        assert_eq!(teams.list().await?.len(), 2);
+
+        let coach_id = reds.coach.id.unwrap();
+        assert_eq!(teams.list_for_coach(coach_id).await?.len(), 1);
+        assert_eq!(coaches.list().await?.len(), 2);
+        let mut coach = coaches.find_by_name("Reds").await?.unwrap();
+        coach.elo = Some(160.0);
+        coach
+            .ratings
+            .insert(RatingSystemKind::NafElo, Rating::NafElo(160.0));
+        coaches.save(&mut coach).await?;
+        let loaded = coaches.find(coach_id).await?.unwrap();
+        assert_eq!(loaded.elo, Some(160.0));
+        assert_eq!(loaded.ratings, coach.ratings);
+        let stored_ratings: String = sqlx::query("SELECT ratings FROM coaches WHERE id = $1")
+            .bind(coach_id)
+            .fetch_one(&pool)
+            .await
+            .map_err(database_error)?
+            .try_get(0)
+            .map_err(database_error)?;
+        assert_eq!(
+            crate::serialization::format_of(&serde_json::from_str(&stored_ratings).unwrap()),
+            crate::serialization::CURRENT_FORMAT
+        );
+
+        reds.players.pop();
+        reds.treasury = 0;
+        teams.save(&mut reds).await?;
+        let loaded = teams.find(1).await?.unwrap();
+        assert_eq!(loaded.players.len(), 10);
+        assert_eq!(loaded.treasury, 0);
+
+        let mut game = Game::create(
+            1,
+            Some(coach),
+            Version::V5S3,
+            test_utils::date(),
+            &reds,
+            &blues,
+            false,
+        )?;
+        game.start();
+        game.push_injury_with_cause(2, 201, Injury::BadlyHurt, InjuryCause::Block, Some(101))?;
+        games.save(&game).await?;
+
+        let loaded = games.find(1).await?.unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&game).unwrap()
+        );
+        assert_eq!(games.list_for_team(2).await?.len(), 1);
+        assert!(games.list_for_team(3).await?.is_empty());
+
+        assert!(games.delete(1).await?);
+        assert!(games.find(1).await?.is_none());
+        assert!(teams.delete(1).await?);
+        assert!(!teams.delete(1).await?);
+        assert_eq!(teams.list().await?.len(), 1);
+
+        Ok(())
+    }
+
+    async fn check_coach_ids_and_errors(pool: AnyPool) -> Result<(), Error> {
+        let coaches = CoachRepository::new(pool.clone());
+        let teams = TeamRepository::new(pool);
+
+        let mut alice = Coach::from_name("Alice");
+        alice.id = Some(5);
+        coaches.save(&mut alice).await?;
+        let mut bob = Coach::from_name("Bob");
+        assert_eq!(coaches.save(&mut bob).await?, 6);
+        assert!(coaches.delete(6).await?);
+
+        let mut reds = Team {
+            coach: alice,
+            ..test_utils::team(1, "Reds", Version::V5S3, 11)
+        };
+        teams.save(&mut reds).await?;
+        assert!(matches!(
+            coaches.delete(5).await,
+            Err(Error::DatabaseConstraintViolation)
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn repositories() -> Result<(), Error> {
+        check_repositories(pool().await).await
+    }
+
+    #[tokio::test]
+    async fn coach_ids_and_errors() -> Result<(), Error> {
+        check_coach_ids_and_errors(pool().await).await
+    }
+
+    /// Runs the repository checks against the empty, disposable Postgres database named by
+    /// `BLOOD_BOWL_POSTGRES_URL`, skipped when it is not set.
+    #[tokio::test]
+    async fn postgres() -> Result<(), Error> {
+        let Ok(url) = std::env::var("BLOOD_BOWL_POSTGRES_URL") else {
+            return Ok(());
+        };
+        let pool = connect(&url).await?;
+
+        clear_postgres(&pool).await?;
+        check_repositories(pool.clone()).await?;
+        clear_postgres(&pool).await?;
+        check_coach_ids_and_errors(pool).await
+    }
+}
@@ -0,0 +1,136 @@
+use crate::coaches::Coach;
+use crate::db::database_error;
+use crate::errors::Error;
+use crate::serialization::{from_versioned_json, to_versioned_json};
+use sqlx::any::AnyRow;
+use sqlx::{AnyConnection, AnyPool, Row};
+
+#[derive(Debug, Clone)]
+pub struct CoachRepository {
+    pool: AnyPool,
+}
+
+impl CoachRepository {
+    pub fn new(pool: AnyPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn save(&self, coach: &mut Coach) -> Result<i32, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        Self::save_with(&mut connection, coach).await
+    }
+
+    pub(crate) async fn save_with(
+        connection: &mut AnyConnection,
+        coach: &mut Coach,
+    ) -> Result<i32, Error> {
+        let id = match coach.id {
+            Some(id) => {
+                sqlx::query(
+                    "INSERT INTO coaches (id, name, elo, ratings) VALUES ($1, $2, $3, $4) \
+                     ON CONFLICT (id) DO UPDATE SET \
+                     name = excluded.name, elo = excluded.elo, ratings = excluded.ratings",
+                )
+                .bind(id)
+                .bind(&coach.name)
+                .bind(coach.elo)
+                .bind(to_versioned_json(&coach.ratings)?)
+                .execute(&mut *connection)
+                .await
+                .map_err(database_error)?;
+
+                if connection.backend_name().eq("PostgreSQL") {
+                    sqlx::query(
+                        "SELECT setval('coaches_id_seq', GREATEST(\
+                         (SELECT MAX(id) FROM coaches), (SELECT last_value FROM coaches_id_seq)))",
+                    )
+                    .execute(&mut *connection)
+                    .await
+                    .map_err(database_error)?;
+                }
+
+                id
+            }
+            None => {
+                let id: i64 = sqlx::query(
+                    "INSERT INTO coaches (name, elo, ratings) VALUES ($1, $2, $3) RETURNING id",
+                )
+                .bind(&coach.name)
+                .bind(coach.elo)
+                .bind(to_versioned_json(&coach.ratings)?)
+                .fetch_one(&mut *connection)
+                .await
+                .map_err(database_error)?
+                .try_get(0)
+                .map_err(database_error)?;
+
+                i32::try_from(id).map_err(|_| Error::InvalidDatabaseData)?
+            }
+        };
+
+        coach.id = Some(id);
+
+        Ok(id)
+    }
+
+    pub async fn find(&self, id: i32) -> Result<Option<Coach>, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        Self::find_with(&mut connection, id).await
+    }
+
+    pub(crate) async fn find_with(
+        connection: &mut AnyConnection,
+        id: i32,
+    ) -> Result<Option<Coach>, Error> {
+        sqlx::query("SELECT id, name, elo, ratings FROM coaches WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&mut *connection)
+            .await
+            .map_err(database_error)?
+            .map(|row| Self::from_row(&row))
+            .transpose()
+    }
+
+    pub async fn find_by_name(&self, name: &str) -> Result<Option<Coach>, Error> {
+        sqlx::query("SELECT id, name, elo, ratings FROM coaches WHERE name = $1 ORDER BY id")
+            .bind(name)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(database_error)?
+            .map(|row| Self::from_row(&row))
+            .transpose()
+    }
+
+    pub async fn list(&self) -> Result<Vec<Coach>, Error> {
+        sqlx::query("SELECT id, name, elo, ratings FROM coaches ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(database_error)?
+            .iter()
+            .map(Self::from_row)
+            .collect()
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<bool, Error> {
+        let result = sqlx::query("DELETE FROM coaches WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(database_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    fn from_row(row: &AnyRow) -> Result<Coach, Error> {
+        let ratings: String = row.try_get("ratings").map_err(database_error)?;
+
+        Ok(Coach {
+            id: Some(row.try_get("id").map_err(database_error)?),
+            name: row.try_get("name").map_err(database_error)?,
+            elo: row.try_get("elo").map_err(database_error)?,
+            ratings: from_versioned_json(&ratings)?,
+        })
+    }
+}
@@ -0,0 +1,192 @@
+use crate::db::{database_error, from_flag, from_text, to_flag, to_text};
+use crate::errors::Error;
+use crate::games::Game;
+use crate::serialization::{from_versioned_json, to_versioned_json};
+use chrono::NaiveDateTime;
+use sqlx::any::AnyRow;
+use sqlx::{AnyConnection, AnyPool, Row};
+
+const GAME_AT_FORMAT: &str = "%Y-%m-%d %H:%M:%S%.f";
+
+#[derive(Debug, Clone)]
+pub struct GameRepository {
+    pool: AnyPool,
+}
+
+impl GameRepository {
+    pub fn new(pool: AnyPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn save(&self, game: &Game) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await.map_err(database_error)?;
+
+        let created_by = match &game.created_by {
+            Some(coach) => Some(to_versioned_json(coach)?),
+            None => None,
+        };
+
+        sqlx::query(
+            "INSERT INTO games (id, title, version, created_by, game_at, started, closed, \
+             first_team_id, second_team_id, first_team, second_team, needs_winner) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) \
+             ON CONFLICT (id) DO UPDATE SET \
+             title = excluded.title, version = excluded.version, \
+             created_by = excluded.created_by, game_at = excluded.game_at, \
+             started = excluded.started, closed = excluded.closed, \
+             first_team_id = excluded.first_team_id, second_team_id = excluded.second_team_id, \
+             first_team = excluded.first_team, second_team = excluded.second_team, \
+             needs_winner = excluded.needs_winner",
+        )
+        .bind(game.id)
+        .bind(game.title.clone())
+        .bind(to_text(&game.version)?)
+        .bind(created_by)
+        .bind(game.game_at.format(GAME_AT_FORMAT).to_string())
+        .bind(to_flag(game.started))
+        .bind(to_flag(game.closed))
+        .bind(game.first_team.id)
+        .bind(game.second_team.id)
+        .bind(to_versioned_json(&game.first_team)?)
+        .bind(to_versioned_json(&game.second_team)?)
+        .bind(to_flag(game.needs_winner))
+        .execute(&mut *transaction)
+        .await
+        .map_err(database_error)?;
+
+        sqlx::query("DELETE FROM game_events WHERE game_id = $1")
+            .bind(game.id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(database_error)?;
+
+        for (sequence, event) in game.events.iter().enumerate() {
+            sqlx::query("INSERT INTO game_events (game_id, sequence, event) VALUES ($1, $2, $3)")
+                .bind(game.id)
+                .bind(i32::try_from(sequence).map_err(|_| Error::InvalidDatabaseData)?)
+                .bind(to_versioned_json(event)?)
+                .execute(&mut *transaction)
+                .await
+                .map_err(database_error)?;
+        }
+
+        transaction.commit().await.map_err(database_error)?;
+
+        Ok(())
+    }
+
+    pub async fn find(&self, id: i32) -> Result<Option<Game>, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        let row = sqlx::query("SELECT * FROM games WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::load(&mut connection, &row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Game>, Error> {
+        self.list_where("SELECT * FROM games ORDER BY game_at, id", None)
+            .await
+    }
+
+    pub async fn list_for_team(&self, team_id: i32) -> Result<Vec<Game>, Error> {
+        self.list_where(
+            "SELECT * FROM games WHERE first_team_id = $1 OR second_team_id = $1 \
+             ORDER BY game_at, id",
+            Some(team_id),
+        )
+        .await
+    }
+
+    async fn list_where(
+        &self,
+        sql: &'static str,
+        parameter: Option<i32>,
+    ) -> Result<Vec<Game>, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        let mut query = sqlx::query(sql);
+        if let Some(parameter) = parameter {
+            query = query.bind(parameter);
+        }
+        let rows = query
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        let mut games = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            games.push(Self::load(&mut connection, row).await?);
+        }
+
+        Ok(games)
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<bool, Error> {
+        let mut transaction = self.pool.begin().await.map_err(database_error)?;
+
+        sqlx::query("DELETE FROM game_events WHERE game_id = $1")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(database_error)?;
+        let result = sqlx::query("DELETE FROM games WHERE id = $1")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(database_error)?;
+
+        transaction.commit().await.map_err(database_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn load(connection: &mut AnyConnection, row: &AnyRow) -> Result<Game, Error> {
+        let id: i32 = row.try_get("id").map_err(database_error)?;
+        let version: String = row.try_get("version").map_err(database_error)?;
+        let created_by: Option<String> = row.try_get("created_by").map_err(database_error)?;
+        let game_at: String = row.try_get("game_at").map_err(database_error)?;
+        let started: i64 = row.try_get("started").map_err(database_error)?;
+        let closed: i64 = row.try_get("closed").map_err(database_error)?;
+        let first_team: String = row.try_get("first_team").map_err(database_error)?;
+        let second_team: String = row.try_get("second_team").map_err(database_error)?;
+        let needs_winner: i64 = row.try_get("needs_winner").map_err(database_error)?;
+
+        let events =
+            sqlx::query("SELECT event FROM game_events WHERE game_id = $1 ORDER BY sequence")
+                .bind(id)
+                .fetch_all(&mut *connection)
+                .await
+                .map_err(database_error)?
+                .iter()
+                .map(|row| {
+                    let event: String = row.try_get("event").map_err(database_error)?;
+                    from_versioned_json(&event)
+                })
+                .collect::<Result<_, Error>>()?;
+
+        Ok(Game {
+            id,
+            title: row.try_get("title").map_err(database_error)?,
+            version: from_text(&version)?,
+            created_by: match created_by {
+                Some(created_by) => Some(from_versioned_json(&created_by)?),
+                None => None,
+            },
+            game_at: NaiveDateTime::parse_from_str(&game_at, GAME_AT_FORMAT)
+                .map_err(|_| Error::InvalidDatabaseData)?,
+            started: from_flag(started),
+            closed: from_flag(closed),
+            first_team: from_versioned_json(&first_team)?,
+            second_team: from_versioned_json(&second_team)?,
+            events,
+            needs_winner: from_flag(needs_winner),
+        })
+    }
+}
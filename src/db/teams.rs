@@ -0,0 +1,355 @@
+use crate::db::coaches::CoachRepository;
+use crate::db::{database_error, from_flag, from_text, to_flag, to_text};
+use crate::errors::Error;
+use crate::players::Player;
+use crate::teams::Team;
+use sqlx::any::AnyRow;
+use sqlx::{AnyConnection, AnyPool, Row};
+use std::collections::HashMap;
+
+struct PlayerChildTable {
+    insert: &'static str,
+    select: &'static str,
+    delete: &'static str,
+}
+
+const PLAYER_CHILD_TABLES: [PlayerChildTable; 3] = [
+    PlayerChildTable {
+        insert: "INSERT INTO player_advancements (team_id, player_id, sequence, advancement) \
+                 VALUES ($1, $2, $3, $4)",
+        select: "SELECT advancement FROM player_advancements \
+                 WHERE team_id = $1 AND player_id = $2 ORDER BY sequence",
+        delete: "DELETE FROM player_advancements WHERE team_id = $1",
+    },
+    PlayerChildTable {
+        insert: "INSERT INTO player_injuries (team_id, player_id, sequence, injury) \
+                 VALUES ($1, $2, $3, $4)",
+        select: "SELECT injury FROM player_injuries \
+                 WHERE team_id = $1 AND player_id = $2 ORDER BY sequence",
+        delete: "DELETE FROM player_injuries WHERE team_id = $1",
+    },
+    PlayerChildTable {
+        insert: "INSERT INTO player_hatred (team_id, player_id, sequence, keyword) \
+                 VALUES ($1, $2, $3, $4)",
+        select: "SELECT keyword FROM player_hatred \
+                 WHERE team_id = $1 AND player_id = $2 ORDER BY sequence",
+        delete: "DELETE FROM player_hatred WHERE team_id = $1",
+    },
+];
+
+#[derive(Debug, Clone)]
+pub struct TeamRepository {
+    pool: AnyPool,
+}
+
+impl TeamRepository {
+    pub fn new(pool: AnyPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn save(&self, team: &mut Team) -> Result<(), Error> {
+        let mut transaction = self.pool.begin().await.map_err(database_error)?;
+
+        let coach_id = CoachRepository::save_with(&mut transaction, &mut team.coach).await?;
+
+        sqlx::query(
+            "INSERT INTO teams (id, version, roster, name, coach_id, treasury, external_logo_url, \
+             dedicated_fans, under_creation, in_offseason) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+             ON CONFLICT (id) DO UPDATE SET \
+             version = excluded.version, roster = excluded.roster, name = excluded.name, \
+             coach_id = excluded.coach_id, treasury = excluded.treasury, \
+             external_logo_url = excluded.external_logo_url, \
+             dedicated_fans = excluded.dedicated_fans, under_creation = excluded.under_creation, \
+             in_offseason = excluded.in_offseason",
+        )
+        .bind(team.id)
+        .bind(to_text(&team.version)?)
+        .bind(to_text(&team.roster)?)
+        .bind(&team.name)
+        .bind(coach_id)
+        .bind(team.treasury)
+        .bind(team.external_logo_url.clone())
+        .bind(i32::from(team.dedicated_fans))
+        .bind(to_flag(team.under_creation))
+        .bind(to_flag(team.in_offseason))
+        .execute(&mut *transaction)
+        .await
+        .map_err(database_error)?;
+
+        Self::delete_children(&mut transaction, team.id).await?;
+
+        for (staff, quantity) in team.staff.iter() {
+            sqlx::query("INSERT INTO team_staff (team_id, staff, quantity) VALUES ($1, $2, $3)")
+                .bind(team.id)
+                .bind(to_text(staff)?)
+                .bind(i32::from(*quantity))
+                .execute(&mut *transaction)
+                .await
+                .map_err(database_error)?;
+        }
+
+        for (number, player) in team.players.iter() {
+            Self::insert_player(&mut transaction, team.id, *number, player).await?;
+        }
+
+        transaction.commit().await.map_err(database_error)?;
+
+        Ok(())
+    }
+
+    async fn insert_player(
+        connection: &mut AnyConnection,
+        team_id: i32,
+        number: i32,
+        player: &Player,
+    ) -> Result<(), Error> {
+        sqlx::query(
+            "INSERT INTO players (team_id, id, number, version, position, roster, name, \
+             star_player_points, player_type, miss_next_game, is_captain) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+        )
+        .bind(team_id)
+        .bind(player.id)
+        .bind(number)
+        .bind(to_text(&player.version)?)
+        .bind(to_text(&player.position)?)
+        .bind(to_text(&player.roster)?)
+        .bind(&player.name)
+        .bind(player.star_player_points)
+        .bind(to_text(&player.player_type)?)
+        .bind(to_flag(player.miss_next_game))
+        .bind(to_flag(player.is_captain))
+        .execute(&mut *connection)
+        .await
+        .map_err(database_error)?;
+
+        let values = [
+            player
+                .advancements
+                .iter()
+                .map(to_text)
+                .collect::<Result<Vec<String>, Error>>()?,
+            player
+                .injuries
+                .iter()
+                .map(to_text)
+                .collect::<Result<Vec<String>, Error>>()?,
+            player
+                .hatred
+                .iter()
+                .map(to_text)
+                .collect::<Result<Vec<String>, Error>>()?,
+        ];
+
+        for (table, values) in PLAYER_CHILD_TABLES.iter().zip(values.iter()) {
+            for (sequence, value) in values.iter().enumerate() {
+                sqlx::query(table.insert)
+                    .bind(team_id)
+                    .bind(player.id)
+                    .bind(i32::try_from(sequence).map_err(|_| Error::InvalidDatabaseData)?)
+                    .bind(value)
+                    .execute(&mut *connection)
+                    .await
+                    .map_err(database_error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn delete_children(connection: &mut AnyConnection, team_id: i32) -> Result<(), Error> {
+        let deletes = PLAYER_CHILD_TABLES.iter().map(|table| table.delete).chain([
+            "DELETE FROM players WHERE team_id = $1",
+            "DELETE FROM team_staff WHERE team_id = $1",
+        ]);
+
+        for delete in deletes {
+            sqlx::query(delete)
+                .bind(team_id)
+                .execute(&mut *connection)
+                .await
+                .map_err(database_error)?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn find(&self, id: i32) -> Result<Option<Team>, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        let row = sqlx::query("SELECT * FROM teams WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        match row {
+            Some(row) => Ok(Some(Self::load(&mut connection, &row).await?)),
+            None => Ok(None),
+        }
+    }
+
+    pub async fn list(&self) -> Result<Vec<Team>, Error> {
+        self.list_where("SELECT * FROM teams ORDER BY id", None)
+            .await
+    }
+
+    pub async fn list_for_coach(&self, coach_id: i32) -> Result<Vec<Team>, Error> {
+        self.list_where(
+            "SELECT * FROM teams WHERE coach_id = $1 ORDER BY id",
+            Some(coach_id),
+        )
+        .await
+    }
+
+    async fn list_where(
+        &self,
+        sql: &'static str,
+        parameter: Option<i32>,
+    ) -> Result<Vec<Team>, Error> {
+        let mut connection = self.pool.acquire().await.map_err(database_error)?;
+
+        let mut query = sqlx::query(sql);
+        if let Some(parameter) = parameter {
+            query = query.bind(parameter);
+        }
+        let rows = query
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?;
+
+        let mut teams = Vec::with_capacity(rows.len());
+        for row in rows.iter() {
+            teams.push(Self::load(&mut connection, row).await?);
+        }
+
+        Ok(teams)
+    }
+
+    pub async fn delete(&self, id: i32) -> Result<bool, Error> {
+        let mut transaction = self.pool.begin().await.map_err(database_error)?;
+
+        Self::delete_children(&mut transaction, id).await?;
+        let result = sqlx::query("DELETE FROM teams WHERE id = $1")
+            .bind(id)
+            .execute(&mut *transaction)
+            .await
+            .map_err(database_error)?;
+
+        transaction.commit().await.map_err(database_error)?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn load(connection: &mut AnyConnection, row: &AnyRow) -> Result<Team, Error> {
+        let id: i32 = row.try_get("id").map_err(database_error)?;
+        let coach_id: i32 = row.try_get("coach_id").map_err(database_error)?;
+        let coach = CoachRepository::find_with(connection, coach_id)
+            .await?
+            .ok_or(Error::InvalidDatabaseData)?;
+        let version: String = row.try_get("version").map_err(database_error)?;
+        let roster: String = row.try_get("roster").map_err(database_error)?;
+        let dedicated_fans: i32 = row.try_get("dedicated_fans").map_err(database_error)?;
+        let under_creation: i64 = row.try_get("under_creation").map_err(database_error)?;
+        let in_offseason: i64 = row.try_get("in_offseason").map_err(database_error)?;
+
+        let mut staff = HashMap::new();
+        for row in sqlx::query("SELECT staff, quantity FROM team_staff WHERE team_id = $1")
+            .bind(id)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?
+            .iter()
+        {
+            let name: String = row.try_get("staff").map_err(database_error)?;
+            let quantity: i32 = row.try_get("quantity").map_err(database_error)?;
+            staff.insert(
+                from_text(&name)?,
+                u8::try_from(quantity).map_err(|_| Error::InvalidDatabaseData)?,
+            );
+        }
+
+        let mut players = Vec::new();
+        for row in sqlx::query("SELECT * FROM players WHERE team_id = $1 ORDER BY number, id")
+            .bind(id)
+            .fetch_all(&mut *connection)
+            .await
+            .map_err(database_error)?
+            .iter()
+        {
+            players.push(Self::load_player(connection, id, row).await?);
+        }
+
+        Ok(Team {
+            id,
+            version: from_text(&version)?,
+            roster: from_text(&roster)?,
+            name: row.try_get("name").map_err(database_error)?,
+            coach,
+            treasury: row.try_get("treasury").map_err(database_error)?,
+            external_logo_url: row.try_get("external_logo_url").map_err(database_error)?,
+            staff,
+            players,
+            dedicated_fans: u8::try_from(dedicated_fans).map_err(|_| Error::InvalidDatabaseData)?,
+            under_creation: from_flag(under_creation),
+            in_offseason: from_flag(in_offseason),
+        })
+    }
+
+    async fn load_player(
+        connection: &mut AnyConnection,
+        team_id: i32,
+        row: &AnyRow,
+    ) -> Result<(i32, Player), Error> {
+        let id: i32 = row.try_get("id").map_err(database_error)?;
+        let version: String = row.try_get("version").map_err(database_error)?;
+        let position: String = row.try_get("position").map_err(database_error)?;
+        let roster: String = row.try_get("roster").map_err(database_error)?;
+        let player_type: String = row.try_get("player_type").map_err(database_error)?;
+        let miss_next_game: i64 = row.try_get("miss_next_game").map_err(database_error)?;
+        let is_captain: i64 = row.try_get("is_captain").map_err(database_error)?;
+
+        let mut values: Vec<Vec<String>> = Vec::with_capacity(PLAYER_CHILD_TABLES.len());
+        for table in PLAYER_CHILD_TABLES.iter() {
+            let rows = sqlx::query(table.select)
+                .bind(team_id)
+                .bind(id)
+                .fetch_all(&mut *connection)
+                .await
+                .map_err(database_error)?;
+            values.push(
+                rows.iter()
+                    .map(|row| row.try_get(0))
+                    .collect::<Result<Vec<String>, sqlx::Error>>()
+                    .map_err(database_error)?,
+            );
+        }
+
+        let player = Player {
+            id,
+            version: from_text(&version)?,
+            position: from_text(&position)?,
+            roster: from_text(&roster)?,
+            name: row.try_get("name").map_err(database_error)?,
+            star_player_points: row.try_get("star_player_points").map_err(database_error)?,
+            player_type: from_text(&player_type)?,
+            miss_next_game: from_flag(miss_next_game),
+            advancements: values[0]
+                .iter()
+                .map(|value| from_text(value))
+                .collect::<Result<_, _>>()?,
+            injuries: values[1]
+                .iter()
+                .map(|value| from_text(value))
+                .collect::<Result<_, _>>()?,
+            hatred: values[2]
+                .iter()
+                .map(|value| from_text(value))
+                .collect::<Result<_, _>>()?,
+            is_captain: from_flag(is_captain),
+        };
+
+        Ok((row.try_get("number").map_err(database_error)?, player))
+    }
+}
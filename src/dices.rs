@@ -1,4 +1,4 @@
-use rand::RngExt;
+use rand::{Rng, RngExt};
 
 pub enum Dice {
     D2,
@@ -11,20 +11,54 @@ pub enum Dice {
 
 impl Dice {
     pub fn roll(self) -> usize {
+        self.roll_with(&mut rand::rng())
+    }
+
+    pub fn roll_with<R: Rng + ?Sized>(self, rng: &mut R) -> usize {
         match self {
-            Dice::D2 => rand::rng().random_range(1..=2),
-            Dice::D3 => rand::rng().random_range(1..=3),
-            Dice::D6 => rand::rng().random_range(1..=6),
-            Dice::D6x2 => Dice::D6.roll() + Dice::D6.roll(),
-            Dice::D8 => rand::rng().random_range(1..=8),
-            Dice::D16 => rand::rng().random_range(1..=16),
+            Dice::D2 => rng.random_range(1..=2),
+            Dice::D3 => rng.random_range(1..=3),
+            Dice::D6 => rng.random_range(1..=6),
+            Dice::D6x2 => Dice::D6.roll_with(rng) + Dice::D6.roll_with(rng),
+            Dice::D8 => rng.random_range(1..=8),
+            Dice::D16 => rng.random_range(1..=16),
         }
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum BlockDie {
+    AttackerDown,
+    BothDown,
+    Push,
+    DefenderStumbles,
+    DefenderDown,
+}
+
+impl BlockDie {
+    pub const FACES: [Self; 6] = [
+        Self::AttackerDown,
+        Self::BothDown,
+        Self::Push,
+        Self::Push,
+        Self::DefenderStumbles,
+        Self::DefenderDown,
+    ];
+
+    pub fn roll() -> Self {
+        Self::roll_with(&mut rand::rng())
+    }
+
+    pub fn roll_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::FACES[Dice::D6.roll_with(rng) - 1]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
 
     #[test]
     fn d2() {
@@ -65,4 +99,35 @@ mod tests {
             assert!(result <= 16);
         }
     }
+
+    #[test]
+    fn seeded_rolls() {
+        let mut first_rng = StdRng::seed_from_u64(42);
+        let mut second_rng = StdRng::seed_from_u64(42);
+
+        for _ in 0..100 {
+            assert_eq!(
+                Dice::D6x2.roll_with(&mut first_rng),
+                Dice::D6x2.roll_with(&mut second_rng)
+            );
+        }
+    }
+
+    #[test]
+    fn block_die() {
+        for _ in 0..1000 {
+            let result = BlockDie::roll();
+
+            assert!(BlockDie::FACES.contains(&result));
+        }
+
+        let mut first_rng = StdRng::seed_from_u64(42);
+        let mut second_rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            assert_eq!(
+                BlockDie::roll_with(&mut first_rng),
+                BlockDie::roll_with(&mut second_rng)
+            );
+        }
+    }
 }
@@ -1,5 +1,11 @@
+use crate::coaches::Coach;
+use crate::errors::Error;
 use crate::games::Game;
+use chrono::NaiveDateTime;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::cmp::min;
+use std::collections::HashSet;
 
 pub const NAF_INITIAL_ELO: f64 = 150.00;
 
@@ -11,11 +17,7 @@ pub fn new_naf_elo_from_game(
     let first_team_elo = game.first_team.coach.elo.unwrap_or(NAF_INITIAL_ELO);
     let second_team_elo = game.second_team.coach.elo.unwrap_or(NAF_INITIAL_ELO);
 
-    let (first_team_points, second_team_points) = match game.winner() {
-        (true, false) => (1.00, 0.00),
-        (false, true) => (0.00, 1.00),
-        _ => (0.5, 0.5),
-    };
+    let (first_team_points, second_team_points) = points_from_winner(game.winner());
 
     let first_team_new_elo = new_naf_elo(
         first_team_elo,
@@ -36,15 +38,30 @@ pub fn new_naf_elo_from_game(
     (first_team_new_elo, second_team_new_elo)
 }
 
-fn new_naf_elo(
+pub(crate) fn points_from_winner(winner: (bool, bool)) -> (f64, f64) {
+    match winner {
+        (true, false) => (1.00, 0.00),
+        (false, true) => (0.00, 1.00),
+        _ => (0.5, 0.5),
+    }
+}
+
+pub fn naf_victory_probability(own_elo: f64, adversary_elo: f64) -> f64 {
+    1.00 / (10f64.powf((adversary_elo - own_elo) / 150.00) + 1.00)
+}
+
+pub fn naf_elo_gap_from_victory_probability(victory_probability: f64) -> f64 {
+    -150.00 * (1.00 / victory_probability - 1.00).log10()
+}
+
+pub(crate) fn new_naf_elo(
     own_elo: f64,
     adversary_elo: f64,
     points: f64,
     competition_coaches_number: Option<usize>,
     competition_maximum_coaches_number: Option<usize>,
 ) -> f64 {
-    let victory_probability: f64 =
-        1.00 / (10f64.powf((adversary_elo as f64 - own_elo as f64) / 150.00) + 1.00);
+    let victory_probability = naf_victory_probability(own_elo, adversary_elo);
 
     let k_value = f64::sqrt(min(
         competition_coaches_number.unwrap_or(2),
@@ -54,3 +71,316 @@ fn new_naf_elo(
 
     own_elo + (k_value * (points - victory_probability))
 }
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct EloRatingEntry {
+    pub game_id: i32,
+    pub rated_at: NaiveDateTime,
+    pub opponent: Coach,
+    pub elo_before: f64,
+    pub elo_after: f64,
+}
+
+impl EloRatingEntry {
+    pub fn delta(&self) -> f64 {
+        self.elo_after - self.elo_before
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CoachEloRating {
+    pub coach: Coach,
+    pub elo: f64,
+    pub history: Vec<EloRatingEntry>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NafEloRatings {
+    pub competition_maximum_coaches_number: Option<usize>,
+    ratings: Vec<CoachEloRating>,
+}
+
+impl NafEloRatings {
+    pub fn new(competition_maximum_coaches_number: Option<usize>) -> Self {
+        Self {
+            competition_maximum_coaches_number,
+            ratings: Vec::new(),
+        }
+    }
+
+    pub fn competition_coaches_number(games: &[Game]) -> usize {
+        let mut coaches: HashSet<&Coach> = HashSet::new();
+
+        for game in games.iter().filter(|game| game.closed) {
+            coaches.insert(&game.first_team.coach);
+            coaches.insert(&game.second_team.coach);
+        }
+
+        coaches.len()
+    }
+
+    fn rating(&self, coach: &Coach) -> Option<&CoachEloRating> {
+        self.ratings.iter().find(|rating| rating.coach.eq(coach))
+    }
+
+    pub fn elo(&self, coach: &Coach) -> f64 {
+        self.rating(coach)
+            .map(|rating| rating.elo)
+            .or(coach.elo)
+            .unwrap_or(NAF_INITIAL_ELO)
+    }
+
+    pub fn history(&self, coach: &Coach) -> Vec<EloRatingEntry> {
+        self.rating(coach)
+            .map(|rating| rating.history.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn ranking(&self) -> Vec<&CoachEloRating> {
+        let mut ranking: Vec<&CoachEloRating> = self.ratings.iter().collect();
+        ranking.sort_by(|rating_a, rating_b| rating_b.elo.total_cmp(&rating_a.elo));
+        ranking
+    }
+
+    pub fn process_competition(&mut self, games: &[Game]) -> Result<(), Error> {
+        let competition_coaches_number = Self::competition_coaches_number(games);
+
+        let mut closed_games: Vec<&Game> = games.iter().filter(|game| game.closed).collect();
+        closed_games.sort_by_key(|game| game.game_at);
+
+        for game in closed_games {
+            self.process_game(game, competition_coaches_number)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn process_game(
+        &mut self,
+        game: &Game,
+        competition_coaches_number: usize,
+    ) -> Result<(f64, f64), Error> {
+        let first_coach = &game.first_team.coach;
+        let second_coach = &game.second_team.coach;
+
+        if first_coach.eq(second_coach) {
+            return Err(Error::SameCoachForBothTeams);
+        }
+
+        let first_coach_elo = self.elo(first_coach);
+        let second_coach_elo = self.elo(second_coach);
+
+        let mut rated_game = game.clone();
+        rated_game.first_team.coach.elo = Some(first_coach_elo);
+        rated_game.second_team.coach.elo = Some(second_coach_elo);
+
+        let (first_coach_new_elo, second_coach_new_elo) = new_naf_elo_from_game(
+            &rated_game,
+            Some(competition_coaches_number),
+            self.competition_maximum_coaches_number
+                .or(Some(competition_coaches_number)),
+        );
+
+        self.record(
+            first_coach,
+            second_coach,
+            game,
+            first_coach_elo,
+            first_coach_new_elo,
+        );
+        self.record(
+            second_coach,
+            first_coach,
+            game,
+            second_coach_elo,
+            second_coach_new_elo,
+        );
+
+        Ok((first_coach_new_elo, second_coach_new_elo))
+    }
+
+    fn record(
+        &mut self,
+        coach: &Coach,
+        opponent: &Coach,
+        game: &Game,
+        elo_before: f64,
+        elo_after: f64,
+    ) {
+        let index = match self
+            .ratings
+            .iter()
+            .position(|rating| rating.coach.eq(coach))
+        {
+            Some(index) => index,
+            None => {
+                self.ratings.push(CoachEloRating {
+                    coach: coach.clone(),
+                    elo: elo_before,
+                    history: Vec::new(),
+                });
+                self.ratings.len() - 1
+            }
+        };
+        let rating = &mut self.ratings[index];
+
+        rating.elo = elo_after;
+        rating.coach.elo = Some(elo_after);
+        rating.history.push(EloRatingEntry {
+            game_id: game.id,
+            rated_at: game.game_at,
+            opponent: opponent.clone(),
+            elo_before,
+            elo_after,
+        });
+    }
+
+    pub fn update_coach(&self, coach: &mut Coach) {
+        if let Some(rating) = self.rating(coach) {
+            coach.elo = Some(rating.elo);
+        }
+    }
+
+    pub fn update_coaches(&self, coaches: &mut [Coach]) {
+        for coach in coaches.iter_mut() {
+            self.update_coach(coach);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Success;
+    use crate::teams::Team;
+    use crate::test_utils;
+    use crate::versions::Version;
+
+    fn closed_game(id: i32, first_team: &Team, second_team: &Team, winner: Option<&Team>) -> Game {
+        let game_at = test_utils::date();
+        let mut game = Game::create(
+            id,
+            None,
+            Version::V5S3,
+            game_at,
+            first_team,
+            second_team,
+            false,
+        )
+        .unwrap();
+
+        game.start();
+        game.push_kicking_team(first_team.id).unwrap();
+
+        if let Some(winner) = winner {
+            game.push_success(winner.id, winner.players[0].1.id, Success::Touchdown)
+                .unwrap();
+        }
+
+        game.end_game().unwrap();
+        game.close_game().unwrap();
+
+        game
+    }
+
+    #[test]
+    fn competition_without_coach_ids() {
+        let team_a = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let team_b = test_utils::team(2, "Bob", Version::V5S3, 1);
+        let team_c = test_utils::team(3, "Carol", Version::V5S3, 1);
+
+        let mut unclosed_game = closed_game(4, &team_b, &team_c, None);
+        unclosed_game.closed = false;
+
+        let games = vec![
+            closed_game(1, &team_a, &team_b, Some(&team_a)),
+            closed_game(2, &team_b, &team_c, Some(&team_c)),
+            closed_game(3, &team_a, &team_c, None),
+            unclosed_game,
+        ];
+
+        assert_eq!(NafEloRatings::competition_coaches_number(&games), 3);
+
+        let mut ratings = NafEloRatings::new(None);
+        ratings.process_competition(&games).unwrap();
+
+        let alice = Coach::from_name("Alice");
+        let bob = Coach::from_name("Bob");
+
+        assert_eq!(ratings.history(&alice).len(), 2);
+        assert_eq!(ratings.history(&bob).len(), 2);
+        assert!(ratings.elo(&alice) > NAF_INITIAL_ELO);
+        assert!(ratings.elo(&bob) < NAF_INITIAL_ELO);
+
+        let total: f64 = ratings.ranking().iter().map(|rating| rating.elo).sum();
+        assert!((total - 3.0 * NAF_INITIAL_ELO).abs() < 1e-9);
+        assert_eq!(ratings.ranking()[0].coach, alice);
+
+        let mut coaches = vec![alice.clone(), bob.clone(), Coach::from_name("Dave")];
+        ratings.update_coaches(&mut coaches);
+        assert_eq!(coaches[0].elo, Some(ratings.elo(&alice)));
+        assert_eq!(coaches[2].elo, None);
+
+        let history = ratings.history(&alice);
+        assert_eq!(history[0].elo_after, history[1].elo_before);
+        assert_eq!(history[0].opponent, bob);
+    }
+
+    #[test]
+    fn same_coach_for_both_teams() {
+        let team_a = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let team_b = test_utils::team(2, "Alice", Version::V5S3, 1);
+
+        let mut ratings = NafEloRatings::new(Some(64));
+
+        assert!(matches!(
+            ratings.process_game(&closed_game(1, &team_a, &team_b, None), 2),
+            Err(Error::SameCoachForBothTeams)
+        ));
+    }
+
+    #[test]
+    fn competition_games_processed_by_date() {
+        let team_a = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let team_b = test_utils::team(2, "Bob", Version::V5S3, 1);
+
+        let first_game = closed_game(1, &team_a, &team_b, Some(&team_a));
+        let mut second_game = closed_game(2, &team_a, &team_b, Some(&team_b));
+        second_game.game_at = first_game.game_at + chrono::Duration::days(1);
+
+        let mut ratings = NafEloRatings::new(None);
+        ratings
+            .process_competition(&[second_game, first_game])
+            .unwrap();
+
+        let history = ratings.history(&Coach::from_name("Alice"));
+        assert_eq!(history[0].game_id, 1);
+        assert_eq!(history[1].game_id, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn ratings_round_trip() {
+        use crate::serialization::{from_versioned_json, to_versioned_json};
+
+        let team_a = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let team_b = test_utils::team(2, "Bob", Version::V5S3, 1);
+
+        let mut ratings = NafEloRatings::new(Some(12));
+        ratings
+            .process_competition(&[closed_game(1, &team_a, &team_b, Some(&team_a))])
+            .unwrap();
+
+        let json = to_versioned_json(&ratings).unwrap();
+        let loaded: NafEloRatings = from_versioned_json(&json).unwrap();
+
+        let alice = Coach::from_name("Alice");
+        assert_eq!(loaded.competition_maximum_coaches_number, Some(12));
+        assert_eq!(loaded.elo(&alice), ratings.elo(&alice));
+        assert_eq!(loaded.history(&alice), ratings.history(&alice));
+        assert_eq!(loaded.ranking().len(), 2);
+    }
+}
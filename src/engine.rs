@@ -0,0 +1,1930 @@
+use crate::actions::Success;
+use crate::blocks::{
+    Block, BlockDiceChooser, BlockOutcome, armour_broken_on, mighty_blow_value, strength_modifier,
+};
+use crate::dices::{BlockDie, Dice};
+use crate::errors::Error;
+use crate::games::{Game, GameStatus};
+use crate::injuries::{Injury, InjuryCause};
+use crate::pitch::{Pitch, PitchFormat, PitchHalf, SetUp, Square};
+use crate::players::Player;
+use crate::probabilities::{ActionRoll, ActionRollKind, PassRange, ProbabilityCalculator};
+use crate::skills::{Skill, SkillUsage};
+use crate::staffs::Staff;
+use crate::teams::Team;
+use crate::versions::Version;
+use crate::weather::Weather;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub mod bot;
+
+const TURNS_PER_HALF: u8 = 8;
+const STAND_UP_COST: u8 = 3;
+const RUSHES: u8 = 2;
+const MAXIMUM_BOUNCES: u8 = 10;
+const MAXIMUM_CHAIN_PUSHES: u8 = 5;
+const DIRECTIONS: [(i8, i8); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum PlayerStatus {
+    Standing,
+    Prone,
+    Stunned,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum DugoutBox {
+    Reserves,
+    KnockedOut,
+    Casualty,
+    SentOff,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct OnPitchPlayer {
+    pub team_id: i32,
+    pub square: Square,
+    pub status: PlayerStatus,
+    pub has_acted: bool,
+    pub movement_used: u8,
+    pub used_pro: bool,
+    pub used_skill_re_rolls: Vec<Skill>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum BallPosition {
+    OffPitch,
+    OnGround(Square),
+    Carried(i32),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum EnginePhase {
+    SetUp { team_id: i32 },
+    KickOff { team_id: i32 },
+    Turn { team_id: i32 },
+    Finished,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum TurnAction {
+    Move {
+        player_id: i32,
+        path: Vec<Square>,
+    },
+    Block {
+        player_id: i32,
+        target_id: i32,
+    },
+    Blitz {
+        player_id: i32,
+        path: Vec<Square>,
+        target_id: i32,
+    },
+    Pass {
+        player_id: i32,
+        path: Vec<Square>,
+        target: Square,
+    },
+    HandOff {
+        player_id: i32,
+        path: Vec<Square>,
+        target_id: i32,
+    },
+    Foul {
+        player_id: i32,
+        path: Vec<Square>,
+        target_id: i32,
+    },
+    EndTurn,
+}
+
+#[derive(Debug)]
+pub struct GameEngine {
+    pub game: Game,
+    pub pitch: Pitch,
+    pub weather: Weather,
+    pub phase: EnginePhase,
+    pub half: u8,
+    pub turns: HashMap<i32, u8>,
+    pub team_re_rolls: HashMap<i32, u8>,
+    pub drive_re_rolls: HashMap<i32, u8>,
+    pub on_pitch: HashMap<i32, OnPitchPlayer>,
+    pub dugout: HashMap<i32, DugoutBox>,
+    pub ball: BallPosition,
+    pub blitz_used: bool,
+    pub pass_used: bool,
+    pub hand_off_used: bool,
+    pub foul_used: bool,
+    pub re_roll_used: bool,
+    first_half_kicking_team_id: i32,
+    drive_kicking_team_id: i32,
+    used_skills: Vec<(i32, Skill, SkillUsage)>,
+    possession: bool,
+    turnover: bool,
+    rng: StdRng,
+}
+
+impl GameEngine {
+    pub fn new(game: Game, seed: u64) -> Result<Self, Error> {
+        if game.version.ne(&Version::V5S3) {
+            return Err(Error::UnsupportedVersion);
+        }
+        if game.status().ne(&GameStatus::GameInProgress) {
+            return Err(Error::GameNotInProgress);
+        }
+
+        let kicking_team_id = game.kicking_team().ok_or(Error::GameNotInProgress)?.id;
+        let mut turns = HashMap::new();
+        let mut team_re_rolls = HashMap::new();
+        let mut dugout = HashMap::new();
+        for team in [&game.first_team, &game.second_team] {
+            turns.insert(team.id, 0);
+            team_re_rolls.insert(team.id, team.staff_quantity(&Staff::ReRoll));
+            for (_, player) in team.available_players() {
+                dugout.insert(player.id, DugoutBox::Reserves);
+            }
+        }
+
+        let mut engine = Self {
+            weather: game.weather().unwrap_or(Weather::PerfectConditions),
+            game,
+            pitch: Pitch::new(PitchFormat::Standard),
+            phase: EnginePhase::SetUp {
+                team_id: kicking_team_id,
+            },
+            half: 1,
+            turns,
+            team_re_rolls,
+            drive_re_rolls: HashMap::new(),
+            on_pitch: HashMap::new(),
+            dugout,
+            ball: BallPosition::OffPitch,
+            blitz_used: false,
+            pass_used: false,
+            hand_off_used: false,
+            foul_used: false,
+            re_roll_used: false,
+            first_half_kicking_team_id: kicking_team_id,
+            drive_kicking_team_id: kicking_team_id,
+            used_skills: Vec::new(),
+            possession: false,
+            turnover: false,
+            rng: StdRng::seed_from_u64(seed),
+        };
+        engine.start_drive(kicking_team_id);
+
+        Ok(engine)
+    }
+
+    pub fn waiting_for(&self) -> Option<i32> {
+        match self.phase {
+            EnginePhase::SetUp { team_id }
+            | EnginePhase::KickOff { team_id }
+            | EnginePhase::Turn { team_id } => Some(team_id),
+            EnginePhase::Finished => None,
+        }
+    }
+
+    pub fn team(&self, team_id: i32) -> Option<&Team> {
+        if self.game.first_team.id.eq(&team_id) {
+            Some(&self.game.first_team)
+        } else if self.game.second_team.id.eq(&team_id) {
+            Some(&self.game.second_team)
+        } else {
+            None
+        }
+    }
+
+    pub fn opponent_id(&self, team_id: i32) -> i32 {
+        if self.game.first_team.id.eq(&team_id) {
+            self.game.second_team.id
+        } else {
+            self.game.first_team.id
+        }
+    }
+
+    pub fn player(&self, player_id: i32) -> Option<&Player> {
+        self.game
+            .first_team
+            .players
+            .iter()
+            .chain(self.game.second_team.players.iter())
+            .map(|(_, player)| player)
+            .find(|player| player.id.eq(&player_id))
+    }
+
+    pub fn defended_half(&self, team_id: i32) -> PitchHalf {
+        if self.game.first_team.id.eq(&team_id) {
+            PitchHalf::Left
+        } else {
+            PitchHalf::Right
+        }
+    }
+
+    pub fn scoring_column(&self, team_id: i32) -> u8 {
+        self.pitch
+            .end_zone_column(&self.defended_half(team_id).opposite())
+    }
+
+    pub fn square_of(&self, player_id: i32) -> Option<Square> {
+        self.on_pitch.get(&player_id).map(|player| player.square)
+    }
+
+    pub fn player_at(&self, square: &Square) -> Option<i32> {
+        self.on_pitch
+            .iter()
+            .find(|(_, player)| player.square.eq(square))
+            .map(|(player_id, _)| *player_id)
+    }
+
+    pub fn ball_carrier(&self) -> Option<i32> {
+        match self.ball {
+            BallPosition::Carried(player_id) => Some(player_id),
+            _ => None,
+        }
+    }
+
+    pub fn reserves(&self, team_id: i32) -> Vec<i32> {
+        let mut reserves: Vec<i32> = self
+            .team(team_id)
+            .map(|team| {
+                team.players
+                    .iter()
+                    .map(|(_, player)| player.id)
+                    .filter(|player_id| self.dugout.get(player_id).eq(&Some(&DugoutBox::Reserves)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        reserves.sort();
+        reserves
+    }
+
+    pub fn players_on_pitch(&self, team_id: i32) -> Vec<(i32, OnPitchPlayer)> {
+        let mut players: Vec<(i32, OnPitchPlayer)> = self
+            .on_pitch
+            .iter()
+            .filter(|(_, player)| player.team_id.eq(&team_id))
+            .map(|(player_id, player)| (*player_id, player.clone()))
+            .collect();
+        players.sort_by_key(|(player_id, _)| *player_id);
+        players
+    }
+
+    pub fn marking_players(&self, square: &Square, team_id: i32) -> u8 {
+        self.marking_players_except(square, team_id, None)
+    }
+
+    fn marking_players_except(&self, square: &Square, team_id: i32, except: Option<i32>) -> u8 {
+        self.on_pitch
+            .iter()
+            .filter(|(player_id, player)| {
+                player.team_id.ne(&team_id)
+                    && player.status.eq(&PlayerStatus::Standing)
+                    && player.square.is_adjacent(square)
+                    && except.ne(&Some(**player_id))
+            })
+            .count() as u8
+    }
+
+    fn marked_by_skill(&self, square: &Square, team_id: i32, skill: &Skill) -> bool {
+        self.on_pitch.iter().any(|(player_id, player)| {
+            player.team_id.ne(&team_id)
+                && player.status.eq(&PlayerStatus::Standing)
+                && player.square.is_adjacent(square)
+                && self
+                    .player(*player_id)
+                    .is_some_and(|player| player.skills().contains(skill))
+        })
+    }
+
+    fn has_skill(&self, player_id: i32, skill: &Skill) -> bool {
+        self.player(player_id)
+            .is_some_and(|player| player.skills().contains(skill))
+    }
+
+    pub fn movement_left(&self, player_id: i32) -> u8 {
+        let Some(on_pitch) = self.on_pitch.get(&player_id) else {
+            return 0;
+        };
+        let movement_allowance = self
+            .player(player_id)
+            .and_then(|player| player.movement_allowance())
+            .unwrap_or(0);
+        let stand_up_cost = if on_pitch.status.eq(&PlayerStatus::Prone) {
+            STAND_UP_COST.min(movement_allowance)
+        } else {
+            0
+        };
+
+        movement_allowance.saturating_sub(on_pitch.movement_used + stand_up_cost)
+    }
+
+    pub fn assists(&self, attacker_id: i32, defender_id: i32) -> (u8, u8) {
+        let (Some(attacker), Some(defender)) = (
+            self.on_pitch.get(&attacker_id),
+            self.on_pitch.get(&defender_id),
+        ) else {
+            return (0, 0);
+        };
+
+        let count = |team_id: i32, excluded_id: i32, square: &Square, opponent_id: i32| {
+            self.on_pitch
+                .iter()
+                .filter(|(player_id, player)| {
+                    player.team_id.eq(&team_id)
+                        && player_id.ne(&&excluded_id)
+                        && player.status.eq(&PlayerStatus::Standing)
+                        && player.square.is_adjacent(square)
+                        && (self.has_skill(**player_id, &Skill::Guard)
+                            || self.marking_players_except(
+                                &player.square,
+                                team_id,
+                                Some(opponent_id),
+                            ) == 0)
+                })
+                .count() as u8
+        };
+
+        (
+            count(attacker.team_id, attacker_id, &defender.square, defender_id),
+            count(defender.team_id, defender_id, &attacker.square, attacker_id),
+        )
+    }
+
+    fn offset(&self, square: &Square, direction: (i8, i8), distance: u8) -> Option<Square> {
+        let x = square.x as i16 + direction.0 as i16 * distance as i16;
+        let y = square.y as i16 + direction.1 as i16 * distance as i16;
+        if x < 0 || y < 0 || x > u8::MAX as i16 || y > u8::MAX as i16 {
+            return None;
+        }
+
+        let square = Square::new(x as u8, y as u8);
+        self.pitch.contains(&square).then_some(square)
+    }
+
+    fn roll(&mut self, dice: Dice) -> u8 {
+        dice.roll_with(&mut self.rng) as u8
+    }
+
+    fn random_direction(&mut self) -> (i8, i8) {
+        DIRECTIONS[self.roll(Dice::D8) as usize - 1]
+    }
+
+    fn start_drive(&mut self, kicking_team_id: i32) {
+        let players_on_pitch: Vec<i32> = self.on_pitch.keys().copied().collect();
+        for player_id in players_on_pitch {
+            self.on_pitch.remove(&player_id);
+            self.dugout.insert(player_id, DugoutBox::Reserves);
+        }
+
+        let knocked_out: Vec<i32> = self
+            .dugout
+            .iter()
+            .filter(|(_, dugout_box)| dugout_box.eq(&&DugoutBox::KnockedOut))
+            .map(|(player_id, _)| *player_id)
+            .collect();
+        let mut knocked_out = knocked_out;
+        knocked_out.sort();
+        for player_id in knocked_out {
+            if self.roll(Dice::D6) >= 4 {
+                self.dugout.insert(player_id, DugoutBox::Reserves);
+            }
+        }
+
+        for (team_id, re_rolls) in self.drive_re_rolls.drain() {
+            self.team_re_rolls
+                .entry(team_id)
+                .and_modify(|team_re_rolls| *team_re_rolls = (*team_re_rolls).min(re_rolls));
+        }
+
+        self.ball = BallPosition::OffPitch;
+        self.drive_kicking_team_id = kicking_team_id;
+        self.phase = EnginePhase::SetUp {
+            team_id: kicking_team_id,
+        };
+    }
+
+    pub fn set_up(&mut self, set_up: SetUp) -> Result<(), Error> {
+        let EnginePhase::SetUp { team_id } = self.phase else {
+            return Err(Error::NotTeamTurn);
+        };
+        if set_up.team_id.ne(&team_id) {
+            return Err(Error::NotTeamTurn);
+        }
+        if set_up.half.ne(&self.defended_half(team_id)) {
+            return Err(Error::SquareNotInOwnHalf);
+        }
+
+        let reserves = self.reserves(team_id);
+        let mut team = self.team(team_id).ok_or(Error::NotAPlayingTeam)?.clone();
+        team.players
+            .retain(|(_, player)| reserves.contains(&player.id));
+        set_up.check_if_rules_compliant(&self.pitch, &team)?;
+
+        for (index, (player_id, square)) in set_up.placements.iter().enumerate() {
+            for (other_player_id, other_square) in set_up.placements.iter().skip(index + 1) {
+                if square.eq(other_square) {
+                    return Err(Error::SquareAlreadyOccupied);
+                }
+                if player_id.eq(other_player_id) {
+                    return Err(Error::PlayerAlreadyOnPitch);
+                }
+            }
+        }
+
+        for (player_id, square) in set_up.placements.iter() {
+            self.dugout.remove(player_id);
+            self.on_pitch.insert(
+                *player_id,
+                OnPitchPlayer {
+                    team_id,
+                    square: *square,
+                    status: PlayerStatus::Standing,
+                    has_acted: false,
+                    movement_used: 0,
+                    used_pro: false,
+                    used_skill_re_rolls: Vec::new(),
+                },
+            );
+        }
+
+        let opponent_id = self.opponent_id(team_id);
+        self.phase = if team_id.eq(&self.drive_kicking_team_id) {
+            EnginePhase::SetUp {
+                team_id: opponent_id,
+            }
+        } else {
+            EnginePhase::KickOff {
+                team_id: opponent_id,
+            }
+        };
+
+        Ok(())
+    }
+
+    pub fn kick_off(&mut self, target: Square) -> Result<(), Error> {
+        let EnginePhase::KickOff { team_id } = self.phase else {
+            return Err(Error::NotTeamTurn);
+        };
+        let receiving_team_id = self.opponent_id(team_id);
+        let receiving_half = self.defended_half(receiving_team_id);
+        if self.pitch.half(&target).ne(&Some(receiving_half)) {
+            return Err(Error::InvalidTarget);
+        }
+
+        self.kick_off_event(team_id);
+        let direction = self.random_direction();
+        let distance = self.roll(Dice::D6);
+        match self.offset(&target, direction, distance) {
+            Some(square) if self.pitch.half(&square).eq(&Some(receiving_half)) => {
+                self.land_ball(square, true, true);
+            }
+            _ => self.ball = BallPosition::OffPitch,
+        }
+
+        let touchback = match self.ball {
+            BallPosition::OffPitch => true,
+            BallPosition::OnGround(square) => self.pitch.half(&square).ne(&Some(receiving_half)),
+            BallPosition::Carried(player_id) => self
+                .on_pitch
+                .get(&player_id)
+                .is_none_or(|player| player.team_id.ne(&receiving_team_id)),
+        };
+        if touchback {
+            self.touchback(receiving_team_id);
+        }
+
+        self.next_turn(receiving_team_id)
+    }
+
+    fn kick_off_event(&mut self, kicking_team_id: i32) {
+        let receiving_team_id = self.opponent_id(kicking_team_id);
+        match self.roll(Dice::D6x2) {
+            3 => {
+                let kicking_team_turns = self.turns.get(&kicking_team_id).copied().unwrap_or(0);
+                for turns in self.turns.values_mut() {
+                    *turns = if kicking_team_turns >= 6 {
+                        turns.saturating_sub(1)
+                    } else {
+                        (*turns + 1).min(TURNS_PER_HALF)
+                    };
+                }
+            }
+            7 => {
+                let [kicking_team_total, receiving_team_total] =
+                    [kicking_team_id, receiving_team_id].map(|team_id| {
+                        self.team(team_id)
+                            .map(|team| team.staff_quantity(&Staff::AssistantCoach))
+                            .unwrap_or(0)
+                            + self.roll(Dice::D6)
+                    });
+                for (team_id, total, opponent_total) in [
+                    (kicking_team_id, kicking_team_total, receiving_team_total),
+                    (receiving_team_id, receiving_team_total, kicking_team_total),
+                ] {
+                    if total >= opponent_total {
+                        let re_rolls = self.team_re_rolls.entry(team_id).or_insert(0);
+                        self.drive_re_rolls.entry(team_id).or_insert(*re_rolls);
+                        *re_rolls += 1;
+                    }
+                }
+            }
+            8 => self.weather = Weather::from_roll(self.roll(Dice::D6x2)),
+            // Other results rely on coach decisions the engine does not model.
+            _ => {}
+        }
+    }
+
+    fn touchback(&mut self, team_id: i32) {
+        let center = Square::new(self.pitch.length / 2, self.pitch.width / 2);
+        self.ball = self
+            .players_on_pitch(team_id)
+            .into_iter()
+            .filter(|(_, player)| player.status.eq(&PlayerStatus::Standing))
+            .min_by_key(|(_, player)| player.square.distance(&center))
+            .map(|(player_id, _)| BallPosition::Carried(player_id))
+            .unwrap_or(BallPosition::OffPitch);
+    }
+
+    fn next_turn(&mut self, team_id: i32) -> Result<(), Error> {
+        let opponent_id = self.opponent_id(team_id);
+        let team_turns = self.turns.get(&team_id).copied().unwrap_or(0);
+        let opponent_turns = self.turns.get(&opponent_id).copied().unwrap_or(0);
+
+        if team_turns < TURNS_PER_HALF {
+            self.start_turn(team_id)
+        } else if opponent_turns < TURNS_PER_HALF {
+            self.start_turn(opponent_id)
+        } else {
+            self.end_half()
+        }
+    }
+
+    fn start_turn(&mut self, team_id: i32) -> Result<(), Error> {
+        let number = self.turns.entry(team_id).or_insert(0);
+        *number += 1;
+        let number = *number as usize;
+        self.game.push_turn_start(team_id, number)?;
+
+        self.blitz_used = false;
+        self.pass_used = false;
+        self.hand_off_used = false;
+        self.foul_used = false;
+        self.re_roll_used = false;
+        self.turnover = false;
+        for player in self.on_pitch.values_mut() {
+            if player.team_id.eq(&team_id) {
+                player.has_acted = false;
+                player.movement_used = 0;
+                player.used_pro = false;
+                player.used_skill_re_rolls.clear();
+                if player.status.eq(&PlayerStatus::Stunned) {
+                    player.status = PlayerStatus::Prone;
+                    player.has_acted = true;
+                }
+            }
+        }
+        self.phase = EnginePhase::Turn { team_id };
+
+        Ok(())
+    }
+
+    fn end_turn(&mut self) -> Result<(), Error> {
+        let EnginePhase::Turn { team_id } = self.phase else {
+            return Err(Error::NotTeamTurn);
+        };
+
+        self.next_turn(self.opponent_id(team_id))
+    }
+
+    fn end_half(&mut self) -> Result<(), Error> {
+        if self.half == 1 {
+            self.game.end_first_half()?;
+            self.half = 2;
+            for turns in self.turns.values_mut() {
+                *turns = 0;
+            }
+            self.used_skills
+                .retain(|(_, _, usage)| usage.eq(&SkillUsage::OncePerGame));
+            self.drive_re_rolls.clear();
+            for team in [&self.game.first_team, &self.game.second_team] {
+                self.team_re_rolls
+                    .insert(team.id, team.staff_quantity(&Staff::ReRoll));
+            }
+            self.start_drive(self.opponent_id(self.first_half_kicking_team_id));
+            Ok(())
+        } else {
+            self.phase = EnginePhase::Finished;
+            self.game.end_game()
+        }
+    }
+
+    fn check_touchdown(&mut self) -> Result<bool, Error> {
+        let BallPosition::Carried(player_id) = self.ball else {
+            return Ok(false);
+        };
+        let Some(carrier) = self.on_pitch.get(&player_id).cloned() else {
+            return Ok(false);
+        };
+        if carrier.status.ne(&PlayerStatus::Standing)
+            || carrier.square.x.ne(&self.scoring_column(carrier.team_id))
+        {
+            return Ok(false);
+        }
+
+        self.game
+            .push_success(carrier.team_id, player_id, Success::Touchdown)?;
+
+        let all_turns_played = self.turns.values().all(|turns| turns >= &TURNS_PER_HALF);
+        if all_turns_played {
+            self.end_half()?;
+        } else {
+            self.start_drive(carrier.team_id);
+        }
+
+        Ok(true)
+    }
+
+    fn check_can_act(&self, team_id: i32, player_id: i32) -> Result<OnPitchPlayer, Error> {
+        match self.on_pitch.get(&player_id) {
+            Some(player)
+                if player.team_id.eq(&team_id)
+                    && !player.has_acted
+                    && player.status.ne(&PlayerStatus::Stunned) =>
+            {
+                Ok(player.clone())
+            }
+            _ => Err(Error::PlayerCanNotAct),
+        }
+    }
+
+    fn check_path(&self, player_id: i32, path: &[Square], extra_cost: u8) -> Result<Square, Error> {
+        let mut current = self.square_of(player_id).ok_or(Error::PlayerCanNotAct)?;
+        let maximum_cost = self.movement_left(player_id) as usize + RUSHES as usize;
+        if path.len() + extra_cost as usize > maximum_cost {
+            return Err(Error::InvalidPath);
+        }
+
+        for square in path.iter() {
+            if !self.pitch.contains(square)
+                || !current.is_adjacent(square)
+                || self.player_at(square).is_some()
+            {
+                return Err(Error::InvalidPath);
+            }
+            current = *square;
+        }
+
+        Ok(current)
+    }
+
+    fn check_opponent_target(
+        &self,
+        team_id: i32,
+        target_id: i32,
+        from: &Square,
+        standing: bool,
+    ) -> Result<(), Error> {
+        match self.on_pitch.get(&target_id) {
+            Some(target)
+                if target.team_id.ne(&team_id)
+                    && target.square.is_adjacent(from)
+                    && target.status.eq(&PlayerStatus::Standing).eq(&standing) =>
+            {
+                Ok(())
+            }
+            _ => Err(Error::InvalidTarget),
+        }
+    }
+
+    fn is_standing(&self, player_id: i32) -> bool {
+        self.on_pitch
+            .get(&player_id)
+            .is_some_and(|player| player.status.eq(&PlayerStatus::Standing))
+    }
+
+    fn holds_ball(&self, player_id: i32) -> bool {
+        self.ball.eq(&BallPosition::Carried(player_id)) && self.is_standing(player_id)
+    }
+
+    pub fn perform(&mut self, action: TurnAction) -> Result<(), Error> {
+        let EnginePhase::Turn { team_id } = self.phase else {
+            return Err(Error::NotTeamTurn);
+        };
+
+        self.possession = self.ball_carrier().is_some_and(|player_id| {
+            self.on_pitch
+                .get(&player_id)
+                .is_some_and(|player| player.team_id.eq(&team_id))
+        });
+
+        match action {
+            TurnAction::EndTurn => return self.end_turn(),
+            TurnAction::Move { player_id, path } => {
+                self.check_can_act(team_id, player_id)?;
+                self.check_path(player_id, &path, 0)?;
+                self.mark_acted(player_id);
+                self.move_along(player_id, &path)?;
+            }
+            TurnAction::Block {
+                player_id,
+                target_id,
+            } => {
+                let player = self.check_can_act(team_id, player_id)?;
+                if player.status.ne(&PlayerStatus::Standing) {
+                    return Err(Error::PlayerCanNotAct);
+                }
+                self.check_opponent_target(team_id, target_id, &player.square, true)?;
+                self.mark_acted(player_id);
+                self.block(player_id, target_id, false)?;
+            }
+            TurnAction::Blitz {
+                player_id,
+                path,
+                target_id,
+            } => {
+                if self.blitz_used {
+                    return Err(Error::ActionAlreadyUsed);
+                }
+                self.check_can_act(team_id, player_id)?;
+                let destination = self.check_path(player_id, &path, 1)?;
+                self.check_opponent_target(team_id, target_id, &destination, true)?;
+                self.blitz_used = true;
+                self.mark_acted(player_id);
+                if self.move_along(player_id, &path)? {
+                    self.block(player_id, target_id, true)?;
+                }
+            }
+            TurnAction::Pass {
+                player_id,
+                path,
+                target,
+            } => {
+                if self.pass_used {
+                    return Err(Error::ActionAlreadyUsed);
+                }
+                self.check_can_act(team_id, player_id)?;
+                let destination = self.check_path(player_id, &path, 0)?;
+                if !self.pitch.contains(&target)
+                    || PassRange::from_distance(destination.distance(&target))
+                        .is_none_or(|range| !range.is_allowed_in(&self.weather))
+                {
+                    return Err(Error::InvalidTarget);
+                }
+                self.pass_used = true;
+                self.mark_acted(player_id);
+                if self.move_along(player_id, &path)? && self.holds_ball(player_id) {
+                    self.throw(player_id, target)?;
+                }
+            }
+            TurnAction::HandOff {
+                player_id,
+                path,
+                target_id,
+            } => {
+                if self.hand_off_used {
+                    return Err(Error::ActionAlreadyUsed);
+                }
+                self.check_can_act(team_id, player_id)?;
+                let destination = self.check_path(player_id, &path, 0)?;
+                match self.on_pitch.get(&target_id) {
+                    Some(target)
+                        if target_id.ne(&player_id)
+                            && target.team_id.eq(&team_id)
+                            && target.square.is_adjacent(&destination)
+                            && target.status.eq(&PlayerStatus::Standing) => {}
+                    _ => return Err(Error::InvalidTarget),
+                }
+                self.hand_off_used = true;
+                self.mark_acted(player_id);
+                if self.move_along(player_id, &path)? && self.holds_ball(player_id) {
+                    self.hand_off(target_id);
+                }
+            }
+            TurnAction::Foul {
+                player_id,
+                path,
+                target_id,
+            } => {
+                if self.foul_used {
+                    return Err(Error::ActionAlreadyUsed);
+                }
+                self.check_can_act(team_id, player_id)?;
+                let destination = self.check_path(player_id, &path, 0)?;
+                self.check_opponent_target(team_id, target_id, &destination, false)?;
+                self.foul_used = true;
+                self.mark_acted(player_id);
+                if self.move_along(player_id, &path)? {
+                    self.foul(player_id, target_id)?;
+                }
+            }
+        }
+
+        if self.check_touchdown()? {
+            return Ok(());
+        }
+
+        let still_in_possession = self.ball_carrier().is_some_and(|player_id| {
+            self.on_pitch
+                .get(&player_id)
+                .is_some_and(|player| player.team_id.eq(&team_id))
+        });
+        if self.possession && !still_in_possession {
+            self.turnover = true;
+        }
+
+        if self.turnover {
+            self.end_turn()?;
+        }
+
+        Ok(())
+    }
+
+    fn mark_acted(&mut self, player_id: i32) {
+        if let Some(player) = self.on_pitch.get_mut(&player_id) {
+            player.has_acted = true;
+        }
+    }
+
+    fn skill_re_roll(
+        &self,
+        player_id: i32,
+        skills: &[Skill],
+        kind: &ActionRollKind,
+    ) -> Option<(Skill, SkillUsage)> {
+        let trigger = kind.skill_trigger();
+        let used_skill_re_rolls = self
+            .on_pitch
+            .get(&player_id)?
+            .used_skill_re_rolls
+            .as_slice();
+        skills
+            .iter()
+            .filter(|skill| skill.ne(&&Skill::Pro))
+            .filter_map(|skill| {
+                skill
+                    .effect(&self.game.version)
+                    .filter(|effect| effect.re_rolls(&trigger))
+                    .map(|effect| (*skill, effect.usage))
+            })
+            .filter(|(skill, usage)| match usage {
+                SkillUsage::OncePerTurn => !used_skill_re_rolls.contains(skill),
+                SkillUsage::OncePerHalf | SkillUsage::OncePerGame => {
+                    !self.used_skills.contains(&(player_id, *skill, *usage))
+                }
+                _ => true,
+            })
+            .min_by_key(|(_, usage)| *usage)
+    }
+
+    fn use_skill_re_roll(&mut self, player_id: i32, skill: Skill, usage: SkillUsage) {
+        match usage {
+            SkillUsage::OncePerTurn => {
+                if let Some(player) = self.on_pitch.get_mut(&player_id) {
+                    player.used_skill_re_rolls.push(skill);
+                }
+            }
+            SkillUsage::OncePerHalf | SkillUsage::OncePerGame => {
+                self.used_skills.push((player_id, skill, usage))
+            }
+            _ => {}
+        }
+    }
+
+    fn use_team_re_roll(&mut self, player_id: i32) -> Option<bool> {
+        let EnginePhase::Turn { team_id } = self.phase else {
+            return None;
+        };
+        let player = self.on_pitch.get(&player_id)?;
+        let re_rolls = self.team_re_rolls.get(&team_id).copied().unwrap_or(0);
+        if player.team_id.ne(&team_id) || self.re_roll_used || re_rolls == 0 {
+            return None;
+        }
+
+        self.re_roll_used = true;
+        self.team_re_rolls.insert(team_id, re_rolls - 1);
+        let loner = self.player(player_id).and_then(|player| {
+            player.skills().iter().find_map(|skill| match skill {
+                Skill::Loner(target) => Some(*target),
+                _ => None,
+            })
+        });
+
+        Some(loner.is_none_or(|target| self.roll(Dice::D6) >= target))
+    }
+
+    fn use_pro(&mut self, player_id: i32) -> Option<bool> {
+        let player = self.on_pitch.get(&player_id).cloned()?;
+        if player.used_pro || !self.has_skill(player_id, &Skill::Pro) {
+            return None;
+        }
+
+        if let Some(player) = self.on_pitch.get_mut(&player_id) {
+            player.used_pro = true;
+        }
+
+        Some(self.roll(Dice::D6) >= 3)
+    }
+
+    fn test(
+        &mut self,
+        player_id: i32,
+        roll: ActionRoll,
+        skill_re_roll_allowed: bool,
+    ) -> (bool, u8) {
+        let Some(calculator) = self
+            .player(player_id)
+            .and_then(|player| ProbabilityCalculator::new(player, self.weather.clone(), 0).ok())
+        else {
+            return (false, 1);
+        };
+        let target = calculator.target(&roll);
+        let modifier = calculator.modifier(&roll);
+        let succeeds = |natural: u8| {
+            natural == 6
+                || (natural != 1
+                    && target.is_some_and(|target| natural as i8 + modifier >= target as i8))
+        };
+
+        let natural = self.roll(Dice::D6);
+        if succeeds(natural) {
+            return (true, natural);
+        }
+
+        let skill_re_roll = self
+            .skill_re_roll(player_id, &calculator.skills, &roll.kind)
+            .filter(|_| skill_re_roll_allowed);
+        let re_roll = if let Some((skill, usage)) = skill_re_roll {
+            self.use_skill_re_roll(player_id, skill, usage);
+            Some(true)
+        } else {
+            self.use_pro(player_id)
+                .or_else(|| self.use_team_re_roll(player_id))
+        };
+
+        match re_roll {
+            Some(true) => {
+                let natural = self.roll(Dice::D6);
+                (succeeds(natural), natural)
+            }
+            _ => (false, natural),
+        }
+    }
+
+    fn move_along(&mut self, player_id: i32, path: &[Square]) -> Result<bool, Error> {
+        let Some(player) = self.on_pitch.get(&player_id).cloned() else {
+            return Ok(false);
+        };
+        let team_id = player.team_id;
+        let movement_allowance = self
+            .player(player_id)
+            .and_then(|player| player.movement_allowance())
+            .unwrap_or(0);
+
+        if player.status.eq(&PlayerStatus::Prone) {
+            if movement_allowance < STAND_UP_COST && self.roll(Dice::D6) < 4 {
+                return Ok(false);
+            }
+            if let Some(player) = self.on_pitch.get_mut(&player_id) {
+                player.status = PlayerStatus::Standing;
+                player.movement_used += STAND_UP_COST.min(movement_allowance);
+            }
+        }
+
+        for square in path.iter() {
+            let Some(player) = self.on_pitch.get(&player_id).cloned() else {
+                return Ok(false);
+            };
+
+            if player.movement_used >= movement_allowance {
+                let (success, _) =
+                    self.test(player_id, ActionRoll::new(ActionRollKind::Rush), true);
+                if !success {
+                    self.place_player(player_id, *square);
+                    self.knock_down(player_id, InjuryCause::FailedRush, None)?;
+                    return Ok(false);
+                }
+            }
+
+            let was_marked = self.marking_players(&player.square, team_id) > 0;
+            let tackled = self.marked_by_skill(&player.square, team_id, &Skill::Tackle);
+            self.place_player(player_id, *square);
+            if let Some(player) = self.on_pitch.get_mut(&player_id) {
+                player.movement_used += 1;
+            }
+
+            if was_marked {
+                let marking_players = self.marking_players(square, team_id);
+                let (success, _) = self.test(
+                    player_id,
+                    ActionRoll::new(ActionRollKind::Dodge).marked_by(marking_players),
+                    !tackled,
+                );
+                if !success {
+                    self.knock_down(player_id, InjuryCause::FailedDodge, None)?;
+                    return Ok(false);
+                }
+            }
+
+            if self.ball.eq(&BallPosition::OnGround(*square)) && !self.pick_up(player_id) {
+                self.turnover = true;
+                return Ok(false);
+            }
+
+            if self.holds_ball(player_id) && square.x.eq(&self.scoring_column(team_id)) {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn place_player(&mut self, player_id: i32, square: Square) {
+        if let Some(player) = self.on_pitch.get_mut(&player_id) {
+            player.square = square;
+        }
+    }
+
+    fn pick_up(&mut self, player_id: i32) -> bool {
+        let Some(player) = self.on_pitch.get(&player_id).cloned() else {
+            return false;
+        };
+        let marking_players = self.marking_players(&player.square, player.team_id);
+        let (success, _) = self.test(
+            player_id,
+            ActionRoll::new(ActionRollKind::PickUp).marked_by(marking_players),
+            true,
+        );
+
+        if success {
+            self.ball = BallPosition::Carried(player_id);
+        } else {
+            self.bounce_from(player.square, false);
+        }
+
+        success
+    }
+
+    fn catch(&mut self, player_id: i32, accurate_pass: bool) -> bool {
+        let Some(player) = self.on_pitch.get(&player_id).cloned() else {
+            return false;
+        };
+        if player.status.ne(&PlayerStatus::Standing) {
+            return false;
+        }
+
+        let marking_players = self.marking_players(&player.square, player.team_id);
+        let (success, _) = self.test(
+            player_id,
+            ActionRoll::new(ActionRollKind::Catch { accurate_pass }).marked_by(marking_players),
+            true,
+        );
+        if success {
+            self.ball = BallPosition::Carried(player_id);
+        }
+
+        success
+    }
+
+    fn land_ball(&mut self, square: Square, accurate_pass: bool, kick_off: bool) {
+        match self.player_at(&square) {
+            Some(player_id) if self.is_standing(player_id) => {
+                if !self.catch(player_id, accurate_pass) {
+                    self.bounce_from(square, kick_off);
+                }
+            }
+            Some(_) => self.bounce_from(square, kick_off),
+            None => {
+                self.ball = BallPosition::OnGround(square);
+                if !kick_off {
+                    self.bounce_from(square, kick_off);
+                }
+            }
+        }
+    }
+
+    fn bounce_from(&mut self, square: Square, kick_off: bool) {
+        let mut from = square;
+
+        for _ in 0..MAXIMUM_BOUNCES {
+            let direction = self.random_direction();
+            let Some(destination) = self.offset(&from, direction, 1) else {
+                if kick_off {
+                    self.ball = BallPosition::OffPitch;
+                } else {
+                    self.throw_in(from);
+                }
+                return;
+            };
+
+            match self.player_at(&destination) {
+                Some(player_id) if self.is_standing(player_id) => {
+                    if self.catch(player_id, false) {
+                        return;
+                    }
+                }
+                Some(_) => {}
+                None => {
+                    self.ball = BallPosition::OnGround(destination);
+                    return;
+                }
+            }
+            from = destination;
+        }
+
+        self.drop_ball_near(from);
+    }
+
+    fn throw_in(&mut self, from: Square) {
+        let center = Square::new(self.pitch.length / 2, self.pitch.width / 2);
+        let direction = (
+            (center.x as i8 - from.x as i8).signum(),
+            (center.y as i8 - from.y as i8).signum(),
+        );
+        let distance = self.roll(Dice::D6x2);
+
+        let mut current = from;
+        for _ in 0..distance {
+            match self.offset(&current, direction, 1) {
+                Some(square) => current = square,
+                None => break,
+            }
+        }
+
+        match self.player_at(&current) {
+            Some(player_id) if self.is_standing(player_id) && self.catch(player_id, false) => {}
+            _ => self.drop_ball_near(current),
+        }
+    }
+
+    fn drop_ball_near(&mut self, square: Square) {
+        let empty_square = std::iter::once(Some(square))
+            .chain(
+                DIRECTIONS
+                    .iter()
+                    .map(|direction| self.offset(&square, *direction, 1)),
+            )
+            .flatten()
+            .find(|square| self.player_at(square).is_none());
+
+        self.ball = BallPosition::OnGround(empty_square.unwrap_or(square));
+    }
+
+    fn throw(&mut self, thrower_id: i32, target: Square) -> Result<(), Error> {
+        let Some(thrower) = self.on_pitch.get(&thrower_id).cloned() else {
+            return Ok(());
+        };
+        let Some(range) = PassRange::from_distance(thrower.square.distance(&target))
+            .filter(|range| range.is_allowed_in(&self.weather))
+        else {
+            return Err(Error::InvalidTarget);
+        };
+        let marking_players = self.marking_players(&thrower.square, thrower.team_id);
+        let roll = ActionRoll::new(ActionRollKind::Pass(range)).marked_by(marking_players);
+        let modifier = self
+            .player(thrower_id)
+            .and_then(|player| ProbabilityCalculator::new(player, self.weather.clone(), 0).ok())
+            .map(|calculator| calculator.modifier(&roll))
+            .unwrap_or(0);
+
+        let (accurate, natural) = self.test(thrower_id, roll, true);
+        if accurate {
+            self.land_ball(target, true, false);
+        } else if natural == 1 {
+            self.ball = BallPosition::OnGround(thrower.square);
+            self.bounce_from(thrower.square, false);
+            self.turnover = true;
+        } else if natural as i8 + modifier <= 1 {
+            let direction = self.random_direction();
+            let distance = self.roll(Dice::D6);
+            match self.offset(&thrower.square, direction, distance) {
+                Some(square) => self.land_ball(square, false, false),
+                None => self.throw_in(thrower.square),
+            }
+        } else {
+            let mut current = target;
+            for _ in 0..3 {
+                let direction = self.random_direction();
+                match self.offset(&current, direction, 1) {
+                    Some(square) => current = square,
+                    None => {
+                        self.throw_in(current);
+                        return Ok(());
+                    }
+                }
+            }
+            self.land_ball(current, false, false);
+        }
+
+        if let Some(receiver_id) = self.ball_carrier()
+            && accurate
+            && receiver_id.ne(&thrower_id)
+            && self
+                .on_pitch
+                .get(&receiver_id)
+                .is_some_and(|receiver| receiver.team_id.eq(&thrower.team_id))
+        {
+            self.game
+                .push_success(thrower.team_id, thrower_id, Success::PassingCompletion)?;
+        }
+
+        Ok(())
+    }
+
+    fn hand_off(&mut self, receiver_id: i32) {
+        let Some(receiver) = self.on_pitch.get(&receiver_id).cloned() else {
+            return;
+        };
+
+        if !self.catch(receiver_id, true) {
+            self.bounce_from(receiver.square, false);
+        }
+    }
+
+    fn block(&mut self, attacker_id: i32, defender_id: i32, blitz: bool) -> Result<(), Error> {
+        let (Some(attacker), Some(defender)) = (
+            self.player(attacker_id).cloned(),
+            self.player(defender_id).cloned(),
+        ) else {
+            return Ok(());
+        };
+
+        if blitz {
+            let movement_allowance = attacker.movement_allowance().unwrap_or(0);
+            let movement_used = self
+                .on_pitch
+                .get(&attacker_id)
+                .map(|player| player.movement_used)
+                .unwrap_or(0);
+            if movement_used >= movement_allowance {
+                let (success, _) =
+                    self.test(attacker_id, ActionRoll::new(ActionRollKind::Rush), true);
+                if !success {
+                    self.knock_down(attacker_id, InjuryCause::FailedRush, None)?;
+                    return Ok(());
+                }
+            }
+            if let Some(player) = self.on_pitch.get_mut(&attacker_id) {
+                player.movement_used += 1;
+            }
+        }
+
+        let (offensive_assists, defensive_assists) = self.assists(attacker_id, defender_id);
+        let mut attacker_strength =
+            attacker.strength().unwrap_or(0) + strength_modifier(&attacker, blitz);
+        let defender_strength = defender.strength().unwrap_or(0);
+        if attacker.skills().contains(&Skill::Dauntless)
+            && defender_strength > attacker_strength
+            && self.roll(Dice::D6) + attacker_strength >= defender_strength
+        {
+            attacker_strength = defender_strength;
+        }
+
+        let (dice, chooser) = Block::dice(
+            attacker_strength + offensive_assists,
+            defender_strength + defensive_assists,
+        );
+        let mut block = Block::new(&attacker, &defender);
+        if blitz {
+            block = block.as_blitz();
+        }
+
+        let mut outcome = self.roll_block_dice(&block, dice, chooser);
+        if outcome.is_turnover() {
+            let re_roll = self
+                .use_pro(attacker_id)
+                .or_else(|| self.use_team_re_roll(attacker_id));
+            if re_roll.eq(&Some(true)) {
+                outcome = self.roll_block_dice(&block, dice, chooser);
+            }
+        }
+
+        match outcome {
+            BlockOutcome::AttackerDown => {
+                self.knock_down(attacker_id, InjuryCause::Block, Some(defender_id))?;
+            }
+            BlockOutcome::BothDown => {
+                self.knock_down(defender_id, InjuryCause::Block, Some(attacker_id))?;
+                self.knock_down(attacker_id, InjuryCause::Block, Some(defender_id))?;
+            }
+            BlockOutcome::BothPlacedProne => {
+                self.place_prone(defender_id);
+                self.place_prone(attacker_id);
+            }
+            BlockOutcome::NoEffect => {}
+            BlockOutcome::DefenderPushed | BlockOutcome::DefenderDown => {
+                let Some(attacker_square) = self.square_of(attacker_id) else {
+                    return Ok(());
+                };
+                let Some(defender_square) = self.square_of(defender_id) else {
+                    return Ok(());
+                };
+
+                let still_on_pitch = block.defender_stands_firm()
+                    || self.push_player(defender_id, attacker_square, 0)?;
+                if !block.defender_stands_firm() && !defender.skills().contains(&Skill::Fend) {
+                    self.place_player(attacker_id, defender_square);
+                    if self.ball.eq(&BallPosition::OnGround(defender_square)) {
+                        self.bounce_from(defender_square, false);
+                    }
+                }
+                if still_on_pitch && outcome.eq(&BlockOutcome::DefenderDown) {
+                    self.knock_down(defender_id, InjuryCause::Block, Some(attacker_id))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn roll_block_dice(
+        &mut self,
+        block: &Block,
+        dice: u8,
+        chooser: BlockDiceChooser,
+    ) -> BlockOutcome {
+        let outcomes: Vec<BlockOutcome> = (0..dice)
+            .map(|_| block.outcome(&BlockDie::roll_with(&mut self.rng)))
+            .collect();
+
+        match chooser {
+            BlockDiceChooser::Attacker => outcomes
+                .into_iter()
+                .max_by_key(|outcome| outcome.attacker_preference()),
+            BlockDiceChooser::Defender => outcomes
+                .into_iter()
+                .max_by_key(|outcome| outcome.defender_preference()),
+        }
+        .unwrap_or(BlockOutcome::NoEffect)
+    }
+
+    fn push_candidates(&self, pusher: &Square, pushed: &Square) -> Vec<Option<Square>> {
+        let direction = (
+            (pushed.x as i8 - pusher.x as i8).signum(),
+            (pushed.y as i8 - pusher.y as i8).signum(),
+        );
+        let sides = if direction.0 == 0 {
+            [(-1, direction.1), (1, direction.1)]
+        } else if direction.1 == 0 {
+            [(direction.0, -1), (direction.0, 1)]
+        } else {
+            [(direction.0, 0), (0, direction.1)]
+        };
+
+        std::iter::once(direction)
+            .chain(sides)
+            .map(|direction| self.offset(pushed, direction, 1))
+            .collect()
+    }
+
+    fn push_player(&mut self, player_id: i32, pusher: Square, depth: u8) -> Result<bool, Error> {
+        let Some(square) = self.square_of(player_id) else {
+            return Ok(false);
+        };
+        let candidates = self.push_candidates(&pusher, &square);
+
+        if candidates.iter().any(|candidate| candidate.is_none()) {
+            self.push_into_crowd(player_id)?;
+            return Ok(false);
+        }
+
+        let destination = match candidates
+            .iter()
+            .flatten()
+            .find(|candidate| self.player_at(candidate).is_none())
+        {
+            Some(destination) => *destination,
+            None if depth < MAXIMUM_CHAIN_PUSHES => {
+                let Some(destination) = candidates.first().copied().flatten() else {
+                    return Ok(true);
+                };
+                if let Some(occupant_id) = self.player_at(&destination) {
+                    self.push_player(occupant_id, square, depth + 1)?;
+                }
+                if self.player_at(&destination).is_some() {
+                    return Ok(true);
+                }
+                destination
+            }
+            None => return Ok(true),
+        };
+
+        self.place_player(player_id, destination);
+        if self.ball.eq(&BallPosition::OnGround(destination)) {
+            self.bounce_from(destination, false);
+        }
+
+        Ok(true)
+    }
+
+    fn push_into_crowd(&mut self, player_id: i32) -> Result<(), Error> {
+        let Some(player) = self.on_pitch.remove(&player_id) else {
+            return Ok(());
+        };
+        self.game
+            .push_pushed_into_crowd(player.team_id, player_id)?;
+        if self.ball.eq(&BallPosition::Carried(player_id)) {
+            self.throw_in(player.square);
+        }
+
+        self.dugout.insert(player_id, DugoutBox::Reserves);
+        self.injury(player_id, player.team_id, InjuryCause::Crowd, None, 0)?;
+        Ok(())
+    }
+
+    fn place_prone(&mut self, player_id: i32) {
+        let Some(player) = self.on_pitch.get_mut(&player_id) else {
+            return;
+        };
+        player.status = PlayerStatus::Prone;
+        let square = player.square;
+        let team_id = player.team_id;
+
+        if self.ball.eq(&BallPosition::Carried(player_id)) {
+            self.bounce_from(square, false);
+        }
+        if self.phase.eq(&EnginePhase::Turn { team_id }) && self.possession {
+            self.turnover |= self.ball_carrier().is_none_or(|carrier_id| {
+                self.on_pitch
+                    .get(&carrier_id)
+                    .is_none_or(|carrier| carrier.team_id.ne(&team_id))
+            });
+        }
+    }
+
+    fn knock_down(
+        &mut self,
+        player_id: i32,
+        cause: InjuryCause,
+        inflicting_player_id: Option<i32>,
+    ) -> Result<(), Error> {
+        let Some(player) = self.on_pitch.get_mut(&player_id) else {
+            return Ok(());
+        };
+        player.status = PlayerStatus::Prone;
+        let square = player.square;
+        let team_id = player.team_id;
+
+        if self.phase.eq(&EnginePhase::Turn { team_id }) {
+            self.turnover = true;
+        }
+        if self.ball.eq(&BallPosition::Carried(player_id)) {
+            self.bounce_from(square, false);
+        }
+
+        self.armour(player_id, cause, inflicting_player_id, 0)?;
+        Ok(())
+    }
+
+    fn armour(
+        &mut self,
+        player_id: i32,
+        cause: InjuryCause,
+        inflicting_player_id: Option<i32>,
+        modifier: i8,
+    ) -> Result<bool, Error> {
+        let Some(victim) = self.player(player_id).cloned() else {
+            return Ok(false);
+        };
+        let team_id = self
+            .on_pitch
+            .get(&player_id)
+            .map(|player| player.team_id)
+            .ok_or(Error::PlayerNotAvailable)?;
+        let armour_value = victim.armour_value().ok_or(Error::PositionNotDefined)? as i8;
+        let (mighty_blow, claws) =
+            match (cause, inflicting_player_id.and_then(|id| self.player(id))) {
+                // Only the blocking player, never a defender knocking the attacker down.
+                (InjuryCause::Block, Some(attacker))
+                    if self.phase.ne(&EnginePhase::Turn { team_id }) =>
+                {
+                    (
+                        mighty_blow_value(attacker) as i8,
+                        armour_broken_on(attacker),
+                    )
+                }
+                _ => (0, None),
+            };
+
+        let first_die = self.roll(Dice::D6);
+        let second_die = self.roll(Dice::D6);
+        let natural = (first_die + second_die) as i8;
+        let armour_roll = natural + modifier;
+
+        let injury_modifier =
+            if armour_roll >= armour_value || claws.is_some_and(|claws| natural >= claws as i8) {
+                mighty_blow
+            } else if mighty_blow > 0 && armour_roll + mighty_blow >= armour_value {
+                0
+            } else {
+                return Ok(first_die == second_die);
+            };
+
+        let doubles = self.injury(
+            player_id,
+            team_id,
+            cause,
+            inflicting_player_id,
+            injury_modifier,
+        )?;
+        Ok(doubles || first_die == second_die)
+    }
+
+    fn injury(
+        &mut self,
+        player_id: i32,
+        team_id: i32,
+        cause: InjuryCause,
+        inflicting_player_id: Option<i32>,
+        modifier: i8,
+    ) -> Result<bool, Error> {
+        let Some(victim) = self.player(player_id).cloned() else {
+            return Ok(false);
+        };
+        let skills = victim.skills();
+        let first_die = self.roll(Dice::D6);
+        let second_die = self.roll(Dice::D6);
+        let roll = (first_die as i8 + second_die as i8 + modifier).max(2) as u8;
+
+        let injury = match Injury::from_injury_roll(
+            &self.game.version,
+            roll,
+            skills.contains(&Skill::Stunty),
+            skills.contains(&Skill::ThickSkull),
+        )? {
+            Some(injury) => injury,
+            None => {
+                let d16 = self.roll(Dice::D16);
+                let d6 = self.roll(Dice::D6);
+                Injury::from_casualty_roll(&self.game.version, d16, d6)?
+            }
+        };
+
+        match injury {
+            Injury::Stunned => {
+                if let Some(player) = self.on_pitch.get_mut(&player_id) {
+                    player.status = PlayerStatus::Stunned;
+                }
+            }
+            Injury::KO => {
+                self.on_pitch.remove(&player_id);
+                self.dugout.insert(player_id, DugoutBox::KnockedOut);
+            }
+            injury => {
+                self.on_pitch.remove(&player_id);
+                self.dugout.insert(player_id, DugoutBox::Casualty);
+                if let (InjuryCause::Block, Some(inflicting_player_id)) =
+                    (cause, inflicting_player_id)
+                {
+                    self.game.push_success(
+                        self.opponent_id(team_id),
+                        inflicting_player_id,
+                        Success::Casualty,
+                    )?;
+                }
+                self.game.push_injury_with_cause(
+                    team_id,
+                    player_id,
+                    injury,
+                    cause,
+                    inflicting_player_id,
+                )?;
+            }
+        }
+
+        Ok(first_die == second_die)
+    }
+
+    fn foul(&mut self, fouler_id: i32, target_id: i32) -> Result<(), Error> {
+        let Some(fouler) = self.on_pitch.get(&fouler_id).cloned() else {
+            return Ok(());
+        };
+        if fouler.status.ne(&PlayerStatus::Standing) {
+            return Ok(());
+        }
+
+        let (offensive_assists, defensive_assists) = self.assists(fouler_id, target_id);
+        let doubles = self.armour(
+            target_id,
+            InjuryCause::Foul,
+            Some(fouler_id),
+            offensive_assists as i8 - defensive_assists as i8,
+        )?;
+
+        if doubles {
+            self.on_pitch.remove(&fouler_id);
+            self.dugout.insert(fouler_id, DugoutBox::SentOff);
+            self.game.push_sent_off(fouler.team_id, fouler_id)?;
+            if self.ball.eq(&BallPosition::Carried(fouler_id)) {
+                self.bounce_from(fouler.square, false);
+            }
+            self.turnover = true;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bot::{Bot, play_game};
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::test_utils;
+
+    fn game(version: Version) -> Game {
+        let game_at = test_utils::date();
+        let mut game = Game::create(
+            1,
+            None,
+            version,
+            game_at,
+            &Team {
+                staff: HashMap::from([(Staff::ReRoll, 3)]),
+                ..test_utils::team(1, "Reds", version, 11)
+            },
+            &Team {
+                staff: HashMap::from([(Staff::ReRoll, 3)]),
+                ..test_utils::team(2, "Blues", version, 11)
+            },
+            false,
+        )
+        .unwrap();
+        game.start();
+        game.push_kicking_team(1).unwrap();
+        game
+    }
+
+    fn engine_after_kick_off(seed: u64, skills: &[(i32, Skill)]) -> Result<GameEngine, Error> {
+        let mut game = game(Version::V5S3);
+        for team in [&mut game.first_team, &mut game.second_team] {
+            for (_, player) in team.players.iter_mut() {
+                player.advancements.extend(
+                    skills
+                        .iter()
+                        .filter(|(player_id, _)| player_id.eq(&player.id))
+                        .map(|(_, skill)| Advancement::ChosenSkill(*skill)),
+                );
+            }
+        }
+
+        let mut engine = GameEngine::new(game, seed)?;
+        let kicking = Bot::new(1);
+        engine.set_up(kicking.set_up(&engine))?;
+        engine.set_up(Bot::new(2).set_up(&engine))?;
+        engine.kick_off(kicking.kick_off_target(&engine))?;
+        Ok(engine)
+    }
+
+    #[test]
+    fn set_up_and_actions() -> Result<(), Error> {
+        assert!(matches!(
+            GameEngine::new(game(Version::V5), 1),
+            Err(Error::UnsupportedVersion)
+        ));
+
+        let mut engine = GameEngine::new(game(Version::V5S3), 1)?;
+        assert_eq!(engine.phase, EnginePhase::SetUp { team_id: 1 });
+        assert!(matches!(
+            engine.perform(TurnAction::EndTurn),
+            Err(Error::NotTeamTurn)
+        ));
+
+        let mut set_up = SetUp::new(1, PitchHalf::Left);
+        set_up.place(&engine.pitch, 101, Square::new(5, 7))?;
+        assert!(matches!(
+            engine.set_up(set_up),
+            Err(Error::NotEnoughPlayersOnLineOfScrimmage)
+        ));
+
+        let kicking = Bot::new(1);
+        let receiving = Bot::new(2);
+        engine.set_up(kicking.set_up(&engine))?;
+        engine.set_up(receiving.set_up(&engine))?;
+        assert_eq!(engine.players_on_pitch(1).len(), 11);
+        assert_eq!(engine.phase, EnginePhase::KickOff { team_id: 1 });
+
+        engine.kick_off(kicking.kick_off_target(&engine))?;
+        assert_eq!(engine.phase, EnginePhase::Turn { team_id: 2 });
+        assert!(
+            engine
+                .game
+                .events
+                .contains(&crate::events::GameEvent::TurnStart {
+                    team_id: 2,
+                    number: 1
+                })
+        );
+
+        let (lineman_id, lineman) = engine.players_on_pitch(2)[0].clone();
+        assert!(matches!(
+            engine.perform(TurnAction::Move {
+                player_id: lineman_id,
+                path: vec![Square::new(lineman.square.x + 5, lineman.square.y)],
+            }),
+            Err(Error::InvalidPath)
+        ));
+        assert!(matches!(
+            engine.perform(TurnAction::Block {
+                player_id: 101,
+                target_id: lineman_id,
+            }),
+            Err(Error::PlayerCanNotAct)
+        ));
+
+        engine.perform(TurnAction::EndTurn)?;
+        assert_eq!(engine.phase, EnginePhase::Turn { team_id: 1 });
+
+        Ok(())
+    }
+
+    #[test]
+    fn bots_play_full_game() -> Result<(), Error> {
+        let mut first_engine = GameEngine::new(game(Version::V5S3), 42)?;
+        play_game(&mut first_engine, &[Bot::new(1), Bot::new(2)])?;
+
+        assert_eq!(first_engine.phase, EnginePhase::Finished);
+        assert!(first_engine.game.game_finished());
+        assert!(first_engine.game.first_half_finished());
+        let turns = first_engine
+            .game
+            .events
+            .iter()
+            .filter(|event| matches!(event, crate::events::GameEvent::TurnStart { .. }))
+            .count();
+        assert_eq!(turns, 32);
+
+        let mut second_engine = GameEngine::new(game(Version::V5S3), 42)?;
+        play_game(&mut second_engine, &[Bot::new(1), Bot::new(2)])?;
+        assert_eq!(first_engine.game.events, second_engine.game.events);
+
+        let mut touchdowns = 0;
+        for seed in 0..3 {
+            let mut engine = GameEngine::new(game(Version::V5S3), seed)?;
+            play_game(&mut engine, &[Bot::new(1), Bot::new(2)])?;
+            let (first_score, second_score) = engine.game.score();
+            touchdowns += first_score + second_score;
+        }
+        assert!(touchdowns > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn skill_re_rolls() -> Result<(), Error> {
+        let mut engine = engine_after_kick_off(
+            1,
+            &[
+                (201, Skill::SureFeet),
+                (202, Skill::SureHands),
+                (203, Skill::HalflingLuck),
+            ],
+        )?;
+        let sure_feet = engine.player(201).unwrap().skills();
+        let rush = ActionRollKind::Rush;
+        assert_eq!(
+            engine.skill_re_roll(201, &sure_feet, &rush),
+            Some((Skill::SureFeet, SkillUsage::OncePerTurn))
+        );
+        engine.use_skill_re_roll(201, Skill::SureFeet, SkillUsage::OncePerTurn);
+        assert_eq!(engine.skill_re_roll(201, &sure_feet, &rush), None);
+
+        let sure_hands = engine.player(202).unwrap().skills();
+        let pick_up = ActionRollKind::PickUp;
+        engine.use_skill_re_roll(202, Skill::SureHands, SkillUsage::Optional);
+        assert!(engine.skill_re_roll(202, &sure_hands, &pick_up).is_some());
+
+        let halfling_luck = engine.player(203).unwrap().skills();
+        engine.use_skill_re_roll(203, Skill::HalflingLuck, SkillUsage::OncePerGame);
+
+        engine.start_turn(2)?;
+        assert!(engine.skill_re_roll(201, &sure_feet, &rush).is_some());
+        assert_eq!(engine.skill_re_roll(203, &halfling_luck, &rush), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn mighty_blow_only_for_the_blocker() -> Result<(), Error> {
+        let mighty_blow = [(101, Skill::MightyBlow), (201, Skill::MightyBlow)];
+        let mut blocker_bonus = false;
+        for seed in 0..100 {
+            let mut engine = engine_after_kick_off(seed, &mighty_blow)?;
+            let mut control = engine_after_kick_off(seed, &[])?;
+            for engine in [&mut engine, &mut control] {
+                engine.knock_down(202, InjuryCause::Block, Some(101))?;
+            }
+            assert_eq!(engine.game.events, control.game.events);
+            assert_eq!(engine.dugout, control.dugout);
+
+            for engine in [&mut engine, &mut control] {
+                engine.knock_down(102, InjuryCause::Block, Some(201))?;
+            }
+            blocker_bonus |= engine.dugout.ne(&control.dugout);
+        }
+        assert!(blocker_bonus);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stand_firm_is_not_pushed() -> Result<(), Error> {
+        let attacker_square = Square::new(1, 1);
+        let defender_square = Square::new(2, 1);
+        let mut defender_down = false;
+        for seed in 0..50 {
+            let mut engine = engine_after_kick_off(seed, &[(101, Skill::StandFirm)])?;
+            engine.place_player(201, attacker_square);
+            engine.place_player(101, defender_square);
+            engine.block(201, 101, false)?;
+
+            assert!(
+                engine
+                    .square_of(201)
+                    .is_none_or(|square| square.eq(&attacker_square))
+            );
+            if let Some(defender) = engine.on_pitch.get(&101) {
+                assert_eq!(defender.square, defender_square);
+                defender_down |= defender.status.ne(&PlayerStatus::Standing);
+            }
+        }
+        assert!(defender_down);
+
+        Ok(())
+    }
+
+    #[test]
+    fn kick_off_events() -> Result<(), Error> {
+        let (mut time_out, mut brilliant_coaching, mut changing_weather) = (false, false, false);
+        for seed in 0..100 {
+            let mut engine = engine_after_kick_off(seed, &[])?;
+            time_out |= engine.turns.get(&1).ne(&Some(&0));
+            changing_weather |= engine.weather.ne(&Weather::PerfectConditions);
+            if !engine.drive_re_rolls.is_empty() {
+                brilliant_coaching = true;
+                assert!(
+                    engine
+                        .team_re_rolls
+                        .values()
+                        .any(|re_rolls| re_rolls.eq(&4))
+                );
+                engine.start_drive(1);
+                assert!(
+                    engine
+                        .team_re_rolls
+                        .values()
+                        .all(|re_rolls| re_rolls.eq(&3))
+                );
+            }
+        }
+        assert!(time_out && brilliant_coaching && changing_weather);
+
+        Ok(())
+    }
+
+    #[test]
+    fn re_rolls_refilled_at_half_time() -> Result<(), Error> {
+        let mut engine = engine_after_kick_off(1, &[])?;
+        let EnginePhase::Turn { team_id } = engine.phase else {
+            panic!("the receiving team should be playing");
+        };
+        let player_id = engine
+            .players_on_pitch(team_id)
+            .first()
+            .map(|(player_id, _)| *player_id)
+            .unwrap();
+        let re_rolls = engine.team_re_rolls[&team_id];
+
+        assert!(engine.use_team_re_roll(player_id).is_some());
+        assert_eq!(engine.team_re_rolls[&team_id], re_rolls - 1);
+
+        engine.end_half()?;
+        assert_eq!(engine.half, 2);
+        assert_eq!(engine.team_re_rolls[&1], 3);
+        assert_eq!(engine.team_re_rolls[&2], 3);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,554 @@
+use super::{
+    BallPosition, DIRECTIONS, EnginePhase, GameEngine, PlayerStatus, RUSHES, TURNS_PER_HALF,
+    TurnAction,
+};
+use crate::blocks::{Block, BlockOutcome};
+use crate::errors::Error;
+use crate::pitch::{PitchHalf, SetUp, Square};
+
+const MAXIMUM_SAFE_RISK: u8 = 2;
+const MAXIMUM_BLOCK_TURNOVER: f64 = 0.12;
+const MINIMUM_BLOCK_KNOCK_DOWN: f64 = 1.0 / 3.0;
+const DODGE_RISK: u8 = 2;
+const RUSH_RISK: u8 = 1;
+
+#[derive(Debug, Copy, Clone)]
+struct Destination {
+    square: Square,
+    risk: u8,
+    length: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Routes {
+    width: usize,
+    layers: Vec<Vec<Option<(u8, Square)>>>,
+    destinations: Vec<Destination>,
+}
+
+impl Routes {
+    fn index(&self, square: &Square) -> usize {
+        square.x as usize * self.width + square.y as usize
+    }
+
+    fn to(&self, square: &Square) -> Option<Destination> {
+        self.destinations
+            .iter()
+            .find(|destination| destination.square.eq(square))
+            .copied()
+    }
+
+    fn path(&self, destination: &Destination) -> Vec<Square> {
+        let mut path = vec![destination.square];
+        for layer in self.layers[2..=destination.length].iter().rev() {
+            if let Some((_, previous)) = layer[self.index(&path[path.len() - 1])] {
+                path.push(previous);
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Bot {
+    pub team_id: i32,
+}
+
+impl Bot {
+    pub fn new(team_id: i32) -> Self {
+        Self { team_id }
+    }
+
+    fn backward(&self, engine: &GameEngine) -> i16 {
+        match engine.defended_half(self.team_id) {
+            PitchHalf::Left => -1,
+            PitchHalf::Right => 1,
+        }
+    }
+
+    pub fn set_up(&self, engine: &GameEngine) -> SetUp {
+        let half = engine.defended_half(self.team_id);
+        let line_of_scrimmage = engine.pitch.line_of_scrimmage_column(&half) as i16;
+        let backward = self.backward(engine);
+        let center = (engine.pitch.width / 2) as i16;
+
+        let mut squares: Vec<Square> = (-1..=1)
+            .map(|row| Square::new(line_of_scrimmage as u8, (center + row) as u8))
+            .collect();
+        for columns_back in [2, 4] {
+            let column = (line_of_scrimmage + backward * columns_back) as u8;
+            for row in [0, -1, 1, -2, 2, -3, 3] {
+                squares.push(Square::new(column, (center + row) as u8));
+            }
+        }
+
+        let mut reserves: Vec<(i32, u8)> = engine
+            .reserves(self.team_id)
+            .into_iter()
+            .map(|player_id| {
+                let strength = engine
+                    .player(player_id)
+                    .and_then(|player| player.strength())
+                    .unwrap_or(0);
+                (player_id, strength)
+            })
+            .collect();
+        reserves.sort_by(|(first_id, first_strength), (second_id, second_strength)| {
+            second_strength
+                .cmp(first_strength)
+                .then(first_id.cmp(second_id))
+        });
+
+        let mut set_up = SetUp::new(self.team_id, half);
+        for ((player_id, _), square) in reserves
+            .into_iter()
+            .zip(squares)
+            .take(engine.pitch.maximum_players_on_pitch)
+        {
+            set_up.placements.push((player_id, square));
+        }
+
+        set_up
+    }
+
+    pub fn kick_off_target(&self, engine: &GameEngine) -> Square {
+        let receiving_half = engine.defended_half(engine.opponent_id(self.team_id));
+        let line_of_scrimmage = engine.pitch.line_of_scrimmage_column(&receiving_half) as i16;
+
+        Square::new(
+            (line_of_scrimmage - self.backward(engine) * 6) as u8,
+            engine.pitch.width / 2,
+        )
+    }
+
+    fn routes(&self, engine: &GameEngine, player_id: i32, extra_cost: u8) -> Routes {
+        let width = engine.pitch.width as usize;
+        let size = engine.pitch.length as usize * width;
+        let mut routes = Routes {
+            width,
+            layers: vec![vec![None; size]],
+            destinations: Vec::new(),
+        };
+        let Some(start) = engine.square_of(player_id) else {
+            return routes;
+        };
+        let movement_left = engine.movement_left(player_id);
+        let maximum_length = (movement_left + RUSHES).saturating_sub(extra_cost);
+
+        let mut occupied = vec![false; size];
+        let mut marked = vec![false; size];
+        for player in engine.on_pitch.values() {
+            occupied[routes.index(&player.square)] = true;
+            if player.team_id.ne(&self.team_id) && player.status.eq(&PlayerStatus::Standing) {
+                for direction in DIRECTIONS {
+                    if let Some(square) = engine.offset(&player.square, direction, 1) {
+                        marked[routes.index(&square)] = true;
+                    }
+                }
+            }
+        }
+
+        let mut best: Vec<Option<usize>> = vec![None; size];
+        let start_index = routes.index(&start);
+        routes.layers[0][start_index] = Some((0, start));
+        let mut frontier = vec![start];
+        for length in 1..=maximum_length as usize {
+            let mut layer: Vec<Option<(u8, Square)>> = vec![None; size];
+            let mut next_frontier = Vec::new();
+            for square in frontier {
+                let Some((risk, _)) = routes.layers[length - 1][routes.index(&square)] else {
+                    continue;
+                };
+                if length > 1 && engine.ball.eq(&BallPosition::OnGround(square)) {
+                    continue;
+                }
+                let next_risk =
+                    risk + if marked[routes.index(&square)] {
+                        DODGE_RISK
+                    } else {
+                        0
+                    } + if length > movement_left as usize {
+                        RUSH_RISK
+                    } else {
+                        0
+                    };
+
+                for direction in DIRECTIONS {
+                    let Some(next) = engine.offset(&square, direction, 1) else {
+                        continue;
+                    };
+                    let next_index = routes.index(&next);
+                    if occupied[next_index] {
+                        continue;
+                    }
+                    match layer[next_index] {
+                        Some((existing_risk, _)) if existing_risk <= next_risk => {}
+                        existing => {
+                            if existing.is_none() {
+                                next_frontier.push(next);
+                            }
+                            layer[next_index] = Some((next_risk, square));
+                        }
+                    }
+                }
+            }
+
+            for square in next_frontier.iter() {
+                let square_index = routes.index(square);
+                let Some((risk, _)) = layer[square_index] else {
+                    continue;
+                };
+                match best[square_index] {
+                    None => {
+                        best[square_index] = Some(routes.destinations.len());
+                        routes.destinations.push(Destination {
+                            square: *square,
+                            risk,
+                            length,
+                        });
+                    }
+                    Some(position) if risk < routes.destinations[position].risk => {
+                        routes.destinations[position].risk = risk;
+                        routes.destinations[position].length = length;
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            routes.layers.push(layer);
+            frontier = next_frontier;
+        }
+
+        routes
+    }
+
+    fn can_act(&self, engine: &GameEngine, player_id: i32) -> bool {
+        engine.on_pitch.get(&player_id).is_some_and(|player| {
+            player.team_id.eq(&self.team_id)
+                && !player.has_acted
+                && player.status.ne(&PlayerStatus::Stunned)
+        })
+    }
+
+    fn acting_players(&self, engine: &GameEngine) -> Vec<i32> {
+        engine
+            .players_on_pitch(self.team_id)
+            .into_iter()
+            .map(|(player_id, _)| player_id)
+            .filter(|player_id| self.can_act(engine, *player_id))
+            .collect()
+    }
+
+    fn progress(&self, engine: &GameEngine, square: &Square) -> u8 {
+        engine.scoring_column(self.team_id).abs_diff(square.x)
+    }
+
+    fn score(&self, engine: &GameEngine, carrier_id: i32) -> Option<TurnAction> {
+        let last_turn = engine
+            .turns
+            .get(&self.team_id)
+            .is_some_and(|turns| turns >= &TURNS_PER_HALF);
+        let scoring_column = engine.scoring_column(self.team_id);
+
+        let routes = self.routes(engine, carrier_id, 0);
+        routes
+            .destinations
+            .iter()
+            .filter(|destination| {
+                destination.square.x.eq(&scoring_column)
+                    && (last_turn || destination.risk <= MAXIMUM_SAFE_RISK)
+            })
+            .min_by_key(|destination| (destination.risk, destination.length, destination.square.y))
+            .map(|destination| TurnAction::Move {
+                player_id: carrier_id,
+                path: routes.path(destination),
+            })
+    }
+
+    fn pick_up(&self, engine: &GameEngine, ball: Square) -> Option<TurnAction> {
+        self.acting_players(engine)
+            .into_iter()
+            .filter_map(|player_id| {
+                let routes = self.routes(engine, player_id, 0);
+                routes
+                    .to(&ball)
+                    .map(|destination| (player_id, destination, routes))
+            })
+            .min_by_key(|(player_id, destination, _)| {
+                (destination.risk, destination.length, *player_id)
+            })
+            .map(|(player_id, destination, routes)| TurnAction::Move {
+                player_id,
+                path: routes.path(&destination),
+            })
+    }
+
+    fn block(&self, engine: &GameEngine) -> Option<TurnAction> {
+        let mut best: Option<(f64, i32, i32)> = None;
+        for attacker_id in self.acting_players(engine) {
+            let Some(attacker) = engine.on_pitch.get(&attacker_id) else {
+                continue;
+            };
+            if attacker.status.ne(&PlayerStatus::Standing)
+                || engine.ball_carrier().eq(&Some(attacker_id))
+            {
+                continue;
+            }
+
+            for (defender_id, defender) in engine.players_on_pitch(engine.opponent_id(self.team_id))
+            {
+                if defender.status.ne(&PlayerStatus::Standing)
+                    || !defender.square.is_adjacent(&attacker.square)
+                {
+                    continue;
+                }
+                let (Some(attacking_player), Some(defending_player)) =
+                    (engine.player(attacker_id), engine.player(defender_id))
+                else {
+                    continue;
+                };
+                let (offensive_assists, defensive_assists) =
+                    engine.assists(attacker_id, defender_id);
+                let Ok(outcomes) = Block::new(attacking_player, defending_player)
+                    .with_assists(offensive_assists, defensive_assists)
+                    .outcomes()
+                else {
+                    continue;
+                };
+                let probability = |filter: fn(&BlockOutcome) -> bool| -> f64 {
+                    outcomes
+                        .iter()
+                        .filter(|(outcome, _)| filter(outcome))
+                        .map(|(_, probability)| probability)
+                        .sum()
+                };
+                let turnover = probability(BlockOutcome::is_turnover);
+                let knock_down = probability(BlockOutcome::is_defender_knocked_down);
+
+                if turnover <= MAXIMUM_BLOCK_TURNOVER
+                    && knock_down >= MINIMUM_BLOCK_KNOCK_DOWN
+                    && best.is_none_or(|(best_knock_down, _, _)| knock_down > best_knock_down)
+                {
+                    best = Some((knock_down, attacker_id, defender_id));
+                }
+            }
+        }
+
+        best.map(|(_, player_id, target_id)| TurnAction::Block {
+            player_id,
+            target_id,
+        })
+    }
+
+    fn blitz(&self, engine: &GameEngine, carrier_id: i32) -> Option<TurnAction> {
+        let carrier = engine.on_pitch.get(&carrier_id)?;
+        if engine.blitz_used || carrier.status.ne(&PlayerStatus::Standing) {
+            return None;
+        }
+
+        self.acting_players(engine)
+            .into_iter()
+            .filter_map(|player_id| {
+                let square = engine.square_of(player_id)?;
+                if square.is_adjacent(&carrier.square) {
+                    return Some((player_id, 0, 0, Vec::new()));
+                }
+
+                let routes = self.routes(engine, player_id, 1);
+                routes
+                    .destinations
+                    .iter()
+                    .filter(|destination| {
+                        destination.square.is_adjacent(&carrier.square)
+                            && destination.risk <= MAXIMUM_SAFE_RISK
+                    })
+                    .min_by_key(|destination| {
+                        (
+                            destination.risk,
+                            destination.length,
+                            destination.square.y,
+                            destination.square.x,
+                        )
+                    })
+                    .map(|destination| {
+                        (
+                            player_id,
+                            destination.risk,
+                            destination.length,
+                            routes.path(destination),
+                        )
+                    })
+            })
+            .min_by_key(|(player_id, risk, length, _)| (*risk, *length, *player_id))
+            .map(|(player_id, _, _, path)| TurnAction::Blitz {
+                player_id,
+                path,
+                target_id: carrier_id,
+            })
+    }
+
+    fn move_towards(
+        &self,
+        engine: &GameEngine,
+        player_id: i32,
+        target: &Square,
+    ) -> Option<TurnAction> {
+        let start = engine.square_of(player_id)?;
+        let movement_left = engine.movement_left(player_id) as usize;
+
+        let routes = self.routes(engine, player_id, 0);
+        routes
+            .destinations
+            .iter()
+            .filter(|destination| {
+                destination.risk == 0
+                    && destination.length <= movement_left
+                    && destination.square.distance(target) < start.distance(target)
+            })
+            .min_by_key(|destination| {
+                (
+                    destination.square.distance(target),
+                    destination.length,
+                    destination.square.y,
+                    destination.square.x,
+                )
+            })
+            .map(|destination| TurnAction::Move {
+                player_id,
+                path: routes.path(destination),
+            })
+    }
+
+    fn support(&self, engine: &GameEngine) -> Option<TurnAction> {
+        let target = match engine.ball {
+            BallPosition::OffPitch => return None,
+            BallPosition::OnGround(square) => square,
+            BallPosition::Carried(carrier_id) => {
+                let carrier = engine.on_pitch.get(&carrier_id)?;
+                if carrier.team_id.eq(&self.team_id) {
+                    let forward = -self.backward(engine) * 2;
+                    let column = (carrier.square.x as i16 + forward)
+                        .clamp(0, engine.pitch.length as i16 - 1);
+                    Square::new(column as u8, carrier.square.y)
+                } else {
+                    carrier.square
+                }
+            }
+        };
+
+        self.acting_players(engine)
+            .into_iter()
+            .filter(|player_id| {
+                engine.ball_carrier().ne(&Some(*player_id))
+                    && engine.square_of(*player_id).is_some_and(|square| {
+                        square.distance(&target) > 1
+                            && engine.marking_players(&square, self.team_id) == 0
+                    })
+                    && engine
+                        .on_pitch
+                        .get(player_id)
+                        .is_some_and(|player| player.status.eq(&PlayerStatus::Standing))
+            })
+            .find_map(|player_id| self.move_towards(engine, player_id, &target))
+    }
+
+    fn advance(&self, engine: &GameEngine, carrier_id: i32) -> Option<TurnAction> {
+        let start = engine.square_of(carrier_id)?;
+        let movement_left = engine.movement_left(carrier_id) as usize;
+
+        let routes = self.routes(engine, carrier_id, 0);
+        routes
+            .destinations
+            .iter()
+            .filter(|destination| {
+                destination.risk == 0
+                    && destination.length <= movement_left
+                    && engine.marking_players(&destination.square, self.team_id) == 0
+                    && self.progress(engine, &destination.square) < self.progress(engine, &start)
+            })
+            .min_by_key(|destination| {
+                (
+                    self.progress(engine, &destination.square),
+                    destination.length,
+                    destination.square.y,
+                    destination.square.x,
+                )
+            })
+            .map(|destination| TurnAction::Move {
+                player_id: carrier_id,
+                path: routes.path(destination),
+            })
+    }
+
+    fn stand_up(&self, engine: &GameEngine) -> Option<TurnAction> {
+        self.acting_players(engine)
+            .into_iter()
+            .find(|player_id| {
+                engine
+                    .on_pitch
+                    .get(player_id)
+                    .is_some_and(|player| player.status.eq(&PlayerStatus::Prone))
+            })
+            .map(|player_id| TurnAction::Move {
+                player_id,
+                path: Vec::new(),
+            })
+    }
+
+    pub fn next_action(&self, engine: &GameEngine) -> TurnAction {
+        let own_carrier = engine.ball_carrier().filter(|carrier_id| {
+            engine.on_pitch.get(carrier_id).is_some_and(|carrier| {
+                carrier.team_id.eq(&self.team_id) && carrier.status.eq(&PlayerStatus::Standing)
+            }) && self.can_act(engine, *carrier_id)
+        });
+        let opponent_carrier = engine.ball_carrier().filter(|carrier_id| {
+            engine
+                .on_pitch
+                .get(carrier_id)
+                .is_some_and(|carrier| carrier.team_id.ne(&self.team_id))
+        });
+
+        own_carrier
+            .and_then(|carrier_id| self.score(engine, carrier_id))
+            .or_else(|| match engine.ball {
+                BallPosition::OnGround(square) => self.pick_up(engine, square),
+                _ => None,
+            })
+            .or_else(|| self.block(engine))
+            .or_else(|| opponent_carrier.and_then(|carrier_id| self.blitz(engine, carrier_id)))
+            .or_else(|| self.support(engine))
+            .or_else(|| own_carrier.and_then(|carrier_id| self.advance(engine, carrier_id)))
+            .or_else(|| self.stand_up(engine))
+            .unwrap_or(TurnAction::EndTurn)
+    }
+
+    pub fn play(&self, engine: &mut GameEngine) -> Result<(), Error> {
+        match engine.phase {
+            EnginePhase::SetUp { team_id } if team_id.eq(&self.team_id) => {
+                engine.set_up(self.set_up(engine))
+            }
+            EnginePhase::KickOff { team_id } if team_id.eq(&self.team_id) => {
+                engine.kick_off(self.kick_off_target(engine))
+            }
+            EnginePhase::Turn { team_id } if team_id.eq(&self.team_id) => {
+                match engine.perform(self.next_action(engine)) {
+                    Ok(()) => Ok(()),
+                    Err(_) => engine.perform(TurnAction::EndTurn),
+                }
+            }
+            _ => Err(Error::NotTeamTurn),
+        }
+    }
+}
+
+pub fn play_game(engine: &mut GameEngine, bots: &[Bot]) -> Result<(), Error> {
+    while let Some(team_id) = engine.waiting_for() {
+        bots.iter()
+            .find(|bot| bot.team_id.eq(&team_id))
+            .ok_or(Error::NotAPlayingTeam)?
+            .play(engine)?;
+    }
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 use crate::translation::{TranslatedName, TypeName};
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::fmt::Formatter;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum Error {
     UnsupportedVersion,
     NotEnoughPlayers,
@@ -29,12 +31,60 @@ pub enum Error {
     GameNeedsAWinner,
     GamePenaltiesShouldHaveAWinner,
     GameAlreadyClosed,
+    GameNotClosed,
     JourneymanPositionNotFound,
     NotAPlayingTeam,
     JourneymenShouldBeOkBeforeBuyingInducements,
     SkillNotAvailableForPlayer,
     CaptainMissing,
     TooMuchCaptains,
+    PlayerCanNotBeCaptain,
+    NotEnoughTeamsForBracket,
+    GameNotInBracket,
+    BracketMatchAlreadyFollowedUp,
+    PlayerNotInTeam,
+    PlayerNumberAlreadyUsed,
+    TierNotAllowedInTournament,
+    TournamentBudgetExceeded,
+    TournamentSkillsExceeded,
+    TournamentSameSkillMaximumExceeded,
+    TournamentEliteSkillNotAllowed,
+    TournamentSkillNotAllowed,
+    InvalidTeamFile,
+    InvalidTeamFileLine,
+    UnknownRoster,
+    UnknownPosition,
+    UnknownSkill,
+    UnknownAdvancement,
+    UnknownInjury,
+    UnknownStaff,
+    InvalidSerializedData,
+    UnsupportedFormat,
+    DatabaseError,
+    DatabaseUnavailable,
+    DatabaseConstraintViolation,
+    DatabaseMigrationFailed,
+    InvalidDatabaseData,
+    UnsupportedDatabase,
+    SquareOutOfPitch,
+    SquareNotInOwnHalf,
+    SquareAlreadyOccupied,
+    PlayerAlreadyOnPitch,
+    PlayerNotAvailable,
+    TooMuchPlayersOnPitch,
+    NotEnoughPlayersOnPitch,
+    NotEnoughPlayersOnLineOfScrimmage,
+    TooMuchPlayersInWideZone,
+    GameNotInProgress,
+    NotTeamTurn,
+    PlayerCanNotAct,
+    InvalidPath,
+    InvalidTarget,
+    ActionAlreadyUsed,
+    UnsupportedLanguage,
+    InvalidTranslations,
+    TranslationsFileNotReadable,
+    TranslationsNotEnabled,
 }
 
 impl TypeName for Error {}
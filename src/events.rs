@@ -3,13 +3,16 @@ use crate::dices::Dice;
 use crate::errors::Error;
 use crate::games::Game;
 use crate::inducements::{Inducement, TreasuryAndPettyCash};
-use crate::injuries::Injury;
+use crate::injuries::{Injury, InjuryCause};
 use crate::positions::{Keyword, Position};
 use crate::prayers::PrayerToNuffle;
 use crate::skills::Skill;
 use crate::teams::Team;
 use crate::versions::Version;
 use crate::weather::Weather;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub struct GameEventWithScoreAndCasualties {
@@ -18,7 +21,8 @@ pub struct GameEventWithScoreAndCasualties {
     pub casualties: (usize, usize),
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum GameEvent {
     // Pre-game sequence
     FanFactor {
@@ -65,6 +69,10 @@ pub enum GameEvent {
         team_id: i32,
         player_id: i32,
         injury: Injury,
+        #[cfg_attr(feature = "serde", serde(default))]
+        cause: Option<InjuryCause>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        inflicting_player_id: Option<i32>,
     },
     Hatred {
         team_id: i32,
@@ -335,6 +343,7 @@ impl Game {
                 team_id,
                 player_id,
                 injury,
+                ..
             }) => {
                 if self.first_team.id.eq(&team_id) {
                     if let Some((_, player)) = self
@@ -518,6 +527,7 @@ impl Game {
                     team_id,
                     player_id,
                     injury,
+                    ..
                 },
             ) => {
                 if self.first_team.id.eq(&team_id) {
@@ -7,16 +7,23 @@ use crate::teams::Team;
 use crate::translation::{TranslatedName, TypeName};
 use crate::versions::Version;
 use chrono::NaiveDateTime;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
+mod game_actions;
 mod game_sequence;
 mod post_game_sequence;
 mod pre_game_sequence;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+pub use game_actions::GameAction;
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub struct Game {
     pub id: i32,
     pub title: Option<String>,
@@ -139,7 +146,8 @@ impl Game {
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GameStatus {
     Scheduled,
     PreGameSequence,
@@ -210,12 +218,13 @@ impl Game {
 mod tests {
     use super::*;
     use crate::inducements::Inducement;
-    use crate::injuries::Injury;
+    use crate::injuries::{Injury, InjuryCause};
     use crate::players::Player;
     use crate::positions::Position;
     use crate::prayers::PrayerToNuffle;
     use crate::rosters::Roster;
     use crate::staffs::Staff;
+    use crate::test_utils;
     use crate::versions::Version;
     use std::collections::HashMap;
 
@@ -225,6 +234,7 @@ mod tests {
             id: Some(1),
             name: "Me".to_string(),
             elo: None,
+            ratings: HashMap::new(),
         };
 
         let team_a = Team {
@@ -296,6 +306,7 @@ mod tests {
             id: Some(2),
             name: "Him".to_string(),
             elo: None,
+            ratings: HashMap::new(),
         };
 
         let team_b = Team {
@@ -571,6 +582,7 @@ mod tests {
             id: Some(1),
             name: "Me".to_string(),
             elo: None,
+            ratings: HashMap::new(),
         };
 
         let team_a = Team {
@@ -642,6 +654,7 @@ mod tests {
             id: Some(2),
             name: "Him".to_string(),
             elo: None,
+            ratings: HashMap::new(),
         };
 
         let team_b = Team {
@@ -824,4 +837,46 @@ mod tests {
             (Some((fans / 2) + 20000 + 10000), Some((fans / 2) + 10000))
         );
     }
+
+    #[test]
+    fn injuries_linked_to_cause_and_inflicting_player() {
+        let game_at = test_utils::date();
+
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            game_at,
+            &test_utils::team(1, "Reds", Version::V5S3, 3),
+            &test_utils::team(2, "Blues", Version::V5S3, 3),
+            false,
+        )
+        .unwrap();
+        game.start();
+
+        game.push_injury_with_cause(2, 201, Injury::Dead, InjuryCause::Block, Some(101))
+            .unwrap();
+        game.push_injury_with_cause(2, 202, Injury::BadlyHurt, InjuryCause::Foul, Some(101))
+            .unwrap();
+        game.push_pushed_into_crowd(2, 203).unwrap();
+        game.push_injury_with_cause(2, 203, Injury::SmashedKnee, InjuryCause::Crowd, Some(102))
+            .unwrap();
+        game.push_injury_with_cause(1, 103, Injury::KO, InjuryCause::FailedDodge, None)
+            .unwrap();
+        game.push_injury(1, 102, Injury::BadlyHurt).unwrap();
+
+        assert_eq!(game.injury_reports().len(), 5);
+        assert_eq!(game.inflicted_casualties(1, 101).len(), 2);
+        assert_eq!(game.kills(1, 101), 1);
+        assert_eq!(game.kills(1, 102), 0);
+        assert_eq!(game.suffered_casualties(2, 203).len(), 1);
+        assert_eq!(game.suffered_casualties(1, 103).len(), 0);
+
+        let eligible_casualties = game.star_player_points_eligible_casualties(1);
+        assert_eq!(eligible_casualties.len(), 1);
+        assert_eq!(eligible_casualties[0].player_id, 201);
+        assert_eq!(game.non_eligible_casualties(1).len(), 2);
+        assert_eq!(game.non_eligible_casualties(2).len(), 1);
+        assert!(game.star_player_points_eligible_casualties(2).is_empty());
+    }
 }
@@ -0,0 +1,196 @@
+use crate::actions::Success;
+use crate::errors::Error;
+use crate::games::Game;
+use crate::inducements::Inducement;
+use crate::injuries::{Injury, InjuryCause};
+use crate::prayers::PrayerToNuffle;
+use crate::weather::Weather;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "action"))]
+pub enum GameAction {
+    Start,
+    GenerateFans,
+    FanFactor {
+        team_id: i32,
+        fan_factor: u8,
+    },
+    GenerateWeather,
+    Weather {
+        weather: Weather,
+    },
+    GenerateJourneymen,
+    BuyInducement {
+        team_id: i32,
+        inducement: Inducement,
+    },
+    PrayerToNuffle {
+        team_id: i32,
+        prayer_to_nuffle: PrayerToNuffle,
+    },
+    GenerateTossWinner,
+    TossWinner {
+        team_id: i32,
+    },
+    KickingTeam {
+        team_id: i32,
+    },
+    Success {
+        team_id: i32,
+        player_id: i32,
+        success: Success,
+    },
+    Injury {
+        team_id: i32,
+        player_id: i32,
+        injury: Injury,
+        #[cfg_attr(feature = "serde", serde(default))]
+        cause: Option<InjuryCause>,
+        #[cfg_attr(feature = "serde", serde(default))]
+        inflicting_player_id: Option<i32>,
+    },
+    SentOff {
+        team_id: i32,
+        player_id: i32,
+    },
+    HalfTime,
+    ExtraTime,
+    Penalties {
+        first_team_score: usize,
+        second_team_score: usize,
+    },
+    GameEnd,
+    GenerateWinnings {
+        #[cfg_attr(feature = "serde", serde(default))]
+        first_team_stalled: bool,
+        #[cfg_attr(feature = "serde", serde(default))]
+        second_team_stalled: bool,
+    },
+    Winnings {
+        team_id: i32,
+        earned_money: u32,
+    },
+    GenerateDedicatedFansUpdates,
+    DedicatedFansUpdate {
+        team_id: i32,
+        delta: i8,
+    },
+    ExpensiveMistakes {
+        team_id: i32,
+        lost_money: i32,
+    },
+    GameClosure,
+}
+
+impl GameAction {
+    pub fn apply(self, game: &mut Game) -> Result<(), Error> {
+        match self {
+            GameAction::Start => game.start(),
+            GameAction::GenerateFans => {
+                game.generate_fans()?;
+            }
+            GameAction::FanFactor {
+                team_id,
+                fan_factor,
+            } => {
+                let team = if game.first_team.id.eq(&team_id) {
+                    game.first_team.clone()
+                } else if game.second_team.id.eq(&team_id) {
+                    game.second_team.clone()
+                } else {
+                    return Err(Error::NotAPlayingTeam);
+                };
+                game.set_team_fan_factor(team, fan_factor)?;
+            }
+            GameAction::GenerateWeather => {
+                game.generate_weather()?;
+            }
+            GameAction::Weather { weather } => {
+                game.push_weather(weather)?;
+            }
+            GameAction::GenerateJourneymen => {
+                game.generate_journeymen()?;
+            }
+            GameAction::BuyInducement {
+                team_id,
+                inducement,
+            } => {
+                game.team_buy_inducement(team_id, inducement)?;
+            }
+            GameAction::PrayerToNuffle {
+                team_id,
+                prayer_to_nuffle,
+            } => {
+                game.push_prayer(team_id, prayer_to_nuffle)?;
+            }
+            GameAction::GenerateTossWinner => {
+                game.generate_toss_winner()?;
+            }
+            GameAction::TossWinner { team_id } => {
+                game.push_toss_winner(team_id)?;
+            }
+            GameAction::KickingTeam { team_id } => {
+                game.push_kicking_team(team_id)?;
+            }
+            GameAction::Success {
+                team_id,
+                player_id,
+                success,
+            } => game.push_success(team_id, player_id, success)?,
+            GameAction::Injury {
+                team_id,
+                player_id,
+                injury,
+                cause: Some(cause),
+                inflicting_player_id,
+            } => game.push_injury_with_cause(
+                team_id,
+                player_id,
+                injury,
+                cause,
+                inflicting_player_id,
+            )?,
+            GameAction::Injury {
+                team_id,
+                player_id,
+                injury,
+                cause: None,
+                ..
+            } => game.push_injury(team_id, player_id, injury)?,
+            GameAction::SentOff { team_id, player_id } => game.push_sent_off(team_id, player_id)?,
+            GameAction::HalfTime => game.end_first_half()?,
+            GameAction::ExtraTime => game.start_extra_time()?,
+            GameAction::Penalties {
+                first_team_score,
+                second_team_score,
+            } => game.push_penalties(first_team_score, second_team_score)?,
+            GameAction::GameEnd => game.end_game()?,
+            GameAction::GenerateWinnings {
+                first_team_stalled,
+                second_team_stalled,
+            } => {
+                game.generate_winnings(first_team_stalled, second_team_stalled)?;
+            }
+            GameAction::Winnings {
+                team_id,
+                earned_money,
+            } => game.push_winnings(team_id, earned_money)?,
+            GameAction::GenerateDedicatedFansUpdates => {
+                game.generate_dedicated_fans_updates()?;
+            }
+            GameAction::DedicatedFansUpdate { team_id, delta } => {
+                game.push_dedicated_fans_update(team_id, delta)?
+            }
+            GameAction::ExpensiveMistakes {
+                team_id,
+                lost_money,
+            } => game.push_expensive_mistakes(team_id, lost_money)?,
+            GameAction::GameClosure => game.close_game()?,
+        }
+
+        Ok(())
+    }
+}
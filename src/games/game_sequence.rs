@@ -2,7 +2,7 @@ use crate::actions::Success;
 use crate::errors::Error;
 use crate::events::GameEvent;
 use crate::games::Game;
-use crate::injuries::Injury;
+use crate::injuries::{Injury, InjuryCause, InjuryReport};
 use crate::players::Player;
 use crate::positions::{Keyword, Position};
 use crate::skills::Skill;
@@ -10,6 +10,10 @@ use crate::teams::Team;
 use crate::translation::TranslatedName;
 
 impl Game {
+    pub fn push_turn_start(&mut self, team_id: i32, number: usize) -> Result<(), Error> {
+        self.process_event(GameEvent::TurnStart { team_id, number })
+    }
+
     pub fn push_injury(
         &mut self,
         team_id: i32,
@@ -20,6 +24,25 @@ impl Game {
             team_id,
             player_id,
             injury,
+            cause: None,
+            inflicting_player_id: None,
+        })
+    }
+
+    pub fn push_injury_with_cause(
+        &mut self,
+        team_id: i32,
+        player_id: i32,
+        injury: Injury,
+        cause: InjuryCause,
+        inflicting_player_id: Option<i32>,
+    ) -> Result<(), Error> {
+        self.process_event(GameEvent::Injury {
+            team_id,
+            player_id,
+            injury,
+            cause: Some(cause),
+            inflicting_player_id,
         })
     }
 
@@ -57,6 +80,7 @@ impl Game {
                 team_id,
                 player_id,
                 injury,
+                ..
             } = event
             {
                 if team_id_for.eq(team_id) && player_id_for.eq(player_id) {
@@ -81,6 +105,90 @@ impl Game {
             .join(", ")
     }
 
+    pub fn injury_reports(&self) -> Vec<InjuryReport> {
+        let mut injury_reports: Vec<InjuryReport> = vec![];
+
+        for event in self.events.iter() {
+            if let GameEvent::Injury {
+                team_id,
+                player_id,
+                injury,
+                cause,
+                inflicting_player_id,
+            } = event
+            {
+                let inflicting_team_id = if inflicting_player_id.is_none() {
+                    None
+                } else if self.first_team.id.eq(team_id) {
+                    Some(self.second_team.id)
+                } else {
+                    Some(self.first_team.id)
+                };
+
+                injury_reports.push(InjuryReport {
+                    team_id: *team_id,
+                    player_id: *player_id,
+                    injury: injury.clone(),
+                    cause: *cause,
+                    inflicting_team_id,
+                    inflicting_player_id: *inflicting_player_id,
+                });
+            }
+        }
+
+        injury_reports
+    }
+
+    pub fn inflicted_casualties(&self, team_id_for: i32, player_id_for: i32) -> Vec<InjuryReport> {
+        self.injury_reports()
+            .into_iter()
+            .filter(|injury_report| {
+                injury_report.is_casualty()
+                    && injury_report.inflicting_team_id.eq(&Some(team_id_for))
+                    && injury_report.inflicting_player_id.eq(&Some(player_id_for))
+            })
+            .collect()
+    }
+
+    pub fn suffered_casualties(&self, team_id_for: i32, player_id_for: i32) -> Vec<InjuryReport> {
+        self.injury_reports()
+            .into_iter()
+            .filter(|injury_report| {
+                injury_report.is_casualty()
+                    && injury_report.team_id.eq(&team_id_for)
+                    && injury_report.player_id.eq(&player_id_for)
+            })
+            .collect()
+    }
+
+    pub fn kills(&self, team_id_for: i32, player_id_for: i32) -> usize {
+        self.inflicted_casualties(team_id_for, player_id_for)
+            .iter()
+            .filter(|injury_report| injury_report.is_kill())
+            .count()
+    }
+
+    pub fn star_player_points_eligible_casualties(&self, team_id_for: i32) -> Vec<InjuryReport> {
+        self.injury_reports()
+            .into_iter()
+            .filter(|injury_report| {
+                injury_report.inflicting_team_id.eq(&Some(team_id_for))
+                    && injury_report.is_star_player_points_eligible()
+            })
+            .collect()
+    }
+
+    pub fn non_eligible_casualties(&self, team_id_for: i32) -> Vec<InjuryReport> {
+        self.injury_reports()
+            .into_iter()
+            .filter(|injury_report| {
+                injury_report.team_id.ne(&team_id_for)
+                    && injury_report.is_casualty()
+                    && !injury_report.is_star_player_points_eligible()
+            })
+            .collect()
+    }
+
     pub fn push_hatred(
         &mut self,
         team_id: i32,
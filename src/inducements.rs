@@ -3,15 +3,18 @@ use crate::positions::Position;
 use crate::rosters::Roster;
 use crate::staffs::FamousCoachingStaff;
 use crate::teams::Team;
-use crate::translation::{LOCALES, TranslatedName, TypeName, language_from};
+use crate::translation::{TranslatedName, TypeName, lookup};
 use crate::versions::Version;
-use fluent_templates::Loader;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub struct TreasuryAndPettyCash {
     pub treasury: i32,
     pub petty_cash: u32,
@@ -39,7 +42,8 @@ impl TreasuryAndPettyCash {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Inducement {
     PrayersToNuffle,
     PartTimeAssistantCoaches,
@@ -75,12 +79,26 @@ impl TranslatedName for Inducement {
                 format!("Mega Star : {}", position.name(lang_id))
             }
             Inducement::FamousCoachingStaff(coach) => format!("Coachs : {}", coach.name(lang_id)),
-            _ => LOCALES.lookup(&language_from(lang_id), &*self.type_name()),
+            _ => lookup(lang_id, &self.type_name()),
         }
     }
 }
 
 impl Inducement {
+    pub fn list(version: &Version) -> Vec<Self> {
+        let mut inducements: Vec<Inducement> = Vec::new();
+
+        for roster in Roster::list(*version) {
+            for inducement in Self::list_available_for_roster(&roster, version) {
+                if !inducements.contains(&inducement) {
+                    inducements.push(inducement);
+                }
+            }
+        }
+
+        inducements
+    }
+
     pub fn list_available_for_roster(roster: &Roster, version: &Version) -> Vec<Self> {
         match version {
             Version::V1 | Version::V2 | Version::V3 | Version::V4 => Vec::new(),
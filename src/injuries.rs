@@ -1,12 +1,18 @@
+use crate::errors::Error;
 use crate::translation::{TranslatedName, TypeName};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(sqlx::Type, Debug, Serialize, Deserialize, Clone, PartialEq)]
-#[sqlx(type_name = "varchar")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "varchar"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Injury {
     Stunned,
     KO,
@@ -27,6 +33,66 @@ pub enum Injury {
 impl TypeName for Injury {}
 impl TranslatedName for Injury {}
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum InjuryCause {
+    Block,
+    Foul,
+    Crowd,
+    FailedDodge,
+    FailedRush,
+    Other,
+}
+
+impl TypeName for InjuryCause {}
+impl TranslatedName for InjuryCause {}
+
+impl InjuryCause {
+    pub fn list() -> Vec<Self> {
+        vec![
+            InjuryCause::Block,
+            InjuryCause::Foul,
+            InjuryCause::Crowd,
+            InjuryCause::FailedDodge,
+            InjuryCause::FailedRush,
+            InjuryCause::Other,
+        ]
+    }
+
+    pub fn is_star_player_points_eligible(&self) -> bool {
+        matches!(self, InjuryCause::Block)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InjuryReport {
+    pub team_id: i32,
+    pub player_id: i32,
+    pub injury: Injury,
+    pub cause: Option<InjuryCause>,
+    pub inflicting_team_id: Option<i32>,
+    pub inflicting_player_id: Option<i32>,
+}
+
+impl InjuryReport {
+    pub fn is_casualty(&self) -> bool {
+        self.injury.is_casualty()
+    }
+
+    pub fn is_kill(&self) -> bool {
+        matches!(self.injury, Injury::Dead)
+    }
+
+    pub fn is_star_player_points_eligible(&self) -> bool {
+        self.is_casualty()
+            && self.inflicting_player_id.is_some()
+            && self
+                .cause
+                .is_some_and(|cause| cause.is_star_player_points_eligible())
+    }
+}
+
 impl Injury {
     pub fn list(version: &Version) -> Vec<Injury> {
         match version {
@@ -36,6 +102,27 @@ impl Injury {
         }
     }
 
+    pub fn from_injury_roll(
+        version: &Version,
+        roll: u8,
+        stunty: bool,
+        thick_skull: bool,
+    ) -> Result<Option<Injury>, Error> {
+        match version {
+            Version::V1 | Version::V2 | Version::V3 | Version::V4 => Err(Error::UnsupportedVersion),
+            Version::V5 => Ok(v5::injury_roll_result(roll, stunty, thick_skull)),
+            Version::V5S3 => Ok(v5s3::injury_roll_result(roll, stunty, thick_skull)),
+        }
+    }
+
+    pub fn from_casualty_roll(version: &Version, d16: u8, d6: u8) -> Result<Injury, Error> {
+        match version {
+            Version::V1 | Version::V2 | Version::V3 | Version::V4 => Err(Error::UnsupportedVersion),
+            Version::V5 => Ok(v5::casualty_roll_result(d16, d6)),
+            Version::V5S3 => Ok(v5s3::casualty_roll_result(d16, d6)),
+        }
+    }
+
     pub fn injury_in_next_version_with_same_impact(
         &self,
         current_version: &Version,
@@ -64,6 +151,10 @@ impl Injury {
         }
     }
 
+    pub fn is_casualty(&self) -> bool {
+        !matches!(self, Injury::Stunned | Injury::KO)
+    }
+
     pub fn is_niggling_injury(&self) -> bool {
         matches!(self, Injury::SeriousInjury)
     }
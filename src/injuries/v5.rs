@@ -16,6 +16,41 @@ pub fn injuries_list() -> Vec<Injury> {
     ]
 }
 
+pub(crate) fn injury_roll_result(roll: u8, stunty: bool, thick_skull: bool) -> Option<Injury> {
+    let (knocked_out_from, casualty_from) = if stunty { (7, 9) } else { (8, 10) };
+    let knocked_out_from = if thick_skull {
+        knocked_out_from + 1
+    } else {
+        knocked_out_from
+    };
+
+    if roll < knocked_out_from {
+        Some(Injury::Stunned)
+    } else if roll < casualty_from {
+        Some(Injury::KO)
+    } else if stunty && roll.eq(&casualty_from) {
+        Some(Injury::BadlyHurt)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn casualty_roll_result(d16: u8, d6: u8) -> Injury {
+    match d16 {
+        ..7 => Injury::BadlyHurt,
+        7..=9 => Injury::SeriouslyHurt,
+        10..=12 => Injury::SeriousInjury,
+        13..=14 => match d6 {
+            ..3 => Injury::HeadInjury,
+            3 => Injury::SmashedKnee,
+            4 => Injury::BrokenArm,
+            5 => Injury::NeckInjury,
+            _ => Injury::DislocatedShoulder,
+        },
+        _ => Injury::Dead,
+    }
+}
+
 pub(crate) fn mapping_with_previous_version(injury_in_previous_version: &Injury) -> Injury {
     match injury_in_previous_version {
         injury_in_previous_version => injury_in_previous_version.clone(),
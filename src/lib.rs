@@ -1,22 +1,39 @@
 pub mod actions;
 pub mod advancements;
+pub mod blocks;
+pub mod brackets;
 pub mod characteristics;
 pub mod coaches;
+#[cfg(feature = "db")]
+pub mod db;
 pub mod dices;
 pub mod elo;
+pub mod engine;
 pub mod errors;
 pub mod events;
 pub mod games;
 pub mod inducements;
 pub mod injuries;
+pub mod pitch;
 pub mod players;
 pub mod positions;
 pub mod prayers;
+pub mod probabilities;
+pub mod ratings;
+pub mod reports;
 pub mod rosters;
+#[cfg(feature = "serde")]
+pub mod serialization;
+pub mod simulations;
 pub mod skills;
 pub mod staffs;
 pub mod stars;
+#[cfg(feature = "serde")]
+pub mod team_files;
 pub mod teams;
+#[cfg(test)]
+mod test_utils;
+pub mod tournaments;
 pub mod translation;
 pub mod versions;
 pub mod weather;
@@ -0,0 +1,369 @@
+use crate::errors::Error;
+use crate::teams::Team;
+use crate::translation::{TranslatedName, TypeName};
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum PitchFormat {
+    Standard,
+    Sevens,
+}
+
+impl TypeName for PitchFormat {}
+impl TranslatedName for PitchFormat {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum PitchHalf {
+    Left,
+    Right,
+}
+
+impl PitchHalf {
+    pub fn opposite(&self) -> Self {
+        match self {
+            PitchHalf::Left => PitchHalf::Right,
+            PitchHalf::Right => PitchHalf::Left,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum WideZone {
+    Top,
+    Bottom,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct Square {
+    pub x: u8,
+    pub y: u8,
+}
+
+impl Square {
+    pub fn new(x: u8, y: u8) -> Self {
+        Self { x, y }
+    }
+
+    pub fn distance(&self, other: &Square) -> u8 {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    pub fn is_adjacent(&self, other: &Square) -> bool {
+        self.distance(other).eq(&1)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct Pitch {
+    pub format: PitchFormat,
+    pub length: u8,
+    pub width: u8,
+    pub wide_zone_width: u8,
+    pub maximum_players_on_pitch: usize,
+    pub minimum_players_on_line_of_scrimmage: usize,
+    pub maximum_players_per_wide_zone: usize,
+}
+
+impl Pitch {
+    pub fn new(format: PitchFormat) -> Self {
+        match format {
+            PitchFormat::Standard => Self {
+                format,
+                length: 26,
+                width: 15,
+                wide_zone_width: 4,
+                maximum_players_on_pitch: 11,
+                minimum_players_on_line_of_scrimmage: 3,
+                maximum_players_per_wide_zone: 2,
+            },
+            PitchFormat::Sevens => Self {
+                format,
+                length: 20,
+                width: 11,
+                wide_zone_width: 2,
+                maximum_players_on_pitch: 7,
+                minimum_players_on_line_of_scrimmage: 3,
+                maximum_players_per_wide_zone: 2,
+            },
+        }
+    }
+
+    pub fn contains(&self, square: &Square) -> bool {
+        square.x < self.length && square.y < self.width
+    }
+
+    pub fn squares(&self) -> Vec<Square> {
+        (0..self.length)
+            .flat_map(|x| (0..self.width).map(move |y| Square::new(x, y)))
+            .collect()
+    }
+
+    pub fn half(&self, square: &Square) -> Option<PitchHalf> {
+        if !self.contains(square) {
+            None
+        } else if square.x < self.length / 2 {
+            Some(PitchHalf::Left)
+        } else {
+            Some(PitchHalf::Right)
+        }
+    }
+
+    pub fn end_zone_column(&self, half: &PitchHalf) -> u8 {
+        match half {
+            PitchHalf::Left => 0,
+            PitchHalf::Right => self.length - 1,
+        }
+    }
+
+    pub fn line_of_scrimmage_column(&self, half: &PitchHalf) -> u8 {
+        match half {
+            PitchHalf::Left => self.length / 2 - 1,
+            PitchHalf::Right => self.length / 2,
+        }
+    }
+
+    pub fn is_in_end_zone(&self, square: &Square, half: &PitchHalf) -> bool {
+        self.contains(square) && square.x.eq(&self.end_zone_column(half))
+    }
+
+    pub fn is_on_line_of_scrimmage(&self, square: &Square, half: &PitchHalf) -> bool {
+        self.contains(square) && square.x.eq(&self.line_of_scrimmage_column(half))
+    }
+
+    pub fn wide_zone(&self, square: &Square) -> Option<WideZone> {
+        if !self.contains(square) {
+            None
+        } else if square.y < self.wide_zone_width {
+            Some(WideZone::Top)
+        } else if square.y >= self.width - self.wide_zone_width {
+            Some(WideZone::Bottom)
+        } else {
+            None
+        }
+    }
+
+    pub fn is_in_wide_zone(&self, square: &Square) -> bool {
+        self.wide_zone(square).is_some()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct SetUp {
+    pub team_id: i32,
+    pub half: PitchHalf,
+    pub placements: Vec<(i32, Square)>,
+}
+
+impl SetUp {
+    pub fn new(team_id: i32, half: PitchHalf) -> Self {
+        Self {
+            team_id,
+            half,
+            placements: Vec::new(),
+        }
+    }
+
+    pub fn place(&mut self, pitch: &Pitch, player_id: i32, square: Square) -> Result<(), Error> {
+        if !pitch.contains(&square) {
+            return Err(Error::SquareOutOfPitch);
+        }
+        if pitch.half(&square).ne(&Some(self.half)) {
+            return Err(Error::SquareNotInOwnHalf);
+        }
+        if self.player_at(&square).is_some() {
+            return Err(Error::SquareAlreadyOccupied);
+        }
+        if self.square_of(player_id).is_some() {
+            return Err(Error::PlayerAlreadyOnPitch);
+        }
+
+        self.placements.push((player_id, square));
+
+        Ok(())
+    }
+
+    pub fn remove(&mut self, player_id: i32) -> Option<Square> {
+        let index = self
+            .placements
+            .iter()
+            .position(|(placed_player_id, _)| placed_player_id.eq(&player_id))?;
+
+        Some(self.placements.remove(index).1)
+    }
+
+    pub fn square_of(&self, player_id: i32) -> Option<Square> {
+        self.placements
+            .iter()
+            .find(|(placed_player_id, _)| placed_player_id.eq(&player_id))
+            .map(|(_, square)| *square)
+    }
+
+    pub fn player_at(&self, square: &Square) -> Option<i32> {
+        self.placements
+            .iter()
+            .find(|(_, placed_square)| placed_square.eq(square))
+            .map(|(player_id, _)| *player_id)
+    }
+
+    pub fn players_on_line_of_scrimmage(&self, pitch: &Pitch) -> usize {
+        self.placements
+            .iter()
+            .filter(|(_, square)| pitch.is_on_line_of_scrimmage(square, &self.half))
+            .count()
+    }
+
+    pub fn players_in_wide_zone(&self, pitch: &Pitch, wide_zone: &WideZone) -> usize {
+        self.placements
+            .iter()
+            .filter(|(_, square)| pitch.wide_zone(square).eq(&Some(*wide_zone)))
+            .count()
+    }
+
+    pub fn check_if_rules_compliant(&self, pitch: &Pitch, team: &Team) -> Result<(), Error> {
+        if self.team_id.ne(&team.id) {
+            return Err(Error::NotAPlayingTeam);
+        }
+
+        let available_players = team.available_players();
+
+        for (player_id, square) in self.placements.iter() {
+            if !available_players
+                .iter()
+                .any(|(_, player)| player.id.eq(player_id))
+            {
+                return Err(Error::PlayerNotAvailable);
+            }
+            if pitch.half(square).ne(&Some(self.half)) {
+                return Err(Error::SquareNotInOwnHalf);
+            }
+        }
+
+        if self.placements.len() > pitch.maximum_players_on_pitch {
+            return Err(Error::TooMuchPlayersOnPitch);
+        }
+
+        let minimum_players_on_line_of_scrimmage = pitch
+            .minimum_players_on_line_of_scrimmage
+            .min(available_players.len());
+        if self.players_on_line_of_scrimmage(pitch) < minimum_players_on_line_of_scrimmage {
+            return Err(Error::NotEnoughPlayersOnLineOfScrimmage);
+        }
+
+        for wide_zone in [WideZone::Top, WideZone::Bottom] {
+            if self.players_in_wide_zone(pitch, &wide_zone) > pitch.maximum_players_per_wide_zone {
+                return Err(Error::TooMuchPlayersInWideZone);
+            }
+        }
+
+        if self.placements.len() < pitch.maximum_players_on_pitch.min(available_players.len()) {
+            return Err(Error::NotEnoughPlayersOnPitch);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::team;
+    use crate::versions::Version;
+
+    #[test]
+    fn zones() {
+        let pitch = Pitch::new(PitchFormat::Standard);
+        assert_eq!(pitch.squares().len(), 26 * 15);
+        assert!(pitch.is_in_end_zone(&Square::new(0, 7), &PitchHalf::Left));
+        assert!(pitch.is_in_end_zone(&Square::new(25, 7), &PitchHalf::Right));
+        assert!(pitch.is_on_line_of_scrimmage(&Square::new(12, 7), &PitchHalf::Left));
+        assert!(pitch.is_on_line_of_scrimmage(&Square::new(13, 7), &PitchHalf::Right));
+        assert_eq!(pitch.half(&Square::new(13, 0)), Some(PitchHalf::Right));
+        assert_eq!(pitch.wide_zone(&Square::new(5, 3)), Some(WideZone::Top));
+        assert_eq!(pitch.wide_zone(&Square::new(5, 4)), None);
+        assert_eq!(pitch.wide_zone(&Square::new(5, 11)), Some(WideZone::Bottom));
+        assert_eq!(pitch.half(&Square::new(26, 0)), None);
+
+        let sevens = Pitch::new(PitchFormat::Sevens);
+        assert_eq!(sevens.squares().len(), 20 * 11);
+        assert!(sevens.is_on_line_of_scrimmage(&Square::new(9, 5), &PitchHalf::Left));
+        assert_eq!(sevens.wide_zone(&Square::new(5, 9)), Some(WideZone::Bottom));
+        assert_eq!(sevens.wide_zone(&Square::new(5, 2)), None);
+    }
+
+    #[test]
+    fn set_up_validation() -> Result<(), Error> {
+        let pitch = Pitch::new(PitchFormat::Standard);
+        let reds = team(1, "Reds", Version::V5S3, 12);
+        let mut set_up = SetUp::new(1, PitchHalf::Left);
+
+        for (index, y) in [6, 7, 8].iter().enumerate() {
+            set_up.place(&pitch, 101 + index as i32, Square::new(12, *y))?;
+        }
+        assert!(matches!(
+            set_up.place(&pitch, 104, Square::new(12, 7)),
+            Err(Error::SquareAlreadyOccupied)
+        ));
+        assert!(matches!(
+            set_up.place(&pitch, 104, Square::new(13, 7)),
+            Err(Error::SquareNotInOwnHalf)
+        ));
+        assert!(matches!(
+            set_up.place(&pitch, 101, Square::new(10, 7)),
+            Err(Error::PlayerAlreadyOnPitch)
+        ));
+        assert!(matches!(
+            set_up.check_if_rules_compliant(&pitch, &reds),
+            Err(Error::NotEnoughPlayersOnPitch)
+        ));
+
+        for (index, y) in [0, 1, 2].iter().enumerate() {
+            set_up.place(&pitch, 104 + index as i32, Square::new(10, *y))?;
+        }
+        assert!(matches!(
+            set_up.check_if_rules_compliant(&pitch, &reds),
+            Err(Error::TooMuchPlayersInWideZone)
+        ));
+        set_up.remove(106);
+
+        for (index, y) in [4, 5, 6, 7, 8, 9, 10].iter().enumerate() {
+            set_up.place(&pitch, 106 + index as i32, Square::new(8, *y))?;
+        }
+        assert!(matches!(
+            set_up.check_if_rules_compliant(&pitch, &reds),
+            Err(Error::TooMuchPlayersOnPitch)
+        ));
+        set_up.remove(112);
+        set_up.check_if_rules_compliant(&pitch, &reds)?;
+
+        set_up.remove(101);
+        assert!(matches!(
+            set_up.check_if_rules_compliant(&pitch, &reds),
+            Err(Error::NotEnoughPlayersOnLineOfScrimmage)
+        ));
+
+        let mut unknown = SetUp::new(1, PitchHalf::Right);
+        unknown.place(&pitch, 142, Square::new(13, 7))?;
+        assert!(matches!(
+            unknown.check_if_rules_compliant(&pitch, &reds),
+            Err(Error::PlayerNotAvailable)
+        ));
+
+        let sevens = Pitch::new(PitchFormat::Sevens);
+        let small_team = team(1, "Reds", Version::V5S3, 2);
+        let mut set_up = SetUp::new(1, PitchHalf::Right);
+        set_up.place(&sevens, 101, Square::new(10, 5))?;
+        set_up.place(&sevens, 102, Square::new(10, 6))?;
+        set_up.check_if_rules_compliant(&sevens, &small_team)?;
+
+        Ok(())
+    }
+}
@@ -7,13 +7,18 @@ use crate::games::Game;
 use crate::injuries::Injury;
 use crate::positions::{Keyword, Position, PositionDefinition};
 use crate::rosters::{Roster, SpecialRule};
-use crate::skills::Skill;
+use crate::skills::{Skill, SkillEffect};
 use crate::teams::Team;
-use crate::translation::{TranslatedName, TypeName};
+use crate::translation::{TranslatedName, TypeName, format_gold_pieces, lookup_count};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum PlayerType {
     FromRoster,
     Journeyman,
@@ -22,7 +27,8 @@ pub enum PlayerType {
     FamousCoachingStaff,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PlayerStatistics {
     pub passing_completions: u32,
     pub throwing_completions: u32,
@@ -99,7 +105,143 @@ impl Game {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayerCareerStatistics {
+    pub player: Player,
+    pub team_id: i32,
+    pub games_played: u32,
+    pub passing_completions: u32,
+    pub throwing_completions: u32,
+    pub deflections: u32,
+    pub interceptions: u32,
+    pub casualties_inflicted: u32,
+    pub casualties_suffered: u32,
+    pub kills: u32,
+    pub touchdowns: u32,
+    pub most_valuable_player: u32,
+    pub star_player_points: u32,
+}
+
+impl PlayerCareerStatistics {
+    pub fn new(team_id: i32, player: &Player) -> Self {
+        Self {
+            player: player.clone(),
+            team_id,
+            games_played: 0,
+            passing_completions: 0,
+            throwing_completions: 0,
+            deflections: 0,
+            interceptions: 0,
+            casualties_inflicted: 0,
+            casualties_suffered: 0,
+            kills: 0,
+            touchdowns: 0,
+            most_valuable_player: 0,
+            star_player_points: 0,
+        }
+    }
+
+    fn add_game_statistics(
+        &mut self,
+        statistics: &PlayerStatistics,
+        casualties_suffered: u32,
+        kills: u32,
+    ) {
+        self.games_played += 1;
+        self.passing_completions += statistics.passing_completions;
+        self.throwing_completions += statistics.throwing_completions;
+        self.deflections += statistics.deflections;
+        self.interceptions += statistics.interceptions;
+        self.casualties_inflicted += statistics.casualties;
+        self.casualties_suffered += casualties_suffered;
+        self.kills += kills;
+        self.touchdowns += statistics.touchdowns;
+        self.most_valuable_player += statistics.most_valuable_player;
+        self.star_player_points += statistics.star_player_points;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PlayersCareerStatistics {
+    pub players: HashMap<i32, PlayerCareerStatistics>,
+}
+
+impl PlayersCareerStatistics {
+    pub fn from_games(games: &[Game]) -> Self {
+        let mut career_statistics = Self::default();
+
+        for game in games.iter() {
+            career_statistics.add_game(game);
+        }
+
+        career_statistics
+    }
+
+    pub fn add_game(&mut self, game: &Game) {
+        if !game.game_finished() {
+            return;
+        }
+
+        for team in [&game.first_team, &game.second_team] {
+            for (_, player) in team.available_players() {
+                let statistics = game.player_statistics(team.id, player.id);
+                let casualties_suffered = game.suffered_casualties(team.id, player.id).len() as u32;
+                let kills = game.kills(team.id, player.id) as u32;
+
+                let career = self
+                    .players
+                    .entry(player.id)
+                    .or_insert_with(|| PlayerCareerStatistics::new(team.id, &player));
+
+                career.player = player.clone();
+                career.team_id = team.id;
+                career.add_game_statistics(&statistics, casualties_suffered, kills);
+            }
+        }
+    }
+
+    pub fn player(&self, player_id: i32) -> Option<&PlayerCareerStatistics> {
+        self.players.get(&player_id)
+    }
+
+    pub fn leaderboard<F>(&self, value: F, limit: usize) -> Vec<&PlayerCareerStatistics>
+    where
+        F: Fn(&PlayerCareerStatistics) -> u32,
+    {
+        let mut leaderboard: Vec<&PlayerCareerStatistics> = self
+            .players
+            .values()
+            .filter(|career| value(career) > 0)
+            .collect();
+
+        leaderboard.sort_by(|career, other_career| {
+            value(other_career)
+                .cmp(&value(career))
+                .then_with(|| career.games_played.cmp(&other_career.games_played))
+                .then_with(|| career.player.id.cmp(&other_career.player.id))
+        });
+        leaderboard.truncate(limit);
+
+        leaderboard
+    }
+
+    pub fn top_scorers(&self, limit: usize) -> Vec<&PlayerCareerStatistics> {
+        self.leaderboard(|career| career.touchdowns, limit)
+    }
+
+    pub fn top_killers(&self, limit: usize) -> Vec<&PlayerCareerStatistics> {
+        self.leaderboard(|career| career.kills, limit)
+    }
+
+    pub fn top_star_player_points(&self, limit: usize) -> Vec<&PlayerCareerStatistics> {
+        self.leaderboard(|career| career.star_player_points, limit)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub struct Player {
     pub id: i32,
     pub version: Version,
@@ -295,6 +437,25 @@ impl Player {
             .characteristic_value(Characteristic::ArmourValue)
     }
 
+    pub fn characteristic(&self, characteristic: Characteristic) -> Option<u8> {
+        match characteristic {
+            Characteristic::MovementAllowance => self.movement_allowance(),
+            Characteristic::Strength => self.strength(),
+            Characteristic::Agility => self.agility(),
+            Characteristic::PassingAbility => self.passing_ability(),
+            Characteristic::ArmourValue => self.armour_value(),
+        }
+    }
+
+    pub fn characteristic_from_position(&self, characteristic: Characteristic) -> Option<u8> {
+        self.position_definition()?
+            .characteristic_value(characteristic)
+    }
+
+    pub fn formatted_characteristic(&self, characteristic: Characteristic) -> String {
+        characteristic.formatted_value(self.characteristic(characteristic))
+    }
+
     pub fn added_skills(&self) -> Vec<Skill> {
         let mut added_skills: Vec<Skill> = Vec::new();
         let initial_skills = self.skills_from_position();
@@ -326,6 +487,13 @@ impl Player {
         vec![self.skills_from_position(), self.added_skills()].concat()
     }
 
+    pub fn skill_effects(&self) -> Vec<SkillEffect> {
+        self.skills()
+            .iter()
+            .filter_map(|skill| skill.effect(&self.version))
+            .collect()
+    }
+
     pub fn skills_from_position(&self) -> Vec<Skill> {
         let mut skills = Vec::new();
 
@@ -443,7 +611,7 @@ impl Player {
         Ok(value)
     }
 
-    fn hiring_value(&self) -> Result<u32, Error> {
+    pub fn hiring_value(&self) -> Result<u32, Error> {
         let position_definition = self
             .position_definition()
             .ok_or(Error::PositionNotDefined)?;
@@ -455,6 +623,18 @@ impl Player {
         Ok(self.hiring_value()? + self.added_value_from_advancements()?)
     }
 
+    pub fn formatted_current_value(&self, lang_id: &str) -> Result<String, Error> {
+        Ok(format_gold_pieces(lang_id, self.current_value()?.into()))
+    }
+
+    pub fn formatted_star_player_points(&self, lang_id: &str) -> String {
+        lookup_count(
+            lang_id,
+            "StarPlayerPointsCount",
+            self.star_player_points.into(),
+        )
+    }
+
     pub fn current_value(&self) -> Result<u32, Error> {
         let roster_definition = self
             .roster
@@ -520,4 +700,95 @@ mod tests {
         assert_eq!(player.id, -1);
         assert!(player.skills().contains(&Skill::Loner(4)));
     }
+
+    #[test]
+    fn career_statistics() {
+        use crate::injuries::InjuryCause;
+        use crate::test_utils;
+
+        let game_at = test_utils::date();
+
+        let mut first_team = test_utils::team(1, "Alice", Version::V5S3, 1);
+        let second_team = test_utils::team(2, "Bob", Version::V5S3, 1);
+        let journeyman = first_team.add_journeyman_with_number(12);
+
+        let mut first_game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            game_at,
+            &first_team,
+            &second_team,
+            false,
+        )
+        .unwrap();
+        first_game.start();
+        first_game
+            .push_success(1, journeyman.id, Success::Touchdown)
+            .unwrap();
+        first_game.push_success(1, 101, Success::Casualty).unwrap();
+        first_game.push_injury(2, 201, Injury::BadlyHurt).unwrap();
+        first_game.push_injury(1, 101, Injury::KO).unwrap();
+        first_game.end_game().unwrap();
+
+        first_team.players[1].1.player_type = PlayerType::FromRoster;
+
+        let mut second_game = Game::create(
+            2,
+            None,
+            Version::V5S3,
+            game_at,
+            &first_team,
+            &second_team,
+            false,
+        )
+        .unwrap();
+        second_game.start();
+        second_game
+            .push_success(1, journeyman.id, Success::Touchdown)
+            .unwrap();
+        second_game
+            .push_success(1, 101, Success::Touchdown)
+            .unwrap();
+        second_game
+            .push_injury_with_cause(
+                1,
+                journeyman.id,
+                Injury::Dead,
+                InjuryCause::Block,
+                Some(201),
+            )
+            .unwrap();
+
+        let mut career_statistics = PlayersCareerStatistics::from_games(&[first_game]);
+        career_statistics.add_game(&second_game);
+        assert_eq!(career_statistics.player(101).unwrap().games_played, 1);
+
+        second_game.end_game().unwrap();
+        career_statistics.add_game(&second_game);
+
+        let hired_journeyman = career_statistics.player(journeyman.id).unwrap();
+        assert_eq!(hired_journeyman.games_played, 2);
+        assert_eq!(hired_journeyman.touchdowns, 2);
+        assert_eq!(hired_journeyman.player.player_type, PlayerType::FromRoster);
+
+        let lineman = career_statistics.player(101).unwrap();
+        assert_eq!(lineman.casualties_inflicted, 1);
+        assert_eq!(lineman.casualties_suffered, 0);
+        assert_eq!(
+            career_statistics.player(201).unwrap().casualties_suffered,
+            1
+        );
+
+        let top_scorers = career_statistics.top_scorers(2);
+        assert_eq!(top_scorers.len(), 2);
+        assert_eq!(top_scorers[0].player.id, journeyman.id);
+        assert_eq!(top_scorers[1].player.id, 101);
+
+        assert_eq!(career_statistics.player(201).unwrap().kills, 1);
+
+        let top_killers = career_statistics.top_killers(5);
+        assert_eq!(top_killers.len(), 1);
+        assert_eq!(top_killers[0].player.id, 201);
+    }
 }
@@ -5,14 +5,19 @@ use crate::skills::{Skill, SkillCategory};
 use crate::staffs::FamousCoachingStaff;
 use crate::translation::{TranslatedName, TypeName};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(sqlx::Type, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-#[sqlx(type_name = "varchar")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "varchar"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Position {
     // Keyword
     All,
@@ -349,8 +354,10 @@ impl Position {
     }
 }
 
-#[derive(sqlx::Type, Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
-#[sqlx(type_name = "varchar")]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "varchar"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Keyword {
     Animal,
     Beastman,
@@ -408,7 +415,8 @@ impl Keyword {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct PositionDefinition {
     pub keywords: Vec<Keyword>,
     pub maximum_quantity: u8,
@@ -460,7 +468,7 @@ impl PositionDefinition {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "i18n"))]
 mod tests {
     use super::*;
 
@@ -1,11 +1,15 @@
 use crate::translation::{TranslatedName, TypeName};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum PrayerToNuffle {
     TreacherousTrapdoor,
     FriendsWithTheRef,
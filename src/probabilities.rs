@@ -0,0 +1,512 @@
+use crate::errors::Error;
+use crate::players::Player;
+use crate::skills::{Skill, SkillCondition, SkillEffect, SkillTrigger, SkillUsage};
+use crate::staffs::Staff;
+use crate::teams::Team;
+use crate::versions::Version;
+use crate::weather::Weather;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod v5;
+pub mod v5s3;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ActionRules {
+    pub team_re_rolls_per_turn: u8,
+    pub pro_target: u8,
+    pub rush_target: u8,
+    pub accurate_pass_catch_modifier: i8,
+    pub inaccurate_pass_catch_modifier: i8,
+}
+
+impl ActionRules {
+    pub fn for_version(version: &Version) -> Result<Self, Error> {
+        match version {
+            Version::V1 | Version::V2 | Version::V3 | Version::V4 => Err(Error::UnsupportedVersion),
+            Version::V5 => Ok(v5::action_rules()),
+            Version::V5S3 => Ok(v5s3::action_rules()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum PassRange {
+    QuickPass,
+    ShortPass,
+    LongPass,
+    LongBomb,
+}
+
+impl PassRange {
+    pub fn from_distance(distance: u8) -> Option<Self> {
+        match distance {
+            0..=3 => Some(PassRange::QuickPass),
+            4..=6 => Some(PassRange::ShortPass),
+            7..=10 => Some(PassRange::LongPass),
+            11..=13 => Some(PassRange::LongBomb),
+            _ => None,
+        }
+    }
+
+    pub fn modifier(&self) -> i8 {
+        match self {
+            PassRange::QuickPass => 0,
+            PassRange::ShortPass => -1,
+            PassRange::LongPass => -2,
+            PassRange::LongBomb => -3,
+        }
+    }
+
+    pub fn is_allowed_in(&self, weather: &Weather) -> bool {
+        match weather {
+            Weather::Blizzard => matches!(self, PassRange::QuickPass | PassRange::ShortPass),
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum ActionRollKind {
+    Dodge,
+    Rush,
+    PickUp,
+    Pass(PassRange),
+    Catch { accurate_pass: bool },
+}
+
+impl ActionRollKind {
+    pub fn skill_trigger(&self) -> SkillTrigger {
+        match self {
+            ActionRollKind::Dodge => SkillTrigger::Dodge,
+            ActionRollKind::Rush => SkillTrigger::Rush,
+            ActionRollKind::PickUp => SkillTrigger::PickUp,
+            ActionRollKind::Pass(_) => SkillTrigger::Pass,
+            ActionRollKind::Catch { .. } => SkillTrigger::Catch,
+        }
+    }
+
+    pub fn skill_conditions(&self) -> Vec<SkillCondition> {
+        match self {
+            ActionRollKind::Pass(PassRange::QuickPass | PassRange::ShortPass) => {
+                vec![SkillCondition::QuickOrShortPass]
+            }
+            ActionRollKind::Pass(PassRange::LongPass | PassRange::LongBomb) => {
+                vec![SkillCondition::LongPassOrBomb]
+            }
+            ActionRollKind::Catch {
+                accurate_pass: true,
+            } => vec![SkillCondition::AccuratePass],
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct ActionRoll {
+    pub kind: ActionRollKind,
+    pub marking_players: u8,
+    pub disturbing_presences: u8,
+    pub other_modifier: i8,
+}
+
+impl ActionRoll {
+    pub fn new(kind: ActionRollKind) -> Self {
+        Self {
+            kind,
+            marking_players: 0,
+            disturbing_presences: 0,
+            other_modifier: 0,
+        }
+    }
+
+    pub fn marked_by(self, marking_players: u8) -> Self {
+        Self {
+            marking_players,
+            ..self
+        }
+    }
+
+    pub fn disturbed_by(self, disturbing_presences: u8) -> Self {
+        Self {
+            disturbing_presences,
+            ..self
+        }
+    }
+
+    pub fn modified_by(self, other_modifier: i8) -> Self {
+        Self {
+            other_modifier,
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct ReRolls {
+    team: u8,
+    pro: bool,
+    used_skills: Vec<Skill>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ProbabilityCalculator {
+    pub version: Version,
+    pub rules: ActionRules,
+    pub skills: Vec<Skill>,
+    pub agility: Option<u8>,
+    pub passing_ability: Option<u8>,
+    pub weather: Weather,
+    pub team_re_rolls: u8,
+}
+
+impl ProbabilityCalculator {
+    pub fn new(player: &Player, weather: Weather, team_re_rolls: u8) -> Result<Self, Error> {
+        Ok(Self {
+            version: player.version,
+            rules: ActionRules::for_version(&player.version)?,
+            skills: player.skills(),
+            agility: player.agility(),
+            passing_ability: player.passing_ability(),
+            weather,
+            team_re_rolls,
+        })
+    }
+
+    pub fn for_team_player(team: &Team, player: &Player, weather: Weather) -> Result<Self, Error> {
+        let team_re_rolls = team.staff.get(&Staff::ReRoll).copied().unwrap_or(0);
+
+        Self::new(player, weather, team_re_rolls)
+    }
+
+    fn has_skill(&self, skill: &Skill) -> bool {
+        self.skills.contains(skill)
+    }
+
+    fn loner_target(&self) -> Option<u8> {
+        self.skills.iter().find_map(|skill| match skill {
+            Skill::Loner(target) => Some(*target),
+            _ => None,
+        })
+    }
+
+    fn skill_effects(&self) -> Vec<(Skill, SkillEffect)> {
+        self.skills
+            .iter()
+            .filter_map(|skill| skill.effect(&self.version).map(|effect| (*skill, effect)))
+            .collect()
+    }
+
+    fn weather_modifier(&self, kind: &ActionRollKind) -> i8 {
+        match (kind, &self.weather) {
+            (ActionRollKind::Rush, Weather::Blizzard) => -1,
+            (ActionRollKind::PickUp | ActionRollKind::Catch { .. }, Weather::PouringRain) => -1,
+            (ActionRollKind::Pass(_), Weather::VerySunny) => -1,
+            _ => 0,
+        }
+    }
+
+    pub fn modifier(&self, roll: &ActionRoll) -> i8 {
+        let trigger = roll.kind.skill_trigger();
+        let conditions = roll.kind.skill_conditions();
+        let effects = self.skill_effects();
+        let ignores_marking = effects
+            .iter()
+            .any(|(_, effect)| effect.ignores_marking(&trigger));
+        let ignores_weather = effects
+            .iter()
+            .any(|(_, effect)| effect.ignores_weather(&trigger));
+
+        let mut modifier = match roll.kind {
+            ActionRollKind::Dodge | ActionRollKind::Rush | ActionRollKind::PickUp => 0,
+            ActionRollKind::Pass(range) => range.modifier() - roll.disturbing_presences as i8,
+            ActionRollKind::Catch { accurate_pass } => {
+                let pass_modifier = if accurate_pass {
+                    self.rules.accurate_pass_catch_modifier
+                } else {
+                    self.rules.inaccurate_pass_catch_modifier
+                };
+                pass_modifier - roll.disturbing_presences as i8
+            }
+        };
+        if !ignores_marking && roll.kind.ne(&ActionRollKind::Rush) {
+            modifier -= roll.marking_players as i8;
+        }
+        if !ignores_weather {
+            modifier += self.weather_modifier(&roll.kind);
+        }
+        modifier += effects
+            .iter()
+            .map(|(_, effect)| effect.modifier(&trigger, &conditions))
+            .sum::<i8>();
+
+        modifier + roll.other_modifier
+    }
+
+    pub fn target(&self, roll: &ActionRoll) -> Option<u8> {
+        match roll.kind {
+            ActionRollKind::Dodge | ActionRollKind::PickUp | ActionRollKind::Catch { .. } => {
+                self.agility
+            }
+            ActionRollKind::Rush => Some(self.rules.rush_target),
+            ActionRollKind::Pass(_) => self.passing_ability,
+        }
+    }
+
+    pub fn successful_faces(&self, roll: &ActionRoll) -> u8 {
+        let Some(target) = self.target(roll) else {
+            return 0;
+        };
+        if matches!(roll.kind, ActionRollKind::Pass(range) if !range.is_allowed_in(&self.weather)) {
+            return 0;
+        }
+        let modifier = self.modifier(roll);
+
+        (1..=6)
+            .filter(|natural: &i8| {
+                natural.eq(&6) || (natural.ne(&1) && natural + modifier >= target as i8)
+            })
+            .count() as u8
+    }
+
+    pub fn probability(&self, roll: &ActionRoll) -> f64 {
+        self.successful_faces(roll) as f64 / 6.0
+    }
+
+    fn initial_re_rolls(&self) -> ReRolls {
+        ReRolls {
+            team: self.team_re_rolls.min(self.rules.team_re_rolls_per_turn),
+            pro: self.has_skill(&Skill::Pro),
+            used_skills: Vec::new(),
+        }
+    }
+
+    fn skill_re_roll(&self, kind: &ActionRollKind, re_rolls: &ReRolls) -> Option<ReRolls> {
+        let trigger = kind.skill_trigger();
+        let (skill, usage) = self
+            .skill_effects()
+            .into_iter()
+            .filter(|(skill, effect)| {
+                skill.ne(&Skill::Pro)
+                    && effect.re_rolls(&trigger)
+                    && !re_rolls.used_skills.contains(skill)
+            })
+            .map(|(skill, effect)| (skill, effect.usage))
+            .min_by_key(|(_, usage)| *usage)?;
+
+        let mut after_skill = re_rolls.clone();
+        if matches!(
+            usage,
+            SkillUsage::OncePerTurn | SkillUsage::OncePerHalf | SkillUsage::OncePerGame
+        ) {
+            after_skill.used_skills.push(skill);
+        }
+        Some(after_skill)
+    }
+
+    fn chain_from(&self, rolls: &[ActionRoll], re_rolls: ReRolls) -> f64 {
+        let Some((roll, remaining_rolls)) = rolls.split_first() else {
+            return 1.0;
+        };
+        let probability = self.probability(roll);
+        let success = probability * self.chain_from(remaining_rolls, re_rolls.clone());
+
+        if let Some(after_skill) = self.skill_re_roll(&roll.kind, &re_rolls) {
+            return success
+                + (1.0 - probability)
+                    * probability
+                    * self.chain_from(remaining_rolls, after_skill);
+        }
+
+        let mut best_re_roll: f64 = 0.0;
+        if re_rolls.pro {
+            let after_pro = ReRolls {
+                pro: false,
+                ..re_rolls.clone()
+            };
+            let pro_probability = (7 - self.rules.pro_target) as f64 / 6.0;
+            best_re_roll = best_re_roll
+                .max(pro_probability * probability * self.chain_from(remaining_rolls, after_pro));
+        }
+        if re_rolls.team > 0 {
+            let after_team = ReRolls {
+                team: re_rolls.team - 1,
+                ..re_rolls
+            };
+            let loner_probability = match self.loner_target() {
+                Some(target) => (7 - target.min(7)) as f64 / 6.0,
+                None => 1.0,
+            };
+            best_re_roll = best_re_roll.max(
+                loner_probability * probability * self.chain_from(remaining_rolls, after_team),
+            );
+        }
+
+        success + (1.0 - probability) * best_re_roll
+    }
+
+    pub fn chain(&self, rolls: &[ActionRoll]) -> f64 {
+        self.chain_from(rolls, self.initial_re_rolls())
+    }
+
+    pub fn chain_without_re_roll(&self, rolls: &[ActionRoll]) -> f64 {
+        rolls.iter().map(|roll| self.probability(roll)).product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::positions::Position;
+    use crate::rosters::Roster;
+
+    fn assert_close(result: f64, expected: f64) {
+        assert!(
+            (result - expected).abs() < 1e-9,
+            "{} is not {}",
+            result,
+            expected
+        );
+    }
+
+    fn lineman(version: Version) -> Player {
+        Player::new(version, Position::HumanLineman, Roster::Human)
+    }
+
+    #[test]
+    fn single_rolls() -> Result<(), Error> {
+        let player = lineman(Version::V5S3);
+        let calculator = ProbabilityCalculator::new(&player, Weather::PerfectConditions, 0)?;
+
+        assert_eq!(
+            calculator.target(&ActionRoll::new(ActionRollKind::Dodge)),
+            Some(3)
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Dodge)),
+            4.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Dodge).marked_by(2)),
+            2.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Dodge).marked_by(5)),
+            1.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Rush)),
+            5.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::ShortPass))),
+            2.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(
+                &ActionRoll::new(ActionRollKind::Catch {
+                    accurate_pass: false,
+                })
+                .disturbed_by(1),
+            ),
+            2.0 / 6.0,
+        );
+
+        let calculator = ProbabilityCalculator::new(&player, Weather::Blizzard, 0)?;
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Rush)),
+            4.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::ShortPass))),
+            2.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::LongPass))),
+            0.0,
+        );
+        let calculator = ProbabilityCalculator::new(&player, Weather::PouringRain, 0)?;
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::PickUp)),
+            3.0 / 6.0,
+        );
+
+        let without_passing_ability = ProbabilityCalculator {
+            passing_ability: None,
+            ..calculator
+        };
+        assert_close(
+            without_passing_ability
+                .probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::QuickPass))),
+            0.0,
+        );
+
+        let mut passer = lineman(Version::V5S3);
+        for skill in [Skill::Accurate, Skill::Cannoneer] {
+            passer.advancements.push(Advancement::ChosenSkill(skill));
+        }
+        let calculator = ProbabilityCalculator::new(&passer, Weather::PerfectConditions, 0)?;
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::ShortPass))),
+            3.0 / 6.0,
+        );
+        assert_close(
+            calculator.probability(&ActionRoll::new(ActionRollKind::Pass(PassRange::LongPass))),
+            2.0 / 6.0,
+        );
+
+        assert!(matches!(
+            ProbabilityCalculator::new(&lineman(Version::V4), Weather::PerfectConditions, 0),
+            Err(Error::UnsupportedVersion)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn chains_with_re_rolls() -> Result<(), Error> {
+        let mut player = lineman(Version::V5);
+        let dodge = ActionRoll::new(ActionRollKind::Dodge);
+        let rush = ActionRoll::new(ActionRollKind::Rush);
+
+        let calculator = ProbabilityCalculator::new(&player, Weather::PerfectConditions, 0)?;
+        assert_close(calculator.chain(&[dodge, rush]), 4.0 / 6.0 * 5.0 / 6.0);
+        assert_close(
+            calculator.chain_without_re_roll(&[dodge, rush]),
+            4.0 / 6.0 * 5.0 / 6.0,
+        );
+
+        let calculator = ProbabilityCalculator::new(&player, Weather::PerfectConditions, 3)?;
+        let dodge_probability: f64 = 4.0 / 6.0;
+        let rush_probability: f64 = 5.0 / 6.0;
+        let expected = dodge_probability
+            * (rush_probability + (1.0 - rush_probability) * rush_probability)
+            + (1.0 - dodge_probability) * dodge_probability * rush_probability;
+        assert_close(calculator.chain(&[dodge, rush]), expected);
+
+        player
+            .advancements
+            .push(Advancement::ChosenSkill(Skill::Dodge));
+        let calculator = ProbabilityCalculator::new(&player, Weather::PerfectConditions, 1)?;
+        let with_dodge = dodge_probability + (1.0 - dodge_probability) * dodge_probability;
+        assert_close(calculator.chain(&[dodge]), with_dodge);
+        assert_close(calculator.chain(&[dodge, dodge]), with_dodge * with_dodge);
+
+        player
+            .advancements
+            .push(Advancement::ChosenSkill(Skill::SureFeet));
+        let calculator = ProbabilityCalculator::new(&player, Weather::PerfectConditions, 0)?;
+        assert_close(
+            calculator.chain(&[rush]),
+            rush_probability + (1.0 - rush_probability) * rush_probability,
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+use crate::probabilities::ActionRules;
+
+pub(crate) fn action_rules() -> ActionRules {
+    ActionRules {
+        team_re_rolls_per_turn: 1,
+        pro_target: 3,
+        rush_target: 2,
+        accurate_pass_catch_modifier: 0,
+        inaccurate_pass_catch_modifier: -1,
+    }
+}
@@ -0,0 +1,5 @@
+use crate::probabilities::ActionRules;
+
+pub(crate) fn action_rules() -> ActionRules {
+    super::v5::action_rules()
+}
@@ -0,0 +1,315 @@
+use crate::coaches::Coach;
+use crate::elo::{NAF_INITIAL_ELO, new_naf_elo, points_from_winner};
+use crate::errors::Error;
+use crate::games::Game;
+use crate::translation::{TranslatedName, TypeName};
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod glicko2;
+pub mod trueskill;
+
+pub use glicko2::Glicko2;
+pub use trueskill::TrueSkill;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum RatingSystemKind {
+    NafElo,
+    Glicko2,
+    TrueSkill,
+}
+
+impl TypeName for RatingSystemKind {}
+impl TranslatedName for RatingSystemKind {}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum Rating {
+    NafElo(f64),
+    Glicko2 {
+        rating: f64,
+        deviation: f64,
+        volatility: f64,
+    },
+    TrueSkill {
+        mu: f64,
+        sigma: f64,
+    },
+}
+
+impl Rating {
+    pub fn kind(&self) -> RatingSystemKind {
+        match self {
+            Rating::NafElo(_) => RatingSystemKind::NafElo,
+            Rating::Glicko2 { .. } => RatingSystemKind::Glicko2,
+            Rating::TrueSkill { .. } => RatingSystemKind::TrueSkill,
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        match self {
+            Rating::NafElo(elo) => *elo,
+            Rating::Glicko2 { rating, .. } => *rating,
+            Rating::TrueSkill { mu, sigma } => mu - 3.0 * sigma,
+        }
+    }
+}
+
+pub trait RatingSystem {
+    fn kind(&self) -> RatingSystemKind;
+
+    fn initial_rating(&self) -> Rating;
+
+    fn rate(
+        &self,
+        first_rating: &Rating,
+        second_rating: &Rating,
+        points: (f64, f64),
+    ) -> (Rating, Rating);
+
+    fn rating(&self, coach: &Coach) -> Rating {
+        coach
+            .rating(&self.kind())
+            .copied()
+            .unwrap_or(self.initial_rating())
+    }
+
+    fn store_rating(&self, coach: &mut Coach, rating: Rating) {
+        coach.ratings.insert(self.kind(), rating);
+    }
+
+    fn process_game(&self, game: &mut Game) -> Result<(), Error> {
+        if !is_rated(game) {
+            return Err(Error::GameNotClosed);
+        }
+
+        if game.first_team.coach.eq(&game.second_team.coach) {
+            return Err(Error::SameCoachForBothTeams);
+        }
+
+        let (first_rating, second_rating) = self.rate(
+            &self.rating(&game.first_team.coach),
+            &self.rating(&game.second_team.coach),
+            points_from_winner(game.winner()),
+        );
+
+        self.store_rating(&mut game.first_team.coach, first_rating);
+        self.store_rating(&mut game.second_team.coach, second_rating);
+
+        Ok(())
+    }
+
+    fn process_rating_period(&self, coaches: &mut Vec<Coach>, games: &[Game]) -> Result<(), Error> {
+        for game in games.iter().filter(|game| is_rated(game)) {
+            let first_index = coach_index(coaches, &game.first_team.coach);
+            let second_index = coach_index(coaches, &game.second_team.coach);
+
+            if first_index == second_index {
+                return Err(Error::SameCoachForBothTeams);
+            }
+
+            let (first_rating, second_rating) = self.rate(
+                &self.rating(&coaches[first_index]),
+                &self.rating(&coaches[second_index]),
+                points_from_winner(game.winner()),
+            );
+
+            self.store_rating(&mut coaches[first_index], first_rating);
+            self.store_rating(&mut coaches[second_index], second_rating);
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn is_rated(game: &Game) -> bool {
+    game.game_finished() && game.closed
+}
+
+pub(crate) fn coach_index(coaches: &mut Vec<Coach>, coach: &Coach) -> usize {
+    match coaches.iter().position(|known_coach| known_coach.eq(coach)) {
+        Some(index) => index,
+        None => {
+            coaches.push(coach.clone());
+            coaches.len() - 1
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NafElo {
+    pub competition_coaches_number: Option<usize>,
+    pub competition_maximum_coaches_number: Option<usize>,
+}
+
+impl NafElo {
+    pub fn new(
+        competition_coaches_number: Option<usize>,
+        competition_maximum_coaches_number: Option<usize>,
+    ) -> Self {
+        Self {
+            competition_coaches_number,
+            competition_maximum_coaches_number,
+        }
+    }
+
+    fn elo(&self, rating: &Rating) -> f64 {
+        match rating {
+            Rating::NafElo(elo) => *elo,
+            _ => NAF_INITIAL_ELO,
+        }
+    }
+}
+
+impl RatingSystem for NafElo {
+    fn kind(&self) -> RatingSystemKind {
+        RatingSystemKind::NafElo
+    }
+
+    fn initial_rating(&self) -> Rating {
+        Rating::NafElo(NAF_INITIAL_ELO)
+    }
+
+    fn rate(
+        &self,
+        first_rating: &Rating,
+        second_rating: &Rating,
+        points: (f64, f64),
+    ) -> (Rating, Rating) {
+        let first_elo = self.elo(first_rating);
+        let second_elo = self.elo(second_rating);
+
+        (
+            Rating::NafElo(new_naf_elo(
+                first_elo,
+                second_elo,
+                points.0,
+                self.competition_coaches_number,
+                self.competition_maximum_coaches_number,
+            )),
+            Rating::NafElo(new_naf_elo(
+                second_elo,
+                first_elo,
+                points.1,
+                self.competition_coaches_number,
+                self.competition_maximum_coaches_number,
+            )),
+        )
+    }
+
+    fn rating(&self, coach: &Coach) -> Rating {
+        Rating::NafElo(coach.elo.unwrap_or(NAF_INITIAL_ELO))
+    }
+
+    fn store_rating(&self, coach: &mut Coach, rating: Rating) {
+        coach.elo = Some(self.elo(&rating));
+        coach.ratings.insert(self.kind(), rating);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Success;
+    use crate::elo::new_naf_elo_from_game;
+    use crate::test_utils;
+    use crate::versions::Version;
+
+    fn finished_game(first_coach: &str, second_coach: &str, touchdowns: (usize, usize)) -> Game {
+        let game_at = test_utils::date();
+        let first_team = test_utils::team(1, first_coach, Version::V5S3, 1);
+        let second_team = test_utils::team(2, second_coach, Version::V5S3, 1);
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            game_at,
+            &first_team,
+            &second_team,
+            false,
+        )
+        .unwrap();
+
+        game.start();
+        game.push_kicking_team(first_team.id).unwrap();
+
+        for (team, number) in [(&first_team, touchdowns.0), (&second_team, touchdowns.1)] {
+            for _ in 0..number {
+                game.push_success(team.id, team.players[0].1.id, Success::Touchdown)
+                    .unwrap();
+            }
+        }
+
+        game.end_game().unwrap();
+        game.close_game().unwrap();
+
+        game
+    }
+
+    #[test]
+    fn naf_elo_matches_existing_behaviour() {
+        let mut game = finished_game("Alice", "Bob", (3, 1));
+        let expected = new_naf_elo_from_game(&game, Some(10), Some(10));
+
+        let rating_system = NafElo::new(Some(10), Some(10));
+        rating_system.process_game(&mut game).unwrap();
+
+        assert_eq!(game.first_team.coach.elo, Some(expected.0));
+        assert_eq!(game.second_team.coach.elo, Some(expected.1));
+        assert_eq!(
+            game.first_team.coach.rating(&RatingSystemKind::NafElo),
+            Some(&Rating::NafElo(expected.0))
+        );
+    }
+
+    #[test]
+    fn unclosed_games_are_not_rated() {
+        let mut unfinished_game = finished_game("Alice", "Bob", (1, 0));
+        unfinished_game.closed = false;
+
+        let rating_system = NafElo::new(None, None);
+        assert!(matches!(
+            rating_system.process_game(&mut unfinished_game),
+            Err(Error::GameNotClosed)
+        ));
+
+        let mut coaches: Vec<Coach> = Vec::new();
+        rating_system
+            .process_rating_period(&mut coaches, &[unfinished_game])
+            .unwrap();
+        assert!(coaches.is_empty());
+    }
+
+    #[test]
+    fn pluggable_rating_systems() {
+        let rating_systems: Vec<Box<dyn RatingSystem>> = vec![
+            Box::new(NafElo::new(None, None)),
+            Box::new(Glicko2::default()),
+            Box::new(TrueSkill::default()),
+        ];
+
+        let games = vec![
+            finished_game("Alice", "Bob", (1, 0)),
+            finished_game("Alice", "Carol", (0, 0)),
+            finished_game("Bob", "Carol", (0, 1)),
+        ];
+
+        for rating_system in rating_systems.iter() {
+            let mut coaches: Vec<Coach> = Vec::new();
+            rating_system
+                .process_rating_period(&mut coaches, &games)
+                .unwrap();
+
+            assert_eq!(coaches.len(), 3);
+
+            let alice = rating_system.rating(&coaches[0]);
+            let bob = rating_system.rating(&coaches[1]);
+            assert_eq!(alice.kind(), rating_system.kind());
+            assert!(alice.value() > bob.value());
+        }
+    }
+}
@@ -0,0 +1,242 @@
+use crate::coaches::Coach;
+use crate::elo::points_from_winner;
+use crate::errors::Error;
+use crate::games::Game;
+use crate::ratings::{Rating, RatingSystem, RatingSystemKind, coach_index, is_rated};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+const GLICKO2_SCALE: f64 = 173.7178;
+const GLICKO2_CONVERGENCE_TOLERANCE: f64 = 0.000001;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Glicko2 {
+    pub tau: f64,
+    pub initial_rating: f64,
+    pub initial_deviation: f64,
+    pub initial_volatility: f64,
+}
+
+impl Default for Glicko2 {
+    fn default() -> Self {
+        Self {
+            tau: 0.5,
+            initial_rating: 1500.0,
+            initial_deviation: 350.0,
+            initial_volatility: 0.06,
+        }
+    }
+}
+
+impl Glicko2 {
+    fn scaled(&self, rating: &Rating) -> (f64, f64, f64) {
+        let (rating, deviation, volatility) = match rating {
+            Rating::Glicko2 {
+                rating,
+                deviation,
+                volatility,
+            } => (*rating, *deviation, *volatility),
+            _ => (
+                self.initial_rating,
+                self.initial_deviation,
+                self.initial_volatility,
+            ),
+        };
+
+        (
+            (rating - 1500.0) / GLICKO2_SCALE,
+            deviation / GLICKO2_SCALE,
+            volatility,
+        )
+    }
+
+    fn unscaled(mu: f64, phi: f64, volatility: f64) -> Rating {
+        Rating::Glicko2 {
+            rating: mu * GLICKO2_SCALE + 1500.0,
+            deviation: phi * GLICKO2_SCALE,
+            volatility,
+        }
+    }
+
+    fn g(phi: f64) -> f64 {
+        1.0 / f64::sqrt(1.0 + 3.0 * phi.powi(2) / PI.powi(2))
+    }
+
+    fn expected_score(mu: f64, opponent_mu: f64, opponent_phi: f64) -> f64 {
+        1.0 / (1.0 + f64::exp(-Self::g(opponent_phi) * (mu - opponent_mu)))
+    }
+
+    pub fn rate_period(&self, rating: &Rating, results: &[(Rating, f64)]) -> Rating {
+        let (mu, phi, volatility) = self.scaled(rating);
+
+        if results.is_empty() {
+            return Self::unscaled(mu, f64::sqrt(phi.powi(2) + volatility.powi(2)), volatility);
+        }
+
+        let mut variance_inverse = 0.0;
+        let mut delta_sum = 0.0;
+        for (opponent_rating, score) in results.iter() {
+            let (opponent_mu, opponent_phi, _) = self.scaled(opponent_rating);
+            let g = Self::g(opponent_phi);
+            let expected_score = Self::expected_score(mu, opponent_mu, opponent_phi);
+
+            variance_inverse += g.powi(2) * expected_score * (1.0 - expected_score);
+            delta_sum += g * (score - expected_score);
+        }
+
+        let variance = 1.0 / variance_inverse;
+        let delta = variance * delta_sum;
+        let new_volatility = self.new_volatility(phi, volatility, variance, delta);
+
+        let pre_period_phi = f64::sqrt(phi.powi(2) + new_volatility.powi(2));
+        let new_phi = 1.0 / f64::sqrt(1.0 / pre_period_phi.powi(2) + 1.0 / variance);
+        let new_mu = mu + new_phi.powi(2) * delta_sum;
+
+        Self::unscaled(new_mu, new_phi, new_volatility)
+    }
+
+    fn new_volatility(&self, phi: f64, volatility: f64, variance: f64, delta: f64) -> f64 {
+        let a = f64::ln(volatility.powi(2));
+        let f = |x: f64| {
+            let exp_x = f64::exp(x);
+            exp_x * (delta.powi(2) - phi.powi(2) - variance - exp_x)
+                / (2.0 * (phi.powi(2) + variance + exp_x).powi(2))
+                - (x - a) / self.tau.powi(2)
+        };
+
+        let mut upper = a;
+        let mut lower = if delta.powi(2) > phi.powi(2) + variance {
+            f64::ln(delta.powi(2) - phi.powi(2) - variance)
+        } else {
+            let mut k = 1.0;
+            while f(a - k * self.tau) < 0.0 {
+                k += 1.0;
+            }
+            a - k * self.tau
+        };
+
+        let mut f_upper = f(upper);
+        let mut f_lower = f(lower);
+
+        while (lower - upper).abs() > GLICKO2_CONVERGENCE_TOLERANCE {
+            let candidate = upper + (upper - lower) * f_upper / (f_lower - f_upper);
+            let f_candidate = f(candidate);
+
+            if f_candidate * f_lower <= 0.0 {
+                upper = lower;
+                f_upper = f_lower;
+            } else {
+                f_upper /= 2.0;
+            }
+
+            lower = candidate;
+            f_lower = f_candidate;
+        }
+
+        f64::exp(upper / 2.0)
+    }
+}
+
+impl RatingSystem for Glicko2 {
+    fn kind(&self) -> RatingSystemKind {
+        RatingSystemKind::Glicko2
+    }
+
+    fn initial_rating(&self) -> Rating {
+        Rating::Glicko2 {
+            rating: self.initial_rating,
+            deviation: self.initial_deviation,
+            volatility: self.initial_volatility,
+        }
+    }
+
+    fn rate(
+        &self,
+        first_rating: &Rating,
+        second_rating: &Rating,
+        points: (f64, f64),
+    ) -> (Rating, Rating) {
+        (
+            self.rate_period(first_rating, &[(*second_rating, points.0)]),
+            self.rate_period(second_rating, &[(*first_rating, points.1)]),
+        )
+    }
+
+    fn process_rating_period(&self, coaches: &mut Vec<Coach>, games: &[Game]) -> Result<(), Error> {
+        let mut results: Vec<Vec<(Rating, f64)>> = vec![vec![]; coaches.len()];
+
+        for game in games.iter().filter(|game| is_rated(game)) {
+            let first_index = coach_index(coaches, &game.first_team.coach);
+            let second_index = coach_index(coaches, &game.second_team.coach);
+
+            if first_index == second_index {
+                return Err(Error::SameCoachForBothTeams);
+            }
+
+            results.resize(coaches.len(), vec![]);
+
+            let (first_points, second_points) = points_from_winner(game.winner());
+            let first_rating = self.rating(&coaches[first_index]);
+            let second_rating = self.rating(&coaches[second_index]);
+
+            results[first_index].push((second_rating, first_points));
+            results[second_index].push((first_rating, second_points));
+        }
+
+        for (coach, coach_results) in coaches.iter_mut().zip(results.iter()) {
+            let rating = self.rate_period(&self.rating(coach), coach_results);
+            self.store_rating(coach, rating);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glicko2_rating(rating: f64, deviation: f64) -> Rating {
+        Rating::Glicko2 {
+            rating,
+            deviation,
+            volatility: 0.06,
+        }
+    }
+
+    #[test]
+    fn glickman_example() {
+        let rating_system = Glicko2::default();
+
+        let new_rating = rating_system.rate_period(
+            &glicko2_rating(1500.0, 200.0),
+            &[
+                (glicko2_rating(1400.0, 30.0), 1.0),
+                (glicko2_rating(1550.0, 100.0), 0.0),
+                (glicko2_rating(1700.0, 300.0), 0.0),
+            ],
+        );
+
+        let Rating::Glicko2 {
+            rating,
+            deviation,
+            volatility,
+        } = new_rating
+        else {
+            panic!("Glicko-2 rating expected");
+        };
+
+        assert!((rating - 1464.06).abs() < 0.01);
+        assert!((deviation - 151.52).abs() < 0.01);
+        assert!((volatility - 0.05999).abs() < 0.00001);
+
+        let Rating::Glicko2 { deviation, .. } =
+            rating_system.rate_period(&glicko2_rating(1500.0, 200.0), &[])
+        else {
+            panic!("Glicko-2 rating expected");
+        };
+        assert!(deviation > 200.0);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::ratings::{Rating, RatingSystem, RatingSystemKind};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_2_SQRT_PI, PI, SQRT_2};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TrueSkill {
+    pub mu: f64,
+    pub sigma: f64,
+    pub beta: f64,
+    pub tau: f64,
+    pub draw_probability: f64,
+}
+
+impl Default for TrueSkill {
+    fn default() -> Self {
+        Self {
+            mu: 25.0,
+            sigma: 25.0 / 3.0,
+            beta: 25.0 / 6.0,
+            tau: 25.0 / 300.0,
+            draw_probability: 0.1,
+        }
+    }
+}
+
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + z / 2.0);
+    let r = t * f64::exp(
+        -z * z - 1.26551223
+            + t * (1.00002368
+                + t * (0.37409196
+                    + t * (0.09678418
+                        + t * (-0.18628806
+                            + t * (0.27886807
+                                + t * (-1.13520398
+                                    + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))),
+    );
+
+    if x < 0.0 { 2.0 - r } else { r }
+}
+
+fn inverse_erfc(y: f64) -> f64 {
+    if y >= 2.0 {
+        return -100.0;
+    }
+    if y <= 0.0 {
+        return 100.0;
+    }
+
+    let below_one = y < 1.0;
+    let y = if below_one { y } else { 2.0 - y };
+    let t = f64::sqrt(-2.0 * f64::ln(y / 2.0));
+    let mut x =
+        -FRAC_1_SQRT_2 * ((2.30753 + t * 0.27061) / (1.0 + t * (0.99229 + t * 0.04481)) - t);
+
+    for _ in 0..2 {
+        let error = erfc(x) - y;
+        x += error / (FRAC_2_SQRT_PI * f64::exp(-x.powi(2)) - x * error);
+    }
+
+    if below_one { x } else { -x }
+}
+
+fn cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+fn pdf(x: f64) -> f64 {
+    f64::exp(-x.powi(2) / 2.0) / f64::sqrt(2.0 * PI)
+}
+
+fn ppf(x: f64) -> f64 {
+    -SQRT_2 * inverse_erfc(2.0 * x)
+}
+
+fn v_win(difference: f64, draw_margin: f64) -> f64 {
+    let x = difference - draw_margin;
+    let denominator = cdf(x);
+
+    if denominator > 0.0 {
+        pdf(x) / denominator
+    } else {
+        -x
+    }
+}
+
+fn w_win(difference: f64, draw_margin: f64) -> f64 {
+    let x = difference - draw_margin;
+    let v = v_win(difference, draw_margin);
+
+    v * (v + x)
+}
+
+fn v_draw(difference: f64, draw_margin: f64) -> f64 {
+    let absolute_difference = difference.abs();
+    let a = draw_margin - absolute_difference;
+    let b = -draw_margin - absolute_difference;
+    let denominator = cdf(a) - cdf(b);
+    let v = if denominator > 0.0 {
+        (pdf(b) - pdf(a)) / denominator
+    } else {
+        a
+    };
+
+    if difference < 0.0 { -v } else { v }
+}
+
+fn w_draw(difference: f64, draw_margin: f64) -> f64 {
+    let absolute_difference = difference.abs();
+    let a = draw_margin - absolute_difference;
+    let b = -draw_margin - absolute_difference;
+    let denominator = cdf(a) - cdf(b);
+    let v = v_draw(absolute_difference, draw_margin);
+
+    v.powi(2) + (a * pdf(a) - b * pdf(b)) / denominator
+}
+
+impl TrueSkill {
+    fn mu_sigma(&self, rating: &Rating) -> (f64, f64) {
+        match rating {
+            Rating::TrueSkill { mu, sigma } => (*mu, *sigma),
+            _ => (self.mu, self.sigma),
+        }
+    }
+
+    fn draw_margin(&self) -> f64 {
+        ppf((self.draw_probability + 1.0) / 2.0) * SQRT_2 * self.beta
+    }
+}
+
+impl RatingSystem for TrueSkill {
+    fn kind(&self) -> RatingSystemKind {
+        RatingSystemKind::TrueSkill
+    }
+
+    fn initial_rating(&self) -> Rating {
+        Rating::TrueSkill {
+            mu: self.mu,
+            sigma: self.sigma,
+        }
+    }
+
+    fn rate(
+        &self,
+        first_rating: &Rating,
+        second_rating: &Rating,
+        points: (f64, f64),
+    ) -> (Rating, Rating) {
+        let (first_mu, first_sigma) = self.mu_sigma(first_rating);
+        let (second_mu, second_sigma) = self.mu_sigma(second_rating);
+
+        let first_variance = first_sigma.powi(2) + self.tau.powi(2);
+        let second_variance = second_sigma.powi(2) + self.tau.powi(2);
+        let c = f64::sqrt(2.0 * self.beta.powi(2) + first_variance + second_variance);
+        let draw_margin = self.draw_margin() / c;
+
+        let second_won = points.1 > points.0;
+        let (difference, direction) = if second_won {
+            ((second_mu - first_mu) / c, -1.0)
+        } else {
+            ((first_mu - second_mu) / c, 1.0)
+        };
+
+        let (v, w) = if points.0 == points.1 {
+            (
+                v_draw(difference, draw_margin),
+                w_draw(difference, draw_margin),
+            )
+        } else {
+            (
+                v_win(difference, draw_margin),
+                w_win(difference, draw_margin),
+            )
+        };
+
+        let update = |mu: f64, variance: f64, sign: f64| Rating::TrueSkill {
+            mu: mu + sign * variance / c * v,
+            sigma: f64::sqrt(variance * (1.0 - variance / c.powi(2) * w)),
+        };
+
+        (
+            update(first_mu, first_variance, direction),
+            update(second_mu, second_variance, -direction),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_one_versus_one() {
+        let rating_system = TrueSkill::default();
+        let initial_rating = rating_system.initial_rating();
+
+        let (Rating::TrueSkill { mu, sigma }, Rating::TrueSkill { mu: other_mu, .. }) =
+            rating_system.rate(&initial_rating, &initial_rating, (1.0, 0.0))
+        else {
+            panic!("TrueSkill ratings expected");
+        };
+        assert!((mu - 29.396).abs() < 0.001);
+        assert!((sigma - 7.171).abs() < 0.001);
+        assert!((other_mu - 20.604).abs() < 0.001);
+
+        let (Rating::TrueSkill { mu, sigma }, _) =
+            rating_system.rate(&initial_rating, &initial_rating, (0.5, 0.5))
+        else {
+            panic!("TrueSkill ratings expected");
+        };
+        assert!((mu - 25.0).abs() < 0.001);
+        assert!((sigma - 6.458).abs() < 0.001);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::translation::{TranslatedName, TypeName};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+pub mod match_report;
+pub mod team_sheet;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ReportFormat {
+    Markdown,
+    Html,
+    PlainText,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ReportLabel {
+    MatchReport,
+    Team,
+    Coach,
+    CurrentTeamValue,
+    Weather,
+    Fans,
+    Date,
+    PreGame,
+    Inducements,
+    PrayersToNuffle,
+    FirstHalf,
+    SecondHalf,
+    ExtraTime,
+    FinalScore,
+    Penalties,
+    StarPlayerPoints,
+    Player,
+    Injuries,
+    Cause,
+    InflictedBy,
+    Winnings,
+    DedicatedFans,
+    ExpensiveMistakes,
+    MostValuablePlayers,
+    SentOff,
+    PushedIntoCrowd,
+    NothingToReport,
+    Roster,
+    Players,
+    Number,
+    Name,
+    Position,
+    ShortMovementAllowance,
+    ShortStrength,
+    ShortAgility,
+    ShortPassingAbility,
+    ShortArmourValue,
+    Skills,
+    HiringValue,
+    CurrentValue,
+    Staff,
+    Quantity,
+    Treasury,
+    TeamValue,
+    SpecialRules,
+    ModifiedCharacteristic,
+}
+
+impl TypeName for ReportLabel {}
+impl TranslatedName for ReportLabel {}
+
+pub(crate) struct ReportWriter {
+    format: ReportFormat,
+    output: String,
+}
+
+impl ReportWriter {
+    pub(crate) fn new(format: ReportFormat) -> Self {
+        Self {
+            format,
+            output: String::new(),
+        }
+    }
+
+    fn escape(&self, text: &str) -> String {
+        match self.format {
+            ReportFormat::Markdown => {
+                let mut escaped = String::new();
+
+                for (index, character) in text.chars().enumerate() {
+                    match character {
+                        '&' => escaped.push_str("&amp;"),
+                        '<' => escaped.push_str("&lt;"),
+                        '>' => escaped.push_str("&gt;"),
+                        '\\' | '`' | '*' | '_' | '[' | ']' | '|' | '~' => {
+                            escaped.push('\\');
+                            escaped.push(character);
+                        }
+                        '#' | '+' | '-' | '=' if index == 0 => {
+                            escaped.push('\\');
+                            escaped.push(character);
+                        }
+                        _ => escaped.push(character),
+                    }
+                }
+
+                escaped
+            }
+            ReportFormat::Html => text
+                .replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;")
+                .replace('"', "&quot;"),
+            ReportFormat::PlainText => text.to_string(),
+        }
+    }
+
+    fn highlight(&self, text: &str) -> String {
+        let text = self.escape(text);
+
+        match self.format {
+            ReportFormat::Markdown => format!("**{}**", text),
+            ReportFormat::Html => format!("<strong>{}</strong>", text),
+            ReportFormat::PlainText => format!("{}*", text),
+        }
+    }
+
+    fn underline(&mut self, text: &str, character: char) {
+        self.output.push_str(&format!(
+            "{}\n{}\n\n",
+            text,
+            character.to_string().repeat(text.chars().count())
+        ));
+    }
+
+    pub(crate) fn title(&mut self, text: &str) {
+        let text = self.escape(text);
+
+        match self.format {
+            ReportFormat::Markdown => self.output.push_str(&format!("# {}\n\n", text)),
+            ReportFormat::Html => self.output.push_str(&format!("<h1>{}</h1>\n", text)),
+            ReportFormat::PlainText => self.underline(&text, '='),
+        }
+    }
+
+    pub(crate) fn heading(&mut self, text: &str) {
+        let text = self.escape(text);
+
+        match self.format {
+            ReportFormat::Markdown => self.output.push_str(&format!("## {}\n\n", text)),
+            ReportFormat::Html => self.output.push_str(&format!("<h2>{}</h2>\n", text)),
+            ReportFormat::PlainText => self.underline(&text, '-'),
+        }
+    }
+
+    pub(crate) fn paragraph(&mut self, text: &str) {
+        let text = self.escape(text);
+
+        match self.format {
+            ReportFormat::Markdown | ReportFormat::PlainText => {
+                self.output.push_str(&format!("{}\n\n", text))
+            }
+            ReportFormat::Html => self.output.push_str(&format!("<p>{}</p>\n", text)),
+        }
+    }
+
+    pub(crate) fn list(&mut self, items: &[String]) {
+        match self.format {
+            ReportFormat::Markdown | ReportFormat::PlainText => {
+                for item in items.iter() {
+                    self.output.push_str(&format!("- {}\n", self.escape(item)));
+                }
+                self.output.push('\n');
+            }
+            ReportFormat::Html => {
+                self.output.push_str("<ul>\n");
+                for item in items.iter() {
+                    self.output
+                        .push_str(&format!("<li>{}</li>\n", self.escape(item)));
+                }
+                self.output.push_str("</ul>\n");
+            }
+        }
+    }
+
+    pub(crate) fn table(&mut self, headers: &[String], rows: &[Vec<String>]) {
+        let rows: Vec<Vec<(String, bool)>> = rows
+            .iter()
+            .map(|row| row.iter().map(|cell| (cell.clone(), false)).collect())
+            .collect();
+
+        self.table_with_highlights(headers, &rows);
+    }
+
+    pub(crate) fn table_with_highlights(
+        &mut self,
+        headers: &[String],
+        rows: &[Vec<(String, bool)>],
+    ) {
+        let headers: Vec<String> = headers.iter().map(|header| self.escape(header)).collect();
+        let rows: Vec<Vec<String>> = rows
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .map(|(cell, highlighted)| {
+                        if *highlighted {
+                            self.highlight(cell)
+                        } else {
+                            self.escape(cell)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        match self.format {
+            ReportFormat::Markdown => {
+                self.output
+                    .push_str(&format!("| {} |\n", headers.join(" | ")));
+                self.output.push_str(&format!(
+                    "|{}\n",
+                    headers.iter().map(|_| " --- |").collect::<String>()
+                ));
+                for row in rows.iter() {
+                    self.output.push_str(&format!("| {} |\n", row.join(" | ")));
+                }
+                self.output.push('\n');
+            }
+            ReportFormat::Html => {
+                self.output.push_str("<table>\n<tr>");
+                for header in headers.iter() {
+                    self.output.push_str(&format!("<th>{}</th>", header));
+                }
+                self.output.push_str("</tr>\n");
+                for row in rows.iter() {
+                    self.output.push_str("<tr>");
+                    for cell in row.iter() {
+                        self.output.push_str(&format!("<td>{}</td>", cell));
+                    }
+                    self.output.push_str("</tr>\n");
+                }
+                self.output.push_str("</table>\n");
+            }
+            ReportFormat::PlainText => {
+                let mut widths: Vec<usize> = headers
+                    .iter()
+                    .map(|header| header.chars().count())
+                    .collect();
+                for row in rows.iter() {
+                    for (index, cell) in row.iter().enumerate() {
+                        if let Some(width) = widths.get_mut(index) {
+                            *width = (*width).max(cell.chars().count());
+                        }
+                    }
+                }
+
+                let format_row = |row: &[String]| -> String {
+                    row.iter()
+                        .zip(widths.iter())
+                        .map(|(cell, width)| {
+                            format!("{}{}", cell, " ".repeat(width - cell.chars().count()))
+                        })
+                        .collect::<Vec<String>>()
+                        .join("  ")
+                        .trim_end()
+                        .to_string()
+                };
+
+                let table = format!(
+                    "{}\n{}\n{}",
+                    format_row(&headers),
+                    widths
+                        .iter()
+                        .map(|width| "-".repeat(*width))
+                        .collect::<Vec<String>>()
+                        .join("  "),
+                    rows.iter()
+                        .map(|row| format_row(row) + "\n")
+                        .collect::<String>()
+                );
+                self.output.push_str(&table);
+                self.output.push('\n');
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> String {
+        self.output
+    }
+}
@@ -0,0 +1,417 @@
+use crate::actions::Success;
+use crate::events::GameEvent;
+use crate::games::Game;
+use crate::reports::{ReportFormat, ReportLabel, ReportWriter};
+use crate::teams::Team;
+use crate::translation::{TranslatedName, format_gold_pieces};
+
+impl Game {
+    pub fn report(&self, format: ReportFormat, lang_id: &str) -> String {
+        let mut writer = ReportWriter::new(format);
+        let teams = [&self.first_team, &self.second_team];
+        let score = self.score();
+
+        let title = match &self.title {
+            Some(title) => title.clone(),
+            None => ReportLabel::MatchReport.name(lang_id),
+        };
+        writer.title(&format!(
+            "{} - {} {} - {} {}",
+            title, self.first_team.name, score.0, score.1, self.second_team.name
+        ));
+
+        writer.table(
+            &[
+                ReportLabel::Team.name(lang_id),
+                ReportLabel::Coach.name(lang_id),
+                ReportLabel::CurrentTeamValue.name(lang_id),
+            ],
+            &teams
+                .iter()
+                .map(|team| {
+                    vec![
+                        team.name.clone(),
+                        team.coach.name.clone(),
+                        team.formatted_current_value(lang_id).unwrap_or_default(),
+                    ]
+                })
+                .collect::<Vec<Vec<String>>>(),
+        );
+
+        let mut header = vec![format!(
+            "{} : {}",
+            ReportLabel::Date.name(lang_id),
+            self.game_at.format("%Y-%m-%d %H:%M")
+        )];
+        if let Some(weather) = self.weather() {
+            header.push(format!(
+                "{} : {}",
+                ReportLabel::Weather.name(lang_id),
+                weather.name(lang_id)
+            ));
+        }
+        if let Some(fans) = self.fans() {
+            header.push(format!("{} : {}", ReportLabel::Fans.name(lang_id), fans));
+        }
+        writer.list(&header);
+
+        writer.heading(&ReportLabel::PreGame.name(lang_id));
+        let (first_inducements, second_inducements) = self.teams_inducements();
+        let (first_prayers, second_prayers) = self.teams_prayers();
+        let mut pre_game = vec![];
+        for (team, inducements, prayers) in [
+            (&self.first_team, first_inducements, first_prayers),
+            (&self.second_team, second_inducements, second_prayers),
+        ] {
+            if !inducements.is_empty() {
+                pre_game.push(format!(
+                    "{} - {} : {}",
+                    team.name,
+                    ReportLabel::Inducements.name(lang_id),
+                    inducements
+                        .iter()
+                        .map(|inducement| inducement.name(lang_id))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+            }
+            if !prayers.is_empty() {
+                pre_game.push(format!(
+                    "{} - {} : {}",
+                    team.name,
+                    ReportLabel::PrayersToNuffle.name(lang_id),
+                    prayers
+                        .iter()
+                        .map(|prayer| prayer.name(lang_id))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ));
+            }
+        }
+        self.write_items(&mut writer, &pre_game, lang_id);
+
+        let mut periods: Vec<(ReportLabel, Vec<String>)> = vec![(ReportLabel::FirstHalf, vec![])];
+        for event_with_score in self.events_sequence_with_score_and_casualties() {
+            match &event_with_score.game_event {
+                GameEvent::HalfTime => periods.push((ReportLabel::SecondHalf, vec![])),
+                GameEvent::ExtraTime => periods.push((ReportLabel::ExtraTime, vec![])),
+                game_event => {
+                    if let Some(description) = self.event_description(game_event, lang_id)
+                        && let Some((_, timeline)) = periods.last_mut()
+                    {
+                        timeline.push(format!(
+                            "[{} - {}] {}",
+                            event_with_score.score.0, event_with_score.score.1, description
+                        ));
+                    }
+                }
+            }
+        }
+        for (period, timeline) in periods.iter() {
+            writer.heading(&period.name(lang_id));
+            self.write_items(&mut writer, timeline, lang_id);
+        }
+
+        writer.heading(&ReportLabel::FinalScore.name(lang_id));
+        writer.paragraph(&format!(
+            "{} {} - {} {}",
+            self.first_team.name, score.0, score.1, self.second_team.name
+        ));
+        if let Some(penalties_score) = self.penalties_score() {
+            writer.paragraph(&format!(
+                "{} : {} - {}",
+                ReportLabel::Penalties.name(lang_id),
+                penalties_score.0,
+                penalties_score.1
+            ));
+        }
+
+        writer.heading(&ReportLabel::StarPlayerPoints.name(lang_id));
+        let mut star_player_points_rows = vec![];
+        for team in teams.iter() {
+            for (number, player, statistics) in self.players_statistics_for_team(team) {
+                if statistics.star_player_points > 0 {
+                    star_player_points_rows.push(vec![
+                        team.name.clone(),
+                        format!("#{} {}", number, player.name(lang_id)),
+                        statistics.star_player_points.to_string(),
+                    ]);
+                }
+            }
+        }
+        if star_player_points_rows.is_empty() {
+            writer.paragraph(&ReportLabel::NothingToReport.name(lang_id));
+        } else {
+            writer.table(
+                &[
+                    ReportLabel::Team.name(lang_id),
+                    ReportLabel::Player.name(lang_id),
+                    ReportLabel::StarPlayerPoints.name(lang_id),
+                ],
+                &star_player_points_rows,
+            );
+        }
+
+        writer.heading(&ReportLabel::Injuries.name(lang_id));
+        let injuries_rows: Vec<Vec<String>> = self
+            .injury_reports()
+            .iter()
+            .map(|injury_report| {
+                vec![
+                    self.player_label(injury_report.team_id, injury_report.player_id, lang_id),
+                    injury_report.injury.name(lang_id),
+                    injury_report
+                        .cause
+                        .map(|cause| cause.name(lang_id))
+                        .unwrap_or_default(),
+                    match (
+                        injury_report.inflicting_team_id,
+                        injury_report.inflicting_player_id,
+                    ) {
+                        (Some(team_id), Some(player_id)) => {
+                            self.player_label(team_id, player_id, lang_id)
+                        }
+                        _ => "".to_string(),
+                    },
+                ]
+            })
+            .collect();
+        if injuries_rows.is_empty() {
+            writer.paragraph(&ReportLabel::NothingToReport.name(lang_id));
+        } else {
+            writer.table(
+                &[
+                    ReportLabel::Player.name(lang_id),
+                    ReportLabel::Injuries.name(lang_id),
+                    ReportLabel::Cause.name(lang_id),
+                    ReportLabel::InflictedBy.name(lang_id),
+                ],
+                &injuries_rows,
+            );
+        }
+
+        let winnings = self.winnings();
+        let dedicated_fans_updates = self.dedicated_fans_updates();
+        let expensive_mistakes = self.expensive_mistakes();
+        let (first_mvps, second_mvps) = self.most_valuable_players();
+        let format_optional = |value: Option<String>| value.unwrap_or("-".to_string());
+
+        writer.heading(&ReportLabel::Winnings.name(lang_id));
+        writer.table(
+            &[
+                ReportLabel::Team.name(lang_id),
+                ReportLabel::Winnings.name(lang_id),
+                ReportLabel::DedicatedFans.name(lang_id),
+                ReportLabel::ExpensiveMistakes.name(lang_id),
+            ],
+            &[
+                vec![
+                    self.first_team.name.clone(),
+                    format_optional(
+                        winnings
+                            .0
+                            .map(|money| format_gold_pieces(lang_id, money.into())),
+                    ),
+                    format_optional(dedicated_fans_updates.0.map(|delta| format!("{:+}", delta))),
+                    format_optional(
+                        expensive_mistakes
+                            .0
+                            .map(|money| format_gold_pieces(lang_id, money.into())),
+                    ),
+                ],
+                vec![
+                    self.second_team.name.clone(),
+                    format_optional(
+                        winnings
+                            .1
+                            .map(|money| format_gold_pieces(lang_id, money.into())),
+                    ),
+                    format_optional(dedicated_fans_updates.1.map(|delta| format!("{:+}", delta))),
+                    format_optional(
+                        expensive_mistakes
+                            .1
+                            .map(|money| format_gold_pieces(lang_id, money.into())),
+                    ),
+                ],
+            ],
+        );
+
+        writer.heading(&ReportLabel::MostValuablePlayers.name(lang_id));
+        let mut most_valuable_players = vec![];
+        for (team, mvps) in [
+            (&self.first_team, first_mvps),
+            (&self.second_team, second_mvps),
+        ] {
+            for mvp in mvps.iter() {
+                most_valuable_players.push(format!(
+                    "{} - {}",
+                    team.name,
+                    self.player_label(team.id, mvp.id, lang_id)
+                ));
+            }
+        }
+        self.write_items(&mut writer, &most_valuable_players, lang_id);
+
+        writer.finish()
+    }
+
+    fn write_items(&self, writer: &mut ReportWriter, items: &[String], lang_id: &str) {
+        if items.is_empty() {
+            writer.paragraph(&ReportLabel::NothingToReport.name(lang_id));
+        } else {
+            writer.list(items);
+        }
+    }
+
+    fn team_by_id(&self, team_id: i32) -> Option<&Team> {
+        if self.first_team.id.eq(&team_id) {
+            Some(&self.first_team)
+        } else if self.second_team.id.eq(&team_id) {
+            Some(&self.second_team)
+        } else {
+            None
+        }
+    }
+
+    fn player_label(&self, team_id: i32, player_id: i32, lang_id: &str) -> String {
+        match self
+            .team_by_id(team_id)
+            .and_then(|team| team.player_by_id(player_id))
+        {
+            Some((number, player)) => format!("#{} {}", number, player.name(lang_id)),
+            None => format!("#{}", player_id),
+        }
+    }
+
+    fn event_description(&self, game_event: &GameEvent, lang_id: &str) -> Option<String> {
+        let (team_id, player_id, description) = match game_event {
+            GameEvent::Success {
+                success: Success::MostValuablePlayer,
+                ..
+            } => return None,
+            GameEvent::Success {
+                team_id,
+                player_id,
+                success,
+                ..
+            } => (team_id, player_id, success.name(lang_id)),
+            GameEvent::Injury {
+                team_id,
+                player_id,
+                injury,
+                ..
+            } => (team_id, player_id, injury.name(lang_id)),
+            GameEvent::Hatred {
+                team_id,
+                player_id,
+                keyword,
+            } => (team_id, player_id, keyword.name(lang_id)),
+            GameEvent::SentOff { team_id, player_id } => {
+                (team_id, player_id, ReportLabel::SentOff.name(lang_id))
+            }
+            GameEvent::PushedIntoCrowd { team_id, player_id } => (
+                team_id,
+                player_id,
+                ReportLabel::PushedIntoCrowd.name(lang_id),
+            ),
+            GameEvent::PlayerSkillSuccess {
+                team_id,
+                player_id,
+                skill,
+            } => (team_id, player_id, skill.name(lang_id)),
+            _ => return None,
+        };
+
+        let team_name = self
+            .team_by_id(*team_id)
+            .map(|team| team.name.clone())
+            .unwrap_or_default();
+
+        Some(format!(
+            "{} - {} : {}",
+            team_name,
+            self.player_label(*team_id, *player_id, lang_id),
+            description
+        ))
+    }
+}
+
+#[cfg(all(test, feature = "i18n"))]
+mod tests {
+    use super::*;
+    use crate::coaches::Coach;
+    use crate::injuries::{Injury, InjuryCause};
+    use crate::players::Player;
+    use crate::positions::Position;
+    use crate::rosters::Roster;
+    use crate::test_utils;
+    use crate::versions::Version;
+    use crate::weather::Weather;
+
+    fn team(id: i32, name: &str) -> Team {
+        let mut team = Team {
+            coach: Coach::from_name(&format!("Coach of {}", name)),
+            ..test_utils::team(id, name, Version::V5S3, 0)
+        };
+        team.players.push((
+            7,
+            Player {
+                id: id * 100,
+                name: format!("Player <{}>", id),
+                ..Player::new(Version::V5S3, Position::HumanLineman, Roster::Human)
+            },
+        ));
+
+        team
+    }
+
+    #[test]
+    fn markdown_and_html_reports() {
+        let game_at = test_utils::date();
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            game_at,
+            &team(1, "Reds"),
+            &team(2, "Blues"),
+            false,
+        )
+        .unwrap();
+
+        game.start();
+        game.push_weather(Weather::Blizzard).unwrap();
+        game.push_kicking_team(1).unwrap();
+        game.push_success(1, 100, Success::Touchdown).unwrap();
+        game.end_first_half().unwrap();
+        game.push_injury_with_cause(2, 200, Injury::BadlyHurt, InjuryCause::Block, Some(100))
+            .unwrap();
+        game.end_game().unwrap();
+        game.push_success(1, 100, Success::MostValuablePlayer)
+            .unwrap();
+
+        let markdown = game.report(ReportFormat::Markdown, "en");
+        assert!(markdown.starts_with("# Match report - Reds 1 - 0 Blues\n"));
+        assert!(
+            markdown.contains(
+                "## First half\n\n- \\[1 - 0\\] Reds - #7 Player &lt;1&gt; : Touchdown\n"
+            )
+        );
+        assert!(markdown.contains(
+            "## Second half\n\n- \\[1 - 0\\] Blues - #7 Player &lt;2&gt; : Badly Hurt\n"
+        ));
+        assert!(
+            markdown
+                .contains("| \\#7 Player &lt;2&gt; | Badly Hurt | Block | \\#7 Player &lt;1&gt; |")
+        );
+        assert!(!markdown.contains("<1>"));
+        assert!(markdown.contains("## Most valuable players\n\n- Reds - #7 Player &lt;1&gt;\n"));
+
+        let html = game.report(ReportFormat::Html, "fr");
+        assert!(html.starts_with("<h1>Compte rendu de match - Reds 1 - 0 Blues</h1>\n"));
+        assert!(html.contains(
+            "<h2>Première mi-temps</h2>\n<ul>\n<li>[1 - 0] Reds - #7 Player &lt;1&gt; : "
+        ));
+        assert!(!html.contains("<1>"));
+    }
+}
@@ -0,0 +1,215 @@
+use crate::characteristics::Characteristic;
+use crate::players::Player;
+use crate::reports::{ReportFormat, ReportLabel, ReportWriter};
+use crate::teams::Team;
+use crate::translation::{TranslatedName, format_gold_pieces};
+
+fn characteristic_cell(player: &Player, characteristic: Characteristic) -> (String, bool) {
+    let value = player.characteristic(characteristic);
+
+    (
+        player.formatted_characteristic(characteristic),
+        value.is_some() && value.ne(&player.characteristic_from_position(characteristic)),
+    )
+}
+
+fn money(value: Option<u32>, lang_id: &str) -> String {
+    match value {
+        Some(value) => format_gold_pieces(lang_id, value.into()),
+        None => "-".to_string(),
+    }
+}
+
+impl Team {
+    pub fn sheet(&self, format: ReportFormat, lang_id: &str) -> String {
+        let mut writer = ReportWriter::new(format);
+
+        writer.title(&self.name);
+        writer.list(&[
+            format!(
+                "{} : {}",
+                ReportLabel::Roster.name(lang_id),
+                self.roster.name(lang_id)
+            ),
+            format!("{} : {}", ReportLabel::Coach.name(lang_id), self.coach.name),
+            format!(
+                "{} : {}",
+                ReportLabel::Treasury.name(lang_id),
+                format_gold_pieces(lang_id, self.treasury.into())
+            ),
+            format!(
+                "{} : {}",
+                ReportLabel::DedicatedFans.name(lang_id),
+                self.dedicated_fans
+            ),
+            format!(
+                "{} : {}",
+                ReportLabel::TeamValue.name(lang_id),
+                money(self.value().ok(), lang_id)
+            ),
+            format!(
+                "{} : {}",
+                ReportLabel::CurrentTeamValue.name(lang_id),
+                money(self.current_value().ok(), lang_id)
+            ),
+        ]);
+
+        writer.heading(&ReportLabel::Players.name(lang_id));
+        let mut players = self.players.clone();
+        players.sort_by_key(|(number, _)| *number);
+        let rows: Vec<Vec<(String, bool)>> = players
+            .iter()
+            .map(|(number, player)| {
+                vec![
+                    (number.to_string(), false),
+                    (player.name(lang_id), false),
+                    (player.position.name(lang_id), false),
+                    characteristic_cell(player, Characteristic::MovementAllowance),
+                    characteristic_cell(player, Characteristic::Strength),
+                    characteristic_cell(player, Characteristic::Agility),
+                    characteristic_cell(player, Characteristic::PassingAbility),
+                    characteristic_cell(player, Characteristic::ArmourValue),
+                    (player.skills_names(lang_id), false),
+                    (player.injuries_names(lang_id), false),
+                    (player.star_player_points.to_string(), false),
+                    (money(player.hiring_value().ok(), lang_id), false),
+                    (money(player.current_value().ok(), lang_id), false),
+                ]
+            })
+            .collect();
+        writer.table_with_highlights(
+            &[
+                ReportLabel::Number.name(lang_id),
+                ReportLabel::Name.name(lang_id),
+                ReportLabel::Position.name(lang_id),
+                ReportLabel::ShortMovementAllowance.name(lang_id),
+                ReportLabel::ShortStrength.name(lang_id),
+                ReportLabel::ShortAgility.name(lang_id),
+                ReportLabel::ShortPassingAbility.name(lang_id),
+                ReportLabel::ShortArmourValue.name(lang_id),
+                ReportLabel::Skills.name(lang_id),
+                ReportLabel::Injuries.name(lang_id),
+                ReportLabel::StarPlayerPoints.name(lang_id),
+                ReportLabel::HiringValue.name(lang_id),
+                ReportLabel::CurrentValue.name(lang_id),
+            ],
+            &rows,
+        );
+        writer.paragraph(&ReportLabel::ModifiedCharacteristic.name(lang_id));
+
+        writer.heading(&ReportLabel::Staff.name(lang_id));
+        let staff_rows: Vec<Vec<String>> = self
+            .roster_definition()
+            .map(|roster_definition| roster_definition.staff_information)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|staff_information| {
+                let quantity = self
+                    .staff
+                    .get(&staff_information.staff)
+                    .copied()
+                    .unwrap_or(0);
+
+                if quantity > 0 {
+                    Some(vec![
+                        staff_information.staff.name(lang_id),
+                        quantity.to_string(),
+                    ])
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if staff_rows.is_empty() {
+            writer.paragraph(&ReportLabel::NothingToReport.name(lang_id));
+        } else {
+            writer.table(
+                &[
+                    ReportLabel::Staff.name(lang_id),
+                    ReportLabel::Quantity.name(lang_id),
+                ],
+                &staff_rows,
+            );
+        }
+
+        writer.heading(&ReportLabel::SpecialRules.name(lang_id));
+        let special_rules = self
+            .roster_definition()
+            .map(|roster_definition| roster_definition.special_rules_names(lang_id))
+            .unwrap_or_default();
+        if special_rules.is_empty() {
+            writer.paragraph(&ReportLabel::NothingToReport.name(lang_id));
+        } else {
+            writer.paragraph(&special_rules);
+        }
+
+        writer.finish()
+    }
+}
+
+#[cfg(all(test, feature = "i18n"))]
+mod tests {
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::coaches::Coach;
+    use crate::injuries::Injury;
+    use crate::players::Player;
+    use crate::positions::Position;
+    use crate::rosters::Roster;
+    use crate::staffs::Staff;
+    use crate::versions::Version;
+    use std::collections::HashMap;
+
+    #[test]
+    fn team_sheets() {
+        let team = Team {
+            id: 1,
+            version: Version::V5S3,
+            roster: Roster::Human,
+            name: "Reds".to_string(),
+            coach: Coach::from_name("Alice"),
+            treasury: -20000,
+            external_logo_url: None,
+            staff: HashMap::from([(Staff::ReRoll, 3), (Staff::Apothecary, 1)]),
+            players: vec![
+                (
+                    2,
+                    Player {
+                        name: "Karl".to_string(),
+                        star_player_points: 3,
+                        advancements: vec![Advancement::Strength],
+                        injuries: vec![Injury::reduces_armour_value(&Version::V5S3)],
+                        ..Player::new(Version::V5S3, Position::HumanLineman, Roster::Human)
+                    },
+                ),
+                (
+                    1,
+                    Player {
+                        name: "Otto".to_string(),
+                        ..Player::new(Version::V5S3, Position::HumanLineman, Roster::Human)
+                    },
+                ),
+            ],
+            dedicated_fans: 2,
+            under_creation: false,
+            in_offseason: false,
+        };
+
+        let markdown = team.sheet(ReportFormat::Markdown, "en");
+        assert!(markdown.starts_with("# Reds\n\n- Roster : Human\n- Coach : Alice\n"));
+        assert!(markdown.contains("| 1 | Otto | Human Lineman | 6 | 3 | 3+ | 4+ | 9+ |"));
+        assert!(markdown.contains("| 2 | Karl | Human Lineman | 6 | **4** | 3+ | 4+ | **8+** |"));
+        assert!(markdown.contains("- Treasury : -20k"));
+        assert!(markdown.contains("| Apothecary | 1 |\n| Re-rolls | 3 |"));
+
+        let html = team.sheet(ReportFormat::Html, "fr");
+        assert!(html.contains("<th>M</th><th>F</th><th>AG</th><th>CP</th><th>AR</th>"));
+        assert!(html.contains("<td><strong>4</strong></td>"));
+
+        let text = team.sheet(ReportFormat::PlainText, "en");
+        assert!(text.starts_with("Reds\n====\n\n"));
+        assert!(text.contains("Players\n-------\n\n#  Name  Position"));
+        assert!(text.contains("4*"));
+        assert!(text.contains("8+*"));
+    }
+}
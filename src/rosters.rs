@@ -1,15 +1,19 @@
 use crate::positions::Position;
 use crate::staffs::{Staff, StaffInformation};
-use crate::translation::{LOCALES, TranslatedName, TypeName, language_from};
+use crate::translation::{TranslatedName, TypeName, lookup};
 use crate::versions::Version;
-use fluent_templates::Loader;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(sqlx::Type, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-#[sqlx(type_name = "varchar")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "varchar"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Roster {
     Amazon,
     BlackOrc,
@@ -46,10 +50,7 @@ pub enum Roster {
 impl TypeName for Roster {}
 impl TranslatedName for Roster {
     fn name(&self, lang_id: &str) -> String {
-        LOCALES.lookup(
-            &language_from(lang_id),
-            &*format!("{}Roster", self.type_name()),
-        )
+        lookup(lang_id, &format!("{}Roster", self.type_name()))
     }
 }
 
@@ -71,7 +72,8 @@ impl Roster {
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SpecialLeague {
     BadlandsBrawl,
     ChaosClash,
@@ -88,7 +90,8 @@ pub enum SpecialLeague {
 impl TypeName for SpecialLeague {}
 impl TranslatedName for SpecialLeague {}
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SpecialRule {
     BrawlingBrutes,
     BriberyAndCorruption,
@@ -116,7 +119,27 @@ pub enum SpecialRule {
 impl TypeName for SpecialRule {}
 impl TranslatedName for SpecialRule {}
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+impl SpecialRule {
+    pub fn list(version: &Version) -> Vec<Self> {
+        let mut special_rules: Vec<SpecialRule> = Vec::new();
+
+        for roster in Roster::list(*version) {
+            let Some(roster_definition) = roster.definition(*version) else {
+                continue;
+            };
+            for special_rule in roster_definition.special_rules {
+                if !special_rules.contains(&special_rule) {
+                    special_rules.push(special_rule);
+                }
+            }
+        }
+
+        special_rules
+    }
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct DedicatedFansInformation {
     pub price: u32,
     pub initial_minimum: u8,
@@ -149,7 +172,8 @@ impl DedicatedFansInformation {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RosterDefinition {
     pub version: Version,
     pub tier: u8,
@@ -0,0 +1,394 @@
+//! Versioned JSON envelope for stored data.
+//!
+//! Values are written as `{"format": N, "version": "x.y.z", "data": ...}`, `version` being the
+//! crate version that wrote them and `data` the serde representation of the value (externally
+//! tagged enums, variant names as written in the code). Only objects with these three keys are
+//! read as envelopes, so legacy data that happens to have `format` and `data` keys is not
+//! mistaken for one. Reading goes
+//! through the migrations from the stored format up to [`CURRENT_FORMAT`], so a renamed variant
+//! or field only needs a new migration here. Data written without an envelope is read as
+//! [`LEGACY_FORMAT`].
+//!
+//! Formats:
+//! - 0: unversioned data, before the envelope.
+//! - 1: envelope introduced. Fields added since format 0 (coach ratings, injury causes) are
+//!   read with `serde(default)`, so no migration is needed.
+//! - 2: `Success::LandingOnfeet` renamed to `Success::LandingOnFeet`.
+
+use crate::coaches::Coach;
+use crate::errors::Error;
+use crate::events::GameEvent;
+use crate::games::Game;
+use crate::players::Player;
+use crate::teams::Team;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Format written by this version of the crate.
+pub const CURRENT_FORMAT: u32 = 2;
+/// Format of data stored without an envelope.
+pub const LEGACY_FORMAT: u32 = 0;
+
+const FORMAT_KEY: &str = "format";
+const VERSION_KEY: &str = "version";
+const DATA_KEY: &str = "data";
+
+/// Migration from format `N` to format `N + 1` at index `N`.
+const MIGRATIONS: [fn(&mut Value); CURRENT_FORMAT as usize] = [migrate_legacy_to_1, migrate_1_to_2];
+
+/// Envelope around stored data, see the module documentation.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Versioned<T> {
+    pub format: u32,
+    pub version: String,
+    pub data: T,
+}
+
+impl<T> Versioned<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            format: CURRENT_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            data,
+        }
+    }
+}
+
+pub fn to_versioned_value<T: Serialize>(value: &T) -> Result<Value, Error> {
+    serde_json::to_value(Versioned::new(value)).map_err(|_| Error::InvalidSerializedData)
+}
+
+pub fn to_versioned_json<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string(&Versioned::new(value)).map_err(|_| Error::InvalidSerializedData)
+}
+
+pub fn to_versioned_json_pretty<T: Serialize>(value: &T) -> Result<String, Error> {
+    serde_json::to_string_pretty(&Versioned::new(value)).map_err(|_| Error::InvalidSerializedData)
+}
+
+pub fn from_versioned_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+    let (format, mut data) = split_envelope(value)?;
+
+    migrate(&mut data, format)?;
+
+    serde_json::from_value(data).map_err(|_| Error::InvalidSerializedData)
+}
+
+pub fn from_versioned_json<T: DeserializeOwned>(json: &str) -> Result<T, Error> {
+    let value: Value = serde_json::from_str(json).map_err(|_| Error::InvalidSerializedData)?;
+
+    from_versioned_value(value)
+}
+
+/// Format of a stored value, [`LEGACY_FORMAT`] when it has no envelope.
+pub fn format_of(value: &Value) -> u32 {
+    split_envelope(value.clone())
+        .map(|(format, _)| format)
+        .unwrap_or(LEGACY_FORMAT)
+}
+
+/// Migrates the data of an envelope from `from_format` to [`CURRENT_FORMAT`].
+pub fn migrate(data: &mut Value, from_format: u32) -> Result<(), Error> {
+    if from_format > CURRENT_FORMAT {
+        return Err(Error::UnsupportedFormat);
+    }
+
+    for migration in MIGRATIONS.iter().skip(from_format as usize) {
+        migration(data);
+    }
+
+    Ok(())
+}
+
+fn split_envelope(value: Value) -> Result<(u32, Value), Error> {
+    let Value::Object(mut object) = value else {
+        return Ok((LEGACY_FORMAT, value));
+    };
+
+    let is_envelope = object.len().eq(&3)
+        && object.contains_key(FORMAT_KEY)
+        && object.get(VERSION_KEY).is_some_and(Value::is_string)
+        && object.contains_key(DATA_KEY);
+    if !is_envelope {
+        return Ok((LEGACY_FORMAT, Value::Object(object)));
+    }
+
+    let format = object
+        .get(FORMAT_KEY)
+        .and_then(Value::as_u64)
+        .and_then(|format| u32::try_from(format).ok())
+        .ok_or(Error::InvalidSerializedData)?;
+    let data = object.remove(DATA_KEY).unwrap_or(Value::Null);
+
+    Ok((format, data))
+}
+
+fn migrate_legacy_to_1(_data: &mut Value) {}
+
+fn rename_event_success(event: &mut Value, from: &str, to: &str) {
+    if let Some(success) = event.pointer_mut("/Success/success")
+        && success.as_str().eq(&Some(from))
+    {
+        *success = Value::String(to.to_string());
+    }
+}
+
+/// Renames a `Success` variant where it can be stored: in a `GameEvent::Success`, alone or in
+/// the events of a game. Other strings, such as team or player names, are left untouched.
+fn rename_success(data: &mut Value, from: &str, to: &str) {
+    if let Some(Value::Array(events)) = data.get_mut("events") {
+        for event in events.iter_mut() {
+            rename_event_success(event, from, to);
+        }
+    } else {
+        rename_event_success(data, from, to);
+    }
+}
+
+fn migrate_1_to_2(data: &mut Value) {
+    rename_success(data, "LandingOnfeet", "LandingOnFeet");
+}
+
+/// JSON Schema of the envelope around `T`.
+pub fn json_schema<T: JsonSchema>() -> Value {
+    serde_json::to_value(schemars::schema_for!(Versioned<T>)).unwrap_or(Value::Null)
+}
+
+pub fn json_schemas() -> Vec<(&'static str, Value)> {
+    vec![
+        ("Coach", json_schema::<Coach>()),
+        ("Player", json_schema::<Player>()),
+        ("Team", json_schema::<Team>()),
+        ("GameEvent", json_schema::<GameEvent>()),
+        ("Game", json_schema::<Game>()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actions::Success;
+    use crate::injuries::{Injury, InjuryCause};
+    use crate::test_utils;
+    use crate::versions::Version;
+
+    #[test]
+    fn round_trips() -> Result<(), Error> {
+        let team = test_utils::team(1, "Reds", Version::V5S3, 11);
+        let json = to_versioned_json(&team)?;
+        assert!(json.starts_with(&format!(
+            "{{\"format\":2,\"version\":\"{}\",\"data\":{{",
+            env!("CARGO_PKG_VERSION")
+        )));
+        let round_trip: Team = from_versioned_json(&json)?;
+        assert_eq!(
+            serde_json::to_value(&round_trip).unwrap(),
+            serde_json::to_value(&team).unwrap()
+        );
+
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            test_utils::date(),
+            &team,
+            &test_utils::team(2, "Blues", Version::V5S3, 11),
+            false,
+        )?;
+        game.start();
+        game.push_injury_with_cause(2, 201, Injury::BadlyHurt, InjuryCause::Block, Some(101))?;
+        let json = to_versioned_json_pretty(&game)?;
+        let round_trip: Game = from_versioned_json(&json)?;
+        assert_eq!(
+            serde_json::to_value(&round_trip).unwrap(),
+            serde_json::to_value(&game).unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn migrations() -> Result<(), Error> {
+        let mut legacy =
+            serde_json::to_value(test_utils::team(1, "Reds", Version::V5S3, 11)).unwrap();
+        legacy["coach"].as_object_mut().unwrap().remove("ratings");
+        assert_eq!(format_of(&legacy), LEGACY_FORMAT);
+        let team: Team = from_versioned_value(legacy)?;
+        assert!(team.coach.ratings.is_empty());
+
+        let event = serde_json::json!({
+            "Injury": {"team_id": 1, "player_id": 101, "injury": "BadlyHurt"}
+        });
+        let event: GameEvent = from_versioned_value(event)?;
+        assert!(matches!(
+            event,
+            GameEvent::Injury {
+                cause: None,
+                inflicting_player_id: None,
+                ..
+            }
+        ));
+
+        let event = serde_json::json!({
+            "format": 1,
+            "version": "0.1.0",
+            "data": {"Success": {
+                "team_id": 1,
+                "player_id": 101,
+                "success": "LandingOnfeet",
+                "star_player_points": 1
+            }}
+        });
+        let event: GameEvent = from_versioned_value(event)?;
+        assert!(matches!(
+            event,
+            GameEvent::Success {
+                success: Success::LandingOnFeet,
+                ..
+            }
+        ));
+
+        let mut game = Game::create(
+            1,
+            None,
+            Version::V5S3,
+            test_utils::date(),
+            &test_utils::team(1, "LandingOnfeet", Version::V5S3, 11),
+            &test_utils::team(2, "Blues", Version::V5S3, 11),
+            false,
+        )?;
+        game.title = Some("LandingOnfeet".to_string());
+        game.start();
+        game.push_success(1, 101, Success::LandingOnFeet)?;
+        let mut stored = to_versioned_value(&game)?;
+        stored["format"] = Value::from(1);
+        for event in stored["data"]["events"].as_array_mut().unwrap() {
+            if let Some(success) = event.pointer_mut("/Success/success") {
+                *success = Value::from("LandingOnfeet");
+            }
+        }
+        let migrated: Game = from_versioned_value(stored)?;
+        assert_eq!(migrated.title, game.title);
+        assert_eq!(migrated.first_team.name, "LandingOnfeet");
+        assert!(migrated.events.iter().any(|event| matches!(
+            event,
+            GameEvent::Success {
+                success: Success::LandingOnFeet,
+                ..
+            }
+        )));
+
+        let lookalike = serde_json::json!({"format": 1, "data": "LandingOnfeet"});
+        assert_eq!(format_of(&lookalike), LEGACY_FORMAT);
+
+        let future = serde_json::json!({
+            "format": CURRENT_FORMAT + 1,
+            "version": "99.0.0",
+            "data": {}
+        });
+        assert_eq!(format_of(&future), CURRENT_FORMAT + 1);
+        assert!(matches!(
+            from_versioned_value::<Team>(future),
+            Err(Error::UnsupportedFormat)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn variant_names() {
+        let names = |values: Vec<Value>| -> Vec<String> {
+            values
+                .iter()
+                .map(|value| value.as_str().unwrap_or_default().to_string())
+                .collect()
+        };
+
+        assert_eq!(
+            names(
+                [
+                    Success::PassingCompletion,
+                    Success::ThrowingCompletion,
+                    Success::LandingOnFeet,
+                    Success::Deflection,
+                    Success::Interception,
+                    Success::Casualty,
+                    Success::Touchdown,
+                    Success::MostValuablePlayer,
+                    Success::StarPlayerPoint,
+                ]
+                .iter()
+                .map(|success| serde_json::to_value(success).unwrap())
+                .collect()
+            ),
+            vec![
+                "PassingCompletion",
+                "ThrowingCompletion",
+                "LandingOnFeet",
+                "Deflection",
+                "Interception",
+                "Casualty",
+                "Touchdown",
+                "MostValuablePlayer",
+                "StarPlayerPoint",
+            ]
+        );
+        assert_eq!(
+            names(
+                Injury::list(&Version::V5S3)
+                    .iter()
+                    .map(|injury| serde_json::to_value(injury).unwrap())
+                    .collect()
+            ),
+            vec![
+                "Stunned",
+                "KO",
+                "BadlyHurt",
+                "SeriouslyHurt",
+                "SeriousInjury",
+                "HeadInjury",
+                "SmashedKnee",
+                "BrokenArm",
+                "DislocatedHip",
+                "NeckInjury",
+                "Dead",
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(GameEvent::Injury {
+                team_id: 1,
+                player_id: 101,
+                injury: Injury::BadlyHurt,
+                cause: Some(InjuryCause::Block),
+                inflicting_player_id: Some(201),
+            })
+            .unwrap(),
+            serde_json::json!({"Injury": {
+                "team_id": 1,
+                "player_id": 101,
+                "injury": "BadlyHurt",
+                "cause": "Block",
+                "inflicting_player_id": 201
+            }})
+        );
+        assert_eq!(
+            serde_json::to_value([Version::V5, Version::V5S3]).unwrap(),
+            serde_json::json!(["V5", "V5S3"])
+        );
+    }
+
+    #[test]
+    fn schemas() {
+        let schemas = json_schemas();
+        assert_eq!(schemas.len(), 5);
+
+        let (_, game_schema) = schemas.iter().find(|(name, _)| name.eq(&"Game")).unwrap();
+        assert!(game_schema["properties"]["format"].is_object());
+        assert!(game_schema["properties"]["data"].is_object());
+        for definition in ["Team", "Player", "GameEvent", "Coach", "Version"] {
+            assert!(game_schema["$defs"][definition].is_object());
+        }
+    }
+}
@@ -0,0 +1,567 @@
+use crate::blocks::Block;
+use crate::dices::Dice;
+use crate::elo::naf_elo_gap_from_victory_probability;
+use crate::errors::Error;
+use crate::pitch::{Pitch, PitchFormat};
+use crate::players::Player;
+use crate::probabilities::{ActionRoll, ActionRollKind, ProbabilityCalculator};
+use crate::teams::Team;
+use crate::weather::Weather;
+use rand::rngs::StdRng;
+use rand::{RngExt, SeedableRng};
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const TURNS_PER_HALF: u8 = 8;
+const BLOCKS_PER_TURN: u8 = 3;
+const KICK_OFF_DISTANCE: i32 = 20;
+const CARRIER_REACH: f64 = 0.2;
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct TeamProfile {
+    pub team_id: i32,
+    pub available_players: u8,
+    pub maximum_players_on_pitch: u8,
+    pub movement: f64,
+    pub pick_up: f64,
+    pub dodge: f64,
+}
+
+impl TeamProfile {
+    fn starters(team: &Team) -> Vec<Player> {
+        let maximum_players_on_pitch = Pitch::new(PitchFormat::Standard).maximum_players_on_pitch;
+        let mut players = team.available_players();
+        players.sort_by_key(|(number, _)| *number);
+
+        players
+            .into_iter()
+            .take(maximum_players_on_pitch)
+            .map(|(_, player)| player)
+            .collect()
+    }
+
+    pub fn from_team(team: &Team) -> Result<Self, Error> {
+        let starters = Self::starters(team);
+        let mut movement = 0.0;
+        let mut pick_up: f64 = 0.0;
+        let mut dodge: f64 = 0.0;
+
+        for player in starters.iter() {
+            let calculator =
+                ProbabilityCalculator::for_team_player(team, player, Weather::PerfectConditions)?;
+            movement += player.movement_allowance().unwrap_or(0) as f64;
+            pick_up = pick_up.max(calculator.chain(&[ActionRoll::new(ActionRollKind::PickUp)]));
+            dodge =
+                dodge.max(calculator.chain(&[ActionRoll::new(ActionRollKind::Dodge).marked_by(1)]));
+        }
+
+        if !starters.is_empty() {
+            movement /= starters.len() as f64;
+        }
+
+        Ok(Self {
+            team_id: team.id,
+            available_players: team.number_of_available_players(),
+            maximum_players_on_pitch: Pitch::new(PitchFormat::Standard).maximum_players_on_pitch
+                as u8,
+            movement,
+            pick_up,
+            dodge,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct BlockingProfile {
+    pub knock_down: f64,
+    pub turnover: f64,
+    pub knocked_out: f64,
+    pub casualty: f64,
+}
+
+impl BlockingProfile {
+    pub fn between(attacking_team: &Team, defending_team: &Team) -> Result<Self, Error> {
+        let attackers = TeamProfile::starters(attacking_team);
+        let defenders = TeamProfile::starters(defending_team);
+        let mut profile = Self {
+            knock_down: 0.0,
+            turnover: 0.0,
+            knocked_out: 0.0,
+            casualty: 0.0,
+        };
+
+        for attacker in attackers.iter() {
+            for defender in defenders.iter() {
+                let odds = Block::new(attacker, defender).with_assists(1, 0).odds()?;
+                profile.knock_down += odds.defender_knocked_down;
+                profile.turnover += odds.turnover;
+                profile.knocked_out += odds.knock_down.knocked_out;
+                profile.casualty += odds.knock_down.casualty;
+            }
+        }
+
+        let blocks = (attackers.len() * defenders.len()) as f64;
+        if blocks > 0.0 {
+            profile.knock_down /= blocks;
+            profile.turnover /= blocks;
+            profile.knocked_out /= blocks;
+            profile.casualty /= blocks;
+        }
+
+        Ok(profile)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct SimulatedGame {
+    pub first_team_touchdowns: u8,
+    pub second_team_touchdowns: u8,
+    pub first_team_casualties_inflicted: u8,
+    pub second_team_casualties_inflicted: u8,
+}
+
+impl SimulatedGame {
+    pub fn winner(&self) -> (bool, bool) {
+        (
+            self.first_team_touchdowns > self.second_team_touchdowns,
+            self.second_team_touchdowns > self.first_team_touchdowns,
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct SimulationResult {
+    pub games: u32,
+    pub first_team_wins: f64,
+    pub draws: f64,
+    pub second_team_wins: f64,
+    pub first_team_touchdowns: Vec<f64>,
+    pub second_team_touchdowns: Vec<f64>,
+    pub first_team_casualties_inflicted: f64,
+    pub second_team_casualties_inflicted: f64,
+}
+
+impl SimulationResult {
+    fn expected_touchdowns(distribution: &[f64]) -> f64 {
+        distribution
+            .iter()
+            .enumerate()
+            .map(|(touchdowns, probability)| touchdowns as f64 * probability)
+            .sum()
+    }
+
+    pub fn first_team_expected_touchdowns(&self) -> f64 {
+        Self::expected_touchdowns(&self.first_team_touchdowns)
+    }
+
+    pub fn second_team_expected_touchdowns(&self) -> f64 {
+        Self::expected_touchdowns(&self.second_team_touchdowns)
+    }
+
+    pub fn expected_points(&self) -> (f64, f64) {
+        (
+            self.first_team_wins + self.draws / 2.0,
+            self.second_team_wins + self.draws / 2.0,
+        )
+    }
+
+    /// Elo gap matching the first team expected points, which are kept half a game away from 0
+    /// and 1 so that a sweep still gives a finite gap.
+    pub fn elo_gap(&self) -> f64 {
+        let margin = 0.5 / self.games.max(1) as f64;
+
+        naf_elo_gap_from_victory_probability(self.expected_points().0.clamp(margin, 1.0 - margin))
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct SideState {
+    available_players: u8,
+    knocked_out: u8,
+    casualties_suffered: u8,
+    touchdowns: u8,
+    turns: u8,
+}
+
+impl SideState {
+    fn new(profile: &TeamProfile) -> Self {
+        Self {
+            available_players: profile.available_players,
+            knocked_out: 0,
+            casualties_suffered: 0,
+            touchdowns: 0,
+            turns: 0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Drive {
+    offense: usize,
+    distance: i32,
+    ball_secured: bool,
+}
+
+impl Drive {
+    fn kick_off(receiving: usize) -> Self {
+        Self {
+            offense: receiving,
+            distance: KICK_OFF_DISTANCE,
+            ball_secured: false,
+        }
+    }
+
+    fn turnover(&mut self) {
+        let length = Pitch::new(PitchFormat::Standard).length as i32;
+        self.offense = 1 - self.offense;
+        self.distance = length - self.distance.max(0);
+        self.ball_secured = false;
+    }
+}
+
+pub struct MatchSimulator {
+    pub first_team: TeamProfile,
+    pub second_team: TeamProfile,
+    pub first_team_blocking: BlockingProfile,
+    pub second_team_blocking: BlockingProfile,
+    rng: StdRng,
+}
+
+impl MatchSimulator {
+    pub fn new(first_team: &Team, second_team: &Team, seed: u64) -> Result<Self, Error> {
+        if first_team.version.ne(&second_team.version) {
+            return Err(Error::TeamsMustMatchGameVersion);
+        }
+
+        Ok(Self {
+            first_team: TeamProfile::from_team(first_team)?,
+            second_team: TeamProfile::from_team(second_team)?,
+            first_team_blocking: BlockingProfile::between(first_team, second_team)?,
+            second_team_blocking: BlockingProfile::between(second_team, first_team)?,
+            rng: StdRng::seed_from_u64(seed),
+        })
+    }
+
+    fn profile(&self, side: usize) -> &TeamProfile {
+        if side == 0 {
+            &self.first_team
+        } else {
+            &self.second_team
+        }
+    }
+
+    fn blocking(&self, side: usize) -> &BlockingProfile {
+        if side == 0 {
+            &self.first_team_blocking
+        } else {
+            &self.second_team_blocking
+        }
+    }
+
+    fn on_pitch(&self, sides: &[SideState; 2], side: usize) -> u8 {
+        sides[side]
+            .available_players
+            .min(self.profile(side).maximum_players_on_pitch)
+    }
+
+    fn kick_off(&mut self, sides: &mut [SideState; 2], receiving: usize) -> Drive {
+        for side in sides.iter_mut() {
+            for _ in 0..side.knocked_out {
+                if Dice::D6.roll_with(&mut self.rng) >= 4 {
+                    side.knocked_out -= 1;
+                    side.available_players += 1;
+                }
+            }
+        }
+
+        Drive::kick_off(receiving)
+    }
+
+    fn block(&mut self, sides: &mut [SideState; 2], attacker: usize) -> (bool, bool) {
+        let blocking = *self.blocking(attacker);
+        let defender = 1 - attacker;
+        let roll: f64 = self.rng.random();
+
+        if roll < blocking.turnover {
+            return (true, false);
+        }
+        if roll >= blocking.turnover + blocking.knock_down {
+            return (false, false);
+        }
+
+        let injury: f64 = self.rng.random();
+        if injury < blocking.casualty {
+            sides[defender].available_players -= 1;
+            sides[defender].casualties_suffered += 1;
+        } else if injury < blocking.casualty + blocking.knocked_out {
+            sides[defender].available_players -= 1;
+            sides[defender].knocked_out += 1;
+        }
+
+        (false, true)
+    }
+
+    fn play_offensive_turn(&mut self, sides: &mut [SideState; 2], drive: &mut Drive) -> bool {
+        let offense = drive.offense;
+        let defense = 1 - offense;
+        let profile = self.profile(offense).clone();
+
+        if !drive.ball_secured {
+            if self.on_pitch(sides, offense) == 0 || !self.rng.random_bool(profile.pick_up) {
+                drive.turnover();
+                return false;
+            }
+            drive.ball_secured = true;
+        }
+
+        let attackers = self.on_pitch(sides, offense) as f64;
+        let defenders = self.on_pitch(sides, defense) as f64;
+        let marked = defenders / (attackers + defenders).max(1.0);
+        if self.rng.random_bool(marked) && !self.rng.random_bool(profile.dodge) {
+            drive.turnover();
+            return false;
+        }
+
+        let mut advance =
+            profile.movement.round() as i32 - 3 + Dice::D3.roll_with(&mut self.rng) as i32 - 1;
+        if attackers < defenders {
+            advance -= 1;
+        }
+        drive.distance -= advance.max(1);
+
+        if drive.distance <= 0 {
+            sides[offense].touchdowns += 1;
+            return true;
+        }
+
+        let blocks = BLOCKS_PER_TURN.min(self.on_pitch(sides, offense));
+        for _ in 0..blocks {
+            if self.on_pitch(sides, defense) == 0 {
+                break;
+            }
+            let (turnover, _) = self.block(sides, offense);
+            if turnover {
+                break;
+            }
+        }
+
+        false
+    }
+
+    fn play_defensive_turn(&mut self, sides: &mut [SideState; 2], drive: &mut Drive) {
+        let defense = 1 - drive.offense;
+        let attackers = self.on_pitch(sides, defense) as f64;
+        let defenders = self.on_pitch(sides, drive.offense).max(1) as f64;
+        let reach = (CARRIER_REACH * attackers / defenders).min(0.9);
+
+        let blocks = BLOCKS_PER_TURN.min(self.on_pitch(sides, defense));
+        for block in 0..blocks {
+            if self.on_pitch(sides, drive.offense) == 0 {
+                break;
+            }
+            let on_carrier = block == 0 && drive.ball_secured && self.rng.random_bool(reach);
+            let (turnover, knocked_down) = self.block(sides, defense);
+            if on_carrier && knocked_down {
+                drive.turnover();
+            }
+            if turnover || on_carrier {
+                return;
+            }
+        }
+    }
+
+    fn play_half(&mut self, sides: &mut [SideState; 2], receiving: usize) {
+        for side in sides.iter_mut() {
+            side.turns = TURNS_PER_HALF;
+        }
+
+        let mut drive = self.kick_off(sides, receiving);
+        let mut active = receiving;
+        while sides.iter().any(|side| side.turns > 0) {
+            if sides[active].turns == 0 {
+                active = 1 - active;
+                continue;
+            }
+            sides[active].turns -= 1;
+
+            if active == drive.offense {
+                if self.play_offensive_turn(sides, &mut drive) {
+                    drive = self.kick_off(sides, 1 - active);
+                }
+            } else {
+                self.play_defensive_turn(sides, &mut drive);
+            }
+
+            active = 1 - active;
+        }
+    }
+
+    pub fn simulate_game(&mut self) -> SimulatedGame {
+        let mut sides = [
+            SideState::new(&self.first_team),
+            SideState::new(&self.second_team),
+        ];
+        let first_receiving = Dice::D2.roll_with(&mut self.rng) - 1;
+
+        self.play_half(&mut sides, first_receiving);
+        self.play_half(&mut sides, 1 - first_receiving);
+
+        SimulatedGame {
+            first_team_touchdowns: sides[0].touchdowns,
+            second_team_touchdowns: sides[1].touchdowns,
+            first_team_casualties_inflicted: sides[1].casualties_suffered,
+            second_team_casualties_inflicted: sides[0].casualties_suffered,
+        }
+    }
+
+    pub fn simulate(&mut self, games: u32) -> SimulationResult {
+        let mut result = SimulationResult {
+            games,
+            first_team_wins: 0.0,
+            draws: 0.0,
+            second_team_wins: 0.0,
+            first_team_touchdowns: Vec::new(),
+            second_team_touchdowns: Vec::new(),
+            first_team_casualties_inflicted: 0.0,
+            second_team_casualties_inflicted: 0.0,
+        };
+        if games == 0 {
+            return result;
+        }
+
+        let weight = 1.0 / games as f64;
+        for _ in 0..games {
+            let game = self.simulate_game();
+
+            match game.winner() {
+                (true, false) => result.first_team_wins += weight,
+                (false, true) => result.second_team_wins += weight,
+                _ => result.draws += weight,
+            }
+
+            for (distribution, touchdowns) in [
+                (
+                    &mut result.first_team_touchdowns,
+                    game.first_team_touchdowns,
+                ),
+                (
+                    &mut result.second_team_touchdowns,
+                    game.second_team_touchdowns,
+                ),
+            ] {
+                if distribution.len() <= touchdowns as usize {
+                    distribution.resize(touchdowns as usize + 1, 0.0);
+                }
+                distribution[touchdowns as usize] += weight;
+            }
+
+            result.first_team_casualties_inflicted +=
+                game.first_team_casualties_inflicted as f64 * weight;
+            result.second_team_casualties_inflicted +=
+                game.second_team_casualties_inflicted as f64 * weight;
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::advancements::Advancement;
+    use crate::skills::Skill;
+    use crate::staffs::Staff;
+    use crate::test_utils;
+    use crate::versions::Version;
+    use std::collections::HashMap;
+    use std::time::{Duration, Instant};
+
+    fn team(id: i32, skills: &[Skill], re_rolls: u8) -> Team {
+        let mut team = Team {
+            staff: HashMap::from([(Staff::ReRoll, re_rolls)]),
+            ..test_utils::team(id, &format!("Team {}", id), Version::V5S3, 11)
+        };
+        for (_, player) in team.players.iter_mut() {
+            player.advancements = skills
+                .iter()
+                .map(|skill| Advancement::ChosenSkill(*skill))
+                .collect();
+        }
+
+        team
+    }
+
+    #[test]
+    fn seeded_simulation() -> Result<(), Error> {
+        let reds = team(1, &[], 2);
+        let blues = team(2, &[], 2);
+
+        let first_result = MatchSimulator::new(&reds, &blues, 7)?.simulate(2000);
+        let second_result = MatchSimulator::new(&reds, &blues, 7)?.simulate(2000);
+        assert_eq!(first_result, second_result);
+
+        let total =
+            first_result.first_team_wins + first_result.draws + first_result.second_team_wins;
+        assert!((total - 1.0).abs() < 1e-9);
+        let distribution: f64 = first_result.first_team_touchdowns.iter().sum();
+        assert!((distribution - 1.0).abs() < 1e-9);
+        assert!(first_result.first_team_expected_touchdowns() > 0.5);
+        assert!(first_result.first_team_casualties_inflicted > 0.0);
+        assert!((first_result.first_team_wins - first_result.second_team_wins).abs() < 0.1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn stronger_team() -> Result<(), Error> {
+        let veterans = team(1, &[Skill::Block, Skill::Dodge, Skill::SureHands], 3);
+        let rookies = team(2, &[], 0);
+
+        let mut simulator = MatchSimulator::new(&veterans, &rookies, 42)?;
+        assert!(
+            simulator.first_team_blocking.knock_down > simulator.second_team_blocking.knock_down
+        );
+        assert!(simulator.first_team.pick_up > simulator.second_team.pick_up);
+
+        let result = simulator.simulate(2000);
+        assert!(result.first_team_wins > result.second_team_wins);
+        assert!(result.first_team_casualties_inflicted > result.second_team_casualties_inflicted);
+        assert!(result.elo_gap() > 0.0);
+        let sweep = SimulationResult {
+            first_team_wins: 1.0,
+            draws: 0.0,
+            second_team_wins: 0.0,
+            ..result.clone()
+        };
+        assert!(sweep.elo_gap().is_finite());
+        assert!(sweep.elo_gap() > result.elo_gap());
+
+        let mut outdated = team(3, &[], 0);
+        outdated.version = Version::V5;
+        assert!(matches!(
+            MatchSimulator::new(&veterans, &outdated, 42),
+            Err(Error::TeamsMustMatchGameVersion)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[ignore = "throughput check, run with `cargo test --release -- --ignored`"]
+    fn hundred_thousand_games_in_seconds() -> Result<(), Error> {
+        let reds = team(1, &[Skill::Block], 2);
+        let blues = team(2, &[Skill::Dodge], 2);
+
+        let start = Instant::now();
+        MatchSimulator::new(&reds, &blues, 1)?.simulate(100_000);
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        Ok(())
+    }
+}
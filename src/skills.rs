@@ -1,16 +1,19 @@
 use crate::players::Player;
-use crate::positions::{Keyword, Position};
-use crate::translation::{LOCALES, TranslatedName, TypeName, language_from};
+use crate::positions::{Keyword, Position, PositionDefinition};
+use crate::rosters::Roster;
+use crate::stars::{mega_star_position_list, star_player_position_definition, star_position_list};
+use crate::translation::{TranslatedName, TypeName, lookup, lookup_with_args};
 use crate::versions::Version;
-use fluent_templates::Loader;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::borrow::Cow;
-use std::collections::HashMap;
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SkillCategory {
     Agility,
     General,
@@ -26,6 +29,19 @@ impl TypeName for SkillCategory {}
 impl TranslatedName for SkillCategory {}
 
 impl SkillCategory {
+    pub fn list() -> Vec<Self> {
+        vec![
+            SkillCategory::General,
+            SkillCategory::Agility,
+            SkillCategory::Strength,
+            SkillCategory::Pass,
+            SkillCategory::Mutation,
+            SkillCategory::Devious,
+            SkillCategory::Trait,
+            SkillCategory::Special,
+        ]
+    }
+
     pub fn skills_available_for_player(&self, player: &Player) -> Vec<Skill> {
         let mut skills = vec![];
 
@@ -47,7 +63,139 @@ impl SkillCategory {
     }
 }
 
-#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum SkillTrigger {
+    Activation,
+    Movement,
+    Dodge,
+    Rush,
+    PickUp,
+    Block,
+    Pass,
+    Catch,
+    Foul,
+    Armour,
+    Injury,
+    KickOff,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum SkillUsage {
+    Passive,
+    Optional,
+    Compulsory,
+    OncePerTurn,
+    OncePerHalf,
+    OncePerGame,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub enum SkillCondition {
+    Always,
+    Blitz,
+    Block,
+    Foul,
+    SkillAction,
+    QuickOrShortPass,
+    LongPassOrBomb,
+    AccuratePass,
+    Opponent,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
+pub struct SkillEffect {
+    pub triggers: Vec<SkillTrigger>,
+    pub usage: SkillUsage,
+    pub re_roll_triggers: Vec<SkillTrigger>,
+    pub modifiers: Vec<(SkillTrigger, i8, SkillCondition)>,
+    pub ignored_marking: Vec<SkillTrigger>,
+    pub ignored_weather: Vec<SkillTrigger>,
+    pub armour_broken_on: Option<u8>,
+}
+
+impl SkillEffect {
+    pub fn new(usage: SkillUsage, triggers: &[SkillTrigger]) -> Self {
+        Self {
+            triggers: triggers.to_vec(),
+            usage,
+            re_roll_triggers: Vec::new(),
+            modifiers: Vec::new(),
+            ignored_marking: Vec::new(),
+            ignored_weather: Vec::new(),
+            armour_broken_on: None,
+        }
+    }
+
+    pub fn with_re_roll(mut self, trigger: SkillTrigger) -> Self {
+        self.re_roll_triggers.push(trigger);
+        self
+    }
+
+    pub fn with_modifier(self, trigger: SkillTrigger, value: i8) -> Self {
+        self.with_modifier_when(trigger, value, SkillCondition::Always)
+    }
+
+    pub fn with_modifier_when(
+        mut self,
+        trigger: SkillTrigger,
+        value: i8,
+        condition: SkillCondition,
+    ) -> Self {
+        self.modifiers.push((trigger, value, condition));
+        self
+    }
+
+    pub fn with_ignored_marking(mut self, trigger: SkillTrigger) -> Self {
+        self.ignored_marking.push(trigger);
+        self
+    }
+
+    pub fn with_ignored_weather(mut self, trigger: SkillTrigger) -> Self {
+        self.ignored_weather.push(trigger);
+        self
+    }
+
+    pub fn with_armour_broken_on(self, armour_roll: u8) -> Self {
+        Self {
+            armour_broken_on: Some(armour_roll),
+            ..self
+        }
+    }
+
+    pub fn applies_to(&self, trigger: &SkillTrigger) -> bool {
+        self.triggers.contains(trigger)
+    }
+
+    pub fn re_rolls(&self, trigger: &SkillTrigger) -> bool {
+        self.re_roll_triggers.contains(trigger)
+    }
+
+    pub fn modifier(&self, trigger: &SkillTrigger, conditions: &[SkillCondition]) -> i8 {
+        self.modifiers
+            .iter()
+            .filter(|(modified_trigger, _, condition)| {
+                modified_trigger.eq(trigger)
+                    && (condition.eq(&SkillCondition::Always) || conditions.contains(condition))
+            })
+            .map(|(_, value, _)| value)
+            .sum()
+    }
+
+    pub fn ignores_marking(&self, trigger: &SkillTrigger) -> bool {
+        self.ignored_marking.contains(trigger)
+    }
+
+    pub fn ignores_weather(&self, trigger: &SkillTrigger) -> bool {
+        self.ignored_weather.contains(trigger)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Skill {
     // General
     Block,
@@ -253,42 +401,76 @@ impl TypeName for Skill {}
 impl TranslatedName for Skill {
     fn name(&self, lang_id: &str) -> String {
         match self {
-            Skill::Animosity(position) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Skill::Animosity(position) => lookup_with_args(
+                lang_id,
                 "Animosity",
-                &HashMap::from([(Cow::from("position"), position.name(lang_id).into())]),
-            ),
-            Skill::BloodLust(value) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
-                "BloodLust",
-                &HashMap::from([(Cow::from("value"), value.into())]),
+                vec![("position", position.name(lang_id).into())],
             ),
-            Skill::DirtyPlayerNumber(value) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Skill::BloodLust(value) => {
+                lookup_with_args(lang_id, "BloodLust", vec![("value", (*value).into())])
+            }
+            Skill::DirtyPlayerNumber(value) => lookup_with_args(
+                lang_id,
                 "DirtyPlayerNumber",
-                &HashMap::from([(Cow::from("value"), value.into())]),
+                vec![("value", (*value).into())],
             ),
-            Skill::Hatred(position) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Skill::Hatred(position) => lookup_with_args(
+                lang_id,
                 "Hatred",
-                &HashMap::from([(Cow::from("keyword"), position.name(lang_id).into())]),
+                vec![("keyword", position.name(lang_id).into())],
             ),
-            Skill::MightyBlowNumber(value) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
+            Skill::MightyBlowNumber(value) => lookup_with_args(
+                lang_id,
                 "MightyBlowNumber",
-                &HashMap::from([(Cow::from("value"), value.into())]),
-            ),
-            Skill::Loner(value) => LOCALES.lookup_with_args(
-                &language_from(lang_id),
-                "Loner",
-                &HashMap::from([(Cow::from("value"), value.into())]),
+                vec![("value", (*value).into())],
             ),
-            _ => LOCALES.lookup(&language_from(lang_id), &*self.type_name()),
+            Skill::Loner(value) => {
+                lookup_with_args(lang_id, "Loner", vec![("value", (*value).into())])
+            }
+            _ => lookup(lang_id, &self.type_name()),
         }
     }
 }
 
 impl Skill {
+    pub fn list(version: &Version) -> Vec<Self> {
+        let mut skills: Vec<Skill> = Vec::new();
+        let mut definitions: Vec<PositionDefinition> = Vec::new();
+
+        for roster in Roster::list(*version) {
+            let Some(roster_definition) = roster.definition(*version) else {
+                continue;
+            };
+            for position in roster_definition.positions {
+                definitions.extend(position.definition(*version, roster));
+            }
+        }
+        for position in [
+            star_position_list(version),
+            mega_star_position_list(version),
+        ]
+        .concat()
+        {
+            definitions.extend(star_player_position_definition(&position, version));
+        }
+
+        let candidates = SkillCategory::list()
+            .into_iter()
+            .flat_map(|skill_category| skill_category.skills_to_be_added(version))
+            .chain(
+                definitions
+                    .into_iter()
+                    .flat_map(|definition| definition.skills),
+            );
+        for skill in candidates {
+            if !skills.contains(&skill) {
+                skills.push(skill);
+            }
+        }
+
+        skills
+    }
+
     pub fn is_primary_for_player(&self, player: &Player) -> bool {
         if let (Some(position_definition), Some(skill_category)) = (
             player.position_definition(),
@@ -355,6 +537,14 @@ impl Skill {
         }
     }
 
+    pub fn effect(&self, version: &Version) -> Option<SkillEffect> {
+        match version {
+            Version::V1 | Version::V2 | Version::V3 | Version::V4 => None,
+            Version::V5 => v5::skill_effect(self),
+            Version::V5S3 => v5s3::skill_effect(self),
+        }
+    }
+
     pub fn could_be_added_for_player_regarding_other_skills(&self, player: &Player) -> bool {
         if player.skills().contains(self) {
             return false;
@@ -392,7 +582,7 @@ impl Skill {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "i18n"))]
 mod tests {
     use super::*;
 
@@ -419,4 +609,60 @@ mod tests {
         let result = Skill::Dauntless.name("fr");
         assert_eq!(result, "Intrépide");
     }
+
+    #[test]
+    fn skill_effects() {
+        let dodge = Skill::Dodge.effect(&Version::V5S3).unwrap();
+        assert!(dodge.re_rolls(&SkillTrigger::Dodge));
+        assert!(!dodge.re_rolls(&SkillTrigger::Rush));
+        assert!(dodge.applies_to(&SkillTrigger::Block));
+        assert!(!dodge.re_rolls(&SkillTrigger::Block));
+        assert_eq!(dodge.usage, SkillUsage::OncePerTurn);
+
+        let block = [SkillCondition::Block];
+        let mighty_blow = Skill::MightyBlowNumber(2).effect(&Version::V5).unwrap();
+        assert_eq!(mighty_blow.modifier(&SkillTrigger::Armour, &block), 2);
+        assert_eq!(mighty_blow.modifier(&SkillTrigger::Armour, &[]), 0);
+        assert_eq!(mighty_blow.modifier(&SkillTrigger::Dodge, &block), 0);
+        assert_eq!(Skill::MightyBlowNumber(2).effect(&Version::V5S3), None);
+        assert_eq!(
+            Skill::MightyBlow
+                .effect(&Version::V5S3)
+                .unwrap()
+                .modifier(&SkillTrigger::Injury, &block),
+            1
+        );
+        assert!(
+            Skill::NervesOfSteel
+                .effect(&Version::V5S3)
+                .unwrap()
+                .ignores_marking(&SkillTrigger::Catch)
+        );
+        assert_eq!(
+            Skill::Claws.effect(&Version::V5).unwrap().armour_broken_on,
+            Some(8)
+        );
+
+        let halfling_luck = Skill::HalflingLuck.effect(&Version::V5S3).unwrap();
+        assert_eq!(halfling_luck.usage, SkillUsage::OncePerGame);
+        assert!(halfling_luck.re_rolls(&SkillTrigger::Catch));
+        for version in [Version::V5, Version::V5S3] {
+            for skill in Skill::list(&version) {
+                assert!(
+                    skill.effect(&version).is_some(),
+                    "{:?} has no effect in {:?}",
+                    skill,
+                    version
+                );
+            }
+        }
+
+        assert_eq!(
+            Skill::Frenzy.effect(&Version::V5S3).unwrap().usage,
+            SkillUsage::Compulsory
+        );
+        assert!(Skill::Taunt.effect(&Version::V5S3).is_some());
+        assert_eq!(Skill::Taunt.effect(&Version::V5), None);
+        assert_eq!(Skill::Block.effect(&Version::V4), None);
+    }
 }
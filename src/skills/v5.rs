@@ -1,4 +1,4 @@
-use crate::skills::{Skill, SkillCategory};
+use crate::skills::{Skill, SkillCategory, SkillCondition, SkillEffect, SkillTrigger, SkillUsage};
 
 pub fn skills_to_be_added_for_category(skill_category: &SkillCategory) -> Vec<Skill> {
     match skill_category {
@@ -245,3 +245,351 @@ pub fn skill_category_for_skill(skill: &Skill) -> Option<SkillCategory> {
         _ => None,
     }
 }
+
+pub fn skill_effect(skill: &Skill) -> Option<SkillEffect> {
+    skill_category_for_skill(skill)?;
+
+    let effect = match skill {
+        Skill::Block => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::Dauntless => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::DirtyPlayerNumber(value) => SkillEffect::new(
+            SkillUsage::Optional,
+            &[
+                SkillTrigger::Foul,
+                SkillTrigger::Armour,
+                SkillTrigger::Injury,
+            ],
+        )
+        .with_modifier_when(SkillTrigger::Armour, *value as i8, SkillCondition::Foul)
+        .with_modifier_when(SkillTrigger::Injury, *value as i8, SkillCondition::Foul),
+        Skill::Fend => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::Frenzy => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Block]),
+        Skill::Kick => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::KickOff]),
+        Skill::Pro => with_any_re_roll(SkillUsage::OncePerTurn),
+        Skill::Shadowing => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::StripBall => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::SureHands => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::PickUp])
+            .with_re_roll(SkillTrigger::PickUp),
+        Skill::Tackle => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Dodge, SkillTrigger::Block],
+        ),
+        Skill::Wrestle => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+
+        Skill::Catch => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Catch])
+            .with_re_roll(SkillTrigger::Catch),
+        Skill::Defensive => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::DivingCatch => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Catch])
+            .with_modifier_when(SkillTrigger::Catch, 1, SkillCondition::AccuratePass),
+        Skill::DivingTackle => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Dodge])
+            .with_modifier_when(SkillTrigger::Dodge, -2, SkillCondition::Opponent),
+        Skill::Dodge => SkillEffect::new(
+            SkillUsage::OncePerTurn,
+            &[SkillTrigger::Dodge, SkillTrigger::Block],
+        )
+        .with_re_roll(SkillTrigger::Dodge),
+        Skill::JumpUp => SkillEffect::new(
+            SkillUsage::Optional,
+            &[SkillTrigger::Movement, SkillTrigger::Block],
+        ),
+        Skill::Leap => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::SideStep => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::SafePairOfHands => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::SneakyGit => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Foul]),
+        Skill::Sprint => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Rush]),
+        Skill::SureFeet => SkillEffect::new(SkillUsage::OncePerTurn, &[SkillTrigger::Rush])
+            .with_re_roll(SkillTrigger::Rush),
+
+        Skill::ArmBar => SkillEffect::new(
+            SkillUsage::Optional,
+            &[
+                SkillTrigger::Dodge,
+                SkillTrigger::Armour,
+                SkillTrigger::Injury,
+            ],
+        )
+        .with_modifier_when(SkillTrigger::Armour, 1, SkillCondition::Opponent)
+        .with_modifier_when(SkillTrigger::Injury, 1, SkillCondition::Opponent),
+        Skill::Brawler => SkillEffect::new(SkillUsage::OncePerTurn, &[SkillTrigger::Block])
+            .with_re_roll(SkillTrigger::Block),
+        Skill::BreakTackle => SkillEffect::new(SkillUsage::OncePerTurn, &[SkillTrigger::Dodge])
+            .with_modifier(SkillTrigger::Dodge, 1),
+        Skill::Grab => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::Guard => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::Juggernaut => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::MightyBlowNumber(value) => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Armour, SkillTrigger::Injury],
+        )
+        .with_modifier_when(SkillTrigger::Armour, *value as i8, SkillCondition::Block)
+        .with_modifier_when(SkillTrigger::Injury, *value as i8, SkillCondition::Block),
+        Skill::MultipleBlock => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::PileDriver => SkillEffect::new(
+            SkillUsage::Optional,
+            &[SkillTrigger::Block, SkillTrigger::Foul],
+        ),
+        Skill::StandFirm => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::StrongArm => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass])
+            .with_modifier_when(SkillTrigger::Pass, 1, SkillCondition::LongPassOrBomb),
+        Skill::ThickSkull => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Injury]),
+
+        Skill::Accurate => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass])
+            .with_modifier_when(SkillTrigger::Pass, 1, SkillCondition::QuickOrShortPass),
+        Skill::Cannoneer => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass])
+            .with_modifier_when(SkillTrigger::Pass, 1, SkillCondition::LongPassOrBomb),
+        Skill::CloudBurster => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::DumpOff => SkillEffect::new(
+            SkillUsage::Optional,
+            &[SkillTrigger::Block, SkillTrigger::Pass],
+        ),
+        Skill::Fumblerooski => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::HailMaryPass => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+        Skill::Leader => SkillEffect::new(SkillUsage::OncePerHalf, &[]),
+        Skill::NervesOfSteel => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Pass, SkillTrigger::Catch],
+        )
+        .with_ignored_marking(SkillTrigger::Pass)
+        .with_ignored_marking(SkillTrigger::Catch),
+        Skill::OnTheBall => SkillEffect::new(
+            SkillUsage::OncePerHalf,
+            &[SkillTrigger::KickOff, SkillTrigger::Pass],
+        ),
+        Skill::Pass => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass])
+            .with_re_roll(SkillTrigger::Pass),
+        Skill::RunningPass => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+        Skill::SafePass => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+
+        Skill::BigHand => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::PickUp])
+            .with_ignored_marking(SkillTrigger::PickUp)
+            .with_ignored_weather(SkillTrigger::PickUp),
+        Skill::Claws => {
+            SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Armour]).with_armour_broken_on(8)
+        }
+        Skill::DisturbingPresence => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Pass, SkillTrigger::Catch],
+        )
+        .with_modifier_when(SkillTrigger::Pass, -1, SkillCondition::Opponent)
+        .with_modifier_when(SkillTrigger::Catch, -1, SkillCondition::Opponent),
+        Skill::ExtraArms => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::PickUp, SkillTrigger::Catch],
+        )
+        .with_modifier(SkillTrigger::PickUp, 1)
+        .with_modifier(SkillTrigger::Catch, 1),
+        Skill::FoulAppearance => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::Horns => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block])
+            .with_modifier_when(SkillTrigger::Block, 1, SkillCondition::Blitz),
+        Skill::IronHardSkin => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Armour]),
+        Skill::MonstrousMouth => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Catch, SkillTrigger::Block],
+        )
+        .with_re_roll(SkillTrigger::Catch),
+        Skill::PrehensileTail => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Dodge])
+            .with_modifier_when(SkillTrigger::Dodge, -1, SkillCondition::Opponent),
+        Skill::Tentacles => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::TwoHeads => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Dodge])
+            .with_modifier(SkillTrigger::Dodge, 1),
+        Skill::VeryLongLegs => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Movement, SkillTrigger::Pass],
+        ),
+
+        Skill::AlwaysHungry => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Pass]),
+        Skill::Animosity(_) => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Pass]),
+        Skill::AnimalSavagery => {
+            SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation])
+        }
+        Skill::BallChain => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Movement]),
+        Skill::BloodLust(_) => {
+            SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation])
+        }
+        Skill::Bombardier => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+        Skill::BoneHead => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation]),
+        Skill::BreatheFire => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::Chainsaw => SkillEffect::new(
+            SkillUsage::Optional,
+            &[
+                SkillTrigger::Block,
+                SkillTrigger::Foul,
+                SkillTrigger::Armour,
+            ],
+        )
+        .with_modifier_when(SkillTrigger::Armour, 3, SkillCondition::SkillAction),
+        Skill::Decay => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Injury]),
+        Skill::Drunkard => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Rush])
+            .with_modifier(SkillTrigger::Rush, -1),
+        Skill::HitAndRun => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::HypnoticGaze => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Activation]),
+        Skill::KickTeamMate => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+        Skill::Loner(_) => SkillEffect::new(SkillUsage::Compulsory, &[]),
+        Skill::MyBall => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::NoHands => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::PickUp, SkillTrigger::Catch],
+        ),
+        Skill::PickMeUp => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Activation]),
+        Skill::PlagueRidden => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Injury]),
+        Skill::PogoStick => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::ProjectileVomit => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::ReallyStupid => {
+            SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation])
+        }
+        Skill::Regeneration => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Injury]),
+        Skill::RightStuff => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::SecretWeapon => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::KickOff]),
+        Skill::Stab => SkillEffect::new(
+            SkillUsage::Optional,
+            &[SkillTrigger::Block, SkillTrigger::Armour],
+        ),
+        Skill::Stunty => SkillEffect::new(
+            SkillUsage::Passive,
+            &[
+                SkillTrigger::Dodge,
+                SkillTrigger::Pass,
+                SkillTrigger::Injury,
+            ],
+        )
+        .with_ignored_marking(SkillTrigger::Dodge),
+        Skill::Swarming => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::KickOff]),
+        Skill::Swoop => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::TakeRoots => SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation]),
+        Skill::Timmmber => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Movement]),
+        Skill::Trickster => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::ThrowTeamMate => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+        Skill::Titchy => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Dodge])
+            .with_modifier(SkillTrigger::Dodge, 1),
+        Skill::UnchannelledFury => {
+            SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation])
+        }
+
+        Skill::BlindRage => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block])
+            .with_re_roll(SkillTrigger::Block),
+        Skill::SavageBlow => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block])
+            .with_re_roll(SkillTrigger::Block),
+        Skill::BlastIt => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass])
+            .with_re_roll(SkillTrigger::Pass),
+        Skill::PutridRegurgitation => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block])
+        }
+        Skill::LookIntoMyEyes => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::GhostlyFlames => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::TastyMorsel => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass]),
+        Skill::StarOfTheShow => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::ASneakyPair => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Foul]),
+        Skill::MesmerizingGaze => {
+            SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Activation])
+        }
+        Skill::BalefulHex => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation]),
+        Skill::BrutalBlock => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::WhirlingDervish => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Dodge]),
+        Skill::FrenziedRush => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::ShotToNothing => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass]),
+        Skill::PrimalSavagery => {
+            SkillEffect::new(SkillUsage::Compulsory, &[SkillTrigger::Activation])
+        }
+        Skill::TwoForOne => SkillEffect::new(SkillUsage::Passive, &[]),
+        Skill::GoredByTheBull => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::Incorporeal => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Dodge]),
+        Skill::Slayer => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::WisdomOfTheWhiteDwarf => {
+            SkillEffect::new(SkillUsage::OncePerTurn, &[SkillTrigger::Activation])
+        }
+        Skill::QuickBite => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::OldPro => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Armour])
+            .with_re_roll(SkillTrigger::Armour),
+        Skill::UnstoppableMomentum => {
+            SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block])
+                .with_re_roll(SkillTrigger::Block)
+        }
+        Skill::DwarvenScourge => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Injury]),
+        Skill::RaidingParty => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Movement]),
+        Skill::TheFlashingBlade => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block])
+        }
+        Skill::SwiftAsTheBreeze => SkillEffect::new(
+            SkillUsage::OncePerGame,
+            &[SkillTrigger::Dodge, SkillTrigger::Rush],
+        )
+        .with_re_roll(SkillTrigger::Dodge)
+        .with_re_roll(SkillTrigger::Rush),
+        Skill::Indomitable => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::BlackInk => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation]),
+        Skill::LordOfChaos => SkillEffect::new(SkillUsage::OncePerHalf, &[]),
+        Skill::ViciousVines => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::MaximumCarnage => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::CrushingBlow => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Armour]),
+        Skill::KickThemWhileTheyAreDown => {
+            SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Foul])
+                .with_re_roll(SkillTrigger::Armour)
+        }
+        Skill::HalflingLuck => with_any_re_roll(SkillUsage::OncePerGame),
+        Skill::ToxinConnoisseur => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block])
+        }
+        Skill::ThinkingManTroll => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::CatchOfTheDay => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Catch]),
+        Skill::BoundingLeap => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Movement]),
+        Skill::BurstOfSpeed => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Rush]),
+        Skill::Ram => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::Yoink => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Catch]),
+        Skill::FuryOfTheBloodGod => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block])
+        }
+        Skill::MasterAssassin => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::PumpUpTheCrowd => SkillEffect::new(SkillUsage::Passive, &[]),
+        Skill::StrongPassingGame => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::FuriousOutburst => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::SneakiestOfTheLot => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Foul]),
+        Skill::BeerBarrelBash => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass]),
+        Skill::SavageMauling => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Injury])
+            .with_re_roll(SkillTrigger::Injury),
+        Skill::WatchOut => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::ExcuseMeAreYouAZoat => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::ThenIStartedBlastin => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass])
+        }
+        Skill::Kaboom => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass]),
+        Skill::AllYouCanEat => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass]),
+        Skill::Reliable => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::ConsummateProfessional => with_any_re_roll(SkillUsage::OncePerGame),
+        Skill::Treacherous => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Activation])
+        }
+        Skill::IllBeBack => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Injury]),
+        Skill::TheBallista => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass])
+            .with_re_roll(SkillTrigger::Pass),
+
+        _ => return None,
+    };
+
+    Some(effect)
+}
+
+fn with_any_re_roll(usage: SkillUsage) -> SkillEffect {
+    let triggers = [
+        SkillTrigger::Dodge,
+        SkillTrigger::Rush,
+        SkillTrigger::PickUp,
+        SkillTrigger::Block,
+        SkillTrigger::Pass,
+        SkillTrigger::Catch,
+    ];
+
+    triggers.into_iter().fold(
+        SkillEffect::new(usage, &triggers),
+        SkillEffect::with_re_roll,
+    )
+}
@@ -1,4 +1,4 @@
-use crate::skills::{Skill, SkillCategory};
+use crate::skills::{Skill, SkillCategory, SkillCondition, SkillEffect, SkillTrigger, SkillUsage};
 
 pub fn skills_to_be_added_for_category(skill_category: &SkillCategory) -> Vec<Skill> {
     match skill_category {
@@ -285,3 +285,72 @@ pub fn is_skill_elite(skill: &Skill) -> bool {
         _ => false,
     }
 }
+
+pub fn skill_effect(skill: &Skill) -> Option<SkillEffect> {
+    skill_category_for_skill(skill)?;
+
+    let effect = match skill {
+        Skill::SteadyFooting => SkillEffect::new(
+            SkillUsage::Optional,
+            &[SkillTrigger::Dodge, SkillTrigger::Rush, SkillTrigger::Block],
+        ),
+        Skill::Taunt => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+
+        Skill::DirtyPlayer => SkillEffect::new(
+            SkillUsage::Optional,
+            &[
+                SkillTrigger::Foul,
+                SkillTrigger::Armour,
+                SkillTrigger::Injury,
+            ],
+        )
+        .with_modifier_when(SkillTrigger::Armour, 1, SkillCondition::Foul)
+        .with_modifier_when(SkillTrigger::Injury, 1, SkillCondition::Foul),
+        Skill::EyeGouge => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::LethalFlight => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Pass, SkillTrigger::Armour],
+        ),
+        Skill::LoneFouler => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Foul])
+            .with_re_roll(SkillTrigger::Foul),
+        Skill::PutTheBootIn => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Foul]),
+        Skill::QuickFoul => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Foul]),
+        Skill::Saboteur => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Block]),
+        Skill::ViolentInnovator => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Injury]),
+
+        Skill::BullsEye => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Pass]),
+        Skill::MightyBlow => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::Armour, SkillTrigger::Injury],
+        )
+        .with_modifier_when(SkillTrigger::Armour, 1, SkillCondition::Block)
+        .with_modifier_when(SkillTrigger::Injury, 1, SkillCondition::Block),
+
+        Skill::GiveAndGo => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Movement]),
+        Skill::Punt => SkillEffect::new(SkillUsage::Optional, &[SkillTrigger::Pass]),
+
+        Skill::Hatred(_) => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block])
+            .with_re_roll(SkillTrigger::Block),
+        Skill::Insignificant => SkillEffect::new(SkillUsage::Passive, &[]),
+        Skill::NoBall => SkillEffect::new(
+            SkillUsage::Passive,
+            &[SkillTrigger::PickUp, SkillTrigger::Catch],
+        ),
+        Skill::Unsteady => SkillEffect::new(SkillUsage::Passive, &[]),
+
+        Skill::DwarfenGrit => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::SlashingNails => {
+            SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Armour]).with_armour_broken_on(8)
+        }
+        Skill::WorkingInTandem => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::KrumpAndSmash => SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Block]),
+        Skill::WoodlandFury => SkillEffect::new(SkillUsage::Passive, &[SkillTrigger::Block]),
+        Skill::BlastingSolvesEverything => {
+            SkillEffect::new(SkillUsage::OncePerGame, &[SkillTrigger::Pass])
+        }
+
+        _ => return super::v5::skill_effect(skill),
+    };
+
+    Some(effect)
+}
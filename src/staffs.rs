@@ -1,15 +1,20 @@
 use crate::positions::{Position, PositionDefinition};
 use crate::rosters::Roster;
 use crate::teams::Team;
-use crate::translation::{TranslatedName, TypeName};
+use crate::translation::{TranslatedName, TypeName, format_gold_pieces};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 pub mod v5;
 pub mod v5s3;
 
-#[derive(sqlx::Type, Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
-#[sqlx(type_name = "varchar")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "sqlx", derive(sqlx::Type))]
+#[cfg_attr(feature = "sqlx", sqlx(type_name = "varchar"))]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Staff {
     Cheerleader,
     AssistantCoach,
@@ -20,7 +25,8 @@ pub enum Staff {
 impl TypeName for Staff {}
 impl TranslatedName for Staff {}
 
-#[derive(Debug, Copy, Serialize, Deserialize, Clone)]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct StaffInformation {
     pub staff: Staff,
     pub price: u32,
@@ -71,9 +77,14 @@ impl StaffInformation {
             self.price
         }
     }
+
+    pub fn formatted_price(&self, lang_id: &str) -> String {
+        format_gold_pieces(lang_id, self.price.into())
+    }
 }
 
-#[derive(Debug, Copy, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum FamousCoachingStaff {
     AyleenAndar,
     FinkDaFixer,
@@ -0,0 +1,862 @@
+use crate::advancements::Advancement;
+use crate::coaches::Coach;
+use crate::errors::Error;
+use crate::injuries::Injury;
+use crate::players::Player;
+use crate::reports::ReportLabel;
+use crate::rosters::Roster;
+use crate::skills::{Skill, SkillCategory};
+use crate::staffs::Staff;
+use crate::teams::Team;
+use crate::translation::{TranslatedName, TypeName, supported_languages};
+use crate::versions::Version;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const STAFF_LIST: [Staff; 4] = [
+    Staff::ReRoll,
+    Staff::Apothecary,
+    Staff::AssistantCoach,
+    Staff::Cheerleader,
+];
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Roster file formats of this crate. They are not the FUMBBL or online team builder formats:
+/// names are written in any supported language and matched back in any of them on import.
+pub enum TeamFileFormat {
+    /// `Label: value` lines for the team and staff, then one
+    /// `number | name | position | skills | spp | advancements | injuries` line per player,
+    /// lists being separated by `,`.
+    PlainText,
+    /// `team`, `roster`, `coach`, `treasury`, `dedicated_fans` and `staff` rows, then
+    /// `player,number,name,position,skills,spp,advancements,injuries` rows, lists being
+    /// separated by `;`.
+    Csv,
+    /// [`TeamFileJson`] document.
+    Json,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ImportDiagnostic {
+    pub line: usize,
+    pub content: String,
+    pub error: Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct TeamImport {
+    pub team: Team,
+    pub diagnostics: Vec<ImportDiagnostic>,
+    pub rules_compliance: Result<(), Error>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TeamFileJson {
+    pub name: String,
+    pub roster: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub coach: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub treasury: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub dedicated_fans: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rerolls: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub apothecary: bool,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub assistant_coaches: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub cheerleaders: u8,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub players: Vec<TeamFilePlayerJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TeamFilePlayerJson {
+    pub number: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub name: String,
+    pub position: String,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skills: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spp: i32,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub advancements: Vec<String>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub injuries: Vec<String>,
+}
+
+#[derive(Default)]
+struct RawPlayer {
+    line: usize,
+    number: i32,
+    name: String,
+    position: String,
+    skills: Vec<String>,
+    star_player_points: i32,
+    advancements: Vec<String>,
+    injuries: Vec<String>,
+}
+
+#[derive(Default)]
+struct RawTeam {
+    name: String,
+    roster: Option<String>,
+    coach: String,
+    treasury: i32,
+    dedicated_fans: u8,
+    staff: Vec<(usize, String, u8)>,
+    players: Vec<RawPlayer>,
+}
+
+fn without_isolation_marks(name: String) -> String {
+    name.replace(['\u{2068}', '\u{2069}'], "")
+}
+
+fn normalized(name: &str) -> String {
+    without_isolation_marks(name.to_string())
+        .trim()
+        .to_lowercase()
+}
+
+fn find_by_name<T: TranslatedName + Clone>(candidates: &[T], name: &str) -> Option<T> {
+    let name = normalized(name);
+    let languages = supported_languages();
+
+    candidates
+        .iter()
+        .find(|candidate| {
+            normalized(&candidate.type_name()).eq(&name)
+                || languages
+                    .iter()
+                    .any(|lang_id| normalized(&candidate.name(lang_id)).eq(&name))
+        })
+        .cloned()
+}
+
+fn label_matches(label: ReportLabel, name: &str) -> bool {
+    let name = normalized(name);
+
+    supported_languages()
+        .iter()
+        .any(|lang_id| normalized(&label.name(lang_id)).eq(&name))
+}
+
+fn addable_skills(version: &Version) -> Vec<Skill> {
+    [
+        SkillCategory::General,
+        SkillCategory::Agility,
+        SkillCategory::Pass,
+        SkillCategory::Strength,
+        SkillCategory::Mutation,
+        SkillCategory::Devious,
+    ]
+    .iter()
+    .flat_map(|skill_category| skill_category.skills_to_be_added(version))
+    .collect()
+}
+
+fn addable_advancements(version: &Version) -> Vec<Advancement> {
+    let mut advancements = vec![
+        Advancement::MovementAllowance,
+        Advancement::Strength,
+        Advancement::Agility,
+        Advancement::PassingAbility,
+        Advancement::ArmourValue,
+    ];
+
+    for skill in addable_skills(version) {
+        advancements.push(Advancement::ChosenSkill(skill));
+        advancements.push(Advancement::RandomSkill(skill));
+    }
+
+    advancements
+}
+
+fn csv_fields(line: &str) -> Vec<String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut characters = line.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match (character, in_quotes) {
+            ('"', true) if characters.peek().eq(&Some(&'"')) => {
+                field.push('"');
+                characters.next();
+            }
+            ('"', _) => in_quotes = !in_quotes,
+            (',', false) => fields.push(std::mem::take(&mut field)),
+            _ => field.push(character),
+        }
+    }
+    fields.push(field);
+
+    fields
+        .iter()
+        .map(|field| field.trim().to_string())
+        .collect()
+}
+
+fn csv_line(fields: &[String]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            if field.contains([',', '"']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.clone()
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+fn skill_names(skills: &str, separator: char) -> Vec<String> {
+    skills
+        .split(separator)
+        .map(|skill| skill.trim().to_string())
+        .filter(|skill| !skill.is_empty())
+        .collect()
+}
+
+fn parse_plain_text(content: &str, diagnostics: &mut Vec<ImportDiagnostic>) -> RawTeam {
+    let mut raw_team = RawTeam::default();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let trimmed_line = line.trim();
+        let invalid_line = |error: Error| ImportDiagnostic {
+            line: line_number,
+            content: trimmed_line.to_string(),
+            error,
+        };
+
+        if trimmed_line.is_empty() || trimmed_line.starts_with("//") {
+            continue;
+        }
+
+        if trimmed_line.contains('|') {
+            let fields: Vec<&str> = trimmed_line.split('|').map(|field| field.trim()).collect();
+
+            match (
+                fields.as_slice(),
+                fields.first().map(|field| field.parse::<i32>()),
+            ) {
+                (
+                    [_, name, position, skills, star_player_points, others @ ..],
+                    Some(Ok(number)),
+                ) if others.is_empty() || others.len() == 2 => {
+                    match star_player_points.parse::<i32>() {
+                        Ok(star_player_points) => raw_team.players.push(RawPlayer {
+                            line: line_number,
+                            number,
+                            name: name.to_string(),
+                            position: position.to_string(),
+                            skills: skill_names(skills, ','),
+                            star_player_points,
+                            advancements: others
+                                .first()
+                                .map(|advancements| skill_names(advancements, ','))
+                                .unwrap_or_default(),
+                            injuries: others
+                                .get(1)
+                                .map(|injuries| skill_names(injuries, ','))
+                                .unwrap_or_default(),
+                        }),
+                        Err(_) => diagnostics.push(invalid_line(Error::InvalidTeamFileLine)),
+                    }
+                }
+                _ => diagnostics.push(invalid_line(Error::InvalidTeamFileLine)),
+            }
+
+            continue;
+        }
+
+        let Some((key, value)) = trimmed_line.split_once(':') else {
+            diagnostics.push(invalid_line(Error::InvalidTeamFileLine));
+            continue;
+        };
+        let value = value.trim();
+
+        if label_matches(ReportLabel::Team, key) {
+            raw_team.name = value.to_string();
+        } else if label_matches(ReportLabel::Roster, key) {
+            raw_team.roster = Some(value.to_string());
+        } else if label_matches(ReportLabel::Coach, key) {
+            raw_team.coach = value.to_string();
+        } else if label_matches(ReportLabel::Treasury, key) {
+            match value.parse::<i32>() {
+                Ok(treasury) => raw_team.treasury = treasury,
+                Err(_) => diagnostics.push(invalid_line(Error::IncorrectTreasury)),
+            }
+        } else if label_matches(ReportLabel::DedicatedFans, key) {
+            match value.parse::<u8>() {
+                Ok(dedicated_fans) => raw_team.dedicated_fans = dedicated_fans,
+                Err(_) => diagnostics.push(invalid_line(Error::InvalidTeamFileLine)),
+            }
+        } else {
+            match value.parse::<u8>() {
+                Ok(quantity) => {
+                    raw_team
+                        .staff
+                        .push((line_number, key.trim().to_string(), quantity))
+                }
+                Err(_) => diagnostics.push(invalid_line(Error::InvalidTeamFileLine)),
+            }
+        }
+    }
+
+    raw_team
+}
+
+fn parse_csv(content: &str, diagnostics: &mut Vec<ImportDiagnostic>) -> RawTeam {
+    let mut raw_team = RawTeam::default();
+
+    for (index, line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let fields = csv_fields(line);
+        let field = |index: usize| fields.get(index).cloned().unwrap_or_default();
+        let number = |index: usize| field(index).parse::<i32>();
+        let quantity = |index: usize| field(index).parse::<u8>();
+
+        let parsed = match field(0).as_str() {
+            "team" => {
+                raw_team.name = field(1);
+                true
+            }
+            "roster" => {
+                raw_team.roster = Some(field(1));
+                true
+            }
+            "coach" => {
+                raw_team.coach = field(1);
+                true
+            }
+            "treasury" => number(1)
+                .map(|treasury| raw_team.treasury = treasury)
+                .is_ok(),
+            "dedicated_fans" => quantity(1)
+                .map(|dedicated_fans| raw_team.dedicated_fans = dedicated_fans)
+                .is_ok(),
+            "staff" => quantity(2)
+                .map(|quantity| raw_team.staff.push((line_number, field(1), quantity)))
+                .is_ok(),
+            "player" => match (number(1), number(5)) {
+                (Ok(player_number), Ok(star_player_points)) => {
+                    raw_team.players.push(RawPlayer {
+                        line: line_number,
+                        number: player_number,
+                        name: field(2),
+                        position: field(3),
+                        skills: skill_names(&field(4), ';'),
+                        star_player_points,
+                        advancements: skill_names(&field(6), ';'),
+                        injuries: skill_names(&field(7), ';'),
+                    });
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        };
+
+        if !parsed {
+            diagnostics.push(ImportDiagnostic {
+                line: line_number,
+                content: line.to_string(),
+                error: Error::InvalidTeamFileLine,
+            });
+        }
+    }
+
+    raw_team
+}
+
+fn parse_json(content: &str) -> Result<RawTeam, Error> {
+    let team_file: TeamFileJson =
+        serde_json::from_str(content).map_err(|_| Error::InvalidTeamFile)?;
+
+    let mut staff = vec![
+        (0, Staff::ReRoll.type_name(), team_file.rerolls),
+        (
+            0,
+            Staff::AssistantCoach.type_name(),
+            team_file.assistant_coaches,
+        ),
+        (0, Staff::Cheerleader.type_name(), team_file.cheerleaders),
+    ];
+    if team_file.apothecary {
+        staff.push((0, Staff::Apothecary.type_name(), 1));
+    }
+
+    Ok(RawTeam {
+        name: team_file.name,
+        roster: Some(team_file.roster),
+        coach: team_file.coach,
+        treasury: team_file.treasury,
+        dedicated_fans: team_file.dedicated_fans,
+        staff,
+        players: team_file
+            .players
+            .into_iter()
+            .enumerate()
+            .map(|(index, player)| RawPlayer {
+                line: index + 1,
+                number: player.number,
+                name: player.name,
+                position: player.position,
+                skills: player.skills,
+                star_player_points: player.spp,
+                advancements: player.advancements,
+                injuries: player.injuries,
+            })
+            .collect(),
+    })
+}
+
+fn build_team(
+    raw_team: RawTeam,
+    version: Version,
+    mut diagnostics: Vec<ImportDiagnostic>,
+) -> Result<TeamImport, Error> {
+    let roster_name = raw_team.roster.ok_or(Error::RosterNotExist)?;
+    let roster = find_by_name(&Roster::list(version), &roster_name).ok_or(Error::UnknownRoster)?;
+    let roster_definition = roster.definition(version).ok_or(Error::RosterNotExist)?;
+    let skills = addable_skills(&version);
+    let advancements = addable_advancements(&version);
+    let injuries = Injury::list(&version);
+
+    let mut staff: HashMap<Staff, u8> = HashMap::new();
+    for (line, staff_name, quantity) in raw_team.staff {
+        match find_by_name(&STAFF_LIST, &staff_name) {
+            Some(known_staff) => {
+                if quantity > 0 {
+                    staff.insert(known_staff, quantity);
+                }
+            }
+            None => diagnostics.push(ImportDiagnostic {
+                line,
+                content: staff_name,
+                error: Error::UnknownStaff,
+            }),
+        }
+    }
+
+    let mut players: Vec<(i32, Player)> = vec![];
+    for raw_player in raw_team.players {
+        let Some(position) = find_by_name(&roster_definition.positions, &raw_player.position)
+        else {
+            diagnostics.push(ImportDiagnostic {
+                line: raw_player.line,
+                content: raw_player.position,
+                error: Error::UnknownPosition,
+            });
+            continue;
+        };
+
+        let mut player = Player {
+            id: players.len() as i32 + 1,
+            name: raw_player.name,
+            star_player_points: raw_player.star_player_points,
+            ..Player::new(version, position, roster)
+        };
+
+        for advancement_name in raw_player.advancements {
+            match find_by_name(&advancements, &advancement_name) {
+                Some(advancement) => player.advancements.push(advancement),
+                None => diagnostics.push(ImportDiagnostic {
+                    line: raw_player.line,
+                    content: advancement_name,
+                    error: Error::UnknownAdvancement,
+                }),
+            }
+        }
+
+        for injury_name in raw_player.injuries {
+            match find_by_name(&injuries, &injury_name) {
+                Some(injury) => player.injuries.push(injury),
+                None => diagnostics.push(ImportDiagnostic {
+                    line: raw_player.line,
+                    content: injury_name,
+                    error: Error::UnknownInjury,
+                }),
+            }
+        }
+
+        let known_skills = player.skills();
+        for skill_name in raw_player.skills {
+            if find_by_name(&known_skills, &skill_name).is_some() {
+                continue;
+            }
+
+            match find_by_name(&skills, &skill_name) {
+                Some(skill) => player.advancements.push(Advancement::ChosenSkill(skill)),
+                None => diagnostics.push(ImportDiagnostic {
+                    line: raw_player.line,
+                    content: skill_name,
+                    error: Error::UnknownSkill,
+                }),
+            }
+        }
+
+        players.push((raw_player.number, player));
+    }
+
+    let team = Team {
+        id: 0,
+        version,
+        roster,
+        name: raw_team.name,
+        coach: Coach::from_name(&raw_team.coach),
+        treasury: raw_team.treasury,
+        external_logo_url: None,
+        staff,
+        players,
+        dedicated_fans: raw_team.dedicated_fans,
+        under_creation: false,
+        in_offseason: false,
+    };
+    let rules_compliance = team.check_if_rules_compliant();
+
+    Ok(TeamImport {
+        team,
+        diagnostics,
+        rules_compliance,
+    })
+}
+
+pub fn import_team(
+    content: &str,
+    format: TeamFileFormat,
+    version: Version,
+) -> Result<TeamImport, Error> {
+    let mut diagnostics = vec![];
+
+    let raw_team = match format {
+        TeamFileFormat::PlainText => parse_plain_text(content, &mut diagnostics),
+        TeamFileFormat::Csv => parse_csv(content, &mut diagnostics),
+        TeamFileFormat::Json => parse_json(content)?,
+    };
+
+    build_team(raw_team, version, diagnostics)
+}
+
+pub fn export_team(team: &Team, format: TeamFileFormat, lang_id: &str) -> String {
+    let mut players = team.players.clone();
+    players.sort_by_key(|(number, _)| *number);
+
+    let staff_quantity = |staff: &Staff| team.staff.get(staff).copied().unwrap_or(0);
+    let player_skills = |player: &Player| -> Vec<String> {
+        player
+            .skills()
+            .iter()
+            .map(|skill| without_isolation_marks(skill.name(lang_id)))
+            .collect()
+    };
+    let player_advancements = |player: &Player| -> Vec<String> {
+        player
+            .advancements
+            .iter()
+            .map(|advancement| without_isolation_marks(advancement.name(lang_id)))
+            .collect()
+    };
+    let player_injuries = |player: &Player| -> Vec<String> {
+        player
+            .injuries
+            .iter()
+            .map(|injury| without_isolation_marks(injury.name(lang_id)))
+            .collect()
+    };
+
+    match format {
+        TeamFileFormat::PlainText => {
+            let mut lines = vec![
+                format!("{}: {}", ReportLabel::Team.name(lang_id), team.name),
+                format!(
+                    "{}: {}",
+                    ReportLabel::Roster.name(lang_id),
+                    team.roster.name(lang_id)
+                ),
+                format!("{}: {}", ReportLabel::Coach.name(lang_id), team.coach.name),
+                format!("{}: {}", ReportLabel::Treasury.name(lang_id), team.treasury),
+                format!(
+                    "{}: {}",
+                    ReportLabel::DedicatedFans.name(lang_id),
+                    team.dedicated_fans
+                ),
+            ];
+
+            for staff in STAFF_LIST.iter() {
+                if staff_quantity(staff) > 0 {
+                    lines.push(format!(
+                        "{}: {}",
+                        staff.name(lang_id),
+                        staff_quantity(staff)
+                    ));
+                }
+            }
+
+            lines.push("".to_string());
+
+            for (number, player) in players.iter() {
+                lines.push(format!(
+                    "{} | {} | {} | {} | {} | {} | {}",
+                    number,
+                    player.name,
+                    player.position.name(lang_id),
+                    player_skills(player).join(", "),
+                    player.star_player_points,
+                    player_advancements(player).join(", "),
+                    player_injuries(player).join(", ")
+                ));
+            }
+
+            lines.join("\n")
+        }
+
+        TeamFileFormat::Csv => {
+            let mut lines = vec![
+                csv_line(&["team".to_string(), team.name.clone()]),
+                csv_line(&["roster".to_string(), team.roster.name(lang_id)]),
+                csv_line(&["coach".to_string(), team.coach.name.clone()]),
+                csv_line(&["treasury".to_string(), team.treasury.to_string()]),
+                csv_line(&[
+                    "dedicated_fans".to_string(),
+                    team.dedicated_fans.to_string(),
+                ]),
+            ];
+
+            for staff in STAFF_LIST.iter() {
+                if staff_quantity(staff) > 0 {
+                    lines.push(csv_line(&[
+                        "staff".to_string(),
+                        staff.name(lang_id),
+                        staff_quantity(staff).to_string(),
+                    ]));
+                }
+            }
+
+            for (number, player) in players.iter() {
+                lines.push(csv_line(&[
+                    "player".to_string(),
+                    number.to_string(),
+                    player.name.clone(),
+                    player.position.name(lang_id),
+                    player_skills(player).join(";"),
+                    player.star_player_points.to_string(),
+                    player_advancements(player).join(";"),
+                    player_injuries(player).join(";"),
+                ]));
+            }
+
+            lines.join("\n")
+        }
+
+        TeamFileFormat::Json => {
+            let team_file = TeamFileJson {
+                name: team.name.clone(),
+                roster: team.roster.name(lang_id),
+                coach: team.coach.name.clone(),
+                treasury: team.treasury,
+                dedicated_fans: team.dedicated_fans,
+                rerolls: staff_quantity(&Staff::ReRoll),
+                apothecary: staff_quantity(&Staff::Apothecary) > 0,
+                assistant_coaches: staff_quantity(&Staff::AssistantCoach),
+                cheerleaders: staff_quantity(&Staff::Cheerleader),
+                players: players
+                    .iter()
+                    .map(|(number, player)| TeamFilePlayerJson {
+                        number: *number,
+                        name: player.name.clone(),
+                        position: player.position.name(lang_id),
+                        skills: player_skills(player),
+                        spp: player.star_player_points,
+                        advancements: player_advancements(player),
+                        injuries: player_injuries(player),
+                    })
+                    .collect(),
+            };
+
+            serde_json::to_string_pretty(&team_file).unwrap_or_default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::positions::Position;
+    use crate::test_utils;
+
+    fn team() -> Team {
+        let mut team = Team {
+            coach: Coach::from_name("Alice"),
+            treasury: 40000,
+            staff: HashMap::from([(Staff::ReRoll, 3), (Staff::Apothecary, 1)]),
+            dedicated_fans: 2,
+            ..test_utils::team(1, "Reds", Version::V5S3, 7)
+        };
+        for (number, player) in team.players.iter_mut() {
+            player.name = format!("Lineman {}", number);
+        }
+        team.players.push((
+            8,
+            Player {
+                id: 108,
+                name: "Karl, the Blitzer".to_string(),
+                star_player_points: 6,
+                advancements: vec![
+                    Advancement::ChosenSkill(Skill::Dodge),
+                    Advancement::RandomSkill(Skill::Tackle),
+                    Advancement::Strength,
+                ],
+                injuries: vec![Injury::SmashedKnee],
+                ..Player::new(Version::V5S3, Position::Blitzer, Roster::Human)
+            },
+        ));
+
+        team
+    }
+
+    #[test]
+    fn round_trips() {
+        let team = team();
+
+        for format in [
+            TeamFileFormat::PlainText,
+            TeamFileFormat::Csv,
+            TeamFileFormat::Json,
+        ] {
+            for lang_id in supported_languages() {
+                let exported = export_team(&team, format, &lang_id);
+                let imported = import_team(&exported, format, Version::V5S3).unwrap();
+
+                assert!(
+                    imported.diagnostics.is_empty(),
+                    "{:?}",
+                    imported.diagnostics
+                );
+                assert_eq!(imported.team.name, team.name);
+                assert_eq!(imported.team.roster, team.roster);
+                assert_eq!(imported.team.coach, team.coach);
+                assert_eq!(imported.team.treasury, team.treasury);
+                assert_eq!(imported.team.dedicated_fans, team.dedicated_fans);
+                assert_eq!(imported.team.staff, team.staff);
+                assert_eq!(imported.team.players.len(), team.players.len());
+
+                let (number, blitzer) = &imported.team.players[7];
+                assert_eq!(*number, 8);
+                assert_eq!(blitzer.name, "Karl, the Blitzer");
+                assert_eq!(blitzer.position, Position::Blitzer);
+                assert_eq!(blitzer.star_player_points, 6);
+                assert_eq!(blitzer.advancements, team.players[7].1.advancements);
+                assert_eq!(blitzer.injuries, vec![Injury::SmashedKnee]);
+            }
+        }
+    }
+
+    #[test]
+    fn diagnostics_per_line() {
+        let content = "Team: Reds
+Roster: Human
+Coach: Alice
+Treasury: lots
+Re-rolls: 2
+Wizards: 1
+1 | Otto | Human Lineman | Block, Jump Up High | 0
+2 | Hans | Human Wizard |  | 0
+3 | Fritz | Human Lineman
+this is not a roster line";
+
+        let imported = import_team(content, TeamFileFormat::PlainText, Version::V5S3).unwrap();
+
+        let diagnostics: Vec<(usize, String)> = imported
+            .diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.error.type_name()))
+            .collect();
+        assert_eq!(
+            diagnostics,
+            vec![
+                (4, "IncorrectTreasury".to_string()),
+                (9, "InvalidTeamFileLine".to_string()),
+                (10, "InvalidTeamFileLine".to_string()),
+                (6, "UnknownStaff".to_string()),
+                (7, "UnknownSkill".to_string()),
+                (8, "UnknownPosition".to_string()),
+            ]
+        );
+        assert_eq!(imported.team.players.len(), 1);
+        assert_eq!(
+            imported.team.players[0].1.advancements,
+            vec![Advancement::ChosenSkill(Skill::Block)]
+        );
+        assert!(imported.rules_compliance.is_err());
+
+        assert!(matches!(
+            import_team("Team: Reds", TeamFileFormat::PlainText, Version::V5S3),
+            Err(Error::RosterNotExist)
+        ));
+        assert!(matches!(
+            import_team("Roster: Elves", TeamFileFormat::PlainText, Version::V5S3),
+            Err(Error::UnknownRoster)
+        ));
+        assert!(matches!(
+            import_team("{", TeamFileFormat::Json, Version::V5S3),
+            Err(Error::InvalidTeamFile)
+        ));
+    }
+
+    #[test]
+    fn csv_diagnostics() {
+        let content = "team,Reds
+roster,Human
+dedicated_fans,300
+staff,Re-rolls,-1
+staff,Apothecary,1
+player,1,Otto,Human Lineman,Block,0,Strength;Random Skill (Wrestle),Head Injury;Bad luck";
+
+        let imported = import_team(content, TeamFileFormat::Csv, Version::V5S3).unwrap();
+
+        let diagnostics: Vec<(usize, String)> = imported
+            .diagnostics
+            .iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.error.type_name()))
+            .collect();
+        assert_eq!(
+            diagnostics,
+            vec![
+                (3, "InvalidTeamFileLine".to_string()),
+                (4, "InvalidTeamFileLine".to_string()),
+                (6, "UnknownInjury".to_string()),
+            ]
+        );
+        assert_eq!(imported.team.dedicated_fans, 0);
+        assert_eq!(imported.team.staff, HashMap::from([(Staff::Apothecary, 1)]));
+
+        let otto = &imported.team.players[0].1;
+        assert_eq!(
+            otto.advancements,
+            vec![
+                Advancement::Strength,
+                Advancement::RandomSkill(Skill::Wrestle),
+                Advancement::ChosenSkill(Skill::Block),
+            ]
+        );
+        assert_eq!(otto.injuries, vec![Injury::HeadInjury]);
+    }
+}
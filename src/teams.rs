@@ -5,8 +5,11 @@ use crate::players::{Player, PlayerType};
 use crate::positions::Position;
 use crate::rosters::{Roster, RosterDefinition, SpecialRule};
 use crate::staffs::{Staff, StaffInformation};
-use crate::translation::TypeName;
+use crate::translation::{TypeName, format_gold_pieces, lookup_count};
 use crate::versions::Version;
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
@@ -14,7 +17,8 @@ use std::hash::{Hash, Hasher};
 pub mod v5;
 pub mod v5s3;
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub struct Team {
     pub id: i32,
     pub version: Version,
@@ -544,6 +548,22 @@ impl Team {
         Ok(self.players_current_value()? + self.staff_value()?)
     }
 
+    pub fn formatted_value(&self, lang_id: &str) -> Result<String, Error> {
+        Ok(format_gold_pieces(lang_id, self.value()?.into()))
+    }
+
+    pub fn formatted_current_value(&self, lang_id: &str) -> Result<String, Error> {
+        Ok(format_gold_pieces(lang_id, self.current_value()?.into()))
+    }
+
+    pub fn formatted_treasury(&self, lang_id: &str) -> String {
+        format_gold_pieces(lang_id, self.treasury.into())
+    }
+
+    pub fn formatted_players_count(&self, lang_id: &str) -> String {
+        lookup_count(lang_id, "PlayersCount", self.players.len() as i64)
+    }
+
     pub fn create_new(
         coach: Coach,
         version: Version,
@@ -553,6 +573,31 @@ impl Team {
         team_positions: HashMap<Position, u8>,
         dedicated_fans: u8,
         captain_position: Option<Position>,
+    ) -> Result<Self, Error> {
+        Self::create_new_with_budget(
+            coach,
+            version,
+            roster,
+            treasury,
+            staff_quantities,
+            team_positions,
+            dedicated_fans,
+            captain_position,
+            Team::initial_treasury(&version),
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_new_with_budget(
+        coach: Coach,
+        version: Version,
+        roster: Roster,
+        treasury: i32,
+        staff_quantities: HashMap<Staff, u8>,
+        team_positions: HashMap<Position, u8>,
+        dedicated_fans: u8,
+        captain_position: Option<Position>,
+        budget: i32,
     ) -> Result<Self, Error> {
         let mut players: Vec<(i32, Player)> = Vec::new();
         let mut number: i32 = 0;
@@ -588,12 +633,16 @@ impl Team {
             in_offseason: false,
         };
 
-        team.check_if_rules_compliant()?;
+        team.check_if_rules_compliant_with_budget(budget)?;
 
         Ok(team)
     }
 
     pub fn check_if_rules_compliant(&self) -> Result<(), Error> {
+        self.check_if_rules_compliant_with_budget(Team::initial_treasury(&self.version))
+    }
+
+    pub fn check_if_rules_compliant_with_budget(&self, budget: i32) -> Result<(), Error> {
         if self.treasury < 0 {
             return Err(Error::TreasuryExceeded);
         }
@@ -659,8 +708,10 @@ impl Team {
                 Version::V1 | Version::V2 | Version::V3 | Version::V4 => {
                     Err(Error::UnsupportedVersion)?
                 }
-                Version::V5 => Ok(v5::expected_remaining_treasury_at_creation(&self)?)?,
-                Version::V5S3 => Ok(v5s3::expected_remaining_treasury_at_creation(&self)?)?,
+                Version::V5 => Ok(v5::expected_remaining_treasury_at_creation(&self, budget)?)?,
+                Version::V5S3 => Ok(v5s3::expected_remaining_treasury_at_creation(
+                    &self, budget,
+                )?)?,
             };
 
             if expected_remaining_treasury != self.treasury {
@@ -709,6 +760,7 @@ mod tests {
                 id: None,
                 name: "Moi".to_string(),
                 elo: None,
+                ratings: HashMap::new(),
             },
             treasury: 30000,
             external_logo_url: None,
@@ -783,6 +835,75 @@ mod tests {
         assert_eq!(team_a.players[5].0, 50);
     }
 
+    #[test]
+    fn create_new_with_budget() {
+        let create = |treasury: i32, budget: i32| {
+            Team::create_new_with_budget(
+                Coach::from_name("Moi"),
+                Version::V5S3,
+                Roster::Human,
+                treasury,
+                HashMap::from([(Staff::ReRoll, 3), (Staff::Apothecary, 1)]),
+                HashMap::from([
+                    (Position::HumanLineman, 7),
+                    (Position::Thrower, 1),
+                    (Position::Catcher, 2),
+                    (Position::Blitzer, 2),
+                    (Position::Ogre, 1),
+                ]),
+                1,
+                Some(Position::Blitzer),
+                budget,
+            )
+        };
+
+        let team = create(65_000, 1_150_000).unwrap();
+        assert_eq!(team.value().unwrap(), 1_085_000);
+        assert!(team.under_creation);
+
+        assert!(matches!(
+            create(75_000, 1_150_000),
+            Err(Error::IncorrectTreasury)
+        ));
+        assert!(matches!(
+            create(65_000, Team::initial_treasury(&Version::V5S3)),
+            Err(Error::IncorrectTreasury)
+        ));
+        assert!(matches!(
+            Team::create_new(
+                Coach::from_name("Moi"),
+                Version::V5S3,
+                Roster::Human,
+                65_000,
+                HashMap::from([(Staff::ReRoll, 3), (Staff::Apothecary, 1)]),
+                HashMap::from([
+                    (Position::HumanLineman, 7),
+                    (Position::Thrower, 1),
+                    (Position::Catcher, 2),
+                    (Position::Blitzer, 2),
+                    (Position::Ogre, 1),
+                ]),
+                1,
+                Some(Position::Blitzer),
+            ),
+            Err(Error::IncorrectTreasury)
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "i18n")]
+    fn formatted_values() {
+        let team = Team {
+            treasury: 30_000,
+            ..crate::test_utils::team(1, "Reikland Reavers", Version::V5, 11)
+        };
+
+        assert_eq!(team.formatted_value("en").unwrap(), "550k");
+        assert_eq!(team.formatted_value("fr").unwrap(), "550 k po");
+        assert_eq!(team.formatted_treasury("de"), "30k GM");
+        assert_eq!(team.formatted_players_count("en"), "11 players");
+    }
+
     #[test]
     fn can_buyout_player() {
         let team_a = Team {
@@ -794,6 +915,7 @@ mod tests {
                 id: None,
                 name: "Moi".to_string(),
                 elo: None,
+                ratings: HashMap::new(),
             },
             treasury: 30000,
             external_logo_url: None,
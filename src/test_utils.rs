@@ -0,0 +1,43 @@
+use crate::coaches::Coach;
+use crate::players::Player;
+use crate::positions::Position;
+use crate::rosters::Roster;
+use crate::teams::Team;
+use crate::versions::Version;
+use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::HashMap;
+
+/// Human team of linemen numbered from 1, each player id being `id * 100 + number`.
+pub(crate) fn team(id: i32, name: &str, version: Version, players_number: i32) -> Team {
+    Team {
+        id,
+        version,
+        roster: Roster::Human,
+        name: name.to_string(),
+        coach: Coach::from_name(name),
+        treasury: 0,
+        external_logo_url: None,
+        staff: HashMap::new(),
+        players: (1..=players_number)
+            .map(|number| {
+                (
+                    number,
+                    Player {
+                        id: id * 100 + number,
+                        ..Player::new(version, Position::HumanLineman, Roster::Human)
+                    },
+                )
+            })
+            .collect(),
+        dedicated_fans: 1,
+        under_creation: false,
+        in_offseason: false,
+    }
+}
+
+/// Date of the test games, 2025-06-01 at 10:00.
+pub(crate) fn date() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2025, 6, 1)
+        .and_then(|date| date.and_hms_opt(10, 0, 0))
+        .unwrap()
+}
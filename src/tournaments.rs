@@ -0,0 +1,471 @@
+use crate::advancements::Advancement;
+use crate::coaches::Coach;
+use crate::errors::Error;
+use crate::players::{Player, PlayerType};
+use crate::positions::Position;
+use crate::rosters::Roster;
+use crate::skills::Skill;
+use crate::staffs::Staff;
+use crate::teams::Team;
+use crate::versions::Version;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TournamentTier {
+    pub tier: u8,
+    pub budget: u32,
+    pub primary_skills: u8,
+    pub secondary_skills: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TournamentRuleset {
+    pub version: Version,
+    pub tiers: Vec<TournamentTier>,
+    pub maximum_same_skill: Option<u8>,
+    pub maximum_skills_per_player: Option<u8>,
+    pub elite_skills_allowed: bool,
+    pub forbidden_skills: Vec<Skill>,
+    pub resurrection: bool,
+}
+
+impl TournamentRuleset {
+    pub fn new(version: Version) -> Self {
+        Self {
+            version,
+            tiers: vec![
+                TournamentTier {
+                    tier: 1,
+                    budget: 1150000,
+                    primary_skills: 6,
+                    secondary_skills: 0,
+                },
+                TournamentTier {
+                    tier: 2,
+                    budget: 1150000,
+                    primary_skills: 6,
+                    secondary_skills: 1,
+                },
+                TournamentTier {
+                    tier: 3,
+                    budget: 1200000,
+                    primary_skills: 7,
+                    secondary_skills: 1,
+                },
+                TournamentTier {
+                    tier: 4,
+                    budget: 1250000,
+                    primary_skills: 8,
+                    secondary_skills: 2,
+                },
+            ],
+            maximum_same_skill: Some(2),
+            maximum_skills_per_player: Some(1),
+            elite_skills_allowed: false,
+            forbidden_skills: Vec::new(),
+            resurrection: true,
+        }
+    }
+
+    pub fn tier_for_roster(&self, roster: &Roster) -> Result<TournamentTier, Error> {
+        let roster_definition = roster
+            .definition(self.version)
+            .ok_or(Error::RosterNotExist)?;
+
+        self.tiers
+            .iter()
+            .find(|tier| tier.tier.eq(&roster_definition.tier))
+            .copied()
+            .ok_or(Error::TierNotAllowedInTournament)
+    }
+
+    pub fn creation_cost(
+        &self,
+        roster: &Roster,
+        staff_quantities: &HashMap<Staff, u8>,
+        team_positions: &HashMap<Position, u8>,
+        dedicated_fans: u8,
+    ) -> Result<u32, Error> {
+        let roster_definition = roster
+            .definition(self.version)
+            .ok_or(Error::RosterNotExist)?;
+
+        let mut cost: u32 = 0;
+
+        for (position, quantity) in team_positions.iter() {
+            let position_definition = position
+                .definition(self.version, *roster)
+                .ok_or(Error::PositionNotDefined)?;
+
+            cost += position_definition.cost * *quantity as u32;
+        }
+
+        for (staff, quantity) in staff_quantities.iter() {
+            let staff_information = roster_definition
+                .get_staff_information(staff)
+                .ok_or(Error::StaffNotInRoster)?;
+
+            cost += staff_information.price * *quantity as u32;
+        }
+
+        cost += dedicated_fans.saturating_sub(1) as u32
+            * roster_definition.dedicated_fans_information.price;
+
+        Ok(cost)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_team(
+        &self,
+        coach: Coach,
+        roster: Roster,
+        staff_quantities: HashMap<Staff, u8>,
+        team_positions: HashMap<Position, u8>,
+        dedicated_fans: u8,
+        captain_position: Option<Position>,
+        skills: Vec<(i32, Skill)>,
+    ) -> Result<Team, Error> {
+        let tier = self.tier_for_roster(&roster)?;
+        let cost =
+            self.creation_cost(&roster, &staff_quantities, &team_positions, dedicated_fans)?;
+
+        if cost > tier.budget {
+            return Err(Error::TournamentBudgetExceeded);
+        }
+
+        let mut team = Team::create_new_with_budget(
+            coach,
+            self.version,
+            roster,
+            tier.budget as i32 - cost as i32,
+            staff_quantities,
+            team_positions,
+            dedicated_fans,
+            captain_position,
+            tier.budget as i32,
+        )?;
+
+        for (number, skill) in skills {
+            self.grant_skill(&mut team, number, skill)?;
+        }
+
+        team.under_creation = false;
+
+        self.check_if_team_compliant(&team)?;
+
+        Ok(team)
+    }
+
+    pub fn grant_skill(&self, team: &mut Team, number: i32, skill: Skill) -> Result<(), Error> {
+        let (_, player) = team
+            .players
+            .iter_mut()
+            .find(|(player_number, _)| player_number.eq(&number))
+            .ok_or(Error::PlayerNotInTeam)?;
+
+        if !skill.is_primary_for_player(player) && !skill.is_secondary_for_player(player) {
+            return Err(Error::SkillNotAvailableForPlayer);
+        }
+
+        if !skill.could_be_added_for_player_regarding_other_skills(player) {
+            return Err(Error::SkillNotAvailableForPlayer);
+        }
+
+        player.advancements.push(Advancement::ChosenSkill(skill));
+
+        Ok(())
+    }
+
+    pub fn granted_skills(team: &Team) -> Vec<(i32, Skill)> {
+        let mut skills: Vec<(i32, Skill)> = Vec::new();
+
+        for (number, player) in team.players.iter() {
+            for advancement in player.advancements.iter() {
+                if let Advancement::ChosenSkill(skill) = advancement {
+                    skills.push((*number, *skill));
+                }
+            }
+        }
+
+        skills
+    }
+
+    pub fn check_if_team_compliant(&self, team: &Team) -> Result<(), Error> {
+        if team.version.ne(&self.version) {
+            return Err(Error::PlayersMustMatchTeamVersion);
+        }
+
+        team.check_if_rules_compliant()?;
+
+        let tier = self.tier_for_roster(&team.roster)?;
+        let mut primary_skills: u8 = 0;
+        let mut secondary_skills: u8 = 0;
+        let mut skills_count: Vec<(Skill, u8)> = Vec::new();
+
+        for (_, player) in team.players.iter() {
+            let player_skills = Self::player_granted_skills(player);
+
+            if let Some(maximum_skills_per_player) = self.maximum_skills_per_player
+                && player_skills.len() > maximum_skills_per_player as usize
+            {
+                return Err(Error::TournamentSkillsExceeded);
+            }
+
+            for skill in player_skills {
+                if self.forbidden_skills.contains(&skill) {
+                    return Err(Error::TournamentSkillNotAllowed);
+                }
+
+                if !self.elite_skills_allowed && skill.is_elite(&self.version) {
+                    return Err(Error::TournamentEliteSkillNotAllowed);
+                }
+
+                if skill.is_primary_for_player(player) {
+                    primary_skills += 1;
+                } else if skill.is_secondary_for_player(player) {
+                    secondary_skills += 1;
+                } else {
+                    return Err(Error::SkillNotAvailableForPlayer);
+                }
+
+                match skills_count
+                    .iter_mut()
+                    .find(|(counted, _)| counted.eq(&skill))
+                {
+                    Some((_, count)) => *count += 1,
+                    None => skills_count.push((skill, 1)),
+                }
+            }
+        }
+
+        if primary_skills > tier.primary_skills || secondary_skills > tier.secondary_skills {
+            return Err(Error::TournamentSkillsExceeded);
+        }
+
+        if let Some(maximum_same_skill) = self.maximum_same_skill
+            && skills_count
+                .iter()
+                .any(|(_, count)| *count > maximum_same_skill)
+        {
+            return Err(Error::TournamentSameSkillMaximumExceeded);
+        }
+
+        let spent =
+            team.players_value()? - Self::granted_skills_value(team)? + team.staff_value()?;
+        let roster_definition = team.roster_definition().ok_or(Error::RosterNotExist)?;
+        let fans_cost = team.dedicated_fans.saturating_sub(1) as u32
+            * roster_definition.dedicated_fans_information.price;
+
+        if spent + fans_cost > tier.budget {
+            return Err(Error::TournamentBudgetExceeded);
+        }
+
+        Ok(())
+    }
+
+    pub fn resurrect(&self, team: &Team) -> Team {
+        let mut resurrected_team = team.clone();
+
+        if !self.resurrection {
+            return resurrected_team;
+        }
+
+        resurrected_team
+            .players
+            .retain(|(_, player)| matches!(player.player_type, PlayerType::FromRoster));
+
+        for (_, player) in resurrected_team.players.iter_mut() {
+            player.star_player_points = 0;
+            player.miss_next_game = false;
+            player.injuries.clear();
+            player.hatred.clear();
+            player
+                .advancements
+                .retain(|advancement| matches!(advancement, Advancement::ChosenSkill(_)));
+        }
+
+        resurrected_team
+    }
+
+    fn player_granted_skills(player: &Player) -> Vec<Skill> {
+        player
+            .advancements
+            .iter()
+            .filter_map(|advancement| match advancement {
+                Advancement::ChosenSkill(skill) => Some(*skill),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn granted_skills_value(team: &Team) -> Result<u32, Error> {
+        let mut value = 0;
+
+        for (_, player) in team.players.iter() {
+            for advancement in player.advancements.iter() {
+                if matches!(advancement, Advancement::ChosenSkill(_)) {
+                    value += advancement.added_value_for_player(player)?;
+                }
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coach() -> Coach {
+        Coach::from_name("Tournament coach")
+    }
+
+    fn human_positions() -> HashMap<Position, u8> {
+        HashMap::from([
+            (Position::HumanLineman, 7),
+            (Position::Thrower, 1),
+            (Position::Catcher, 2),
+            (Position::Blitzer, 2),
+        ])
+    }
+
+    #[test]
+    fn create_resurrection_team() {
+        let ruleset = TournamentRuleset::new(Version::V5S3);
+
+        let team = ruleset
+            .create_team(
+                coach(),
+                Roster::Human,
+                HashMap::from([(Staff::ReRoll, 3), (Staff::Apothecary, 1)]),
+                human_positions(),
+                1,
+                Some(Position::Blitzer),
+                vec![],
+            )
+            .unwrap();
+
+        assert!(!team.under_creation);
+        assert_eq!(team.number_of_players(), 12);
+
+        let tier = ruleset.tier_for_roster(&Roster::Human).unwrap();
+        assert_eq!(team.treasury as u32, tier.budget - team.value().unwrap());
+    }
+
+    #[test]
+    fn skill_packs() {
+        let ruleset = TournamentRuleset::new(Version::V5S3);
+        let mut team = ruleset
+            .create_team(
+                coach(),
+                Roster::Human,
+                HashMap::from([(Staff::ReRoll, 3)]),
+                human_positions(),
+                1,
+                Some(Position::Blitzer),
+                vec![],
+            )
+            .unwrap();
+
+        let numbers: Vec<(i32, Position)> = team
+            .players
+            .iter()
+            .map(|(number, player)| (*number, player.position))
+            .collect();
+        let linemen: Vec<i32> = numbers
+            .iter()
+            .filter(|(_, position)| position.eq(&Position::HumanLineman))
+            .map(|(number, _)| *number)
+            .collect();
+
+        ruleset
+            .grant_skill(&mut team, linemen[0], Skill::Wrestle)
+            .unwrap();
+        ruleset
+            .grant_skill(&mut team, linemen[1], Skill::Wrestle)
+            .unwrap();
+        ruleset.check_if_team_compliant(&team).unwrap();
+        assert_eq!(TournamentRuleset::granted_skills(&team).len(), 2);
+
+        let mut too_much_wrestle = team.clone();
+        ruleset
+            .grant_skill(&mut too_much_wrestle, linemen[2], Skill::Wrestle)
+            .unwrap();
+        assert!(matches!(
+            ruleset.check_if_team_compliant(&too_much_wrestle),
+            Err(Error::TournamentSameSkillMaximumExceeded)
+        ));
+
+        let mut elite = team.clone();
+        ruleset
+            .grant_skill(&mut elite, linemen[2], Skill::Block)
+            .unwrap();
+        assert!(matches!(
+            ruleset.check_if_team_compliant(&elite),
+            Err(Error::TournamentEliteSkillNotAllowed)
+        ));
+
+        let mut two_skills = team.clone();
+        ruleset
+            .grant_skill(&mut two_skills, linemen[0], Skill::Fend)
+            .unwrap();
+        assert!(matches!(
+            ruleset.check_if_team_compliant(&two_skills),
+            Err(Error::TournamentSkillsExceeded)
+        ));
+
+        assert!(matches!(
+            ruleset.grant_skill(&mut team, linemen[3], Skill::Claws),
+            Err(Error::SkillNotAvailableForPlayer)
+        ));
+    }
+
+    #[test]
+    fn budget_exceeded() {
+        let ruleset = TournamentRuleset::new(Version::V5S3);
+
+        let result = ruleset.create_team(
+            coach(),
+            Roster::Human,
+            HashMap::from([(Staff::ReRoll, 8), (Staff::Apothecary, 1)]),
+            human_positions(),
+            1,
+            Some(Position::Blitzer),
+            vec![],
+        );
+
+        assert!(matches!(result, Err(Error::TournamentBudgetExceeded)));
+    }
+
+    #[test]
+    fn resurrect() {
+        let ruleset = TournamentRuleset::new(Version::V5S3);
+        let mut team = ruleset
+            .create_team(
+                coach(),
+                Roster::Human,
+                HashMap::from([(Staff::ReRoll, 3)]),
+                human_positions(),
+                1,
+                Some(Position::Blitzer),
+                vec![],
+            )
+            .unwrap();
+
+        team.players[0].1.star_player_points = 6;
+        team.players[0]
+            .1
+            .receive_injury(crate::injuries::Injury::SeriousInjury);
+        team.add_journeyman_with_number(0);
+
+        let resurrected_team = ruleset.resurrect(&team);
+        assert_eq!(resurrected_team.number_of_players(), 12);
+        assert_eq!(resurrected_team.players[0].1.star_player_points, 0);
+        assert!(resurrected_team.players[0].1.injuries.is_empty());
+    }
+}
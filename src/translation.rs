@@ -1,25 +1,308 @@
+use crate::errors::Error;
 use convert_case::{Case, Casing};
-use fluent_templates::{LanguageIdentifier, Loader, langid};
+#[cfg(feature = "i18n")]
+use fluent_templates::fluent_bundle::{FluentArgs, FluentResource, FluentValue};
+#[cfg(feature = "i18n")]
+use fluent_templates::{FluentBundle, LanguageIdentifier, Loader, langid};
+#[cfg(feature = "i18n")]
+use std::borrow::Cow;
+#[cfg(feature = "i18n")]
+use std::collections::HashMap;
 use std::fmt::Debug;
+use std::path::Path;
+#[cfg(feature = "i18n")]
+use std::sync::{LazyLock, RwLock};
 
+#[cfg(feature = "i18n")]
 fluent_templates::static_loader! {
     pub(crate) static LOCALES = {
         locales: "./locales",
         fallback_language: "en",
+        customise: |bundle| bundle.set_use_isolating(false),
     };
 }
 
+#[cfg(feature = "i18n")]
+static RUNTIME_LOCALES: LazyLock<
+    RwLock<HashMap<LanguageIdentifier, FluentBundle<FluentResource>>>,
+> = LazyLock::new(|| RwLock::new(HashMap::new()));
+
+#[cfg(feature = "i18n")]
 pub(crate) const ENGLISH: LanguageIdentifier = langid!("en");
+#[cfg(feature = "i18n")]
 pub(crate) const FRENCH: LanguageIdentifier = langid!("fr");
+#[cfg(feature = "i18n")]
+pub(crate) const GERMAN: LanguageIdentifier = langid!("de");
+#[cfg(feature = "i18n")]
+pub(crate) const SPANISH: LanguageIdentifier = langid!("es");
+#[cfg(feature = "i18n")]
+pub(crate) const ITALIAN: LanguageIdentifier = langid!("it");
+
+#[cfg(feature = "i18n")]
+const SUPPORTED_LANGUAGES: [LanguageIdentifier; 5] = [ENGLISH, FRENCH, GERMAN, SPANISH, ITALIAN];
+
+#[cfg(feature = "i18n")]
+pub fn supported_languages() -> Vec<String> {
+    SUPPORTED_LANGUAGES
+        .iter()
+        .map(|language| language.to_string())
+        .collect()
+}
 
+#[cfg(not(feature = "i18n"))]
+pub fn supported_languages() -> Vec<String> {
+    vec!["en".to_string()]
+}
+
+#[cfg(feature = "i18n")]
 pub(crate) fn language_from(lang_id: &str) -> LanguageIdentifier {
-    match lang_id {
-        "en" => ENGLISH,
-        "fr" => FRENCH,
-        _ => ENGLISH,
+    let Ok(requested) = lang_id.parse::<LanguageIdentifier>() else {
+        return ENGLISH;
+    };
+
+    SUPPORTED_LANGUAGES
+        .into_iter()
+        .find(|language| language.language == requested.language)
+        .unwrap_or(ENGLISH)
+}
+
+#[cfg(feature = "i18n")]
+pub(crate) fn supported_language(lang_id: &str) -> Result<LanguageIdentifier, Error> {
+    let requested = lang_id
+        .parse::<LanguageIdentifier>()
+        .map_err(|_| Error::UnsupportedLanguage)?;
+
+    SUPPORTED_LANGUAGES
+        .into_iter()
+        .find(|language| language.language == requested.language)
+        .ok_or(Error::UnsupportedLanguage)
+}
+
+#[cfg(feature = "i18n")]
+pub fn load_translations(lang_id: &str, source: &str) -> Result<(), Error> {
+    let language = supported_language(lang_id)?;
+    let resource =
+        FluentResource::try_new(source.to_string()).map_err(|_| Error::InvalidTranslations)?;
+
+    let mut runtime_locales = RUNTIME_LOCALES
+        .write()
+        .map_err(|_| Error::InvalidTranslations)?;
+    runtime_locales
+        .entry(language.clone())
+        .or_insert_with(|| {
+            let mut bundle = FluentBundle::new_concurrent(vec![language]);
+            bundle.set_use_isolating(false);
+            bundle
+        })
+        .add_resource_overriding(resource);
+
+    Ok(())
+}
+
+#[cfg(not(feature = "i18n"))]
+pub fn load_translations(_lang_id: &str, _source: &str) -> Result<(), Error> {
+    Err(Error::TranslationsNotEnabled)
+}
+
+pub fn load_translations_file(lang_id: &str, path: impl AsRef<Path>) -> Result<(), Error> {
+    let source = std::fs::read_to_string(path).map_err(|_| Error::TranslationsFileNotReadable)?;
+
+    load_translations(lang_id, &source)
+}
+
+pub(crate) enum TranslationArgument {
+    Text(String),
+    Number(i64),
+}
+
+impl From<String> for TranslationArgument {
+    fn from(value: String) -> Self {
+        TranslationArgument::Text(value)
+    }
+}
+
+impl From<u8> for TranslationArgument {
+    fn from(value: u8) -> Self {
+        TranslationArgument::Number(i64::from(value))
+    }
+}
+
+impl From<i64> for TranslationArgument {
+    fn from(value: i64) -> Self {
+        TranslationArgument::Number(value)
+    }
+}
+
+#[cfg(feature = "i18n")]
+fn runtime_lookup(
+    language: &LanguageIdentifier,
+    key: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let runtime_locales = RUNTIME_LOCALES.read().ok()?;
+    let bundle = runtime_locales.get(language)?;
+    let pattern = bundle.get_message(key)?.value()?;
+
+    let mut errors = Vec::new();
+    let value = bundle.format_pattern(pattern, args, &mut errors);
+
+    if errors.is_empty() {
+        Some(value.into_owned())
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "i18n")]
+fn lookup_complete(
+    lang_id: &str,
+    key: &str,
+    args: Option<&HashMap<Cow<'static, str>, FluentValue>>,
+) -> String {
+    let fluent_args: Option<FluentArgs> = args.map(|args| {
+        args.iter()
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect()
+    });
+
+    let language = language_from(lang_id);
+    for language in [language.clone(), ENGLISH] {
+        if let Some(value) = runtime_lookup(&language, key, fluent_args.as_ref()) {
+            return value;
+        }
+        if let Ok(value) = LOCALES.lookup_single_language(&language, key, args) {
+            return value;
+        }
+    }
+
+    LOCALES.lookup_complete(&language, key, args)
+}
+
+#[cfg(feature = "i18n")]
+pub(crate) fn lookup(lang_id: &str, key: &str) -> String {
+    lookup_complete(lang_id, key, None)
+}
+
+#[cfg(not(feature = "i18n"))]
+pub(crate) fn lookup(_lang_id: &str, key: &str) -> String {
+    key.to_case(Case::Title)
+}
+
+#[cfg(feature = "i18n")]
+pub(crate) fn lookup_with_args(
+    lang_id: &str,
+    key: &str,
+    args: Vec<(&'static str, TranslationArgument)>,
+) -> String {
+    let args: HashMap<Cow<'static, str>, FluentValue> = args
+        .into_iter()
+        .map(|(name, value)| {
+            let value = match value {
+                TranslationArgument::Text(text) => FluentValue::from(text),
+                TranslationArgument::Number(number) => FluentValue::from(number),
+            };
+            (Cow::from(name), value)
+        })
+        .collect();
+
+    lookup_complete(lang_id, key, Some(&args))
+}
+
+#[cfg(not(feature = "i18n"))]
+pub(crate) fn lookup_with_args(
+    _lang_id: &str,
+    key: &str,
+    args: Vec<(&'static str, TranslationArgument)>,
+) -> String {
+    let args: Vec<String> = args
+        .into_iter()
+        .map(|(_, value)| match value {
+            TranslationArgument::Text(text) => text,
+            TranslationArgument::Number(number) => number.to_string(),
+        })
+        .collect();
+
+    format!("{} ({})", key.to_case(Case::Title), args.join(", "))
+}
+
+#[cfg(feature = "i18n")]
+fn digit_group_separator(lang_id: &str) -> &'static str {
+    match language_from(lang_id).language.as_str() {
+        "fr" => "\u{202f}",
+        "de" | "es" | "it" => ".",
+        _ => ",",
     }
 }
 
+#[cfg(not(feature = "i18n"))]
+fn digit_group_separator(_lang_id: &str) -> &'static str {
+    ","
+}
+
+#[cfg(feature = "i18n")]
+fn decimal_separator(lang_id: &str) -> &'static str {
+    match language_from(lang_id).language.as_str() {
+        "fr" | "de" | "es" | "it" => ",",
+        _ => ".",
+    }
+}
+
+#[cfg(not(feature = "i18n"))]
+fn decimal_separator(_lang_id: &str) -> &'static str {
+    "."
+}
+
+pub fn format_number(lang_id: &str, value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let groups: Vec<&str> = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|group| std::str::from_utf8(group).unwrap())
+        .collect();
+    let sign = if value < 0 { "-" } else { "" };
+
+    format!("{}{}", sign, groups.join(digit_group_separator(lang_id)))
+}
+
+/// Thousands of gold pieces, keeping any remainder as decimals (1035500 is 1,035.5).
+fn format_thousands(lang_id: &str, value: i64) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let thousands = format_number(lang_id, (value / 1000).abs());
+    let remainder = format!("{:03}", (value % 1000).unsigned_abs());
+    let decimals = remainder.trim_end_matches('0');
+
+    if decimals.is_empty() {
+        format!("{}{}", sign, thousands)
+    } else {
+        format!(
+            "{}{}{}{}",
+            sign,
+            thousands,
+            decimal_separator(lang_id),
+            decimals
+        )
+    }
+}
+
+#[cfg(feature = "i18n")]
+pub fn format_gold_pieces(lang_id: &str, value: i64) -> String {
+    lookup_with_args(
+        lang_id,
+        "GoldPieces",
+        vec![("value", format_thousands(lang_id, value).into())],
+    )
+}
+
+#[cfg(not(feature = "i18n"))]
+pub fn format_gold_pieces(lang_id: &str, value: i64) -> String {
+    format!("{}k", format_thousands(lang_id, value))
+}
+
+pub(crate) fn lookup_count(lang_id: &str, key: &str, count: i64) -> String {
+    lookup_with_args(lang_id, key, vec![("count", count.into())])
+}
+
 pub trait TypeName: Debug {
     fn snake_case_type(&self) -> String {
         self.type_name().to_case(Case::Snake)
@@ -28,6 +311,14 @@ pub trait TypeName: Debug {
     fn type_name(&self) -> String {
         format!("{:?}", self)
     }
+
+    fn variant_name(&self) -> String {
+        let type_name = self.type_name();
+        match type_name.split_once('(') {
+            Some((variant_name, _)) => variant_name.to_string(),
+            None => type_name,
+        }
+    }
 }
 
 pub trait TranslatedName: TypeName {
@@ -36,14 +327,17 @@ pub trait TranslatedName: TypeName {
     }
 
     fn name(&self, lang_id: &str) -> String {
-        LOCALES.lookup(&language_from(lang_id), &*self.type_name())
+        lookup(lang_id, &self.type_name())
     }
 
+    #[cfg(feature = "i18n")]
     fn description(&self, lang_id: &str) -> String {
-        LOCALES.lookup(
-            &language_from(lang_id),
-            &format!("{}_description", &*self.type_name()),
-        )
+        lookup(lang_id, &format!("{}_description", self.variant_name()))
+    }
+
+    #[cfg(not(feature = "i18n"))]
+    fn description(&self, _lang_id: &str) -> String {
+        String::new()
     }
 
     fn first_letter(&self, lang_id: &str) -> String {
@@ -57,3 +351,170 @@ pub trait TranslatedName: TypeName {
         result
     }
 }
+
+#[cfg(all(test, feature = "i18n"))]
+mod tests {
+    use super::*;
+    use crate::characteristics::Characteristic;
+    use crate::inducements::Inducement;
+    use crate::positions::Keyword;
+    use crate::prayers::PrayerToNuffle;
+    use crate::rosters::SpecialRule;
+    use crate::skills::Skill;
+    use crate::staffs::StaffInformation;
+    use crate::versions::Version;
+    use crate::weather::Weather;
+
+    fn keys(file: &str) -> Vec<&str> {
+        file.lines()
+            .filter_map(|line| line.split_once(" = "))
+            .map(|(key, _)| key)
+            .collect()
+    }
+
+    fn missing_keys<T: TranslatedName>(items: Vec<T>, keys: &[&str]) -> Vec<String> {
+        let mut missing = Vec::new();
+        for item in items {
+            let name_key = item.variant_name();
+            let description_key = format!("{}_description", name_key);
+            for key in [name_key, description_key] {
+                if !keys.contains(&key.as_str()) && !missing.contains(&key) {
+                    missing.push(key);
+                }
+            }
+        }
+        missing
+    }
+
+    #[test]
+    fn names_and_descriptions_are_translated() {
+        for (lang_id, file) in [
+            ("en", include_str!("../locales/en/main.ftl")),
+            ("fr", include_str!("../locales/fr/main.ftl")),
+            ("de", include_str!("../locales/de/main.ftl")),
+            ("es", include_str!("../locales/es/main.ftl")),
+            ("it", include_str!("../locales/it/main.ftl")),
+        ] {
+            let keys = keys(file);
+            for version in [Version::V5, Version::V5S3] {
+                let missing = [
+                    missing_keys(Skill::list(&version), &keys),
+                    missing_keys(SpecialRule::list(&version), &keys),
+                    missing_keys(PrayerToNuffle::list(&version), &keys),
+                    missing_keys(Inducement::list(&version), &keys),
+                    missing_keys(Keyword::list(&version), &keys),
+                    missing_keys(Weather::options_list(), &keys),
+                ]
+                .concat();
+
+                assert!(
+                    missing.is_empty(),
+                    "{} {:?} is missing {:?}",
+                    lang_id,
+                    version,
+                    missing
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn descriptions() {
+        assert_eq!(
+            Skill::Catch.description("en"),
+            "This player may re-roll a failed Agility test when attempting to catch the ball."
+        );
+        assert_eq!(
+            Skill::Loner(4).description("fr"),
+            lookup("fr", "Loner_description")
+        );
+        assert_eq!(
+            Weather::Blizzard.description("en"),
+            lookup("en", "Blizzard_description")
+        );
+    }
+
+    #[test]
+    fn language_negotiation() {
+        assert_eq!(supported_languages(), vec!["en", "fr", "de", "es", "it"]);
+        assert_eq!(language_from("fr"), FRENCH);
+        assert_eq!(language_from("fr-CA"), FRENCH);
+        assert_eq!(language_from("de-AT"), GERMAN);
+        assert_eq!(language_from("es"), SPANISH);
+        assert_eq!(language_from("it-IT"), ITALIAN);
+        assert_eq!(language_from("pt-BR"), ENGLISH);
+        assert_eq!(language_from(""), ENGLISH);
+        assert_eq!(Skill::Catch.name("fr-CA"), "Réception");
+    }
+
+    #[derive(Debug)]
+    enum HomebrewRoster {
+        DwarfGiants,
+    }
+
+    impl TypeName for HomebrewRoster {}
+    impl TranslatedName for HomebrewRoster {}
+
+    #[test]
+    fn runtime_translations() {
+        load_translations("en", "DwarfGiants = Dwarf Giants").unwrap();
+        load_translations("fr-CA", "DwarfGiants = Géants Nains").unwrap();
+        assert_eq!(HomebrewRoster::DwarfGiants.name("en"), "Dwarf Giants");
+        assert_eq!(HomebrewRoster::DwarfGiants.name("fr"), "Géants Nains");
+        assert_eq!(HomebrewRoster::DwarfGiants.name("it"), "Dwarf Giants");
+
+        assert!(matches!(
+            load_translations("pt", "DwarfGiants = Gigantes Anões"),
+            Err(Error::UnsupportedLanguage)
+        ));
+        assert!(matches!(
+            load_translations("en", "DwarfGiants"),
+            Err(Error::InvalidTranslations)
+        ));
+        assert!(matches!(
+            load_translations_file("en", "locales/en/missing.ftl"),
+            Err(Error::TranslationsFileNotReadable)
+        ));
+    }
+
+    #[test]
+    fn formatting() {
+        assert_eq!(format_number("en", 1030000), "1,030,000");
+        assert_eq!(format_number("de", -1030), "-1.030");
+        assert_eq!(format_number("it-CH", 999), "999");
+        assert_eq!(format_gold_pieces("en", 1030000), "1,030k");
+        assert_eq!(format_gold_pieces("es", 1030000), "1.030k mo");
+        assert_eq!(format_gold_pieces("en", 1035500), "1,035.5k");
+        assert_eq!(format_gold_pieces("fr", 1035500), "1\u{202f}035,5 k po");
+        assert_eq!(format_gold_pieces("en", 500), "0.5k");
+        assert_eq!(format_gold_pieces("de", -1250), "-1,25k GM");
+        assert_eq!(StaffInformation::REROLL_70.formatted_price("fr"), "70 k po");
+
+        assert_eq!(lookup_count("en", "PlayersCount", 1), "1 player");
+        assert_eq!(lookup_count("en", "PlayersCount", 0), "0 players");
+        assert_eq!(lookup_count("fr", "PlayersCount", 0), "0 joueur");
+        assert_eq!(
+            lookup_count("it", "StarPlayerPointsCount", 6),
+            "6 punti Star Player"
+        );
+        assert_eq!(Skill::Loner(4).name("en"), "Loner (4+)");
+
+        assert_eq!(Characteristic::Agility.formatted_value(Some(3)), "3+");
+        assert_eq!(Characteristic::PassingAbility.formatted_value(None), "-");
+        assert_eq!(Characteristic::ArmourValue.formatted_value(Some(9)), "9+");
+        assert_eq!(Characteristic::Strength.formatted_value(Some(4)), "4");
+    }
+}
+
+#[cfg(all(test, not(feature = "i18n")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_translations_need_i18n() {
+        assert!(matches!(
+            load_translations("en", "DwarfGiants = Dwarf Giants"),
+            Err(Error::TranslationsNotEnabled)
+        ));
+    }
+}
@@ -1,8 +1,12 @@
 use crate::dices::Dice;
 use crate::translation::{TranslatedName, TypeName};
+#[cfg(feature = "serde")]
+use schemars::JsonSchema;
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize, JsonSchema))]
 pub enum Weather {
     SwelteringHeat,
     VerySunny,
@@ -16,7 +20,11 @@ impl TranslatedName for Weather {}
 
 impl Weather {
     pub fn roll() -> Self {
-        match Dice::D6x2.roll() {
+        Self::from_roll(Dice::D6x2.roll() as u8)
+    }
+
+    pub fn from_roll(roll: u8) -> Self {
+        match roll {
             2 => Self::SwelteringHeat,
             3 => Self::VerySunny,
             11 => Self::PouringRain,